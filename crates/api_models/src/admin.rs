@@ -276,6 +276,10 @@ pub struct MerchantAccountResponse {
     /// Used to indicate the status of the recon module for a merchant account
     #[schema(value_type = ReconStatus, example = "not_requested")]
     pub recon_status: enums::ReconStatus,
+
+    /// The KYC status of the merchant
+    #[schema(value_type = Option<KycStatus>, example = "verified")]
+    pub kyc_status: Option<enums::KycStatus>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -477,6 +481,10 @@ pub struct MerchantConnectorCreate {
 
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// A boolean value to indicate if the credentials of a payout connector are to be verified with the connector before the connector account is saved. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub verify_credentials: Option<bool>,
 }
 
 // Different patterns of authentication.
@@ -708,6 +716,10 @@ pub struct MerchantConnectorUpdate {
 
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// A boolean value to indicate if the credentials of a payout connector are to be verified with the connector before the connector account is saved. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub verify_credentials: Option<bool>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -825,6 +837,16 @@ pub struct ToggleKVRequest {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKycStatusUpdate {
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+    /// The KYC status of the merchant, which is required to be verified for card payouts when
+    /// the merchant is configured so
+    #[schema(value_type = KycStatus, example = "verified")]
+    pub kyc_status: enums::KycStatus,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -912,6 +934,25 @@ pub struct BusinessProfileCreate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Whether the available balance of the connector account should be checked before fulfilling a payout
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub check_balance_before_fulfill: Option<bool>,
+
+    /// Countries to which payouts are blocked for compliance reasons. Overrides the platform wide list of blocked countries when passed
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["KP", "IR"]))]
+    pub payout_blocked_countries: Option<Vec<api_enums::CountryAlpha2>>,
+
+    /// Whether payouts to customers whose KYC is not verified should be held instead of being fulfilled
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub require_recipient_kyc: Option<bool>,
+
+    /// Payout method used when a payout is created without a payout_type
+    #[cfg(feature = "payouts")]
+    pub default_payout_method: Option<DefaultPayoutMethod>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -981,6 +1022,25 @@ pub struct BusinessProfileResponse {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Whether the available balance of the connector account should be checked before fulfilling a payout
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub check_balance_before_fulfill: Option<bool>,
+
+    /// Countries to which payouts are blocked for compliance reasons. Overrides the platform wide list of blocked countries when passed
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["KP", "IR"]))]
+    pub payout_blocked_countries: Option<Vec<api_enums::CountryAlpha2>>,
+
+    /// Whether payouts to customers whose KYC is not verified should be held instead of being fulfilled
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub require_recipient_kyc: Option<bool>,
+
+    /// Payout method used when a payout is created without a payout_type
+    #[cfg(feature = "payouts")]
+    pub default_payout_method: Option<DefaultPayoutMethod>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1042,6 +1102,38 @@ pub struct BusinessProfileUpdate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Whether the available balance of the connector account should be checked before fulfilling a payout
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub check_balance_before_fulfill: Option<bool>,
+
+    /// Countries to which payouts are blocked for compliance reasons. Overrides the platform wide list of blocked countries when passed
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["KP", "IR"]))]
+    pub payout_blocked_countries: Option<Vec<api_enums::CountryAlpha2>>,
+
+    /// Whether payouts to customers whose KYC is not verified should be held instead of being fulfilled
+    #[cfg(feature = "payouts")]
+    #[schema(default = false, example = true)]
+    pub require_recipient_kyc: Option<bool>,
+
+    /// Payout method used when a payout is created without a payout_type
+    #[cfg(feature = "payouts")]
+    pub default_payout_method: Option<DefaultPayoutMethod>,
+}
+
+/// Default payout method of a business profile, applied to payouts created without a payout_type
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
+pub struct DefaultPayoutMethod {
+    /// The payout_type applied to payouts created without one
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The payout method which the payout method data of these payouts is required to be of, e.g. sepa for bank payouts
+    #[schema(value_type = Option<PaymentMethodType>, example = "sepa")]
+    pub payout_method_type: Option<api_enums::PaymentMethodType>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
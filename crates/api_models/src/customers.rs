@@ -3,7 +3,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::payments;
+use crate::{enums as api_enums, payments};
 
 /// The customer details
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
@@ -39,6 +39,9 @@ pub struct CustomerRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The KYC status of the customer. Payouts held for the customer are released once it is verified
+    #[schema(value_type = Option<KycStatus>, example = "verified")]
+    pub kyc_status: Option<api_enums::KycStatus>,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -76,6 +79,9 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The KYC status of the customer
+    #[schema(value_type = Option<KycStatus>, example = "verified")]
+    pub kyc_status: Option<api_enums::KycStatus>,
 }
 
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
@@ -154,6 +154,24 @@ impl Connector {
     pub fn supports_access_token_for_payout(&self, payout_method: PayoutType) -> bool {
         matches!((self, payout_method), (Self::Paypal, _))
     }
+    #[cfg(feature = "payouts")]
+    pub fn supports_partial_payout_fulfillment(&self, payout_method: PayoutType) -> bool {
+        match (self, payout_method) {
+            (Self::Adyen, PayoutType::Card) | (Self::Paypal, PayoutType::Wallet) => true,
+            #[cfg(feature = "dummy_connector")]
+            (
+                Self::DummyConnector1
+                | Self::DummyConnector2
+                | Self::DummyConnector3
+                | Self::DummyConnector4
+                | Self::DummyConnector5
+                | Self::DummyConnector6
+                | Self::DummyConnector7,
+                _,
+            ) => true,
+            _ => false,
+        }
+    }
     pub fn supports_access_token(&self, payment_method: PaymentMethod) -> bool {
         matches!(
             (self, payment_method),
@@ -337,6 +355,34 @@ pub enum AuthenticationConnectors {
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum PayoutConnectors {
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "phonypay")]
+    #[strum(serialize = "phonypay")]
+    DummyConnector1,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "fauxpay")]
+    #[strum(serialize = "fauxpay")]
+    DummyConnector2,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "pretendpay")]
+    #[strum(serialize = "pretendpay")]
+    DummyConnector3,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "stripe_test")]
+    #[strum(serialize = "stripe_test")]
+    DummyConnector4,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "adyen_test")]
+    #[strum(serialize = "adyen_test")]
+    DummyConnector5,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "checkout_test")]
+    #[strum(serialize = "checkout_test")]
+    DummyConnector6,
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "paypal_test")]
+    #[strum(serialize = "paypal_test")]
+    DummyConnector7,
     Adyen,
     Wise,
     Paypal,
@@ -346,6 +392,20 @@ pub enum PayoutConnectors {
 impl From<PayoutConnectors> for RoutableConnectors {
     fn from(value: PayoutConnectors) -> Self {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Self::DummyConnector1,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector2 => Self::DummyConnector2,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector3 => Self::DummyConnector3,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector4 => Self::DummyConnector4,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector5 => Self::DummyConnector5,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector6 => Self::DummyConnector6,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector7 => Self::DummyConnector7,
             PayoutConnectors::Adyen => Self::Adyen,
             PayoutConnectors::Wise => Self::Wise,
             PayoutConnectors::Paypal => Self::Paypal,
@@ -357,6 +417,20 @@ impl From<PayoutConnectors> for RoutableConnectors {
 impl From<PayoutConnectors> for Connector {
     fn from(value: PayoutConnectors) -> Self {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Self::DummyConnector1,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector2 => Self::DummyConnector2,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector3 => Self::DummyConnector3,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector4 => Self::DummyConnector4,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector5 => Self::DummyConnector5,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector6 => Self::DummyConnector6,
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector7 => Self::DummyConnector7,
             PayoutConnectors::Adyen => Self::Adyen,
             PayoutConnectors::Wise => Self::Wise,
             PayoutConnectors::Paypal => Self::Paypal,
@@ -369,6 +443,20 @@ impl TryFrom<Connector> for PayoutConnectors {
     type Error = String;
     fn try_from(value: Connector) -> Result<Self, Self::Error> {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector1 => Ok(Self::DummyConnector1),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector2 => Ok(Self::DummyConnector2),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector3 => Ok(Self::DummyConnector3),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector4 => Ok(Self::DummyConnector4),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector5 => Ok(Self::DummyConnector5),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector6 => Ok(Self::DummyConnector6),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector7 => Ok(Self::DummyConnector7),
             Connector::Adyen => Ok(Self::Adyen),
             Connector::Wise => Ok(Self::Wise),
             Connector::Paypal => Ok(Self::Paypal),
@@ -60,6 +60,7 @@ impl_misc_api_event_type!(
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
+    MerchantKycStatusUpdate,
     MerchantAccountDeleteResponse,
     MerchantAccountUpdate,
     CardInfoResponse,
@@ -549,6 +549,22 @@ impl HeaderPayload {
     }
 }
 
+#[derive(Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema)]
+pub struct PaymentLinkedPayoutResponse {
+    /// Unique identifier for the payout
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+    /// The payout amount, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus, example = "success")]
+    pub status: api_enums::PayoutStatus,
+}
+
 #[derive(
     Default, Debug, serde::Serialize, Clone, PartialEq, ToSchema, router_derive::PolymorphicSchema,
 )]
@@ -994,6 +1010,10 @@ pub struct CardToken {
     /// The CVC number for the card
     #[schema(value_type = Option<String>)]
     pub card_cvc: Option<Secret<String>>,
+
+    /// The token issued by the connector for the card, when the card was tokenized on the client using the connector's hosted fields (e.g. HelcimPay.js)
+    #[schema(value_type = Option<String>)]
+    pub connector_token: Option<Secret<String>>,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -3053,6 +3073,11 @@ pub struct PaymentsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub captures: Option<Vec<CaptureResponse>>,
 
+    /// List of payouts funded by this payment
+    #[schema(value_type = Option<Vec<PaymentLinkedPayoutResponse>>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_payouts: Option<Vec<PaymentLinkedPayoutResponse>>,
+
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -3464,6 +3489,28 @@ pub struct TimeRange {
     pub end_time: Option<PrimitiveDateTime>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsReconcileRequest {
+    /// The identifier of the merchant connector account whose transactions are reconciled
+    pub merchant_connector_id: String,
+    /// The start time of the transactions to reconcile
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// The end time of the transactions to reconcile
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsReconcileResponse {
+    /// The connector the transactions were processed by
+    pub connector: String,
+    /// The status of each transaction processed by the connector in the time range, by the
+    /// connector transaction id
+    pub transactions: HashMap<String, api_enums::AttemptStatus>,
+}
+
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize)]
 pub struct VerifyResponse {
     pub verify_id: Option<String>,
@@ -3643,6 +3690,8 @@ pub struct PaymentsRetrieveRequest {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// If enabled provides list of payouts funded by the payment
+    pub expand_payouts: Option<bool>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -4118,6 +4167,8 @@ pub struct PaymentRetrieveBody {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// If enabled provides list of payouts funded by the payment
+    pub expand_payouts: Option<bool>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -1,10 +1,12 @@
+use std::collections::HashMap;
+
 use cards::CardNumber;
 use common_utils::{
     consts::default_payouts_list_limit,
     crypto,
     pii::{self, Email},
 };
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
@@ -45,6 +47,10 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Currency, example = "USD")]
     pub currency: Option<api_enums::Currency>,
 
+    /// The currency in which the payout is funded. If it differs from `currency`, an FX quote is fetched and stored on the payout. Defaults to `currency`
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub source_currency: Option<api_enums::Currency>,
+
     /// Specifies routing algorithm for selecting a connector
     #[schema(value_type = Option<RoutingAlgorithm>, example = json!({
         "type": "single",
@@ -64,6 +70,10 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = PayoutType, example = "card")]
     pub payout_type: Option<api_enums::PayoutType>,
 
+    /// The speed at which the payout is to reach the recipient. Defaults to `standard`, `instant` payouts are rejected if the connector does not support them for the payout_type
+    #[schema(value_type = Option<PayoutPriority>, example = "instant")]
+    pub priority: Option<api_enums::PayoutPriority>,
+
     /// The payout method information required for carrying out a payout
     #[schema(value_type = Option<PayoutMethodData>)]
     pub payout_method_data: Option<PayoutMethodData>,
@@ -137,7 +147,7 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Option<bool>, default = false)]
     pub recurring: Option<bool>,
 
-    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    /// A flat map of string keys and values, with up to 20 keys and up to 1KB in size. Metadata is useful for storing additional information on a payout, and payouts can be filtered by its keys.
     #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
 
@@ -145,9 +155,67 @@ pub struct PayoutCreateRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payout_token: Option<String>,
 
+    /// Provide the identifier of a payout method saved for the customer, as returned by the customer payout methods list
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: Option<String>,
+
     /// The business profile to use for this payment, if not passed the default business profile
     /// associated with the merchant account will be used.
     pub profile_id: Option<String>,
+
+    /// The time at which the payout is to be processed. If not passed, the payout is processed immediately.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// The recurring payout schedule which created the payout, set internally on every run of the schedule
+    #[serde(skip)]
+    pub payout_schedule_id: Option<String>,
+
+    /// The payment funding this payout, for splitting the settlement of a marketplace payment. The payment needs to be captured, and the payouts linked to it can not exceed its captured amount less its refunds
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub source_payment_id: Option<String>,
+
+    /// The percentage of the captured amount of the source payment to be paid out, between 1 and 100. The amount of the payout is computed from it, and can not be passed along with it
+    #[schema(value_type = Option<u8>, minimum = 1, maximum = 100, example = 20)]
+    pub source_payment_split_percentage: Option<u8>,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// Unique identifier for the payout, taken from the request path
+    #[serde(skip)]
+    pub payout_id: String,
+
+    /// A description of the payout
+    #[schema(example = "It's my first payout request", value_type = Option<String>)]
+    pub description: Option<String>,
+
+    /// A flat map of string keys and values, with up to 20 keys and up to 1KB in size. Metadata is useful for storing additional information on a payout, and payouts can be filtered by its keys.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// description: The recipient's email address
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Email>,
+
+    /// The recipient's phone number
+    #[schema(value_type = Option<String>, max_length = 255, example = "3141592653")]
+    pub phone: Option<Secret<String>>,
+
+    /// The country code for the recipient's phone number
+    #[schema(max_length = 255, example = "+1")]
+    pub phone_country_code: Option<String>,
+
+    /// The payout amount cannot be updated, passing this field results in an error
+    #[schema(value_type = Option<i64>, example = 1000)]
+    #[serde(default, deserialize_with = "payments::amount::deserialize_option")]
+    pub amount: Option<payments::Amount>,
+
+    /// The payout currency cannot be updated, passing this field results in an error
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
@@ -164,6 +232,68 @@ impl Default for PayoutMethodData {
     }
 }
 
+impl PayoutMethodData {
+    /// Returns a representation of the payout method data that is safe to log
+    pub fn redacted(&self) -> RedactedPayoutMethodData<'_> {
+        RedactedPayoutMethodData(self)
+    }
+}
+
+/// Payout method data with card numbers, account numbers and IBANs masked down to their last four
+/// characters, and other personal details left out. Both `Display` and `Debug` print the redacted
+/// form, so it can be used with either `%` or `?` in log fields.
+pub struct RedactedPayoutMethodData<'a>(&'a PayoutMethodData);
+
+impl RedactedPayoutMethodData<'_> {
+    fn mask(value: &str) -> String {
+        let chars = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<Vec<_>>();
+        match chars.len().checked_sub(4) {
+            Some(masked_length) if masked_length > 0 => format!(
+                "****{}",
+                chars.iter().skip(masked_length).collect::<String>()
+            ),
+            _ => "****".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for RedactedPayoutMethodData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            PayoutMethodData::Card(card) => write!(
+                f,
+                "card {{ card_number: {} }}",
+                Self::mask(card.card_number.peek())
+            ),
+            PayoutMethodData::Bank(Bank::Ach(ach)) => write!(
+                f,
+                "bank(ach) {{ bank_account_number: {}, bank_routing_number: {} }}",
+                Self::mask(ach.bank_account_number.peek()),
+                Self::mask(ach.bank_routing_number.peek())
+            ),
+            PayoutMethodData::Bank(Bank::Bacs(bacs)) => write!(
+                f,
+                "bank(bacs) {{ bank_account_number: {}, bank_sort_code: {} }}",
+                Self::mask(bacs.bank_account_number.peek()),
+                Self::mask(bacs.bank_sort_code.peek())
+            ),
+            PayoutMethodData::Bank(Bank::Sepa(sepa)) => {
+                write!(f, "bank(sepa) {{ iban: {} }}", Self::mask(sepa.iban.peek()))
+            }
+            PayoutMethodData::Wallet(Wallet::Paypal(_)) => write!(f, "wallet(paypal)"),
+        }
+    }
+}
+
+impl std::fmt::Debug for RedactedPayoutMethodData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
 #[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct Card {
     /// The card number
@@ -401,10 +531,58 @@ pub struct PayoutCreateResponse {
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<PrimitiveDateTime>,
 
+    /// Time at which the payout is scheduled to be processed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// The currency in which the payout is funded
+    #[schema(value_type = Currency, example = "USD")]
+    pub source_currency: api_enums::Currency,
+
+    /// The quoted exchange rate from `source_currency` to `currency`, present only for cross currency payouts
+    #[schema(value_type = Option<String>, example = "0.9215")]
+    pub fx_rate: Option<String>,
+
+    /// The payout amount converted to `source_currency` at the quoted exchange rate, in the lowest denomination of `source_currency`
+    #[schema(value_type = Option<i64>, example = 1085)]
+    pub converted_amount: Option<i64>,
+
+    /// Time at which the quoted exchange rate expires. The payout needs to be re-quoted to be confirmed after this time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub fx_quote_expires_at: Option<PrimitiveDateTime>,
+
+    /// The speed at which the payout is to reach the recipient
+    #[schema(value_type = PayoutPriority, example = "standard")]
+    pub priority: api_enums::PayoutPriority,
+
+    /// The amount of the payout fulfilled so far, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub fulfilled_amount: Option<i64>,
+
+    /// The recurring payout schedule which created the payout
+    #[schema(example = "ps_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_schedule_id: Option<String>,
+
+    /// The payment funding this payout
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub source_payment_id: Option<String>,
+
     /// List of attempts
     #[schema(value_type = Option<Vec<PayoutAttemptResponse>>)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attempts: Option<Vec<PayoutAttemptResponse>>,
+
+    /// Whether the connector supports syncing payouts, present only when the payout was synced using `force_sync`. The stored details of the payout are returned when this is `false`
+    #[schema(value_type = Option<bool>, example = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_sync_supported: Option<bool>,
+
+    /// The routing decision trail of the active attempt, present only when `routing` is expanded
+    #[schema(value_type = Option<PayoutRoutingTrail>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing: Option<PayoutRoutingTrail>,
 }
 
 #[derive(
@@ -441,11 +619,85 @@ pub struct PayoutAttemptResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
+    /// Time at which the attempt was created
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    /// Time at which the attempt was last modified
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified_at: Option<PrimitiveDateTime>,
+    /// Whether the connector of the attempt was forced in the fulfill request instead of being routed
+    #[schema(example = false)]
+    pub manual_routing_override: bool,
+}
+
+/// The routing evaluation which decided the connector of a payout attempt
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PayoutRoutingTrail {
+    /// The connectors evaluated for the payout, in the order of preference
+    #[schema(example = json!(["wise", "adyen"]))]
+    pub candidates: Vec<String>,
+    /// The connector chosen for the attempt, absent if every candidate was skipped
+    #[schema(example = "adyen")]
+    pub chosen_connector: Option<String>,
+    /// The candidates which were skipped, along with the reason for skipping them
+    pub skipped: Vec<PayoutRoutingSkippedCandidate>,
+    /// The routing algorithm which ordered the candidates, absent if they were ordered by the
+    /// routing algorithm in the request or by the fallback configuration
+    pub routing_algorithm: Option<PayoutRoutingAlgorithmVersion>,
+}
+
+/// The version of the routing algorithm active for the business profile when a payout was routed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PayoutRoutingAlgorithmVersion {
+    /// The routing algorithm which ordered the candidates
+    #[schema(example = "routing_RsEMQxxTdDhGvXsKj0v4")]
+    pub algorithm_id: String,
+    /// Unix timestamp of when the routing algorithm was activated
+    #[schema(example = 1715769600)]
+    pub activated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PayoutRoutingSkippedCandidate {
+    /// The connector which was skipped
+    #[schema(example = "wise")]
+    pub connector: String,
+    /// The reason the connector was skipped
+    #[schema(example = "unsupported_currency")]
+    pub reason: PayoutRoutingSkipReason,
+}
+
+/// The reason a connector was skipped while routing a payout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutRoutingSkipReason {
+    /// The payout currency is not accepted by the connector
+    UnsupportedCurrency,
+    /// The connector is not enabled for payouts under the business profile
+    Disabled,
+    /// The payout amount is over the maximum amount configured for the connector
+    OverLimit,
+    /// The connector does not support the priority of the payout
+    UnsupportedPriority,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, ToSchema)]
 pub struct PayoutRetrieveBody {
     pub force_sync: Option<bool>,
+    pub expand: Option<PayoutExpandableField>,
+}
+
+/// Related resources which can be expanded in the payout retrieve response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutExpandableField {
+    /// List all the attempts made for the payout, ordered by their creation time
+    Attempts,
+    /// Include the routing decision trail of the active attempt
+    Routing,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -464,6 +716,11 @@ pub struct PayoutRetrieveRequest {
     /// (defaults to false)
     #[schema(value_type = Option<bool>, default = false, example = true)]
     pub force_sync: Option<bool>,
+
+    /// Pass `attempts` to list all the attempts made for the payout, or `routing` to include the
+    /// routing decision trail of the active attempt
+    #[schema(value_type = Option<PayoutExpandableField>, example = "attempts")]
+    pub expand: Option<PayoutExpandableField>,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -477,10 +734,21 @@ pub struct PayoutActionRequest {
         example = "payout_mbabizu24mvu3mela5njyhpit4"
     )]
     pub payout_id: String,
+
+    /// The connector to force the payout through on fulfillment, bypassing routing. A new attempt
+    /// is created for the connector, which must be configured for payouts under the business profile
+    #[schema(value_type = Option<PayoutConnectors>, example = "wise")]
+    pub connector: Option<api_enums::PayoutConnectors>,
+
+    /// The amount to be fulfilled, in the lowest denomination of the currency. It cannot exceed the
+    /// amount of the payout yet to be fulfilled, which is fulfilled as a whole when not passed.
+    /// A part of the amount can be fulfilled only through connectors supporting it, and the payout
+    /// is `partially_fulfilled` until its whole amount is fulfilled
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub amount: Option<i64>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
-#[serde(deny_unknown_fields)]
 pub struct PayoutListConstraints {
     /// The identifier for customer
     #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
@@ -508,6 +776,58 @@ pub struct PayoutListConstraints {
     #[serde(flatten)]
     #[schema(value_type = Option<TimeRange>)]
     pub time_range: Option<payments::TimeRange>,
+
+    /// Metadata of the payouts to filter by, passed as `metadata[key]=value`. Payouts having every
+    /// key of the filter with the given value are returned. Parameters other than the ones above
+    /// are rejected
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_metadata_filter",
+        serialize_with = "serialize_metadata_filter"
+    )]
+    #[schema(value_type = Option<Object>, example = json!({"metadata[batch_ref]": "batch_2024_05"}))]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Deserializes the `metadata[key]=value` query parameters of the payouts list, which are the
+/// parameters left over from the other constraints
+fn deserialize_metadata_filter<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let metadata = HashMap::<String, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(param, value)| {
+            param
+                .strip_prefix("metadata[")
+                .and_then(|key| key.strip_suffix(']'))
+                .filter(|key| !key.is_empty())
+                .map(|key| (key.to_string(), value))
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "unknown field `{param}`, metadata filters are expected as `metadata[key]`"
+                    ))
+                })
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    Ok((!metadata.is_empty()).then_some(metadata))
+}
+
+fn serialize_metadata_filter<S>(
+    metadata: &Option<HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(
+        metadata
+            .iter()
+            .flatten()
+            .map(|(key, value)| (format!("metadata[{key}]"), value)),
+    )
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -550,6 +870,10 @@ pub struct PayoutListFilterConstraints {
     /// Type of recipient
     #[schema(value_type = PayoutEntityType, example = "Individual")]
     pub entity_type: Option<common_enums::PayoutEntityType>,
+    /// Metadata of the payouts to filter by. Payouts having every key of the filter with the
+    /// given value are returned
+    #[schema(value_type = Option<Object>, example = json!({"batch_ref": "batch_2024_05"}))]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -571,3 +895,456 @@ pub struct PayoutListFilters {
     /// The list of available payment method filters
     pub payout_method: Vec<common_enums::PayoutType>,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct CustomerPayoutMethodId {
+    /// The identifier for the customer
+    pub customer_id: String,
+    /// The identifier for the saved payout method
+    pub payout_method_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CustomerPayoutMethodsListResponse {
+    /// List of payout methods saved for the customer
+    pub customer_payout_methods: Vec<CustomerPayoutMethod>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CustomerPayoutMethod {
+    /// The identifier of the saved payout method, which can be passed as `payout_method_id` while creating a payout
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: String,
+
+    /// The identifier for the customer
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The type of payout method
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The sub-type of payout method
+    #[schema(value_type = Option<PaymentMethodType>, example = "sepa")]
+    pub payout_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Last four digits of the card, for card payout methods
+    #[schema(example = "4242")]
+    pub card_last4: Option<String>,
+
+    /// Masked account number or IBAN, for bank payout methods
+    #[schema(example = "******************3000")]
+    pub masked_account_number: Option<String>,
+
+    /// Time at which the payout method was saved
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutMethodDeleteResponse {
+    /// The identifier of the deleted payout method
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: String,
+
+    /// Whether the payout method was deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutValidateResponse {
+    /// Whether the payout create request passed validation
+    #[schema(example = true)]
+    pub valid: bool,
+
+    /// The connector the payout would be routed to, if the request is valid and a payout
+    /// connector is configured for the business profile
+    #[schema(value_type = Option<PayoutConnectors>, example = "wise")]
+    pub connector: Option<api_enums::PayoutConnectors>,
+
+    /// The validation errors for the request, if it is invalid
+    pub errors: Vec<PayoutValidationError>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PayoutValidationError {
+    /// The error code, same as the one returned by the create payout API
+    #[schema(example = "IR_04")]
+    pub code: String,
+
+    /// The error message
+    #[schema(example = "Missing required param: payout_method_data")]
+    pub message: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutRequiredFieldsRequest {
+    /// The payout connector the payout would be made through
+    pub connector: api_enums::PayoutConnectors,
+
+    /// The type of the payout method
+    pub payout_type: api_enums::PayoutType,
+
+    /// The country of the payout method, payout methods of any country are returned when not set
+    pub country: Option<api_enums::CountryAlpha2>,
+
+    /// The currency of the payout, payout methods of any currency are returned when not set
+    pub currency: Option<api_enums::Currency>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutRequiredFieldsResponse {
+    /// The payout connector the fields are required by
+    #[schema(value_type = PayoutConnectors, example = "wise")]
+    pub connector: api_enums::PayoutConnectors,
+
+    /// The type of the payout method
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The payout methods of the payout type supported by the connector, with the fields each
+    /// of them requires
+    pub payout_methods: Vec<PayoutMethodRequiredFields>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PayoutMethodRequiredFields {
+    /// The payout method
+    #[schema(value_type = PaymentMethodType, example = "sepa")]
+    pub payout_method_type: api_enums::PaymentMethodType,
+
+    /// The fields required for the payout method, ordered by the path of the field
+    pub required_fields: Vec<PayoutRequiredFieldInfo>,
+}
+
+/// A field required by a payout connector for a payout method
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+pub struct PayoutRequiredFieldInfo {
+    /// Path of the field in the payout create request
+    #[schema(example = "payout_method_data.bank.iban")]
+    pub required_field: String,
+
+    /// Display name of the field in the front-end
+    #[schema(example = "iban")]
+    pub display_name: String,
+
+    /// Type of the field
+    #[schema(value_type = FieldType)]
+    pub field_type: api_enums::FieldType,
+
+    /// Regular expression the value of the field has to match, if any
+    #[schema(example = "^[A-Z]{2}[0-9]{2}( ?[A-Z0-9]){11,30}$")]
+    pub validation_regex: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutEventsRequest {
+    /// The identifier for the payout
+    pub payout_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutEventsResponse {
+    /// The identifier for the payout
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The status transitions of the payout, ordered by the time they occurred
+    pub events: Vec<PayoutEvent>,
+}
+
+/// A status transition of a payout, recorded for auditing
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PayoutEvent {
+    /// The identifier for the event
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The type of the status transition
+    #[schema(value_type = PayoutAuditEventType, example = "created")]
+    pub event_type: api_enums::PayoutAuditEventType,
+
+    /// Who or what moved the payout to the new status
+    pub actor: PayoutEventActor,
+
+    /// The status of the payout before the transition, absent for the creation of the payout
+    #[schema(value_type = Option<PayoutStatus>, example = "requires_fulfillment")]
+    pub previous_status: Option<api_enums::PayoutStatus>,
+
+    /// The status of the payout after the transition
+    #[schema(value_type = PayoutStatus, example = "success")]
+    pub new_status: api_enums::PayoutStatus,
+
+    /// The reason for the transition, such as the error returned by the connector
+    #[schema(example = "Insufficient balance in the connector account")]
+    pub reason: Option<String>,
+
+    /// Time at which the transition occurred
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Who or what moved a payout between statuses
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PayoutEventActor {
+    /// A request authenticated with an API key of the merchant
+    ApiKey { key_id: String },
+    /// A request authenticated with the admin API key
+    AdminApiKey,
+    /// A user of the dashboard
+    User { user_id: Option<String> },
+    /// A request authenticated with the merchant id, such as an incoming webhook
+    Merchant { merchant_id: String },
+    /// A job run internally by the application
+    InternalJob { job_name: String },
+    /// A request which was not authenticated
+    Unauthenticated,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutScheduleCreateRequest {
+    /// The identifier for the customer receiving the payouts
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier of the payout method saved for the customer, to which the payouts are made
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: String,
+
+    /// The currency of the payouts
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The payout_type of the payouts. Defaults to the default payout method of the business profile
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// How often the payouts are made
+    pub cadence: PayoutScheduleCadence,
+
+    /// How the amount of every payout is determined
+    pub amount_source: PayoutScheduleAmountSource,
+
+    /// The time from which the payouts are made, at the same time of the day. Defaults to the time of creation
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub starts_at: Option<PrimitiveDateTime>,
+
+    /// A description of the payouts
+    #[schema(example = "Weekly seller settlement")]
+    pub description: Option<String>,
+
+    /// The business profile to use for the payouts, if not passed the default business profile
+    /// associated with the merchant account will be used.
+    pub profile_id: Option<String>,
+}
+
+/// How often the payouts of a recurring payout schedule are made
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PayoutScheduleCadence {
+    /// Every week on the given day, from 1 for Monday to 7 for Sunday
+    Weekly { day_of_week: u8 },
+    /// Every month on the given day, or on the last day of the months which are shorter
+    Monthly { day_of_month: u8 },
+}
+
+/// How the amount of every payout of a recurring payout schedule is determined
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PayoutScheduleAmountSource {
+    /// The same amount for every payout, in the lowest denomination of the currency
+    Fixed { amount: i64 },
+    /// The amount returned by the merchant's endpoint, which is called before every payout with a
+    /// `PayoutScheduleAmountCallbackRequest`
+    Callback { url: String },
+}
+
+/// The request sent to the callback endpoint of a recurring payout schedule, before every payout
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutScheduleAmountCallbackRequest {
+    /// The identifier for the payout schedule
+    #[schema(example = "ps_mbabizu24mvu3mela5njyhpit4")]
+    pub schedule_id: String,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The time of the run of the schedule
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub run_at: PrimitiveDateTime,
+}
+
+/// The response expected from the callback endpoint of a recurring payout schedule
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+pub struct PayoutScheduleAmountCallbackResponse {
+    /// The amount of the payout, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutScheduleRetrieveRequest {
+    /// The identifier for the payout schedule
+    pub schedule_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutScheduleResponse {
+    /// The identifier for the payout schedule
+    #[schema(example = "ps_mbabizu24mvu3mela5njyhpit4")]
+    pub schedule_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The identifier for the customer receiving the payouts
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier of the payout method saved for the customer, to which the payouts are made
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payout_method_id: String,
+
+    /// The business profile used for the payouts
+    pub profile_id: Option<String>,
+
+    /// The currency of the payouts
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The payout_type of the payouts
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// How often the payouts are made
+    pub cadence: PayoutScheduleCadence,
+
+    /// How the amount of every payout is determined
+    pub amount_source: PayoutScheduleAmountSource,
+
+    /// A description of the payouts
+    #[schema(example = "Weekly seller settlement")]
+    pub description: Option<String>,
+
+    /// The status of the payout schedule
+    #[schema(value_type = PayoutScheduleStatus, example = "active")]
+    pub status: api_enums::PayoutScheduleStatus,
+
+    /// Time of the next run of the schedule, absent once the schedule is cancelled
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<PrimitiveDateTime>,
+
+    /// Time of the last run of the schedule
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+
+    /// The identifier for the payout created by the last successful run of the schedule
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub last_payout_id: Option<String>,
+
+    /// The reason the last run of the schedule failed to create a payout, absent if it succeeded
+    #[schema(example = "Missing required param: payout_method_data")]
+    pub last_failure_reason: Option<String>,
+
+    /// Time at which the payout schedule was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_redacted_payout_method_data_masks_numbers() {
+        let card = PayoutMethodData::Card(Card {
+            card_number: CardNumber::from_str("4111111111111111").unwrap(),
+            expiry_month: Secret::new("12".to_string()),
+            expiry_year: Secret::new("2030".to_string()),
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+        });
+        let ach = PayoutMethodData::Bank(Bank::Ach(AchBankTransfer {
+            bank_account_number: Secret::new("000123456789".to_string()),
+            bank_routing_number: Secret::new("110000000".to_string()),
+            ..Default::default()
+        }));
+        let bacs = PayoutMethodData::Bank(Bank::Bacs(BacsBankTransfer {
+            bank_account_number: Secret::new("55779911".to_string()),
+            bank_sort_code: Secret::new("200000".to_string()),
+            ..Default::default()
+        }));
+        let sepa = PayoutMethodData::Bank(Bank::Sepa(SepaBankTransfer {
+            iban: Secret::new("NL46TEST0136169112".to_string()),
+            bic: Some(Secret::new("ABNANL2A".to_string())),
+            ..Default::default()
+        }));
+        let paypal = PayoutMethodData::Wallet(Wallet::Paypal(Paypal {
+            email: Some(Email::from_str("john.doe@example.com").unwrap()),
+        }));
+
+        for (payout_method_data, expected, sensitive_values) in [
+            (
+                card,
+                "card { card_number: ****1111 }",
+                vec!["4111111111111111", "John Doe", "2030"],
+            ),
+            (
+                ach,
+                "bank(ach) { bank_account_number: ****6789, bank_routing_number: ****0000 }",
+                vec!["000123456789", "110000000"],
+            ),
+            (
+                bacs,
+                "bank(bacs) { bank_account_number: ****9911, bank_sort_code: ****0000 }",
+                vec!["55779911", "200000"],
+            ),
+            (
+                sepa,
+                "bank(sepa) { iban: ****9112 }",
+                vec!["NL46TEST0136169112", "ABNANL2A"],
+            ),
+            (paypal, "wallet(paypal)", vec!["john.doe@example.com"]),
+        ] {
+            let redacted = payout_method_data.redacted();
+            for output in [redacted.to_string(), format!("{redacted:?}")] {
+                assert_eq!(output, expected);
+                for sensitive_value in &sensitive_values {
+                    assert!(!output.contains(sensitive_value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_redacted_payout_method_data_masks_short_values_entirely() {
+        let ach = PayoutMethodData::Bank(Bank::Ach(AchBankTransfer {
+            bank_account_number: Secret::new("1234".to_string()),
+            bank_routing_number: Secret::new("".to_string()),
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            ach.redacted().to_string(),
+            "bank(ach) { bank_account_number: ****, bank_routing_number: **** }"
+        );
+    }
+}
@@ -39,6 +39,14 @@ pub enum IncomingWebhookEvent {
     MandateRevoked,
     EndpointVerification,
     ExternalAuthenticationARes,
+    #[cfg(feature = "payouts")]
+    PayoutSuccess,
+    #[cfg(feature = "payouts")]
+    PayoutFailure,
+    #[cfg(feature = "payouts")]
+    PayoutProcessing,
+    #[cfg(feature = "payouts")]
+    PayoutCancelled,
 }
 
 pub enum WebhookFlow {
@@ -50,6 +58,8 @@ pub enum WebhookFlow {
     BankTransfer,
     Mandate,
     ExternalAuthentication,
+    #[cfg(feature = "payouts")]
+    Payout,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -73,6 +83,11 @@ pub enum WebhookResponseTracker {
         mandate_id: String,
         status: common_enums::MandateStatus,
     },
+    #[cfg(feature = "payouts")]
+    Payout {
+        payout_id: String,
+        status: common_enums::PayoutStatus,
+    },
     NoEffect,
 }
 
@@ -83,6 +98,8 @@ impl WebhookResponseTracker {
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_string()),
             Self::NoEffect | Self::Mandate { .. } => None,
+            #[cfg(feature = "payouts")]
+            Self::Payout { .. } => None,
         }
     }
 }
@@ -119,6 +136,11 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::SourceChargeable
             | IncomingWebhookEvent::SourceTransactionCreated => Self::BankTransfer,
             IncomingWebhookEvent::ExternalAuthenticationARes => Self::ExternalAuthentication,
+            #[cfg(feature = "payouts")]
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutProcessing
+            | IncomingWebhookEvent::PayoutCancelled => Self::Payout,
         }
     }
 }
@@ -143,12 +165,20 @@ pub enum AuthenticationIdType {
     ConnectorAuthenticationId(String),
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Clone)]
+pub enum PayoutIdType {
+    PayoutId(String),
+}
+
 #[derive(Clone)]
 pub enum ObjectReferenceId {
     PaymentId(payments::PaymentIdType),
     RefundId(RefundIdType),
     MandateId(MandateIdType),
     ExternalAuthenticationID(AuthenticationIdType),
+    #[cfg(feature = "payouts")]
+    PayoutId(PayoutIdType),
 }
 
 pub struct IncomingWebhookDetails {
@@ -2113,6 +2113,9 @@ pub enum PayoutStatus {
     RequiresCreation,
     RequiresPayoutMethodData,
     RequiresFulfillment,
+    Scheduled,
+    RequiresVendorAccountCreation,
+    PartiallyFulfilled,
 }
 
 #[derive(
@@ -2174,6 +2177,116 @@ pub enum PayoutEntityType {
     Personal,
 }
 
+/// The speed at which the funds of a payout reach the recipient
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutPriority {
+    /// Funds are settled in the connector's regular payout cycle
+    #[default]
+    Standard,
+    /// Funds are made available to the recipient within minutes
+    Instant,
+}
+
+/// The type of a status transition of a payout, recorded in its audit trail
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutAuditEventType {
+    /// The payout was created
+    Created,
+    /// The payout was confirmed and is being processed
+    Confirmed,
+    /// The payout was fulfilled, entirely or in part
+    Fulfilled,
+    /// The payout failed or was found ineligible
+    Failed,
+    /// The payout was cancelled
+    Cancelled,
+    /// The payout was retried with a new attempt
+    Retried,
+}
+
+/// The status of a recurring payout schedule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutScheduleStatus {
+    /// Payouts are made on every run of the schedule
+    #[default]
+    Active,
+    /// The schedule was cancelled and no further payouts are made
+    Cancelled,
+}
+
+/// The status of the KYC verification of a customer receiving payouts, or of a merchant making them
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KycStatus {
+    /// The KYC documents of the customer are yet to be verified
+    Pending,
+    /// The customer has been verified and can receive payouts
+    Verified,
+    /// The KYC documents of the customer were rejected
+    Rejected,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -39,6 +39,10 @@ pub const PAYOUTS_LIST_MAX_LIMIT_POST: u32 = 20;
 pub fn default_payouts_list_limit() -> u32 {
     10
 }
+/// Maximum number of keys in the metadata of a payout
+pub const PAYOUT_METADATA_MAX_KEYS: usize = 20;
+/// Maximum size of the metadata of a payout, in bytes of its JSON encoding
+pub const PAYOUT_METADATA_MAX_SIZE_IN_BYTES: usize = 1024;
 
 /// surcharge percentage maximum precision length
 pub const SURCHARGE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
@@ -38,6 +38,29 @@ pub struct Request {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub body: Option<RequestContent>,
+    pub timeout_secs: Option<u64>,
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+/// Retries of a request on transient failures of the server, only honoured for `GET` requests
+/// as retrying a request with side effects could, for instance, charge a customer twice
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts made in total, including the first one
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Delay before the given retry (starting at 1), doubling from the base delay on every retry
+    /// and capped at the max delay
+    pub fn get_backoff_delay_ms(&self, retry: u32) -> u64 {
+        let multiplier = 2u64.saturating_pow(retry.saturating_sub(1));
+        self.base_delay_ms
+            .saturating_mul(multiplier)
+            .min(self.max_delay_ms)
+    }
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -81,6 +104,23 @@ impl Request {
             certificate: None,
             certificate_key: None,
             body: None,
+            timeout_secs: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Copies a request without a body, requests with a body can not be copied as multipart forms
+    /// are not clonable
+    pub fn clone_without_body(&self) -> Self {
+        Self {
+            url: self.url.clone(),
+            headers: self.headers.clone(),
+            method: self.method,
+            certificate: self.certificate.clone(),
+            certificate_key: self.certificate_key.clone(),
+            body: None,
+            timeout_secs: self.timeout_secs,
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -113,6 +153,8 @@ pub struct RequestBuilder {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub body: Option<RequestContent>,
+    pub timeout_secs: Option<u64>,
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 impl RequestBuilder {
@@ -124,6 +166,8 @@ impl RequestBuilder {
             certificate: None,
             certificate_key: None,
             body: None,
+            timeout_secs: None,
+            retry_policy: None,
         }
     }
 
@@ -167,6 +211,16 @@ impl RequestBuilder {
         self
     }
 
+    pub fn timeout_secs(mut self, timeout_secs: Option<u64>) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: Option<RetryPolicy>) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     pub fn build(self) -> Request {
         Request {
             method: self.method,
@@ -175,6 +229,8 @@ impl RequestBuilder {
             certificate: self.certificate,
             certificate_key: self.certificate_key,
             body: self.body,
+            timeout_secs: self.timeout_secs,
+            retry_policy: self.retry_policy,
         }
     }
 }
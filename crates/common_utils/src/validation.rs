@@ -19,6 +19,29 @@ pub fn validate_phone_number(phone_number: &str) -> Result<(), ValidationError>
     Ok(())
 }
 
+/// Parses a given phone number using the [phonenumber] crate, inferring the country code from
+/// `default_country` (ISO 3166-1 alpha-2 code) when the number has no country code
+///
+/// It returns the country code (e.g. `+1`) and the national number of the phone number,
+/// or a [ValidationError::InvalidValue] in case it could not parse the phone number
+pub fn normalize_phone_number(
+    phone_number: &str,
+    default_country: Option<&str>,
+) -> Result<(String, String), ValidationError> {
+    let default_country =
+        default_country.and_then(|country| country.parse::<phonenumber::country::Id>().ok());
+    let phone_number = phonenumber::parse(default_country, phone_number).map_err(|e| {
+        ValidationError::InvalidValue {
+            message: format!("Could not parse phone number: {phone_number}, because: {e:?}"),
+        }
+    })?;
+
+    Ok((
+        format!("+{}", phone_number.code().value()),
+        phone_number.national().to_string(),
+    ))
+}
+
 /// Performs a simple validation against a provided email address.
 pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     #[deny(clippy::invalid_regex)]
@@ -107,6 +130,27 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test_case("8056594427", Some("US"), "+1", "8056594427" ; "US number without country code")]
+    #[test_case("020 7946 0958", Some("GB"), "+44", "2079460958" ; "GB number with trunk prefix")]
+    #[test_case("+44 20 7946 0958", Some("US"), "+44", "2079460958" ; "number with country code")]
+    fn test_normalize_phone_number(
+        phone_number: &str,
+        default_country: Option<&str>,
+        country_code: &str,
+        national_number: &str,
+    ) {
+        assert_eq!(
+            normalize_phone_number(phone_number, default_country).ok(),
+            Some((country_code.to_string(), national_number.to_string()))
+        );
+    }
+
+    #[test_case("8056594427", None ; "number without country code or default country")]
+    #[test_case("phone", Some("US") ; "non numeric phone number")]
+    fn test_normalize_invalid_phone_number(phone_number: &str, default_country: Option<&str>) {
+        assert!(normalize_phone_number(phone_number, default_country).is_err());
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
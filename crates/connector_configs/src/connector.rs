@@ -213,6 +213,20 @@ impl ConnectorConfig {
     ) -> Result<Option<ConnectorTomlConfig>, String> {
         let connector_data = Self::new()?;
         match connector {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector2 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector3 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector4 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector5 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector6 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector7 => Ok(connector_data.dummy_connector),
             PayoutConnectors::Adyen => Ok(connector_data.adyen_payout),
             PayoutConnectors::Wise => Ok(connector_data.wise_payout),
             PayoutConnectors::Paypal => Ok(connector_data.paypal),
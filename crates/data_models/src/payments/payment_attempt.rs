@@ -433,6 +433,10 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    ConnectorMetadataUpdate {
+        connector_metadata: Option<serde_json::Value>,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
@@ -2,6 +2,8 @@ pub mod payout_attempt;
 #[allow(clippy::module_inception)]
 pub mod payouts;
 
+use std::collections::HashMap;
+
 use common_enums as storage_enums;
 use common_utils::consts;
 use time::PrimitiveDateTime;
@@ -11,6 +13,14 @@ pub enum PayoutFetchConstraints {
     List(Box<PayoutListParams>),
 }
 
+/// Position after which the next page of payouts is fetched when walking through all payouts of a
+/// time range. Payouts are ordered by their creation time, with ties broken by the payout id.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutsCursor {
+    pub created_at: PrimitiveDateTime,
+    pub payout_id: String,
+}
+
 pub struct PayoutListParams {
     pub offset: u32,
     pub starting_at: Option<PrimitiveDateTime>,
@@ -24,9 +34,25 @@ pub struct PayoutListParams {
     pub starting_after_id: Option<String>,
     pub ending_before_id: Option<String>,
     pub entity_type: Option<common_enums::PayoutEntityType>,
+    pub metadata: Option<HashMap<String, String>>,
     pub limit: Option<u32>,
 }
 
+impl PayoutListParams {
+    /// Returns the metadata filter as a JSON object, which is contained in the metadata of the
+    /// payouts matching the filter
+    pub fn get_metadata_filter(&self) -> Option<serde_json::Value> {
+        self.metadata.as_ref().map(|metadata| {
+            serde_json::Value::Object(
+                metadata
+                    .iter()
+                    .map(|(key, value)| (key.clone(), serde_json::Value::String(value.clone())))
+                    .collect(),
+            )
+        })
+    }
+}
+
 impl From<api_models::payouts::PayoutListConstraints> for PayoutFetchConstraints {
     fn from(value: api_models::payouts::PayoutListConstraints) -> Self {
         Self::List(Box::new(PayoutListParams {
@@ -44,6 +70,7 @@ impl From<api_models::payouts::PayoutListConstraints> for PayoutFetchConstraints
             starting_after_id: value.starting_after,
             ending_before_id: value.ending_before,
             entity_type: None,
+            metadata: value.metadata,
             limit: Some(std::cmp::min(
                 value.limit,
                 consts::PAYOUTS_LIST_MAX_LIMIT_GET,
@@ -67,6 +94,7 @@ impl From<api_models::payments::TimeRange> for PayoutFetchConstraints {
             starting_after_id: None,
             ending_before_id: None,
             entity_type: None,
+            metadata: None,
             limit: None,
         }))
     }
@@ -90,6 +118,7 @@ impl From<api_models::payouts::PayoutListFilterConstraints> for PayoutFetchConst
                 starting_after_id: None,
                 ending_before_id: None,
                 entity_type: value.entity_type,
+                metadata: value.metadata,
                 limit: Some(std::cmp::min(
                     value.limit,
                     consts::PAYOUTS_LIST_MAX_LIMIT_POST,
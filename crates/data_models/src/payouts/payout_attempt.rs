@@ -31,6 +31,20 @@ pub trait PayoutAttemptInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError>;
 
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &str,
+        _payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError>;
+
+    async fn find_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        _merchant_id: &str,
+        _payout_attempt_ids: &[String],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payouts(
         &self,
         payout: &[Payouts],
@@ -70,6 +84,9 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub manual_routing_override: bool,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -93,6 +110,9 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub manual_routing_override: bool,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
 }
 
 impl Default for PayoutAttemptNew {
@@ -119,6 +139,9 @@ impl Default for PayoutAttemptNew {
             profile_id: String::default(),
             merchant_connector_id: None,
             routing_info: None,
+            manual_routing_override: false,
+            routing_trail: None,
+            amount: None,
         }
     }
 }
@@ -142,6 +165,15 @@ pub enum PayoutAttemptUpdate {
     UpdateRouting {
         connector: String,
         routing_info: Option<serde_json::Value>,
+        routing_trail: Option<serde_json::Value>,
+    },
+    RoutingFailed {
+        error_code: String,
+        error_message: String,
+        routing_trail: Option<serde_json::Value>,
+    },
+    AmountUpdate {
+        amount: i64,
     },
 }
 
@@ -157,6 +189,8 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
 }
 
 impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
@@ -191,9 +225,26 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
             PayoutAttemptUpdate::UpdateRouting {
                 connector,
                 routing_info,
+                routing_trail,
             } => Self {
                 connector: Some(connector),
                 routing_info,
+                routing_trail,
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::RoutingFailed {
+                error_code,
+                error_message,
+                routing_trail,
+            } => Self {
+                status: Some(storage_enums::PayoutStatus::Failed),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                routing_trail,
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
                 ..Default::default()
             },
         }
@@ -6,7 +6,7 @@ use time::PrimitiveDateTime;
 
 use super::payout_attempt::PayoutAttempt;
 #[cfg(feature = "olap")]
-use super::PayoutFetchConstraints;
+use super::{PayoutFetchConstraints, PayoutsCursor};
 use crate::errors;
 
 #[async_trait::async_trait]
@@ -32,6 +32,15 @@ pub trait PayoutsInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, errors::StorageError>;
 
+    async fn update_payout_by_merchant_id_payout_id(
+        &self,
+        _merchant_id: &str,
+        _payout_id: &str,
+        _payout: PayoutsUpdate,
+        _payout_attempt: &PayoutAttempt,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Payouts, errors::StorageError>;
+
     async fn find_optional_payout_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
@@ -39,6 +48,20 @@ pub trait PayoutsInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, errors::StorageError>;
 
+    async fn find_payouts_by_merchant_id_source_payment_id(
+        &self,
+        _merchant_id: &str,
+        _source_payment_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
+
+    async fn get_total_payout_amount_by_merchant_id_source_payment_id(
+        &self,
+        _merchant_id: &str,
+        _source_payment_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, errors::StorageError>;
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -65,6 +88,16 @@ pub trait PayoutsInterface {
         time_range: &api_models::payments::TimeRange,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_time_range_after_cursor(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+        cursor: Option<&PayoutsCursor>,
+        limit: u32,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -89,6 +122,13 @@ pub struct Payouts {
     pub attempt_count: i16,
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub fx_rate: Option<String>,
+    pub fx_quote_expires_at: Option<PrimitiveDateTime>,
+    pub priority: storage_enums::PayoutPriority,
+    pub fulfilled_amount: Option<i64>,
+    pub payout_schedule_id: Option<String>,
+    pub source_payment_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -113,6 +153,13 @@ pub struct PayoutsNew {
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
     pub attempt_count: i16,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub fx_rate: Option<String>,
+    pub fx_quote_expires_at: Option<PrimitiveDateTime>,
+    pub priority: storage_enums::PayoutPriority,
+    pub fulfilled_amount: Option<i64>,
+    pub payout_schedule_id: Option<String>,
+    pub source_payment_id: Option<String>,
 }
 
 impl Default for PayoutsNew {
@@ -140,6 +187,13 @@ impl Default for PayoutsNew {
             profile_id: String::default(),
             status: storage_enums::PayoutStatus::default(),
             attempt_count: 1,
+            scheduled_at: None,
+            fx_rate: None,
+            fx_quote_expires_at: None,
+            priority: storage_enums::PayoutPriority::default(),
+            fulfilled_amount: None,
+            payout_schedule_id: None,
+            source_payment_id: None,
         }
     }
 }
@@ -171,6 +225,18 @@ pub enum PayoutsUpdate {
     StatusUpdate {
         status: storage_enums::PayoutStatus,
     },
+    MetadataUpdate {
+        description: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    FxQuoteUpdate {
+        fx_rate: Option<String>,
+        fx_quote_expires_at: Option<PrimitiveDateTime>,
+    },
+    FulfillmentUpdate {
+        status: storage_enums::PayoutStatus,
+        fulfilled_amount: Option<i64>,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -188,6 +254,9 @@ pub struct PayoutsUpdateInternal {
     pub profile_id: Option<String>,
     pub status: Option<storage_enums::PayoutStatus>,
     pub attempt_count: Option<i16>,
+    pub fx_rate: Option<Option<String>>,
+    pub fx_quote_expires_at: Option<Option<PrimitiveDateTime>>,
+    pub fulfilled_amount: Option<i64>,
 }
 
 impl From<PayoutsUpdate> for PayoutsUpdateInternal {
@@ -235,6 +304,30 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PayoutsUpdate::MetadataUpdate {
+                description,
+                metadata,
+            } => Self {
+                description,
+                metadata,
+                ..Default::default()
+            },
+            PayoutsUpdate::FxQuoteUpdate {
+                fx_rate,
+                fx_quote_expires_at,
+            } => Self {
+                fx_rate: Some(fx_rate),
+                fx_quote_expires_at: Some(fx_quote_expires_at),
+                ..Default::default()
+            },
+            PayoutsUpdate::FulfillmentUpdate {
+                status,
+                fulfilled_amount,
+            } => Self {
+                status: Some(status),
+                fulfilled_amount,
+                ..Default::default()
+            },
         }
     }
 }
@@ -36,6 +36,10 @@ pub struct BusinessProfile {
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
+    pub check_balance_before_fulfill: Option<bool>,
+    pub payout_blocked_countries: Option<serde_json::Value>,
+    pub require_recipient_kyc: Option<bool>,
+    pub default_payout_method: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -63,6 +67,10 @@ pub struct BusinessProfileNew {
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
+    pub check_balance_before_fulfill: Option<bool>,
+    pub payout_blocked_countries: Option<serde_json::Value>,
+    pub require_recipient_kyc: Option<bool>,
+    pub default_payout_method: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -87,6 +95,10 @@ pub struct BusinessProfileUpdateInternal {
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
+    pub check_balance_before_fulfill: Option<bool>,
+    pub payout_blocked_countries: Option<serde_json::Value>,
+    pub require_recipient_kyc: Option<bool>,
+    pub default_payout_method: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -109,6 +121,10 @@ pub enum BusinessProfileUpdate {
         payment_link_config: Option<serde_json::Value>,
         session_expiry: Option<i64>,
         authentication_connector_details: Option<serde_json::Value>,
+        check_balance_before_fulfill: Option<bool>,
+        payout_blocked_countries: Option<serde_json::Value>,
+        require_recipient_kyc: Option<bool>,
+        default_payout_method: Option<serde_json::Value>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -136,6 +152,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 payment_link_config,
                 session_expiry,
                 authentication_connector_details,
+                check_balance_before_fulfill,
+                payout_blocked_countries,
+                require_recipient_kyc,
+                default_payout_method,
             } => Self {
                 profile_name,
                 modified_at,
@@ -154,6 +174,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 payment_link_config,
                 session_expiry,
                 authentication_connector_details,
+                check_balance_before_fulfill,
+                payout_blocked_countries,
+                require_recipient_kyc,
+                default_payout_method,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -190,6 +214,10 @@ impl From<BusinessProfileNew> for BusinessProfile {
             session_expiry: new.session_expiry,
             authentication_connector_details: new.authentication_connector_details,
             is_extended_card_info_enabled: new.is_extended_card_info_enabled,
+            check_balance_before_fulfill: new.check_balance_before_fulfill,
+            payout_blocked_countries: new.payout_blocked_countries,
+            require_recipient_kyc: new.require_recipient_kyc,
+            default_payout_method: new.default_payout_method,
         }
     }
 }
@@ -215,6 +243,10 @@ impl BusinessProfileUpdate {
             session_expiry,
             authentication_connector_details,
             is_extended_card_info_enabled,
+            check_balance_before_fulfill,
+            payout_blocked_countries,
+            require_recipient_kyc,
+            default_payout_method,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -237,6 +269,11 @@ impl BusinessProfileUpdate {
             session_expiry,
             authentication_connector_details,
             is_extended_card_info_enabled,
+            check_balance_before_fulfill: check_balance_before_fulfill
+                .or(source.check_balance_before_fulfill),
+            payout_blocked_countries: payout_blocked_countries.or(source.payout_blocked_countries),
+            require_recipient_kyc: require_recipient_kyc.or(source.require_recipient_kyc),
+            default_payout_method: default_payout_method.or(source.default_payout_method),
             ..source
         }
     }
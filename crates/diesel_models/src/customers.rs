@@ -2,7 +2,7 @@ use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
-use crate::{encryption::Encryption, schema::customers};
+use crate::{encryption::Encryption, enums as storage_enums, schema::customers};
 
 #[derive(
     Clone, Debug, Insertable, router_derive::DebugAsDisplay, serde::Deserialize, serde::Serialize,
@@ -21,6 +21,7 @@ pub struct CustomerNew {
     pub created_at: PrimitiveDateTime,
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
+    pub kyc_status: Option<storage_enums::KycStatus>,
 }
 
 impl From<CustomerNew> for Customer {
@@ -40,6 +41,7 @@ impl From<CustomerNew> for Customer {
             modified_at: customer_new.modified_at,
             address_id: customer_new.address_id,
             default_payment_method_id: None,
+            kyc_status: customer_new.kyc_status,
         }
     }
 }
@@ -61,6 +63,7 @@ pub struct Customer {
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
+    pub kyc_status: Option<storage_enums::KycStatus>,
 }
 
 #[derive(
@@ -84,6 +87,7 @@ pub struct CustomerUpdateInternal {
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
+    pub kyc_status: Option<storage_enums::KycStatus>,
 }
 
 impl CustomerUpdateInternal {
@@ -98,6 +102,7 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            kyc_status,
             ..
         } = self;
 
@@ -114,6 +119,7 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            kyc_status: kyc_status.or(source.kyc_status),
             ..source
         }
     }
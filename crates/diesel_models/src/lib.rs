@@ -34,6 +34,8 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_audit_event;
+pub mod payout_schedule;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -339,6 +339,10 @@ pub enum PaymentAttemptUpdate {
         authentication_id: Option<String>,
         updated_by: String,
     },
+    ConnectorMetadataUpdate {
+        connector_metadata: Option<serde_json::Value>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -849,6 +853,14 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::ConnectorMetadataUpdate {
+                connector_metadata,
+                updated_by,
+            } => Self {
+                connector_metadata,
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
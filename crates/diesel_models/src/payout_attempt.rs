@@ -29,6 +29,9 @@ pub struct PayoutAttempt {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub manual_routing_override: bool,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
 }
 
 #[derive(
@@ -66,6 +69,9 @@ pub struct PayoutAttemptNew {
     pub profile_id: String,
     pub merchant_connector_id: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub manual_routing_override: bool,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +93,15 @@ pub enum PayoutAttemptUpdate {
     UpdateRouting {
         connector: String,
         routing_info: Option<serde_json::Value>,
+        routing_trail: Option<serde_json::Value>,
+    },
+    RoutingFailed {
+        error_code: String,
+        error_message: String,
+        routing_trail: Option<serde_json::Value>,
+    },
+    AmountUpdate {
+        amount: i64,
     },
 }
 
@@ -103,6 +118,8 @@ pub struct PayoutAttemptUpdateInternal {
     pub business_label: Option<String>,
     pub connector: Option<String>,
     pub routing_info: Option<serde_json::Value>,
+    pub routing_trail: Option<serde_json::Value>,
+    pub amount: Option<i64>,
     pub last_modified_at: PrimitiveDateTime,
 }
 
@@ -119,6 +136,8 @@ impl Default for PayoutAttemptUpdateInternal {
             business_label: None,
             connector: None,
             routing_info: None,
+            routing_trail: None,
+            amount: None,
             last_modified_at: common_utils::date_time::now(),
         }
     }
@@ -156,9 +175,26 @@ impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
             PayoutAttemptUpdate::UpdateRouting {
                 connector,
                 routing_info,
+                routing_trail,
             } => Self {
                 connector: Some(connector),
                 routing_info,
+                routing_trail,
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::RoutingFailed {
+                error_code,
+                error_message,
+                routing_trail,
+            } => Self {
+                status: Some(storage_enums::PayoutStatus::Failed),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                routing_trail,
+                ..Default::default()
+            },
+            PayoutAttemptUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
                 ..Default::default()
             },
         }
@@ -178,6 +214,8 @@ impl PayoutAttemptUpdate {
             business_label,
             connector,
             routing_info,
+            routing_trail,
+            amount,
             last_modified_at,
         } = self.into();
         PayoutAttempt {
@@ -191,6 +229,8 @@ impl PayoutAttemptUpdate {
             business_label: business_label.or(source.business_label),
             connector: connector.or(source.connector),
             routing_info: routing_info.or(source.routing_info),
+            routing_trail: routing_trail.or(source.routing_trail),
+            amount: amount.or(source.amount),
             last_modified_at,
             ..source
         }
@@ -0,0 +1,34 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_audit_event};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_audit_event, primary_key(event_id))]
+pub struct PayoutAuditEvent {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub payout_id: String,
+    pub event_type: storage_enums::PayoutAuditEventType,
+    pub actor: serde_json::Value,
+    pub previous_status: Option<storage_enums::PayoutStatus>,
+    pub new_status: storage_enums::PayoutStatus,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_audit_event)]
+pub struct PayoutAuditEventNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub payout_id: String,
+    pub event_type: storage_enums::PayoutAuditEventType,
+    pub actor: serde_json::Value,
+    pub previous_status: Option<storage_enums::PayoutStatus>,
+    pub new_status: storage_enums::PayoutStatus,
+    pub reason: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
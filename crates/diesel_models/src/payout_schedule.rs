@@ -0,0 +1,136 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_schedule};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_schedule, primary_key(schedule_id))]
+pub struct PayoutSchedule {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub payout_method_id: String,
+    pub profile_id: Option<String>,
+    pub currency: storage_enums::Currency,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub cadence: serde_json::Value,
+    pub amount_source: serde_json::Value,
+    pub description: Option<String>,
+    pub status: storage_enums::PayoutScheduleStatus,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub last_payout_id: Option<String>,
+    pub last_failure_reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_schedule)]
+pub struct PayoutScheduleNew {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub payout_method_id: String,
+    pub profile_id: Option<String>,
+    pub currency: storage_enums::Currency,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub cadence: serde_json::Value,
+    pub amount_source: serde_json::Value,
+    pub description: Option<String>,
+    pub status: storage_enums::PayoutScheduleStatus,
+    pub next_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum PayoutScheduleUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutScheduleStatus,
+        next_run_at: Option<PrimitiveDateTime>,
+    },
+    RunUpdate {
+        next_run_at: PrimitiveDateTime,
+        last_run_at: PrimitiveDateTime,
+        last_payout_id: Option<String>,
+        last_failure_reason: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_schedule)]
+pub struct PayoutScheduleUpdateInternal {
+    pub status: Option<storage_enums::PayoutScheduleStatus>,
+    pub next_run_at: Option<Option<PrimitiveDateTime>>,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub last_payout_id: Option<Option<String>>,
+    pub last_failure_reason: Option<Option<String>>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutScheduleUpdate> for PayoutScheduleUpdateInternal {
+    fn from(payout_schedule_update: PayoutScheduleUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match payout_schedule_update {
+            PayoutScheduleUpdate::StatusUpdate {
+                status,
+                next_run_at,
+            } => Self {
+                status: Some(status),
+                next_run_at: Some(next_run_at),
+                last_run_at: None,
+                last_payout_id: None,
+                last_failure_reason: None,
+                last_modified_at,
+            },
+            PayoutScheduleUpdate::RunUpdate {
+                next_run_at,
+                last_run_at,
+                last_payout_id,
+                last_failure_reason,
+            } => Self {
+                status: None,
+                next_run_at: Some(Some(next_run_at)),
+                last_run_at: Some(last_run_at),
+                last_payout_id: Some(last_payout_id),
+                last_failure_reason: Some(last_failure_reason),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+impl PayoutScheduleUpdate {
+    pub fn apply_changeset(self, source: PayoutSchedule) -> PayoutSchedule {
+        let PayoutScheduleUpdateInternal {
+            status,
+            next_run_at,
+            last_run_at,
+            last_payout_id,
+            last_failure_reason,
+            last_modified_at,
+        } = self.into();
+        PayoutSchedule {
+            status: status.unwrap_or(source.status),
+            next_run_at: next_run_at.unwrap_or(source.next_run_at),
+            last_run_at: last_run_at.or(source.last_run_at),
+            last_payout_id: last_payout_id.unwrap_or(source.last_payout_id),
+            last_failure_reason: last_failure_reason.unwrap_or(source.last_failure_reason),
+            last_modified_at,
+            ..source
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Default, Debug, Deserialize, Serialize, Clone)]
+pub struct PayoutScheduleRunTrackingData {
+    pub schedule_id: String,
+    pub merchant_id: String,
+}
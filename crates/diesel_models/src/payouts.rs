@@ -32,6 +32,15 @@ pub struct Payouts {
     pub attempt_count: i16,
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub fx_rate: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub fx_quote_expires_at: Option<PrimitiveDateTime>,
+    pub priority: storage_enums::PayoutPriority,
+    pub fulfilled_amount: Option<i64>,
+    pub payout_schedule_id: Option<String>,
+    pub source_payment_id: Option<String>,
 }
 
 #[derive(
@@ -70,6 +79,15 @@ pub struct PayoutsNew {
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
     pub attempt_count: i16,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub fx_rate: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub fx_quote_expires_at: Option<PrimitiveDateTime>,
+    pub priority: storage_enums::PayoutPriority,
+    pub fulfilled_amount: Option<i64>,
+    pub payout_schedule_id: Option<String>,
+    pub source_payment_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,6 +117,18 @@ pub enum PayoutsUpdate {
     StatusUpdate {
         status: storage_enums::PayoutStatus,
     },
+    MetadataUpdate {
+        description: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    FxQuoteUpdate {
+        fx_rate: Option<String>,
+        fx_quote_expires_at: Option<PrimitiveDateTime>,
+    },
+    FulfillmentUpdate {
+        status: storage_enums::PayoutStatus,
+        fulfilled_amount: Option<i64>,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -118,6 +148,9 @@ pub struct PayoutsUpdateInternal {
     pub status: Option<storage_enums::PayoutStatus>,
     pub last_modified_at: PrimitiveDateTime,
     pub attempt_count: Option<i16>,
+    pub fx_rate: Option<Option<String>>,
+    pub fx_quote_expires_at: Option<Option<PrimitiveDateTime>>,
+    pub fulfilled_amount: Option<i64>,
 }
 
 impl Default for PayoutsUpdateInternal {
@@ -137,6 +170,9 @@ impl Default for PayoutsUpdateInternal {
             status: None,
             last_modified_at: common_utils::date_time::now(),
             attempt_count: None,
+            fx_rate: None,
+            fx_quote_expires_at: None,
+            fulfilled_amount: None,
         }
     }
 }
@@ -186,6 +222,30 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PayoutsUpdate::MetadataUpdate {
+                description,
+                metadata,
+            } => Self {
+                description,
+                metadata,
+                ..Default::default()
+            },
+            PayoutsUpdate::FxQuoteUpdate {
+                fx_rate,
+                fx_quote_expires_at,
+            } => Self {
+                fx_rate: Some(fx_rate),
+                fx_quote_expires_at: Some(fx_quote_expires_at),
+                ..Default::default()
+            },
+            PayoutsUpdate::FulfillmentUpdate {
+                status,
+                fulfilled_amount,
+            } => Self {
+                status: Some(status),
+                fulfilled_amount,
+                ..Default::default()
+            },
         }
     }
 }
@@ -207,6 +267,9 @@ impl PayoutsUpdate {
             status,
             last_modified_at,
             attempt_count,
+            fx_rate,
+            fx_quote_expires_at,
+            fulfilled_amount,
         } = self.into();
         Payouts {
             amount: amount.unwrap_or(source.amount),
@@ -223,7 +286,17 @@ impl PayoutsUpdate {
             status: status.unwrap_or(source.status),
             last_modified_at,
             attempt_count: attempt_count.unwrap_or(source.attempt_count),
+            fx_rate: fx_rate.unwrap_or(source.fx_rate),
+            fx_quote_expires_at: fx_quote_expires_at.unwrap_or(source.fx_quote_expires_at),
+            fulfilled_amount: fulfilled_amount.or(source.fulfilled_amount),
             ..source
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Default, Debug, Deserialize, Serialize, Clone)]
+pub struct PayoutScheduleTrackingData {
+    pub payout_id: String,
+    pub merchant_id: String,
+}
@@ -209,6 +209,8 @@ pub enum ProcessTrackerRunner {
     DeleteTokenizeDataWorkflow,
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
+    ScheduledPayoutWorkflow,
+    PayoutScheduleWorkflow,
 }
 
 #[cfg(test)]
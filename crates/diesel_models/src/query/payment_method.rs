@@ -148,6 +148,28 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_customer_id_merchant_id_status_payment_methods(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        status: storage_enums::PaymentMethodStatus,
+        payment_methods: Vec<storage_enums::PaymentMethod>,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::status.eq(status))
+                .and(dsl::payment_method.eq_any(payment_methods)),
+            limit,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payout_audit_event::{PayoutAuditEvent, PayoutAuditEventNew},
+    schema::payout_audit_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutAuditEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutAuditEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutAuditEvent {
+    pub async fn list_by_merchant_id_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.eq(payout_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
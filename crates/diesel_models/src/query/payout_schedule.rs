@@ -0,0 +1,53 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payout_schedule::{
+        PayoutSchedule, PayoutScheduleNew, PayoutScheduleUpdate, PayoutScheduleUpdateInternal,
+    },
+    schema::payout_schedule::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutSchedule {
+    pub async fn find_by_merchant_id_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(schedule_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: &str,
+        payout_schedule_update: PayoutScheduleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(schedule_id.to_owned())),
+            PayoutScheduleUpdateInternal::from(payout_schedule_update),
+        )
+        .await
+    }
+}
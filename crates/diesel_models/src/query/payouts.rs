@@ -1,9 +1,10 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl, Table};
+use error_stack::{report, ResultExt};
 
 use super::generics;
 use crate::{
-    errors,
+    enums, errors,
     payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal},
     schema::payouts::dsl,
     PgPooledConn, StorageResult,
@@ -87,4 +88,54 @@ impl Payouts {
         )
         .await
     }
+
+    pub async fn find_by_merchant_id_source_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        source_payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::source_payment_id.eq(source_payment_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Returns the total amount of the payouts funded by a payment, leaving out the payouts which
+    /// failed or were cancelled
+    pub async fn get_total_amount_by_merchant_id_source_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        source_payment_id: &str,
+    ) -> StorageResult<i64> {
+        let filter = <Self as HasTable>::table().select(dsl::amount).filter(
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::source_payment_id.eq(source_payment_id.to_owned()))
+                .and(dsl::status.ne_all(vec![
+                    enums::PayoutStatus::Failed,
+                    enums::PayoutStatus::Cancelled,
+                    enums::PayoutStatus::Ineligible,
+                ])),
+        );
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get the total amount of the payouts linked to a payment")
+        .map(|amounts| amounts.into_iter().sum())
+    }
 }
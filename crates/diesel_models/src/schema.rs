@@ -194,6 +194,10 @@ diesel::table! {
         session_expiry -> Nullable<Int8>,
         authentication_connector_details -> Nullable<Jsonb>,
         is_extended_card_info_enabled -> Nullable<Bool>,
+        check_balance_before_fulfill -> Nullable<Bool>,
+        payout_blocked_countries -> Nullable<Jsonb>,
+        require_recipient_kyc -> Nullable<Bool>,
+        default_payout_method -> Nullable<Jsonb>,
     }
 }
 
@@ -293,6 +297,8 @@ diesel::table! {
         address_id -> Nullable<Varchar>,
         #[max_length = 64]
         default_payment_method_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        kyc_status -> Nullable<Varchar>,
     }
 }
 
@@ -630,6 +636,8 @@ diesel::table! {
         default_profile -> Nullable<Varchar>,
         recon_status -> ReconStatus,
         payment_link_config -> Nullable<Jsonb>,
+        #[max_length = 32]
+        kyc_status -> Nullable<Varchar>,
     }
 }
 
@@ -963,6 +971,63 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         routing_info -> Nullable<Jsonb>,
+        manual_routing_override -> Bool,
+        routing_trail -> Nullable<Jsonb>,
+        amount -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_audit_event (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        #[max_length = 32]
+        event_type -> Varchar,
+        actor -> Jsonb,
+        previous_status -> Nullable<PayoutStatus>,
+        new_status -> PayoutStatus,
+        reason -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_schedule (schedule_id) {
+        #[max_length = 64]
+        schedule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        payout_method_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        currency -> Currency,
+        payout_type -> Nullable<PayoutType>,
+        cadence -> Jsonb,
+        amount_source -> Jsonb,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        next_run_at -> Nullable<Timestamp>,
+        last_run_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        last_payout_id -> Nullable<Varchar>,
+        last_failure_reason -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
     }
 }
 
@@ -1000,6 +1065,17 @@ diesel::table! {
         #[max_length = 64]
         profile_id -> Varchar,
         status -> PayoutStatus,
+        scheduled_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        fx_rate -> Nullable<Varchar>,
+        fx_quote_expires_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        priority -> Varchar,
+        fulfilled_amount -> Nullable<Int8>,
+        #[max_length = 64]
+        payout_schedule_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        source_payment_id -> Nullable<Varchar>,
     }
 }
 
@@ -1221,6 +1297,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_audit_event,
+    payout_schedule,
     payouts,
     process_tracker,
     refund,
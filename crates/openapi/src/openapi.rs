@@ -94,6 +94,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::update_merchant_kyc_status,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::payment_connector_create,
@@ -161,12 +162,22 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for payouts
         routes::payouts::payouts_create,
+        routes::payouts::payouts_validate,
         routes::payouts::payouts_retrieve,
         routes::payouts::payouts_update,
+        routes::payouts::payouts_update_details,
         routes::payouts::payouts_cancel,
         routes::payouts::payouts_fulfill,
+        routes::payouts::payouts_list_events,
         routes::payouts::payouts_list,
         routes::payouts::payouts_filter,
+        routes::payouts::payouts_export,
+        routes::payouts::payouts_required_fields,
+        routes::payouts::payouts_list_saved_payout_methods,
+        routes::payouts::payouts_delete_saved_payout_method,
+        routes::payouts::payout_schedules_create,
+        routes::payouts::payout_schedules_retrieve,
+        routes::payouts::payout_schedules_cancel,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -259,6 +270,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
         api_models::admin::BusinessPaymentLinkConfig,
+        api_models::admin::DefaultPayoutMethod,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
         api_models::disputes::DisputeResponse,
@@ -392,6 +404,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::PaymentLinkedPayoutResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
@@ -415,6 +428,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
         api_models::payments::TimeRange,
+        api_models::payments::PaymentsReconcileRequest,
+        api_models::payments::PaymentsReconcileResponse,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -434,17 +449,44 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutAttemptResponse,
         api_models::payouts::PayoutActionRequest,
         api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutUpdateRequest,
         api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutValidateResponse,
+        api_models::payouts::PayoutValidationError,
+        api_models::payouts::PayoutRequiredFieldsResponse,
+        api_models::payouts::PayoutMethodRequiredFields,
+        api_models::payouts::PayoutRequiredFieldInfo,
+        api_models::payouts::PayoutEventsResponse,
+        api_models::payouts::PayoutEvent,
+        api_models::payouts::PayoutEventActor,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutRetrieveRequest,
+        api_models::payouts::PayoutExpandableField,
+        api_models::payouts::PayoutRoutingTrail,
+        api_models::payouts::PayoutRoutingSkippedCandidate,
+        api_models::payouts::PayoutRoutingAlgorithmVersion,
+        api_models::payouts::PayoutRoutingSkipReason,
         api_models::payouts::PayoutMethodData,
+        api_models::payouts::CustomerPayoutMethodsListResponse,
+        api_models::payouts::CustomerPayoutMethod,
+        api_models::payouts::PayoutMethodDeleteResponse,
         api_models::payouts::Bank,
+        api_models::payouts::PayoutScheduleCreateRequest,
+        api_models::payouts::PayoutScheduleCadence,
+        api_models::payouts::PayoutScheduleAmountSource,
+        api_models::payouts::PayoutScheduleAmountCallbackRequest,
+        api_models::payouts::PayoutScheduleAmountCallbackResponse,
+        api_models::payouts::PayoutScheduleResponse,
         api_models::enums::PayoutEntityType,
+        api_models::enums::PayoutPriority,
         api_models::enums::PayoutStatus,
         api_models::enums::PayoutType,
+        api_models::enums::KycStatus,
+        api_models::enums::PayoutAuditEventType,
+        api_models::enums::PayoutScheduleStatus,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
@@ -458,6 +500,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::MerchantKycStatusUpdate,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
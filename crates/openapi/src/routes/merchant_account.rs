@@ -155,3 +155,30 @@ pub async fn delete_merchant_account() {}
     security(("admin_api_key" = []))
 )]
 pub async fn merchant_account_kv_status() {}
+
+/// Merchant Account - Update KYC Status
+///
+/// To update the KYC status of a merchant account, only with the admin API key
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/kyc",
+    request_body (
+        content = MerchantKycStatusUpdate,
+        examples (
+            ("Mark the KYC of the Merchant as verified" = (
+                value = json!({
+                "kyc_status": "verified"
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "KYC Status Updated", body = MerchantAccountResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the KYC Status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_merchant_kyc_status() {}
@@ -13,12 +13,27 @@
 )]
 pub async fn payouts_create() {}
 
+/// Payouts - Validate
+#[utoipa::path(
+    post,
+    path = "/payouts/validate",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout create request validated", body = PayoutValidateResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "Validate a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_validate() {}
+
 /// Payouts - Retrieve
 #[utoipa::path(
     get,
     path = "/payouts/{payout_id}",
     params(
-        ("payout_id" = String, Path, description = "The identifier for payout]")
+        ("payout_id" = String, Path, description = "The identifier for payout]"),
+        ("expand" = Option<PayoutExpandableField>, Query, description = "Pass `attempts` to include all the attempts made for the payout, or `routing` to include the routing decision trail of the active attempt")
     ),
     responses(
         (status = 200, description = "Payout retrieved", body = PayoutCreateResponse),
@@ -32,7 +47,7 @@ pub async fn payouts_retrieve() {}
 
 /// Payouts - Update
 #[utoipa::path(
-    post,
+    put,
     path = "/payouts/{payout_id}",
     params(
         ("payout_id" = String, Path, description = "The identifier for payout]")
@@ -48,6 +63,25 @@ pub async fn payouts_retrieve() {}
 )]
 pub async fn payouts_update() {}
 
+/// Payouts - Update Details
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout details updated", body = PayoutCreateResponse),
+        (status = 400, description = "Payout or the requested field cannot be updated"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Update Payout Details",
+    security(("api_key" = []))
+)]
+pub async fn payouts_update_details() {}
+
 /// Payouts - Cancel
 #[utoipa::path(
     post,
@@ -84,10 +118,30 @@ pub async fn payouts_cancel() {}
 )]
 pub async fn payouts_fulfill() {}
 
+/// Payouts - List events
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}/events",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout events retrieved", body = PayoutEventsResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List events of a Payout",
+    security(("api_key" = []))
+)]
+pub async fn payouts_list_events() {}
+
 /// Payouts - List
 #[utoipa::path(
     get,
     path = "/payouts/list",
+    params(
+        ("metadata[key]" = Option<String>, Query, description = "Filter the payouts having the value for the key in their metadata, can be passed for several keys")
+    ),
     responses(
         (status = 200, description = "Payouts listed", body = PayoutListResponse),
         (status = 404, description = "Payout not found")
@@ -111,3 +165,125 @@ pub async fn payouts_list() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_filter() {}
+
+/// Payouts - Export
+#[utoipa::path(
+    get,
+    path = "/payouts/export",
+    params(
+        ("start_time" = PrimitiveDateTime, Query, description = "Export payouts created at or after this time"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "Export payouts created at or before this time, defaults to now")
+    ),
+    responses(
+        (status = 200, description = "Payouts exported as CSV", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid time range")
+    ),
+    tag = "Payouts",
+    operation_id = "Export payouts",
+    security(("api_key" = []))
+)]
+pub async fn payouts_export() {}
+
+/// Payouts - Required fields
+#[utoipa::path(
+    get,
+    path = "/payouts/required_fields",
+    params(
+        ("connector" = PayoutConnectors, Query, description = "The payout connector the payout would be made through"),
+        ("payout_type" = PayoutType, Query, description = "The type of the payout method"),
+        ("country" = Option<CountryAlpha2>, Query, description = "The country of the payout method"),
+        ("currency" = Option<Currency>, Query, description = "The currency of the payout")
+    ),
+    responses(
+        (status = 200, description = "Required fields of the payout methods retrieved", body = PayoutRequiredFieldsResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve the required fields of payout methods",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+pub async fn payouts_required_fields() {}
+
+/// Payouts - List saved payout methods for a Customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/payout_methods",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer")
+    ),
+    responses(
+        (status = 200, description = "Saved payout methods retrieved", body = CustomerPayoutMethodsListResponse),
+        (status = 404, description = "Customer does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List saved payout methods for a Customer",
+    security(("api_key" = []))
+)]
+pub async fn payouts_list_saved_payout_methods() {}
+
+/// Payouts - Delete a saved payout method of a Customer
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/payout_methods/{payout_method_id}",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer"),
+        ("payout_method_id" = String, Path, description = "The unique identifier for the saved payout method")
+    ),
+    responses(
+        (status = 200, description = "Saved payout method deleted", body = PayoutMethodDeleteResponse),
+        (status = 404, description = "Payout method does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Delete a saved payout method",
+    security(("api_key" = []))
+)]
+pub async fn payouts_delete_saved_payout_method() {}
+
+/// Payouts - Create a Payout Schedule
+#[utoipa::path(
+    post,
+    path = "/payouts/schedules",
+    request_body=PayoutScheduleCreateRequest,
+    responses(
+        (status = 200, description = "Payout schedule created", body = PayoutScheduleResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Schedule",
+    security(("api_key" = []))
+)]
+pub async fn payout_schedules_create() {}
+
+/// Payouts - Retrieve a Payout Schedule
+#[utoipa::path(
+    get,
+    path = "/payouts/schedules/{schedule_id}",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for payout schedule")
+    ),
+    responses(
+        (status = 200, description = "Payout schedule retrieved", body = PayoutScheduleResponse),
+        (status = 404, description = "Payout schedule does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Schedule",
+    security(("api_key" = []))
+)]
+pub async fn payout_schedules_retrieve() {}
+
+/// Payouts - Cancel a Payout Schedule
+#[utoipa::path(
+    post,
+    path = "/payouts/schedules/{schedule_id}/cancel",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for payout schedule")
+    ),
+    responses(
+        (status = 200, description = "Payout schedule cancelled", body = PayoutScheduleResponse),
+        (status = 400, description = "Payout schedule is already cancelled"),
+        (status = 404, description = "Payout schedule does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Schedule",
+    security(("api_key" = []))
+)]
+pub async fn payout_schedules_cancel() {}
@@ -263,6 +263,38 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
                     workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
                 )),
+                storage::ProcessTrackerRunner::ScheduledPayoutWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::scheduled_payouts::ScheduledPayoutWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run scheduled payout workflow when payouts are disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutScheduleWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::payout_schedules::PayoutScheduleWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout schedule workflow when payouts are disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -126,6 +126,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
     PayoutNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout schedule")]
+    PayoutScheduleNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such event")]
     EventNotFound,
 
@@ -484,6 +487,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
             errors::ApiErrorResponse::PayoutFailed { data } => Self::PayoutFailed,
+            errors::ApiErrorResponse::InsufficientConnectorBalance { .. } => Self::PayoutFailed,
 
             errors::ApiErrorResponse::MandateUpdateFailed
             | errors::ApiErrorResponse::MandateSerializationFailed
@@ -528,6 +532,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
             errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::PayoutScheduleNotFound => Self::PayoutScheduleNotFound,
             errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
@@ -559,6 +564,21 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::InvalidRequestData { message } => {
                 Self::InvalidRequestData { message }
             }
+            errors::ApiErrorResponse::PayoutCountryBlocked { country } => {
+                Self::InvalidRequestData {
+                    message: format!("Payouts to {country} are blocked for compliance reasons"),
+                }
+            }
+            errors::ApiErrorResponse::SelfPayoutBlocked => Self::InvalidRequestData {
+                message: "Payouts to accounts owned by the merchant are not allowed".to_string(),
+            },
+            errors::ApiErrorResponse::MerchantKycIncomplete { payout_type } => {
+                Self::InvalidRequestData {
+                    message: format!(
+                        "{payout_type} payouts are not allowed until the KYC of the merchant is verified"
+                    ),
+                }
+            }
             errors::ApiErrorResponse::PreconditionFailed { message } => {
                 Self::PreconditionFailed { message }
             }
@@ -678,6 +698,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
             | Self::PayoutNotFound
+            | Self::PayoutScheduleNotFound
             | Self::EventNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount { .. }
@@ -114,6 +114,9 @@ where
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::FileDataStream((file_data_stream, content_type))) => {
+            api::http_response_file_data_stream(file_data_stream, content_type)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
@@ -101,6 +101,272 @@ impl Default for super::settings::Refund {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl Default for super::settings::Payouts {
+    fn default() -> Self {
+        Self {
+            payout_eligibility: false,
+            max_schedule_horizon_in_days: 90,
+            fx_quote_validity_in_seconds: 900,
+            blocked_countries: HashSet::from([
+                enums::CountryAlpha2::CU,
+                enums::CountryAlpha2::IR,
+                enums::CountryAlpha2::KP,
+                enums::CountryAlpha2::SY,
+            ]),
+            required_fields: super::settings::PayoutRequiredFields::default(),
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+fn payout_required_field(
+    required_field: &str,
+    display_name: &str,
+    field_type: enums::FieldType,
+    validation_regex: Option<&str>,
+) -> (String, api_models::payouts::PayoutRequiredFieldInfo) {
+    (
+        required_field.to_string(),
+        api_models::payouts::PayoutRequiredFieldInfo {
+            required_field: required_field.to_string(),
+            display_name: display_name.to_string(),
+            field_type,
+            validation_regex: validation_regex.map(str::to_string),
+        },
+    )
+}
+
+#[cfg(feature = "payouts")]
+fn payout_billing_name_fields() -> Vec<(String, api_models::payouts::PayoutRequiredFieldInfo)> {
+    vec![
+        payout_required_field(
+            "billing.address.first_name",
+            "billing_first_name",
+            enums::FieldType::UserBillingName,
+            None,
+        ),
+        payout_required_field(
+            "billing.address.last_name",
+            "billing_last_name",
+            enums::FieldType::UserBillingName,
+            None,
+        ),
+    ]
+}
+
+#[cfg(feature = "payouts")]
+fn payout_billing_address_fields() -> Vec<(String, api_models::payouts::PayoutRequiredFieldInfo)> {
+    vec![
+        payout_required_field(
+            "billing.address.line1",
+            "line1",
+            enums::FieldType::UserAddressLine1,
+            None,
+        ),
+        payout_required_field(
+            "billing.address.city",
+            "city",
+            enums::FieldType::UserAddressCity,
+            None,
+        ),
+        payout_required_field(
+            "billing.address.zip",
+            "zip",
+            enums::FieldType::UserAddressPincode,
+            None,
+        ),
+        payout_required_field(
+            "billing.address.country",
+            "country",
+            enums::FieldType::UserAddressCountry {
+                options: vec!["ALL".to_string()],
+            },
+            None,
+        ),
+    ]
+}
+
+#[cfg(feature = "payouts")]
+impl Default for super::settings::PayoutRequiredFields {
+    fn default() -> Self {
+        use super::settings::{
+            PayoutConnectorFields, PayoutMethodTypeRequiredFields, PayoutRequiredFieldFinal,
+        };
+
+        let iban = payout_required_field(
+            "payout_method_data.bank.iban",
+            "iban",
+            enums::FieldType::Text,
+            Some("^[A-Z]{2}[0-9]{2}( ?[A-Z0-9]){11,30}$"),
+        );
+        Self(HashMap::from([
+            (
+                enums::PayoutType::Card,
+                PayoutMethodTypeRequiredFields(HashMap::from([(
+                    enums::PaymentMethodType::Debit,
+                    PayoutConnectorFields {
+                        fields: HashMap::from([(
+                            enums::PayoutConnectors::Adyen,
+                            PayoutRequiredFieldFinal {
+                                countries: None,
+                                currencies: None,
+                                fields: HashMap::from([
+                                    payout_required_field(
+                                        "payout_method_data.card.card_number",
+                                        "card_number",
+                                        enums::FieldType::UserCardNumber,
+                                        Some("^[0-9]{12,19}$"),
+                                    ),
+                                    payout_required_field(
+                                        "payout_method_data.card.expiry_month",
+                                        "expiry_month",
+                                        enums::FieldType::UserCardExpiryMonth,
+                                        Some("^(0?[1-9]|1[0-2])$"),
+                                    ),
+                                    payout_required_field(
+                                        "payout_method_data.card.expiry_year",
+                                        "expiry_year",
+                                        enums::FieldType::UserCardExpiryYear,
+                                        Some("^([0-9]{2}|[0-9]{4})$"),
+                                    ),
+                                    payout_required_field(
+                                        "payout_method_data.card.card_holder_name",
+                                        "card_holder_name",
+                                        enums::FieldType::UserFullName,
+                                        None,
+                                    ),
+                                ]),
+                            },
+                        )]),
+                    },
+                )])),
+            ),
+            (
+                enums::PayoutType::Bank,
+                PayoutMethodTypeRequiredFields(HashMap::from([
+                    (
+                        enums::PaymentMethodType::Ach,
+                        PayoutConnectorFields {
+                            fields: HashMap::from([(
+                                enums::PayoutConnectors::Wise,
+                                PayoutRequiredFieldFinal {
+                                    countries: Some(HashSet::from([enums::CountryAlpha2::US])),
+                                    currencies: Some(HashSet::from([enums::Currency::USD])),
+                                    fields: HashMap::from_iter(
+                                        [
+                                            payout_required_field(
+                                                "payout_method_data.bank.bank_account_number",
+                                                "bank_account_number",
+                                                enums::FieldType::Text,
+                                                Some("^[0-9]{4,17}$"),
+                                            ),
+                                            payout_required_field(
+                                                "payout_method_data.bank.bank_routing_number",
+                                                "bank_routing_number",
+                                                enums::FieldType::Text,
+                                                Some("^[0-9]{9}$"),
+                                            ),
+                                        ]
+                                        .into_iter()
+                                        .chain(payout_billing_address_fields()),
+                                    ),
+                                },
+                            )]),
+                        },
+                    ),
+                    (
+                        enums::PaymentMethodType::Bacs,
+                        PayoutConnectorFields {
+                            fields: HashMap::from([(
+                                enums::PayoutConnectors::Wise,
+                                PayoutRequiredFieldFinal {
+                                    countries: Some(HashSet::from([enums::CountryAlpha2::GB])),
+                                    currencies: Some(HashSet::from([enums::Currency::GBP])),
+                                    fields: HashMap::from_iter(
+                                        [
+                                            payout_required_field(
+                                                "payout_method_data.bank.bank_account_number",
+                                                "bank_account_number",
+                                                enums::FieldType::Text,
+                                                Some("^[0-9]{8}$"),
+                                            ),
+                                            payout_required_field(
+                                                "payout_method_data.bank.bank_sort_code",
+                                                "bank_sort_code",
+                                                enums::FieldType::Text,
+                                                Some("^[0-9]{6}$"),
+                                            ),
+                                        ]
+                                        .into_iter()
+                                        .chain(payout_billing_address_fields()),
+                                    ),
+                                },
+                            )]),
+                        },
+                    ),
+                    (
+                        enums::PaymentMethodType::Sepa,
+                        PayoutConnectorFields {
+                            fields: HashMap::from([
+                                (
+                                    enums::PayoutConnectors::Adyen,
+                                    PayoutRequiredFieldFinal {
+                                        countries: None,
+                                        currencies: Some(HashSet::from([enums::Currency::EUR])),
+                                        fields: HashMap::from_iter(
+                                            [iban.clone()]
+                                                .into_iter()
+                                                .chain(payout_billing_name_fields()),
+                                        ),
+                                    },
+                                ),
+                                (
+                                    enums::PayoutConnectors::Wise,
+                                    PayoutRequiredFieldFinal {
+                                        countries: None,
+                                        currencies: Some(HashSet::from([enums::Currency::EUR])),
+                                        fields: HashMap::from_iter(
+                                            [iban]
+                                                .into_iter()
+                                                .chain(payout_billing_address_fields()),
+                                        ),
+                                    },
+                                ),
+                            ]),
+                        },
+                    ),
+                ])),
+            ),
+            (
+                enums::PayoutType::Wallet,
+                PayoutMethodTypeRequiredFields(HashMap::from([(
+                    enums::PaymentMethodType::Paypal,
+                    PayoutConnectorFields {
+                        fields: HashMap::from([(
+                            enums::PayoutConnectors::Adyen,
+                            PayoutRequiredFieldFinal {
+                                countries: None,
+                                currencies: None,
+                                fields: HashMap::from_iter(
+                                    [payout_required_field(
+                                        "payout_method_data.wallet.paypal.email",
+                                        "email",
+                                        enums::FieldType::UserEmailAddress,
+                                        None,
+                                    )]
+                                    .into_iter()
+                                    .chain(payout_billing_name_fields()),
+                                ),
+                            },
+                        )]),
+                    },
+                )])),
+            ),
+        ]))
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -1171,24 +1437,6 @@ impl Default for super::settings::RequiredFields {
                                                     value: None,
                                                 }
                                             ),
-                                            (
-                                                "billing.address.first_name".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.first_name".to_string(),
-                                                     display_name: "card_holder_name".to_string(),
-                                                    field_type: enums::FieldType::UserFullName,
-                                                    value: None,
-                                                }
-                                            ),
-                                            (
-                                                "billing.address.last_name".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.last_name".to_string(),
-                                                     display_name: "card_holder_name".to_string(),
-                                                    field_type: enums::FieldType::UserFullName,
-                                                    value: None,
-                                                }
-                                            ),
                                             (
                                                 "billing.address.zip".to_string(),
                                                 RequiredFieldInfo {
@@ -1198,15 +1446,6 @@ impl Default for super::settings::RequiredFields {
                                                     value: None,
                                                 }
                                             ),
-                                            (
-                                                "billing.address.line1".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.line1".to_string(),
-                                                    display_name: "line1".to_string(),
-                                                    field_type: enums::FieldType::UserAddressLine1,
-                                                    value: None,
-                                                }
-                                            ),
                                         ]
                                     ),
                                     common: HashMap::new(),
@@ -3274,24 +3513,6 @@ impl Default for super::settings::RequiredFields {
                                                     value: None,
                                                 }
                                             ),
-                                            (
-                                                "billing.address.first_name".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.first_name".to_string(),
-                                                    display_name: "card_holder_name".to_string(),
-                                                    field_type: enums::FieldType::UserFullName,
-                                                    value: None,
-                                                }
-                                            ),
-                                            (
-                                                "billing.address.last_name".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.last_name".to_string(),
-                                                     display_name: "card_holder_name".to_string(),
-                                                    field_type: enums::FieldType::UserFullName,
-                                                    value: None,
-                                                }
-                                            ),
                                             (
                                                 "billing.address.zip".to_string(),
                                                 RequiredFieldInfo {
@@ -3301,15 +3522,6 @@ impl Default for super::settings::RequiredFields {
                                                     value: None,
                                                 }
                                             ),
-                                            (
-                                                "billing.address.line1".to_string(),
-                                                RequiredFieldInfo {
-                                                    required_field: "payment_method_data.billing.address.line1".to_string(),
-                                                    display_name: "line1".to_string(),
-                                                    field_type: enums::FieldType::UserAddressLine1,
-                                                    value: None,
-                                                }
-                                            ),
                                         ]
                                     ),
                                     common: HashMap::new(),
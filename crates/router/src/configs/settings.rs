@@ -228,6 +228,9 @@ pub struct DummyConnector {
     pub refund_tolerance: u64,
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
+    pub payout_ttl: i64,
+    pub payout_duration: u64,
+    pub payout_tolerance: u64,
     pub authorize_ttl: i64,
     pub assets_base_url: String,
     pub default_return_url: String,
@@ -506,7 +509,7 @@ pub struct Connectors {
     pub globalpay: ConnectorParams,
     pub globepay: ConnectorParams,
     pub gocardless: ConnectorParams,
-    pub helcim: ConnectorParams,
+    pub helcim: ConnectorParamsWithFlowTimeouts,
     pub iatapay: ConnectorParams,
     pub klarna: ConnectorParams,
     pub mollie: ConnectorParams,
@@ -550,6 +553,58 @@ pub struct ConnectorParams {
     pub secondary_base_url: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithFlowTimeouts {
+    pub base_url: String,
+    pub secondary_base_url: Option<String>,
+    pub timeouts: ConnectorFlowTimeouts,
+    pub sync_retries: ConnectorSyncRetries,
+}
+
+/// Timeouts (in seconds) for the requests made to a connector in each flow, the API client
+/// request timeout is used for flows without one
+#[derive(Debug, Deserialize, Clone, Copy, Default, router_derive::ConfigValidate)]
+#[serde(default)]
+pub struct ConnectorFlowTimeouts {
+    pub authorize: Option<u64>,
+    pub capture: Option<u64>,
+    pub void: Option<u64>,
+    pub refund: Option<u64>,
+    pub sync: Option<u64>,
+}
+
+/// Retries of the sync requests made to a connector on transient failures, the delay between the
+/// attempts doubles from `base_delay_ms` up to `max_delay_ms` and is jittered
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct ConnectorSyncRetries {
+    /// Attempts made in total, `1` disables the retries
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for ConnectorSyncRetries {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 2000,
+        }
+    }
+}
+
+impl From<ConnectorSyncRetries> for common_utils::request::RetryPolicy {
+    fn from(sync_retries: ConnectorSyncRetries) -> Self {
+        Self {
+            max_attempts: sync_retries.max_attempts,
+            base_delay_ms: sync_retries.base_delay_ms,
+            max_delay_ms: sync_retries.max_delay_ms,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
 #[serde(default)]
 pub struct ConnectorParamsWithModeType {
@@ -742,9 +797,49 @@ impl Settings<SecuredSecret> {
 }
 
 #[cfg(feature = "payouts")]
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
 pub struct Payouts {
     pub payout_eligibility: bool,
+    /// Maximum number of days into the future for which a payout can be scheduled
+    pub max_schedule_horizon_in_days: i64,
+    /// Number of seconds for which an FX quote fetched for a cross currency payout is valid
+    pub fx_quote_validity_in_seconds: i64,
+    /// Countries to which payouts are blocked, unless overridden in the business profile
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub blocked_countries: HashSet<api_models::enums::CountryAlpha2>,
+    /// Fields required by payout connectors for each payout method, advertised to clients and
+    /// validated before a payout is made through the connector
+    pub required_fields: PayoutRequiredFields,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+pub struct PayoutRequiredFields(pub HashMap<enums::PayoutType, PayoutMethodTypeRequiredFields>);
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+pub struct PayoutMethodTypeRequiredFields(
+    pub HashMap<enums::PaymentMethodType, PayoutConnectorFields>,
+);
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+pub struct PayoutConnectorFields {
+    pub fields: HashMap<enums::PayoutConnectors, PayoutRequiredFieldFinal>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Default, Deserialize, Clone)]
+#[serde(default)]
+pub struct PayoutRequiredFieldFinal {
+    /// Countries the connector supports the payout method in, any country when not set
+    #[serde(deserialize_with = "deserialize_optional_hashset")]
+    pub countries: Option<HashSet<enums::CountryAlpha2>>,
+    /// Currencies the connector supports the payout method in, any currency when not set
+    #[serde(deserialize_with = "deserialize_optional_hashset")]
+    pub currencies: Option<HashSet<enums::Currency>>,
+    pub fields: HashMap<String, api_models::payouts::PayoutRequiredFieldInfo>,
 }
 
 #[derive(Debug, Clone, Default)]
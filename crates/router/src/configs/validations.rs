@@ -180,3 +180,38 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+/// Not derived, so that errors of the sync retries name the connector they are configured for
+impl super::settings::ConnectorParamsWithFlowTimeouts {
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "base_url must not be empty for {parent_field}"
+            )))
+        })?;
+
+        self.timeouts.validate("timeouts")?;
+        self.sync_retries
+            .validate(&format!("connectors.{parent_field}.sync_retries"))
+    }
+}
+
+impl super::settings::ConnectorSyncRetries {
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "max_attempts must not be 0 for {parent_field}"
+            )))
+        })?;
+
+        when(self.base_delay_ms > self.max_delay_ms, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "base_delay_ms must not be greater than max_delay_ms for {parent_field}"
+            )))
+        })
+    }
+}
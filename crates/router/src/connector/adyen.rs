@@ -667,7 +667,9 @@ impl
 
         let is_multiple_capture_sync = match data.request.sync_type {
             types::SyncRequestType::MultipleCaptureSync(_) => true,
-            types::SyncRequestType::SinglePaymentSync => false,
+            types::SyncRequestType::DateRangeSync(_)
+            | types::SyncRequestType::ReferenceSync
+            | types::SyncRequestType::SinglePaymentSync => false,
         };
         types::RouterData::try_from((
             types::ResponseRouterData {
@@ -1068,8 +1070,35 @@ impl
     }
 }
 
+#[cfg(not(feature = "payouts"))]
 impl api::Payouts for Adyen {}
 #[cfg(feature = "payouts")]
+impl api::Payouts for Adyen {
+    // The payment methods of the merchant account are listed, which fails unless both the API key
+    // and the merchant account are valid
+    fn build_payout_credentials_verification_request(
+        &self,
+        connector_auth_type: &types::ConnectorAuthType,
+        connector_meta_data: &Option<common_utils::pii::SecretSerdeValue>,
+        test_mode: Option<bool>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let endpoint =
+            build_env_specific_endpoint(self.base_url(connectors), test_mode, connector_meta_data)?;
+        let connector_req = adyen::AdyenPaymentMethodsRequest::try_from(connector_auth_type)?;
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&format!("{}{}/paymentMethods", endpoint, ADYEN_API_VERSION))
+            .attach_default_headers()
+            .header(headers::CONTENT_TYPE, self.common_get_content_type())
+            .headers(self.get_auth_header(connector_auth_type)?)
+            .set_body(RequestContent::Json(Box::new(connector_req)))
+            .build();
+
+        Ok(Some(request))
+    }
+}
+#[cfg(feature = "payouts")]
 impl api::PayoutCancel for Adyen {}
 #[cfg(feature = "payouts")]
 impl api::PayoutCreate for Adyen {}
@@ -4523,6 +4523,25 @@ pub struct AdyenPayoutCancelRequest {
     merchant_account: Secret<String>,
 }
 
+// Lists the payment methods of the merchant account, only to verify the credentials of the account
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentMethodsRequest {
+    merchant_account: Secret<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl TryFrom<&types::ConnectorAuthType> for AdyenPaymentMethodsRequest {
+    type Error = Error;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        let auth = AdyenAuthType::try_from(auth_type)?;
+        Ok(Self {
+            merchant_account: auth.merchant_account,
+        })
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl TryFrom<&PayoutMethodData> for PayoutCardDetails {
     type Error = Error;
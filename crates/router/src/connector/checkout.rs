@@ -411,7 +411,9 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
     ) -> CustomResult<String, errors::ConnectorError> {
         let suffix = match req.request.sync_type {
             types::SyncRequestType::MultipleCaptureSync(_) => "/actions",
-            types::SyncRequestType::SinglePaymentSync => "",
+            types::SyncRequestType::DateRangeSync(_)
+            | types::SyncRequestType::ReferenceSync
+            | types::SyncRequestType::SinglePaymentSync => "",
         };
         Ok(format!(
             "{}{}{}{}",
@@ -466,7 +468,9 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
                 })
                 .change_context(errors::ConnectorError::ResponseHandlingFailed)
             }
-            types::SyncRequestType::SinglePaymentSync => {
+            types::SyncRequestType::DateRangeSync(_)
+            | types::SyncRequestType::ReferenceSync
+            | types::SyncRequestType::SinglePaymentSync => {
                 let response: checkout::PaymentsResponse = res
                     .response
                     .parse_struct("PaymentsResponse")
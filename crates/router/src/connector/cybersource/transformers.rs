@@ -2340,6 +2340,8 @@ impl From<&ClientProcessorInformation> for types::AdditionalPaymentMethodConnect
         Self::Card {
             authentication_data: None,
             payment_checks,
+            card_network: None,
+            card_type: None,
         }
     }
 }
@@ -2,6 +2,8 @@ pub mod transformers;
 
 use std::fmt::Debug;
 
+#[cfg(feature = "payouts")]
+use common_utils::ext_traits::ByteSliceExt;
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
@@ -41,6 +43,23 @@ impl<const T: u8> api::Refund for DummyConnector<T> {}
 impl<const T: u8> api::RefundExecute for DummyConnector<T> {}
 impl<const T: u8> api::RefundSync for DummyConnector<T> {}
 impl<const T: u8> api::PaymentToken for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutCreate for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutFulfill for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutCancel for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutSync for DummyConnector<T> {}
+
+#[cfg(not(feature = "payouts"))]
+impl<const T: u8> api::Payouts for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::Payouts for DummyConnector<T> {
+    fn supports_payout_sync(&self) -> bool {
+        true
+    }
+}
 
 impl<const T: u8>
     ConnectorIntegration<
@@ -588,8 +607,360 @@ impl<const T: u8> ConnectorIntegration<api::RSync, types::RefundsData, types::Re
     }
 }
 
+#[cfg(feature = "payouts")]
+impl<const T: u8>
+    ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/payout", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = transformers::DummyConnectorPayoutRequest::<T>::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .set_body(types::PayoutCreateType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("transformers PayoutCreateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8>
+    ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match get_connector_payout_id(req) {
+            Some(payout_id) => Ok(format!(
+                "{}/payouts/{}/fulfill",
+                self.base_url(connectors),
+                payout_id
+            )),
+            None => Ok(format!("{}/payout", self.base_url(connectors))),
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        // Instant payouts are not created beforehand, so they are created and fulfilled at once
+        let connector_req = transformers::DummyConnectorPayoutRequest::<T> {
+            fulfill: true,
+            ..transformers::DummyConnectorPayoutRequest::<T>::try_from(req)?
+        };
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let mut request_builder = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutFulfillType::get_headers(
+                self, req, connectors,
+            )?);
+        if get_connector_payout_id(req).is_none() {
+            request_builder = request_builder.set_body(types::PayoutFulfillType::get_request_body(
+                self, req, connectors,
+            )?);
+        }
+        Ok(Some(request_builder.build()))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("transformers PayoutFulfillResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8>
+    ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let payout_id =
+            get_connector_payout_id(req).ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            })?;
+        Ok(format!(
+            "{}/payouts/{}/cancel",
+            self.base_url(connectors),
+            payout_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("transformers PayoutCancelResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8> ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let payout_id =
+            get_connector_payout_id(req).ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            })?;
+        Ok(format!(
+            "{}/payouts/{}",
+            self.base_url(connectors),
+            payout_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PayoutSyncType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoSync>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("transformers PayoutSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+// Payouts which are fulfilled instantly are stored with an empty connector payout id
+#[cfg(feature = "payouts")]
+fn get_connector_payout_id<F>(req: &types::PayoutsRouterData<F>) -> Option<&str> {
+    req.request
+        .connector_payout_id
+        .as_deref()
+        .filter(|payout_id| !payout_id.is_empty())
+}
+
 #[async_trait::async_trait]
 impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
+    #[cfg(feature = "payouts")]
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        let webhook: transformers::DummyConnectorPayoutWebhook = request
+            .body
+            .parse_struct("DummyConnectorPayoutWebhook")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(api::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::PayoutId(webhook.reference),
+        ))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_object_reference_id(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -597,6 +968,19 @@ impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 
+    #[cfg(feature = "payouts")]
+    fn get_webhook_event_type(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let webhook: transformers::DummyConnectorPayoutWebhook = request
+            .body
+            .parse_struct("DummyConnectorPayoutWebhook")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(&webhook))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_event_type(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -604,6 +988,19 @@ impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
         Ok(api::IncomingWebhookEvent::EventNotSupported)
     }
 
+    #[cfg(feature = "payouts")]
+    fn get_webhook_resource_object(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        let webhook: transformers::DummyConnectorPayoutWebhook = request
+            .body
+            .parse_struct("DummyConnectorPayoutWebhook")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook))
+    }
+
+    #[cfg(not(feature = "payouts"))]
     fn get_webhook_resource_object(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -362,6 +362,117 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+// PAYOUTS :
+// Type definition for PayoutRequest
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPayoutRequest<const T: u8> {
+    pub amount: i64,
+    pub currency: Currency,
+    pub payout_type: enums::PayoutType,
+    pub iban: Option<Secret<String>>,
+    pub connector: DummyConnectors,
+    pub fulfill: bool,
+    pub reference: String,
+    pub webhook_url: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8, F> TryFrom<&types::PayoutsRouterData<F>> for DummyConnectorPayoutRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let iban = match item.payout_method_data {
+            Some(api::PayoutMethodData::Bank(api::BankPayout::Sepa(ref bank))) => {
+                Some(bank.iban.clone())
+            }
+            _ => None,
+        };
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.destination_currency,
+            payout_type: item.request.payout_type,
+            iban,
+            connector: Into::<DummyConnectors>::into(T),
+            fulfill: false,
+            reference: item.request.payout_id.clone(),
+            webhook_url: item.request.webhook_url.clone(),
+        })
+    }
+}
+
+// Type definition for PayoutResponse
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutStatus {
+    Created,
+    Pending,
+    Succeeded,
+    Cancelled,
+}
+
+#[cfg(feature = "payouts")]
+impl From<DummyConnectorPayoutStatus> for enums::PayoutStatus {
+    fn from(item: DummyConnectorPayoutStatus) -> Self {
+        match item {
+            DummyConnectorPayoutStatus::Created => Self::RequiresFulfillment,
+            DummyConnectorPayoutStatus::Pending => Self::Pending,
+            DummyConnectorPayoutStatus::Succeeded => Self::Success,
+            DummyConnectorPayoutStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutResponse {
+    status: DummyConnectorPayoutStatus,
+    id: String,
+    amount: i64,
+    currency: Currency,
+    created: String,
+    payout_type: enums::PayoutType,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(item.response.status)),
+                connector_payout_id: item.response.id,
+                payout_eligible: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Type definition for payout webhooks
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutWebhook {
+    pub reference: String,
+    pub payout: DummyConnectorPayoutResponse,
+}
+
+#[cfg(feature = "payouts")]
+impl From<&DummyConnectorPayoutWebhook> for api::IncomingWebhookEvent {
+    fn from(webhook: &DummyConnectorPayoutWebhook) -> Self {
+        match webhook.payout.status {
+            DummyConnectorPayoutStatus::Pending => Self::PayoutProcessing,
+            DummyConnectorPayoutStatus::Succeeded => Self::PayoutSuccess,
+            DummyConnectorPayoutStatus::Cancelled => Self::PayoutCancelled,
+            DummyConnectorPayoutStatus::Created => Self::EventNotSupported,
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
@@ -529,7 +529,9 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
 
         let is_multiple_capture_sync = match data.request.sync_type {
             types::SyncRequestType::MultipleCaptureSync(_) => true,
-            types::SyncRequestType::SinglePaymentSync => false,
+            types::SyncRequestType::DateRangeSync(_)
+            | types::SyncRequestType::ReferenceSync
+            | types::SyncRequestType::SinglePaymentSync => false,
         };
         types::RouterData::try_from((
             types::ResponseRouterData {
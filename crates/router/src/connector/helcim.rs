@@ -1,20 +1,21 @@
 pub mod transformers;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, Secret};
 use transformers as helcim;
 
-use super::utils::{to_connector_meta, PaymentsAuthorizeRequestData};
+use super::utils::PaymentsAuthorizeRequestData;
 use crate::{
     configs::settings,
     consts::NO_ERROR_CODE,
     core::errors::{self, CustomResult},
     events::connector_api_logs::ConnectorEvent,
     headers,
+    routes::metrics,
     services::{
         self,
         request::{self, Mask},
@@ -23,7 +24,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon, ConnectorCommonExt},
-        ErrorResponse, Response,
+        domain, ErrorResponse, Response,
     },
     utils::{self, BytesExt},
 };
@@ -31,6 +32,12 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Helcim;
 
+impl Helcim {
+    /// Helcim expects amounts in the base unit of the currency, e.g. dollars for USD, unless the
+    /// account is configured for the v2 API in the connector metadata
+    pub const CURRENCY_UNIT: api::CurrencyUnit = api::CurrencyUnit::Base;
+}
+
 impl api::Payment for Helcim {}
 impl api::PaymentSession for Helcim {}
 impl api::ConnectorAccessToken for Helcim {}
@@ -38,20 +45,63 @@ impl api::MandateSetup for Helcim {}
 impl api::PaymentAuthorize for Helcim {}
 impl api::PaymentSync for Helcim {}
 impl api::PaymentCapture for Helcim {}
+impl api::PaymentIncrementalAuthorization for Helcim {}
 impl api::PaymentVoid for Helcim {}
 impl api::Refund for Helcim {}
 impl api::RefundExecute for Helcim {}
 impl api::RefundSync for Helcim {}
 impl api::PaymentToken for Helcim {}
+impl api::ConnectorCustomer for Helcim {}
+impl api::ConnectorMandateRevoke for Helcim {}
 
 impl Helcim {
     pub fn connector_transaction_id(
         &self,
         connector_meta: &Option<serde_json::Value>,
+        connector_transaction_id: Option<&str>,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
-        let meta: helcim::HelcimMetaData = to_connector_meta(connector_meta.clone())?;
+        let meta = helcim::HelcimMetaData::try_from_connector_metadata(
+            connector_meta,
+            connector_transaction_id,
+        )?;
         Ok(Some(meta.preauth_transaction_id.to_string()))
     }
+
+    //The time Helcim took to respond is measured around the dispatch of the request and handed
+    //over in the connector event. It is recorded per flow, and responses slower than the threshold
+    //are logged as a warning. Error responses carry no connector metadata, so the default
+    //threshold applies to them.
+    fn record_response_time(
+        &self,
+        flow: &'static str,
+        event_builder: Option<&ConnectorEvent>,
+        connector_meta_data: Option<&Secret<serde_json::Value>>,
+    ) {
+        let Some(latency) = event_builder
+            .map(ConnectorEvent::get_latency)
+            .map(|latency| u64::try_from(latency).unwrap_or(u64::MAX))
+        else {
+            return;
+        };
+        metrics::CONNECTOR_REQUEST_TIME.record(
+            &metrics::CONTEXT,
+            Duration::from_millis(latency).as_secs_f64(),
+            &[
+                metrics::request::add_attributes("connector", "helcim"),
+                metrics::request::add_attributes("flow", flow),
+            ],
+        );
+        let threshold = helcim::get_slow_request_threshold_ms(connector_meta_data);
+        if latency > threshold {
+            router_env::logger::warn!(
+                connector = "helcim",
+                flow,
+                latency_ms = latency,
+                threshold_ms = threshold,
+                "Helcim responded slower than the threshold"
+            );
+        }
+    }
 }
 
 impl
@@ -98,7 +148,7 @@ impl ConnectorCommon for Helcim {
     }
 
     fn get_currency_unit(&self) -> api::CurrencyUnit {
-        api::CurrencyUnit::Base
+        Self::CURRENCY_UNIT
     }
 
     fn common_get_content_type(&self) -> &'static str {
@@ -115,10 +165,17 @@ impl ConnectorCommon for Helcim {
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
         let auth = helcim::HelcimAuthType::try_from(auth_type)
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
-        Ok(vec![(
+        let mut auth_header = vec![(
             headers::API_TOKEN.to_string(),
             auth.api_key.expose().into_masked(),
-        )])
+        )];
+        if let Some(account_id) = auth.account_id {
+            auth_header.push((
+                headers::ACCOUNT_ID.to_string(),
+                account_id.expose().into_masked(),
+            ));
+        }
+        Ok(auth_header)
     }
 
     fn build_error_response(
@@ -126,25 +183,64 @@ impl ConnectorCommon for Helcim {
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
-        let response: helcim::HelcimErrorResponse = res
-            .response
-            .parse_struct("HelcimErrorResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let retry_after = helcim::get_retry_after(res.headers.as_ref());
+        if res.status_code == 429 {
+            router_env::logger::info!(connector_response=?res.response, ?retry_after);
+            return Err(report!(errors::ConnectorError::RateLimitReceived {
+                retry_after
+            }));
+        }
+
+        let response =
+            helcim::HelcimErrorResponse::from_response_body(&res.response, res.status_code);
 
         event_builder.map(|i| i.set_error_response_body(&response));
         router_env::logger::info!(connector_response=?response);
 
-        let error_string = match response {
-            transformers::HelcimErrorResponse::Payment(response) => match response.errors {
-                transformers::HelcimErrorTypes::StringType(error) => error,
-                transformers::HelcimErrorTypes::JsonType(error) => error.to_string(),
-            },
-            transformers::HelcimErrorResponse::General(error_string) => error_string,
-        };
+        if response.is_rate_limited() {
+            return Err(report!(errors::ConnectorError::RateLimitReceived {
+                retry_after
+            }));
+        }
+
+        let error_string = response.get_error_message();
+
+        if response.is_card_token_expired() {
+            return Ok(ErrorResponse {
+                status_code: res.status_code,
+                code: helcim::HELCIM_CARD_TOKEN_EXPIRED_CODE.to_owned(),
+                message: helcim::HELCIM_CARD_TOKEN_EXPIRED_MESSAGE.to_owned(),
+                reason: Some(error_string),
+                attempt_status: None,
+                connector_transaction_id: None,
+            });
+        }
+
+        // Errors of Helcim's gateway are not declines of the payment, so they can be retried
+        if res.status_code >= 500 {
+            router_env::logger::info!("Helcim gateway error");
+            return Ok(ErrorResponse {
+                status_code: res.status_code,
+                code: helcim::HELCIM_GATEWAY_ERROR_CODE.to_owned(),
+                message: helcim::HELCIM_GATEWAY_ERROR_MESSAGE.to_owned(),
+                reason: Some(error_string),
+                attempt_status: None,
+                connector_transaction_id: None,
+            });
+        }
+
+        // The normalized decline category is surfaced as the error code so that retries can be
+        // configured for it, irrespective of how Helcim words the decline
+        let decline_category = response.get_decline_category();
+        if let Some(decline_category) = decline_category {
+            router_env::logger::info!(%decline_category, "Helcim decline");
+        }
 
         Ok(ErrorResponse {
             status_code: res.status_code,
-            code: NO_ERROR_CODE.to_owned(),
+            code: decline_category
+                .map(|decline_category| decline_category.to_string())
+                .unwrap_or(NO_ERROR_CODE.to_owned()),
             message: error_string.clone(),
             reason: Some(error_string),
             attempt_status: None,
@@ -157,18 +253,74 @@ impl ConnectorValidation for Helcim {
     fn validate_capture_method(
         &self,
         capture_method: Option<enums::CaptureMethod>,
-        _pmt: Option<enums::PaymentMethodType>,
+        pmt: Option<enums::PaymentMethodType>,
     ) -> CustomResult<(), errors::ConnectorError> {
         let capture_method = capture_method.unwrap_or_default();
         match capture_method {
-            enums::CaptureMethod::Automatic | enums::CaptureMethod::Manual => Ok(()),
+            enums::CaptureMethod::Automatic => Ok(()),
+            enums::CaptureMethod::Manual => match pmt {
+                Some(enums::PaymentMethodType::Ach) => Err(
+                    super::utils::construct_not_supported_error_report(capture_method, self.id()),
+                ),
+                _ => Ok(()),
+            },
             enums::CaptureMethod::ManualMultiple | enums::CaptureMethod::Scheduled => Err(
                 super::utils::construct_not_supported_error_report(capture_method, self.id()),
             ),
         }
     }
+
+    fn get_connector_specifications(&self) -> Option<api::ConnectorSpecifications> {
+        Some(api::ConnectorSpecifications {
+            supported_payment_methods: HELCIM_SUPPORTED_PAYMENT_METHODS,
+            supported_capture_methods: &[
+                enums::CaptureMethod::Automatic,
+                enums::CaptureMethod::Manual,
+            ],
+            supported_countries: &[enums::CountryAlpha2::US, enums::CountryAlpha2::CA],
+            supported_currencies: &[enums::Currency::USD, enums::Currency::CAD],
+            minimum_amount: None,
+            maximum_amount: None,
+            // 3DS results of an external authentication are passed through, Helcim does not
+            // authenticate payments itself
+            supports_three_ds: false,
+            supports_refunds: true,
+            supports_void: true,
+            supports_payment_method_token_revocation: true,
+            supports_reference_sync: true,
+        })
+    }
 }
 
+const HELCIM_CARD_NETWORKS: &[enums::CardNetwork] = &[
+    enums::CardNetwork::Visa,
+    enums::CardNetwork::Mastercard,
+    enums::CardNetwork::AmericanExpress,
+    enums::CardNetwork::Discover,
+];
+
+//Cards are saved as Helcim card tokens for recurring payments, ACH is not
+const HELCIM_SUPPORTED_PAYMENT_METHODS: &[api::SupportedPaymentMethod] = &[
+    api::SupportedPaymentMethod {
+        payment_method: enums::PaymentMethod::Card,
+        payment_method_type: enums::PaymentMethodType::Credit,
+        card_networks: HELCIM_CARD_NETWORKS,
+        supports_mandates: true,
+    },
+    api::SupportedPaymentMethod {
+        payment_method: enums::PaymentMethod::Card,
+        payment_method_type: enums::PaymentMethodType::Debit,
+        card_networks: HELCIM_CARD_NETWORKS,
+        supports_mandates: true,
+    },
+    api::SupportedPaymentMethod {
+        payment_method: enums::PaymentMethod::BankDebit,
+        payment_method_type: enums::PaymentMethodType::Ach,
+        card_networks: &[],
+        supports_mandates: false,
+    },
+];
+
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
     for Helcim
 {
@@ -180,6 +332,186 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
 {
 }
 
+impl
+    ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::PaymentsResponseData,
+    > for Helcim
+{
+    fn get_headers(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}v2/customers", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = helcim::HelcimCustomerRequest::try_from(&req.request)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorCustomerType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::ConnectorCustomerType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::ConnectorCustomerType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorCustomerRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::ConnectorCustomerRouterData, errors::ConnectorError>
+    where
+        types::PaymentsResponseData: Clone,
+    {
+        let response: helcim::HelcimCustomerResponse = res
+            .response
+            .parse_struct("HelcimCustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+//Revoking the mandate deletes the card token saved against the Helcim customer
+impl
+    ConnectorIntegration<
+        api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > for Helcim
+{
+    fn get_headers(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_http_method(&self) -> services::Method {
+        services::Method::Delete
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}v2/customers/cards", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = helcim::HelcimDeleteCardRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::MandateRevokeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Delete)
+                .url(&types::MandateRevokeType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::MandateRevokeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::MandateRevokeType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::MandateRevokeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::MandateRevokeRouterData, errors::ConnectorError> {
+        //The body of a successful deletion carries nothing of interest, it is only logged
+        let response = String::from_utf8_lossy(&res.response).to_string();
+        event_builder.map(|i| i.set_response_body(&serde_json::json!({ "response": response })));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::MandateRevokeRouterData {
+            response: Ok(types::MandateRevokeResponseData {
+                mandate_status: enums::MandateStatus::Revoked,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl
     ConnectorIntegration<
         api::SetupMandate,
@@ -213,25 +545,22 @@ impl
     }
     fn build_request(
         &self,
-        _req: &types::SetupMandateRouterData,
-        _connectors: &settings::Connectors,
+        req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
-        Err(
-            errors::ConnectorError::NotImplemented("Setup Mandate flow for Helcim".to_string())
-                .into(),
-        )
-
-        // Ok(Some(
-        //     services::RequestBuilder::new()
-        //         .method(services::Method::Post)
-        //         .url(&types::SetupMandateType::get_url(self, req, connectors)?)
-        //         .attach_default_headers()
-        //         .headers(types::SetupMandateType::get_headers(self, req, connectors)?)
-        //         .set_body(types::SetupMandateType::get_request_body(
-        //             self, req, connectors,
-        //         )?)
-        //         .build(),
-        // ))
+        //A verify authorizes the card without an amount, so it is bound by the authorize timeout
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::SetupMandateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::SetupMandateType::get_headers(self, req, connectors)?)
+                .set_body(types::SetupMandateType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .timeout_secs(connectors.helcim.timeouts.authorize)
+                .build(),
+        ))
     }
     fn handle_response(
         &self,
@@ -280,6 +609,10 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        //Bank payments are processed through the ACH api and are always auto captured
+        if let domain::PaymentMethodData::BankDebit(_) = req.request.payment_method_data {
+            return Ok(format!("{}v2/ach/purchase", self.base_url(connectors)));
+        }
         if req.request.is_auto_capture()? {
             return Ok(format!("{}v2/payment/purchase", self.base_url(connectors)));
         }
@@ -291,8 +624,12 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let currency_unit =
+            helcim::HelcimConnectorMetadataObject::try_from(req.connector_meta_data.as_ref())?
+                .api_version
+                .get_currency_unit();
         let connector_router_data = helcim::HelcimRouterData::try_from((
-            &self.get_currency_unit(),
+            &currency_unit,
             req.request.currency,
             req.request.amount,
             req,
@@ -319,6 +656,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
                 .set_body(types::PaymentsAuthorizeType::get_request_body(
                     self, req, connectors,
                 )?)
+                .timeout_secs(connectors.helcim.timeouts.authorize)
                 .build(),
         ))
     }
@@ -329,6 +667,11 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        self.record_response_time(
+            "authorize",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
         let response: helcim::HelcimPaymentsResponse = res
             .response
             .parse_struct("Helcim PaymentsAuthorizeResponse")
@@ -349,6 +692,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("authorize", event_builder.as_deref(), None);
         self.build_error_response(res, event_builder)
     }
 }
@@ -381,12 +725,39 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         req: &types::PaymentsSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        if let types::SyncRequestType::DateRangeSync(date_range) = &req.request.sync_type {
+            let query_params =
+                serde_urlencoded::to_string(helcim::HelcimDateRangeSyncRequest::from(date_range))
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+            return Ok(format!(
+                "{}v2/card-transactions?{query_params}",
+                self.base_url(connectors)
+            ));
+        }
+
+        if helcim::is_reference_sync(&req.request) {
+            let query_params =
+                serde_urlencoded::to_string(helcim::HelcimTransactionSearchRequest::from(req))
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+            return Ok(format!(
+                "{}v2/card-transactions?{query_params}",
+                self.base_url(connectors)
+            ));
+        }
+
         let connector_payment_id = req
             .request
             .connector_transaction_id
             .get_connector_transaction_id()
             .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
 
+        if req.request.payment_method_type == Some(enums::PaymentMethodType::Ach) {
+            return Ok(format!(
+                "{}v2/ach/transactions/{connector_payment_id}",
+                self.base_url(connectors)
+            ));
+        }
+
         Ok(format!(
             "{}v2/card-transactions/{connector_payment_id}",
             self.base_url(connectors)
@@ -404,6 +775,8 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
                 .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .timeout_secs(connectors.helcim.timeouts.sync)
+                .retry_policy(Some(connectors.helcim.sync_retries.into()))
                 .build(),
         ))
     }
@@ -414,6 +787,43 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        self.record_response_time(
+            "sync",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
+        if let types::SyncRequestType::DateRangeSync(_) = data.request.sync_type {
+            let response: helcim::HelcimTransactionListResponse = res
+                .response
+                .parse_struct("helcim TransactionListResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+            event_builder.map(|i| i.set_response_body(&response));
+            router_env::logger::info!(connector_response=?response);
+
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
+
+        if helcim::is_reference_sync(&data.request) {
+            let response: helcim::HelcimTransactionSearchResponse = res
+                .response
+                .parse_struct("helcim TransactionSearchResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+            event_builder.map(|i| i.set_response_body(&response));
+            router_env::logger::info!(connector_response=?response);
+
+            return types::RouterData::try_from(types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            });
+        }
+
         let response: helcim::HelcimPaymentsResponse = res
             .response
             .parse_struct("helcim PaymentsSyncResponse")
@@ -434,6 +844,7 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("sync", event_builder.as_deref(), None);
         self.build_error_response(res, event_builder)
     }
 
@@ -472,10 +883,14 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         req: &types::PaymentsCaptureRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let currency_unit =
+            helcim::HelcimConnectorMetadataObject::try_from(req.connector_meta_data.as_ref())?
+                .api_version
+                .get_currency_unit();
         let connector_router_data = helcim::HelcimRouterData::try_from((
-            &self.get_currency_unit(),
+            &currency_unit,
             req.request.currency,
-            req.request.amount_to_capture,
+            helcim::get_capture_amount(&req.request)?,
             req,
         ))?;
         let connector_req = helcim::HelcimCaptureRequest::try_from(&connector_router_data)?;
@@ -498,6 +913,7 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
                 .set_body(types::PaymentsCaptureType::get_request_body(
                     self, req, connectors,
                 )?)
+                .timeout_secs(connectors.helcim.timeouts.capture)
                 .build(),
         ))
     }
@@ -508,6 +924,11 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        self.record_response_time(
+            "capture",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
         let response: helcim::HelcimPaymentsResponse = res
             .response
             .parse_struct("Helcim PaymentsCaptureResponse")
@@ -528,16 +949,21 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("capture", event_builder.as_deref(), None);
         self.build_error_response(res, event_builder)
     }
 }
 
-impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
-    for Helcim
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Helcim
 {
     fn get_headers(
         &self,
-        req: &types::PaymentsCancelRouterData,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
         self.build_headers(req, connectors)
@@ -549,33 +975,50 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
 
     fn get_url(
         &self,
-        _req: &types::PaymentsCancelRouterData,
+        _req: &types::PaymentsIncrementalAuthorizationRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!("{}v2/payment/reverse", self.base_url(connectors)))
+        Ok(format!(
+            "{}v2/payment/preauth/increment",
+            self.base_url(connectors)
+        ))
     }
 
     fn get_request_body(
         &self,
-        req: &types::PaymentsCancelRouterData,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
-        let connector_req = helcim::HelcimVoidRequest::try_from(req)?;
+        let currency_unit =
+            helcim::HelcimConnectorMetadataObject::try_from(req.connector_meta_data.as_ref())?
+                .api_version
+                .get_currency_unit();
+        let connector_router_data = helcim::HelcimRouterData::try_from((
+            &currency_unit,
+            req.request.currency,
+            req.request.total_amount,
+            req,
+        ))?;
+        let connector_req = helcim::HelcimIncrementalAuthRequest::try_from(&connector_router_data)?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
     fn build_request(
         &self,
-        req: &types::PaymentsCancelRouterData,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
         Ok(Some(
             services::RequestBuilder::new()
                 .method(services::Method::Post)
-                .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
+                .url(&types::IncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
                 .attach_default_headers()
-                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
-                .set_body(types::PaymentsVoidType::get_request_body(
+                .headers(types::IncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::IncrementalAuthorizationType::get_request_body(
                     self, req, connectors,
                 )?)
                 .build(),
@@ -584,13 +1027,14 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
 
     fn handle_response(
         &self,
-        data: &types::PaymentsCancelRouterData,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
-    ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
         let response: helcim::HelcimPaymentsResponse = res
             .response
-            .parse_struct("HelcimPaymentsResponse")
+            .parse_struct("Helcim PaymentsIncrementalAuthorizationResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
         event_builder.map(|i| i.set_response_body(&response));
@@ -601,7 +1045,6 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
             data: data.clone(),
             http_code: res.status_code,
         })
-        .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
 
     fn get_error_response(
@@ -613,10 +1056,12 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
     }
 }
 
-impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData> for Helcim {
+impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
+    for Helcim
+{
     fn get_headers(
         &self,
-        req: &types::RefundsRouterData<api::Execute>,
+        req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
         self.build_headers(req, connectors)
@@ -628,42 +1073,153 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
 
     fn get_url(
         &self,
-        _req: &types::RefundsRouterData<api::Execute>,
+        _req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!("{}v2/payment/refund", self.base_url(connectors)))
+        Ok(format!("{}v2/payment/reverse", self.base_url(connectors)))
     }
 
     fn get_request_body(
         &self,
-        req: &types::RefundsRouterData<api::Execute>,
+        req: &types::PaymentsCancelRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
-        let connector_router_data = helcim::HelcimRouterData::try_from((
-            &self.get_currency_unit(),
-            req.request.currency,
-            req.request.refund_amount,
-            req,
-        ))?;
-        let connector_req = helcim::HelcimRefundRequest::try_from(&connector_router_data)?;
+        let connector_req = helcim::HelcimVoidRequest::try_from(req)?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
     fn build_request(
         &self,
-        req: &types::RefundsRouterData<api::Execute>,
+        req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
-        let request = services::RequestBuilder::new()
-            .method(services::Method::Post)
-            .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
-            .attach_default_headers()
-            .headers(types::RefundExecuteType::get_headers(
-                self, req, connectors,
-            )?)
-            .set_body(types::RefundExecuteType::get_request_body(
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+                .set_body(types::PaymentsVoidType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .timeout_secs(connectors.helcim.timeouts.void)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCancelRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+        self.record_response_time(
+            "void",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
+        let response: helcim::HelcimPaymentsResponse = res
+            .response
+            .parse_struct("HelcimPaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("void", event_builder.as_deref(), None);
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData> for Helcim {
+    fn get_headers(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = self.build_headers(req, connectors)?;
+        //Retries of the same refund reuse its idempotency key, so Helcim does not refund twice
+        header.retain(|(key, _)| key != headers::IDEMPOTENCY_KEY);
+        header.push((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            helcim::get_refund_idempotency_key(&req.request.refund_id)?.into_masked(),
+        ));
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        match helcim::HelcimRefundType::try_from(req)? {
+            helcim::HelcimRefundType::Refund => {
+                Ok(format!("{}v2/payment/refund", self.base_url(connectors)))
+            }
+            helcim::HelcimRefundType::Void => {
+                Ok(format!("{}v2/payment/reverse", self.base_url(connectors)))
+            }
+            helcim::HelcimRefundType::BankRefund => {
+                Ok(format!("{}v2/ach/refund", self.base_url(connectors)))
+            }
+            helcim::HelcimRefundType::BankVoid => {
+                Ok(format!("{}v2/ach/reverse", self.base_url(connectors)))
+            }
+        }
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let currency_unit =
+            helcim::HelcimConnectorMetadataObject::try_from(req.connector_meta_data.as_ref())?
+                .api_version
+                .get_currency_unit();
+        let connector_router_data = helcim::HelcimRouterData::try_from((
+            &currency_unit,
+            req.request.currency,
+            req.request.refund_amount,
+            req,
+        ))?;
+        let connector_req = helcim::HelcimRefundRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::RefundExecuteType::get_headers(
                 self, req, connectors,
             )?)
+            .set_body(types::RefundExecuteType::get_request_body(
+                self, req, connectors,
+            )?)
+            .timeout_secs(connectors.helcim.timeouts.refund)
             .build();
         Ok(Some(request))
     }
@@ -674,10 +1230,15 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::RefundsRouterData<api::Execute>, errors::ConnectorError> {
-        let response: helcim::RefundResponse =
-            res.response
-                .parse_struct("helcim RefundResponse")
-                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        self.record_response_time(
+            "refund",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
+        let response: helcim::HelcimRefundResponse = res
+            .response
+            .parse_struct("helcim HelcimRefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
@@ -694,6 +1255,7 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("refund", event_builder.as_deref(), None);
         self.build_error_response(res, event_builder)
     }
 }
@@ -730,6 +1292,19 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
             .clone()
             .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
 
+        //Refunds of bank payments are ACH transactions
+        if helcim::HelcimRefundMetaData::try_from_connector_metadata(
+            &req.request.connector_metadata,
+        )?
+        .bank_payment
+        .is_some()
+        {
+            return Ok(format!(
+                "{}v2/ach/transactions/{connector_refund_id}",
+                self.base_url(connectors)
+            ));
+        }
+
         Ok(format!(
             "{}v2/card-transactions/{connector_refund_id}",
             self.base_url(connectors)
@@ -747,6 +1322,8 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
                 .url(&types::RefundSyncType::get_url(self, req, connectors)?)
                 .attach_default_headers()
                 .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .timeout_secs(connectors.helcim.timeouts.sync)
+                .retry_policy(Some(connectors.helcim.sync_retries.into()))
                 .build(),
         ))
     }
@@ -757,6 +1334,11 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
         event_builder: Option<&mut ConnectorEvent>,
         res: Response,
     ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        self.record_response_time(
+            "refund_sync",
+            event_builder.as_deref(),
+            data.connector_meta_data.as_ref(),
+        );
         let response: helcim::RefundResponse = res
             .response
             .parse_struct("helcim RefundSyncResponse")
@@ -777,6 +1359,7 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
         res: Response,
         event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.record_response_time("refund_sync", event_builder.as_deref(), None);
         self.build_error_response(res, event_builder)
     }
 }
@@ -804,3 +1387,2581 @@ impl api::IncomingWebhook for Helcim {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{collections::HashMap, marker::PhantomData};
+
+    use masking::{PeekInterface, Secret};
+
+    use super::*;
+
+    fn get_router_data<F, Req, Res>(request: Req) -> types::RouterData<F, Req, Res> {
+        types::RouterData {
+            flow: PhantomData,
+            status: enums::AttemptStatus::Started,
+            request,
+            response: Err(ErrorResponse::default()),
+            connector: "helcim".to_string(),
+            auth_type: enums::AuthenticationType::NoThreeDs,
+            test_mode: None,
+            return_url: None,
+            attempt_id: "pay_123_1".to_string(),
+            description: None,
+            customer_id: None,
+            merchant_id: "merchant_123".to_string(),
+            reference_id: None,
+            access_token: None,
+            session_token: None,
+            payment_method: enums::PaymentMethod::Card,
+            amount_captured: None,
+            preprocessing_id: None,
+            payment_method_id: None,
+            connector_customer: None,
+            connector_auth_type: types::ConnectorAuthType::HeaderKey {
+                api_key: Secret::new("helcim_api_token".to_string()),
+            },
+            connector_meta_data: None,
+            payment_method_token: None,
+            connector_api_version: None,
+            recurring_mandate_payment_data: None,
+            payment_method_status: None,
+            connector_request_reference_id: "pay_123_1".to_string(),
+            address: types::PaymentAddress::new(None, None, None),
+            payment_id: "pay_123".to_string(),
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            payment_method_balance: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            apple_pay_flow: None,
+            frm_metadata: None,
+            refund_id: None,
+            dispute_id: None,
+            connector_response: None,
+        }
+    }
+
+    fn get_browser_info() -> Option<types::BrowserInformation> {
+        Some(types::BrowserInformation {
+            ip_address: Some(std::net::IpAddr::from([127, 0, 0, 1])),
+            ..Default::default()
+        })
+    }
+
+    fn get_mocked_response(response: serde_json::Value) -> Response {
+        Response {
+            headers: None,
+            response: response.to_string().into(),
+            status_code: 200,
+        }
+    }
+
+    fn get_request_body(request_body: RequestContent) -> serde_json::Value {
+        serde_json::from_str(request_body.get_inner_value().peek()).unwrap()
+    }
+
+    fn get_authorize_data(capture_method: enums::CaptureMethod) -> types::PaymentsAuthorizeData {
+        types::PaymentsAuthorizeData {
+            payment_method_data: domain::PaymentMethodData::Card(domain::Card::default()),
+            email: None,
+            customer_name: None,
+            amount: 1000,
+            confirm: true,
+            currency: enums::Currency::USD,
+            metadata: None,
+            mandate_id: None,
+            webhook_url: None,
+            customer_id: None,
+            off_session: None,
+            browser_info: get_browser_info(),
+            session_token: None,
+            order_details: None,
+            order_category: None,
+            capture_method: Some(capture_method),
+            enrolled_for_3ds: false,
+            router_return_url: None,
+            surcharge_details: None,
+            setup_future_usage: None,
+            payment_experience: None,
+            payment_method_type: None,
+            statement_descriptor: None,
+            setup_mandate_details: None,
+            complete_authorize_url: None,
+            related_transaction_id: None,
+            statement_descriptor_suffix: None,
+            request_incremental_authorization: false,
+            authentication_data: None,
+            customer_acceptance: None,
+        }
+    }
+
+    fn get_refunds_data(
+        connector_refund_id: Option<String>,
+        connector_metadata: Option<serde_json::Value>,
+    ) -> types::RefundsData {
+        types::RefundsData {
+            refund_id: "ref_123".to_string(),
+            connector_transaction_id: "20163746".to_string(),
+            connector_refund_id,
+            currency: enums::Currency::USD,
+            payment_amount: 1000,
+            reason: None,
+            webhook_url: None,
+            refund_amount: 400,
+            connector_metadata,
+            browser_info: get_browser_info(),
+        }
+    }
+
+    #[test]
+    fn should_hand_off_metadata_through_authorize_capture_refund_and_sync() {
+        let connectors = settings::Connectors::default();
+
+        // Authorize: the preauth transaction id is stored in the connector metadata
+        let authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Manual));
+        assert_eq!(
+            types::PaymentsAuthorizeType::get_url(&Helcim, &authorize_router_data, &connectors)
+                .unwrap(),
+            "v2/payment/preauth"
+        );
+
+        let authorize_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "preauth",
+                "invoiceNumber": "pay_123_1"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            authorize_router_data.status,
+            enums::AttemptStatus::Authorized
+        );
+        let authorize_metadata = match authorize_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                connector_metadata,
+                ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            authorize_metadata,
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "authorized_amount": 1000,
+                "surcharge_amount": 0
+            }))
+        );
+
+        // Capture: the preauth transaction id is read back from the connector metadata
+        let connector_transaction_id = Helcim
+            .connector_transaction_id(&authorize_metadata, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(connector_transaction_id, "20163745");
+
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1000,
+                currency: enums::Currency::USD,
+                connector_transaction_id,
+                payment_amount: 1000,
+                connector_meta: authorize_metadata.clone(),
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_request = get_request_body(
+            types::PaymentsCaptureType::get_request_body(
+                &Helcim,
+                &capture_router_data,
+                &connectors,
+            )
+            .unwrap(),
+        );
+        assert_eq!(capture_request["preAuthTransactionId"], 20163745);
+        assert_eq!(capture_request["amount"], 10.0);
+
+        let capture_router_data = types::PaymentsCaptureType::handle_response(
+            &Helcim,
+            &capture_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163746,
+                "status": "APPROVED",
+                "type": "capture"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(capture_router_data.status, enums::AttemptStatus::Charged);
+        assert!(matches!(
+            capture_router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(ref transaction_id),
+                ..
+            }) if transaction_id == "20163746"
+        ));
+        let capture_metadata = match capture_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            capture_metadata,
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "authorized_amount": 1000,
+                "surcharge_amount": 0,
+                "captures": [
+                    { "capture_transaction_id": 20163746, "amount": 1000, "refunded_amount": 0 }
+                ]
+            }))
+        );
+
+        // Refund: the refunded amount is accumulated in the connector metadata of the payment
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, capture_metadata));
+        assert_eq!(
+            types::RefundExecuteType::get_url(&Helcim, &refund_router_data, &connectors).unwrap(),
+            "v2/payment/refund"
+        );
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["originalTransactionId"], 20163746);
+        assert_eq!(refund_request["amount"], 4.0);
+
+        let refund_router_data = types::RefundExecuteType::handle_response(
+            &Helcim,
+            &refund_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163747,
+                "status": "APPROVED",
+                "type": "refund"
+            })),
+        )
+        .unwrap();
+
+        let refund_response = refund_router_data.response.unwrap();
+        assert_eq!(refund_response.connector_refund_id, "20163747");
+        assert_eq!(refund_response.refund_status, enums::RefundStatus::Success);
+        let refund_metadata = refund_router_data.request.connector_metadata;
+        assert_eq!(
+            helcim::HelcimRefundMetaData::try_from_connector_metadata(&refund_metadata)
+                .unwrap()
+                .refunded_amount,
+            400
+        );
+        assert_eq!(
+            Helcim
+                .connector_transaction_id(&refund_metadata, None)
+                .unwrap(),
+            Some("20163745".to_string())
+        );
+
+        // Sync: the refund is looked up by the transaction id returned on refund
+        let refund_sync_router_data: types::RefundSyncRouterData = get_router_data(
+            get_refunds_data(Some(refund_response.connector_refund_id), refund_metadata),
+        );
+        assert_eq!(
+            types::RefundSyncType::get_url(&Helcim, &refund_sync_router_data, &connectors).unwrap(),
+            "v2/card-transactions/20163747"
+        );
+
+        let refund_sync_router_data = types::RefundSyncType::handle_response(
+            &Helcim,
+            &refund_sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163747,
+                "status": "APPROVED",
+                "type": "refund"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            refund_sync_router_data.response.unwrap().refund_status,
+            enums::RefundStatus::Success
+        );
+    }
+
+    fn authorize_manually(
+        authorize_data: types::PaymentsAuthorizeData,
+    ) -> Option<serde_json::Value> {
+        let authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(authorize_data);
+        let authorize_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "preauth",
+                "invoiceNumber": "pay_123_1"
+            })),
+        )
+        .unwrap();
+        match authorize_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        }
+    }
+
+    fn get_capture_request(
+        amount_to_capture: i64,
+        connector_meta: Option<serde_json::Value>,
+    ) -> CustomResult<Option<RequestContent>, errors::ConnectorError> {
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1000,
+                connector_meta,
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        types::PaymentsCaptureType::get_request_body(
+            &Helcim,
+            &capture_router_data,
+            &settings::Connectors::default(),
+        )
+    }
+
+    fn get_authorize_request_with_card_expiry(
+        capture_method: enums::CaptureMethod,
+        card_exp_month: String,
+        card_exp_year: String,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let mut authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::Card(domain::Card {
+                    card_exp_month: Secret::new(card_exp_month),
+                    card_exp_year: Secret::new(card_exp_year),
+                    ..Default::default()
+                }),
+                ..get_authorize_data(capture_method)
+            });
+        authorize_router_data.connector_meta_data = Some(Secret::new(
+            serde_json::json!({ "card_expiry_horizon_months": 2 }),
+        ));
+        types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_router_data,
+            &settings::Connectors::default(),
+        )
+        .map(get_request_body)
+    }
+
+    #[test]
+    fn should_reject_manual_capture_of_card_expiring_within_horizon() {
+        let current_date_time = common_utils::date_time::now();
+        let card_exp_month = format!("{:02}", u8::from(current_date_time.month()));
+        let card_exp_year = current_date_time.year().to_string();
+
+        let result = get_authorize_request_with_card_expiry(
+            enums::CaptureMethod::Manual,
+            card_exp_month.clone(),
+            card_exp_year.clone(),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_expiry"
+            }
+        ));
+
+        // Automatic captures are captured along with the authorization
+        assert!(get_authorize_request_with_card_expiry(
+            enums::CaptureMethod::Automatic,
+            card_exp_month,
+            card_exp_year,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn should_accept_manual_capture_of_far_future_card() {
+        assert!(get_authorize_request_with_card_expiry(
+            enums::CaptureMethod::Manual,
+            "12".to_string(),
+            "2099".to_string(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn should_capture_up_to_the_authorized_amount() {
+        let authorize_metadata =
+            authorize_manually(get_authorize_data(enums::CaptureMethod::Manual));
+
+        // Without an amount to capture the router passes the payment amount, and the
+        // full authorized amount is captured
+        let capture_request =
+            get_request_body(get_capture_request(1000, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 10.0);
+
+        // An explicit amount below the authorized amount is captured as requested
+        let capture_request =
+            get_request_body(get_capture_request(600, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 6.0);
+
+        // Payments authorized before the amounts were recorded fall back to the payment amount
+        let capture_request = get_request_body(
+            get_capture_request(
+                1000,
+                Some(serde_json::json!({ "preauth_transaction_id": 20163745 })),
+            )
+            .unwrap(),
+        );
+        assert_eq!(capture_request["amount"], 10.0);
+
+        // Capturing more than was authorized is rejected before reaching the connector
+        let result = get_capture_request(1200, authorize_metadata);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Capture amount 1200 exceeds the authorized amount 1000"
+        ));
+    }
+
+    #[test]
+    fn should_capture_the_surcharge_authorized_with_the_payment() {
+        let authorize_metadata = authorize_manually(types::PaymentsAuthorizeData {
+            amount: 1055,
+            surcharge_details: Some(crate::core::payments::types::SurchargeDetails {
+                original_amount: 1000,
+                surcharge: common_utils::types::Surcharge::Fixed(50),
+                tax_on_surcharge: None,
+                surcharge_amount: 50,
+                tax_on_surcharge_amount: 5,
+                final_amount: 1055,
+            }),
+            ..get_authorize_data(enums::CaptureMethod::Manual)
+        });
+        assert_eq!(
+            authorize_metadata,
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "authorized_amount": 1055,
+                "surcharge_amount": 55,
+                "amount_breakdown": {
+                    "base_amount": 1000,
+                    "tip_amount": 0,
+                    "convenience_fee": 55
+                }
+            }))
+        );
+
+        let capture_request =
+            get_request_body(get_capture_request(1000, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 10.55);
+
+        // Partial captures carry the share of the surcharge for the amount captured, whether or
+        // not they are the last one
+        let capture_request =
+            get_request_body(get_capture_request(600, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 6.33);
+        let capture_request =
+            get_request_body(get_capture_request(400, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 4.22);
+
+        let result = get_capture_request(1001, authorize_metadata);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Capture amount 1056 exceeds the authorized amount 1055"
+        ));
+    }
+
+    fn get_authorize_router_data_with_tip(base_amount: i64) -> types::PaymentsAuthorizeRouterData {
+        types::RouterData {
+            address: types::PaymentAddress::new(
+                None,
+                Some(api_models::payments::Address {
+                    address: Some(api_models::payments::AddressDetails {
+                        zip: Some(Secret::new("94122".to_string())),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                None,
+            ),
+            ..get_router_data(types::PaymentsAuthorizeData {
+                amount: 1205,
+                payment_method_data: domain::PaymentMethodData::CardToken(domain::CardToken {
+                    card_holder_name: None,
+                    card_cvc: None,
+                    connector_token: Some(Secret::new("f3c0a8e1d2b9c7a6".to_string())),
+                }),
+                metadata: Some(Secret::new(serde_json::json!({
+                    "tip_amount": 150,
+                    "base_amount": base_amount
+                }))),
+                surcharge_details: Some(crate::core::payments::types::SurchargeDetails {
+                    original_amount: 1150,
+                    surcharge: common_utils::types::Surcharge::Fixed(50),
+                    tax_on_surcharge: None,
+                    surcharge_amount: 50,
+                    tax_on_surcharge_amount: 5,
+                    final_amount: 1205,
+                }),
+                ..get_authorize_data(enums::CaptureMethod::Automatic)
+            })
+        }
+    }
+
+    #[test]
+    fn should_send_tip_and_convenience_fee_separately() {
+        let connectors = settings::Connectors::default();
+        let authorize_router_data = get_authorize_router_data_with_tip(1000);
+        let authorize_request = get_request_body(
+            types::PaymentsAuthorizeType::get_request_body(
+                &Helcim,
+                &authorize_router_data,
+                &connectors,
+            )
+            .unwrap(),
+        );
+        assert_eq!(authorize_request["amount"], 12.05);
+        assert_eq!(authorize_request["tipAmount"], 1.5);
+        assert_eq!(authorize_request["convenienceFee"], 0.55);
+
+        // The breakdown is reflected in the metadata of the payment for reconciliation
+        let authorize_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "purchase"
+            })),
+        )
+        .unwrap();
+        let connector_metadata = match authorize_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            connector_metadata,
+            Some(serde_json::json!({
+                "amount_breakdown": {
+                    "base_amount": 1000,
+                    "tip_amount": 150,
+                    "convenience_fee": 55
+                }
+            }))
+        );
+
+        // Payments without a tip or a convenience fee are sent without them
+        let authorize_router_data = get_authorize_router_data_with_tip(1000);
+        let authorize_router_data = types::RouterData {
+            request: types::PaymentsAuthorizeData {
+                amount: 1000,
+                metadata: None,
+                surcharge_details: None,
+                ..authorize_router_data.request.clone()
+            },
+            ..authorize_router_data
+        };
+        let authorize_request = get_request_body(
+            types::PaymentsAuthorizeType::get_request_body(
+                &Helcim,
+                &authorize_router_data,
+                &connectors,
+            )
+            .unwrap(),
+        );
+        assert!(authorize_request.get("tipAmount").is_none());
+        assert!(authorize_request.get("convenienceFee").is_none());
+    }
+
+    #[test]
+    fn should_validate_the_amount_breakdown() {
+        let connectors = settings::Connectors::default();
+
+        // A base amount rounded off by a cent is tolerated
+        let authorize_router_data = get_authorize_router_data_with_tip(1001);
+        assert!(types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_router_data,
+            &connectors
+        )
+        .is_ok());
+
+        let authorize_router_data = get_authorize_router_data_with_tip(900);
+        let result = types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_router_data,
+            &connectors,
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Amount 1205 does not match the base amount 900, tip 150 and convenience fee 55 of the payment"
+        ));
+    }
+
+    #[test]
+    fn should_refund_against_the_capture_or_the_purchase_transaction() {
+        let connectors = settings::Connectors::default();
+
+        // Payments captured at authorization have no capture metadata and are refunded
+        // against the purchase
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, None));
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["originalTransactionId"], 20163746);
+
+        // Manually captured payments are refunded against the capture recorded in the metadata
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(
+                None,
+                Some(serde_json::json!({
+                    "preauth_transaction_id": 20163745,
+                    "capture_transaction_id": 20163760
+                })),
+            ));
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["originalTransactionId"], 20163760);
+    }
+
+    #[test]
+    fn should_store_batch_id_in_metadata_on_authorize_and_capture() {
+        // Purchase: the batch id is the only metadata of payments captured at authorization
+        let purchase_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        let purchase_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &purchase_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "purchase",
+                "batchId": 4521
+            })),
+        )
+        .unwrap();
+        let purchase_metadata = match purchase_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            purchase_metadata,
+            Some(serde_json::json!({ "batch_id": 4521 }))
+        );
+
+        // PreAuth: nothing is stored when Helcim returns no batch id
+        let preauth_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Manual));
+        let preauth_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &preauth_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "preauth"
+            })),
+        )
+        .unwrap();
+        let preauth_metadata = match preauth_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            preauth_metadata,
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "authorized_amount": 1000,
+                "surcharge_amount": 0
+            }))
+        );
+
+        // Capture: the batch id is stored alongside the preauth metadata
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1000,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1000,
+                connector_meta: preauth_metadata,
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_router_data = types::PaymentsCaptureType::handle_response(
+            &Helcim,
+            &capture_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163746,
+                "status": "APPROVED",
+                "type": "capture",
+                "batchId": 4522
+            })),
+        )
+        .unwrap();
+        let capture_metadata = match capture_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            capture_metadata,
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "authorized_amount": 1000,
+                "surcharge_amount": 0,
+                "captures": [
+                    { "capture_transaction_id": 20163746, "amount": 1000, "refunded_amount": 0 }
+                ],
+                "batch_id": 4522
+            }))
+        );
+    }
+
+    #[test]
+    fn should_keep_card_token_in_metadata_without_mandate() {
+        let connectors = settings::Connectors::default();
+
+        // Purchase: the card token and customer code are stored although no mandate is set up
+        let purchase_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        let purchase_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &purchase_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163746,
+                "status": "APPROVED",
+                "type": "purchase",
+                "cardToken": "27f2b2c8a3b7e5a1d2c9f0",
+                "customerCode": "CST1000"
+            })),
+        )
+        .unwrap();
+        let (purchase_metadata, mandate_reference) = match purchase_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata,
+                mandate_reference,
+                ..
+            }) => (connector_metadata, mandate_reference),
+            _ => (None, None),
+        };
+        assert!(mandate_reference.is_none());
+        assert_eq!(
+            purchase_metadata,
+            Some(serde_json::json!({
+                "card_token": {
+                    "card_token": "27f2b2c8a3b7e5a1d2c9f0",
+                    "customer_code": "CST1000"
+                }
+            }))
+        );
+
+        // Refund: the purchase is still refunded by its transaction id
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, purchase_metadata));
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["originalTransactionId"], 20163746);
+        assert!(refund_request.get("cardToken").is_none());
+
+        // PreAuth: the card token is stored alongside the preauth metadata
+        let preauth_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Manual));
+        let preauth_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &preauth_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "preauth",
+                "cardToken": "27f2b2c8a3b7e5a1d2c9f0"
+            })),
+        )
+        .unwrap();
+        let preauth_metadata = match preauth_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            preauth_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get("card_token")),
+            Some(&serde_json::json!({ "card_token": "27f2b2c8a3b7e5a1d2c9f0" }))
+        );
+
+        // Capture: the preauth is captured by its transaction id and the card token is carried over
+        let capture_request =
+            get_request_body(get_capture_request(1000, preauth_metadata.clone()).unwrap());
+        assert_eq!(capture_request["preAuthTransactionId"], 20163745);
+        assert!(capture_request.get("cardToken").is_none());
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1000,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1000,
+                connector_meta: preauth_metadata,
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_router_data = types::PaymentsCaptureType::handle_response(
+            &Helcim,
+            &capture_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163760,
+                "status": "APPROVED",
+                "type": "capture"
+            })),
+        )
+        .unwrap();
+        let capture_metadata = match capture_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            helcim::HelcimCardTokenMetaData::from_connector_metadata(capture_metadata.as_ref())
+                .map(|card_token| card_token.card_token.peek().to_owned()),
+            Some("27f2b2c8a3b7e5a1d2c9f0".to_string())
+        );
+
+        // Refund: the capture recorded alongside the card token is refunded
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, capture_metadata));
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["originalTransactionId"], 20163760);
+    }
+
+    fn get_refund_idempotency_key(
+        refund_router_data: &types::RefundsRouterData<api::Execute>,
+    ) -> Option<String> {
+        let connectors = settings::Connectors::default();
+        let refund_headers =
+            types::RefundExecuteType::get_headers(&Helcim, refund_router_data, &connectors)
+                .unwrap();
+        let idempotency_keys: Vec<String> = refund_headers
+            .into_iter()
+            .filter(|(key, _)| key == headers::IDEMPOTENCY_KEY)
+            .map(|(_, value)| value.into_inner())
+            .collect();
+        assert_eq!(idempotency_keys.len(), 1);
+        idempotency_keys.into_iter().next()
+    }
+
+    #[test]
+    fn should_not_refund_twice_when_a_refund_is_retried() {
+        // First attempt: the refund is processed by Helcim
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, None));
+        let idempotency_key = get_refund_idempotency_key(&refund_router_data).unwrap();
+        assert_eq!(idempotency_key.len(), 25);
+        assert!(idempotency_key.starts_with("HS_"));
+
+        let first_attempt = types::RefundExecuteType::handle_response(
+            &Helcim,
+            &refund_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163747,
+                "status": "APPROVED",
+                "type": "refund"
+            })),
+        )
+        .unwrap();
+        let first_response = first_attempt.response.unwrap();
+        assert_eq!(first_response.connector_refund_id, "20163747");
+        assert_eq!(first_response.refund_status, enums::RefundStatus::Success);
+
+        // Retried attempt: the same idempotency key is sent, and Helcim reports the refund
+        // processed by the first attempt
+        let retried_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, None));
+        assert_eq!(
+            get_refund_idempotency_key(&retried_router_data),
+            Some(idempotency_key.clone())
+        );
+
+        let retried_attempt = types::RefundExecuteType::handle_response(
+            &Helcim,
+            &retried_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163747,
+                "notice": "Transaction already exists"
+            })),
+        )
+        .unwrap();
+        let retried_response = retried_attempt.response.unwrap();
+        assert_eq!(retried_response.connector_refund_id, "20163747");
+        assert_eq!(retried_response.refund_status, enums::RefundStatus::Success);
+        assert_eq!(
+            helcim::HelcimRefundMetaData::try_from_connector_metadata(
+                &retried_attempt.request.connector_metadata
+            )
+            .unwrap()
+            .refunded_amount,
+            400
+        );
+
+        // A different refund of the same payment is sent with its own idempotency key
+        let second_refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(types::RefundsData {
+                refund_id: "ref_456".to_string(),
+                ..get_refunds_data(None, None)
+            });
+        let second_idempotency_key =
+            get_refund_idempotency_key(&second_refund_router_data).unwrap();
+        assert_eq!(second_idempotency_key.len(), 25);
+        assert_ne!(second_idempotency_key, idempotency_key);
+    }
+
+    fn get_payment_methods_enabled(
+        payment_methods_enabled: serde_json::Value,
+    ) -> Vec<api_models::admin::PaymentMethodsEnabled> {
+        serde_json::from_value(payment_methods_enabled).unwrap()
+    }
+
+    fn get_payment_method_types(
+        payment_method: &str,
+        payment_method_type: serde_json::Value,
+    ) -> Vec<api_models::admin::PaymentMethodsEnabled> {
+        let mut payment_method_type = payment_method_type;
+        for (field, default) in [
+            ("recurring_enabled", serde_json::json!(false)),
+            ("installment_payment_enabled", serde_json::json!(false)),
+        ] {
+            payment_method_type
+                .as_object_mut()
+                .unwrap()
+                .entry(field)
+                .or_insert(default);
+        }
+        get_payment_methods_enabled(serde_json::json!([{
+            "payment_method": payment_method,
+            "payment_method_types": [payment_method_type]
+        }]))
+    }
+
+    #[test]
+    fn should_accept_payment_methods_supported_by_helcim() {
+        let specifications = Helcim.get_connector_specifications().unwrap();
+
+        let payment_methods_enabled = get_payment_methods_enabled(serde_json::json!([
+            {
+                "payment_method": "card",
+                "payment_method_types": [
+                    {
+                        "payment_method_type": "credit",
+                        "card_networks": ["Visa", "Mastercard", "AmericanExpress", "Discover"],
+                        "accepted_currencies": { "type": "enable_only", "list": ["USD", "CAD"] },
+                        "accepted_countries": { "type": "enable_only", "list": ["US", "CA"] },
+                        "minimum_amount": 1,
+                        "maximum_amount": 68607706,
+                        "recurring_enabled": true,
+                        "installment_payment_enabled": false
+                    },
+                    {
+                        "payment_method_type": "debit",
+                        "accepted_currencies": { "type": "disable_only", "list": ["EUR"] },
+                        "recurring_enabled": true,
+                        "installment_payment_enabled": false
+                    }
+                ]
+            },
+            {
+                "payment_method": "bank_debit",
+                "payment_method_types": [
+                    {
+                        "payment_method_type": "ach",
+                        "recurring_enabled": false,
+                        "installment_payment_enabled": false
+                    }
+                ]
+            },
+            { "payment_method": "card" }
+        ]));
+
+        assert!(specifications
+            .validate_payment_methods_enabled("helcim", &payment_methods_enabled)
+            .is_ok());
+        assert_eq!(
+            specifications.supported_capture_methods,
+            &[
+                enums::CaptureMethod::Automatic,
+                enums::CaptureMethod::Manual
+            ]
+        );
+        assert!(specifications.supports_refunds && specifications.supports_void);
+        assert!(specifications.supports_payment_method_token_revocation);
+        assert!(specifications.supports_reference_sync);
+    }
+
+    #[test]
+    fn should_reject_payment_methods_not_supported_by_helcim() {
+        let specifications = Helcim.get_connector_specifications().unwrap();
+
+        for (payment_methods_enabled, expected_message) in [
+            (
+                get_payment_methods_enabled(serde_json::json!([{ "payment_method": "pay_later" }])),
+                "helcim does not support the payment method pay_later",
+            ),
+            (
+                get_payment_method_types(
+                    "bank_debit",
+                    serde_json::json!({ "payment_method_type": "sepa" }),
+                ),
+                "helcim does not support the payment method type sepa for the payment method bank_debit",
+            ),
+            (
+                get_payment_method_types(
+                    "card",
+                    serde_json::json!({
+                        "payment_method_type": "credit",
+                        "card_networks": ["Visa", "JCB"]
+                    }),
+                ),
+                "helcim does not support the card network JCB for the payment method type credit",
+            ),
+            (
+                get_payment_method_types(
+                    "bank_debit",
+                    serde_json::json!({
+                        "payment_method_type": "ach",
+                        "recurring_enabled": true
+                    }),
+                ),
+                "helcim does not support recurring payments for the payment method type ach",
+            ),
+            (
+                get_payment_method_types(
+                    "card",
+                    serde_json::json!({
+                        "payment_method_type": "debit",
+                        "accepted_currencies": { "type": "enable_only", "list": ["USD", "EUR"] }
+                    }),
+                ),
+                "helcim does not support the currency EUR for the payment method type debit",
+            ),
+            (
+                get_payment_method_types(
+                    "card",
+                    serde_json::json!({
+                        "payment_method_type": "credit",
+                        "accepted_countries": { "type": "enable_only", "list": ["GB"] }
+                    }),
+                ),
+                "helcim does not support the country GB for the payment method type credit",
+            ),
+            (
+                get_payment_method_types(
+                    "wallet",
+                    serde_json::json!({ "payment_method_type": "apple_pay" }),
+                ),
+                "helcim does not support the payment method wallet",
+            ),
+        ] {
+            let result = specifications
+                .validate_payment_methods_enabled("helcim", &payment_methods_enabled);
+            assert!(
+                matches!(
+                    result.unwrap_err().current_context(),
+                    errors::ApiErrorResponse::InvalidRequestData { message }
+                        if message == expected_message
+                ),
+                "{expected_message}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_wallet_payments() {
+        let apple_pay = domain::WalletData::ApplePay(domain::ApplePayWalletData {
+            payment_data: "eyJkYXRhIjoiZW5jcnlwdGVkIn0=".to_string(),
+            payment_method: domain::ApplepayPaymentMethod {
+                display_name: "Visa 1111".to_string(),
+                network: "Visa".to_string(),
+                pm_type: "debit".to_string(),
+            },
+            transaction_identifier: "transaction_identifier".to_string(),
+        });
+        //Even a token decrypted by the merchant is rejected, as its cryptogram cannot be sent to Helcim
+        let google_pay = domain::WalletData::GooglePay(domain::GooglePayWalletData {
+            pm_type: "CARD".to_string(),
+            description: "Visa 1111".to_string(),
+            info: domain::GooglePayPaymentMethodInfo {
+                card_network: "VISA".to_string(),
+                card_details: "1111".to_string(),
+            },
+            tokenization_data: domain::GpayTokenizationData {
+                token_type: "PAYMENT_GATEWAY".to_string(),
+                token: serde_json::json!({
+                    "paymentMethod": "CARD",
+                    "paymentMethodDetails": {
+                        "authMethod": "CRYPTOGRAM_3DS",
+                        "pan": "4111111111111111",
+                        "expirationMonth": 7,
+                        "expirationYear": 2028,
+                        "cryptogram": "AAAAAA8AAAAAAAAAAAAAAAAAAAA=",
+                        "eciIndicator": "05"
+                    }
+                })
+                .to_string(),
+            },
+        });
+
+        for wallet_data in [apple_pay, google_pay] {
+            let authorize_router_data: types::PaymentsAuthorizeRouterData =
+                get_router_data(types::PaymentsAuthorizeData {
+                    payment_method_data: domain::PaymentMethodData::Wallet(wallet_data),
+                    ..get_authorize_data(enums::CaptureMethod::Automatic)
+                });
+
+            let result = types::PaymentsAuthorizeType::get_request_body(
+                &Helcim,
+                &authorize_router_data,
+                &settings::Connectors::default(),
+            );
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ConnectorError::NotImplemented(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn should_apply_flow_timeouts_to_requests() {
+        let mut connectors = settings::Connectors::default();
+        connectors.helcim.timeouts = settings::ConnectorFlowTimeouts {
+            capture: Some(60),
+            refund: Some(90),
+            ..Default::default()
+        };
+        let connector_metadata = Some(serde_json::json!({ "preauth_transaction_id": 20163745 }));
+
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1000,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1000,
+                connector_meta: connector_metadata.clone(),
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_request =
+            types::PaymentsCaptureType::build_request(&Helcim, &capture_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(capture_request.timeout_secs, Some(60));
+
+        // Refunds are made against the capture of the authorization
+        let connector_metadata = Some(serde_json::json!({
+            "preauth_transaction_id": 20163745,
+            "captures": [{ "capture_transaction_id": 20163746, "amount": 1000 }]
+        }));
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, connector_metadata.clone()));
+        let refund_request =
+            types::RefundExecuteType::build_request(&Helcim, &refund_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(refund_request.timeout_secs, Some(90));
+
+        // Flows without a timeout configured fall back to the API client request timeout
+        let refund_sync_router_data: types::RefundSyncRouterData = get_router_data(
+            get_refunds_data(Some("20163747".to_string()), connector_metadata),
+        );
+        let refund_sync_request =
+            types::RefundSyncType::build_request(&Helcim, &refund_sync_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(refund_sync_request.timeout_secs, None);
+    }
+
+    #[test]
+    fn should_only_retry_sync_requests() {
+        let mut connectors = settings::Connectors::default();
+        connectors.helcim.sync_retries.max_attempts = 4;
+        let retry_policy = Some(services::RetryPolicy {
+            max_attempts: 4,
+            base_delay_ms: 200,
+            max_delay_ms: 2000,
+        });
+
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "20163745".to_string(),
+                ),
+                currency: enums::Currency::USD,
+                ..Default::default()
+            });
+        let sync_request =
+            types::PaymentsSyncType::build_request(&Helcim, &sync_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(sync_request.retry_policy, retry_policy);
+
+        let connector_metadata = Some(serde_json::json!({
+            "preauth_transaction_id": 20163745,
+            "captures": [{ "capture_transaction_id": 20163746, "amount": 1000 }]
+        }));
+        let refund_sync_router_data: types::RefundSyncRouterData = get_router_data(
+            get_refunds_data(Some("20163747".to_string()), connector_metadata.clone()),
+        );
+        let refund_sync_request =
+            types::RefundSyncType::build_request(&Helcim, &refund_sync_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(refund_sync_request.retry_policy, retry_policy);
+
+        //Requests with side effects are never retried, the connector might have processed them
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1000,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1000,
+                connector_meta: Some(serde_json::json!({ "preauth_transaction_id": 20163745 })),
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_request =
+            types::PaymentsCaptureType::build_request(&Helcim, &capture_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(capture_request.retry_policy, None);
+
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, connector_metadata));
+        let refund_request =
+            types::RefundExecuteType::build_request(&Helcim, &refund_router_data, &connectors)
+                .unwrap()
+                .unwrap();
+        assert_eq!(refund_request.retry_policy, None);
+    }
+
+    #[test]
+    fn should_map_partially_approved_purchase_to_partial_charged() {
+        let authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+
+        let fully_approved_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163748,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "pay_123_1",
+                "approvedAmount": 10.0
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            fully_approved_router_data.status,
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(fully_approved_router_data.amount_captured, None);
+
+        let partially_approved_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163749,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "pay_123_1",
+                "approvedAmount": 6.29
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            partially_approved_router_data.status,
+            enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(partially_approved_router_data.amount_captured, Some(629));
+
+        // Accounts on v2 of the API are sent, and approve, amounts in the minor unit
+        let mut authorize_router_data = authorize_router_data;
+        authorize_router_data.connector_meta_data =
+            Some(Secret::new(serde_json::json!({ "api_version": "v2" })));
+        let partially_approved_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163750,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "pay_123_1",
+                "approvedAmount": 629
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            partially_approved_router_data.status,
+            enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(partially_approved_router_data.amount_captured, Some(629));
+    }
+
+    #[test]
+    fn should_limit_captures_of_partially_approved_preauth_to_the_approved_amount() {
+        let authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Manual));
+
+        let partially_approved_router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &authorize_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "preauth",
+                "invoiceNumber": "pay_123_1",
+                "approvedAmount": 6.29
+            })),
+        )
+        .unwrap();
+
+        // Nothing is captured by the preauth, so it is authorized for the approved amount
+        assert_eq!(
+            partially_approved_router_data.status,
+            enums::AttemptStatus::Authorized
+        );
+        assert_eq!(partially_approved_router_data.amount_captured, None);
+        let authorize_metadata = match partially_approved_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        };
+        assert_eq!(
+            authorize_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get("approved_amount")),
+            Some(&serde_json::json!(629))
+        );
+
+        let capture_request =
+            get_request_body(get_capture_request(629, authorize_metadata.clone()).unwrap());
+        assert_eq!(capture_request["amount"], 6.29);
+
+        let result = get_capture_request(1000, authorize_metadata);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Capture amount 1000 exceeds the authorized amount 629"
+        ));
+    }
+
+    #[test]
+    fn should_keep_transactions_with_unknown_type_pending_on_sync() {
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "20163751".to_string(),
+                ),
+                currency: enums::Currency::USD,
+                ..Default::default()
+            });
+
+        let sync_router_data = types::PaymentsSyncType::handle_response(
+            &Helcim,
+            &sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163751,
+                "status": "APPROVED",
+                "type": "withdraw",
+                "invoiceNumber": "pay_123_1"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(sync_router_data.status, enums::AttemptStatus::Pending);
+
+        let refund_sync_router_data: types::RefundSyncRouterData =
+            get_router_data(get_refunds_data(Some("20163752".to_string()), None));
+
+        let refund_sync_router_data = types::RefundSyncType::handle_response(
+            &Helcim,
+            &refund_sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163752,
+                "status": "SETTLING",
+                "type": "withdraw"
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(
+            refund_sync_router_data.response.unwrap().refund_status,
+            enums::RefundStatus::ManualReview
+        );
+    }
+
+    #[test]
+    fn should_sync_the_transactions_in_a_date_range() {
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::NoResponseId,
+                sync_type: types::SyncRequestType::DateRangeSync(types::TransactionDateRange {
+                    start_time: time::macros::datetime!(2024-03-01 0:00),
+                    end_time: time::macros::datetime!(2024-03-01 23:59:59),
+                }),
+                ..Default::default()
+            });
+
+        assert_eq!(
+            types::PaymentsSyncType::get_url(
+                &Helcim,
+                &sync_router_data,
+                &settings::Connectors::default()
+            )
+            .unwrap(),
+            "v2/card-transactions?dateFrom=2024-03-01&dateTo=2024-03-01"
+        );
+
+        let sync_router_data = types::PaymentsSyncType::handle_response(
+            &Helcim,
+            &sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!([
+                {
+                    "transactionId": 20163761,
+                    "status": "APPROVED",
+                    "type": "purchase",
+                    "invoiceNumber": "pay_123_1"
+                },
+                {
+                    "transactionId": 20163762,
+                    "status": "DECLINED",
+                    "type": "purchase",
+                    "invoiceNumber": "pay_456_1"
+                }
+            ])),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            sync_router_data.response.unwrap(),
+            types::PaymentsResponseData::TransactionListSyncResponse { transaction_status_list }
+                if transaction_status_list == HashMap::from([
+                    ("20163761".to_string(), enums::AttemptStatus::Charged),
+                    ("20163762".to_string(), enums::AttemptStatus::Failure),
+                ])
+        ));
+    }
+
+    //Counts the events of a level logged while it is the default subscriber
+    struct EventCounter(
+        router_env::tracing::Level,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    );
+
+    impl router_env::tracing::Subscriber for EventCounter {
+        fn enabled(&self, _metadata: &router_env::tracing::Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(
+            &self,
+            _span: &router_env::tracing::span::Attributes<'_>,
+        ) -> router_env::tracing::span::Id {
+            router_env::tracing::span::Id::from_u64(1)
+        }
+        fn record(
+            &self,
+            _span: &router_env::tracing::span::Id,
+            _values: &router_env::tracing::span::Record<'_>,
+        ) {
+        }
+        fn record_follows_from(
+            &self,
+            _span: &router_env::tracing::span::Id,
+            _follows: &router_env::tracing::span::Id,
+        ) {
+        }
+        fn event(&self, event: &router_env::tracing::Event<'_>) {
+            if *event.metadata().level() == self.0 {
+                self.1.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+        fn enter(&self, _span: &router_env::tracing::span::Id) {}
+        fn exit(&self, _span: &router_env::tracing::span::Id) {}
+    }
+
+    //Syncs a payment of 10.00 USD, returning the synced router data along with the number of
+    //error level events logged while handling the response
+    fn sync_payment_of_ten_dollars(
+        response: serde_json::Value,
+    ) -> (types::PaymentsSyncRouterData, usize) {
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "20163751".to_string(),
+                ),
+                currency: enums::Currency::USD,
+                amount: 1000,
+                ..Default::default()
+            });
+        let error_events = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let sync_router_data = router_env::tracing::subscriber::with_default(
+            EventCounter(router_env::tracing::Level::ERROR, error_events.clone()),
+            || {
+                types::PaymentsSyncType::handle_response(
+                    &Helcim,
+                    &sync_router_data,
+                    None,
+                    get_mocked_response(response),
+                )
+            },
+        )
+        .unwrap();
+        (
+            sync_router_data,
+            error_events.load(std::sync::atomic::Ordering::SeqCst),
+        )
+    }
+
+    fn get_connector_event(latency: u128) -> ConnectorEvent {
+        ConnectorEvent::new(
+            "helcim".to_string(),
+            "Authorize",
+            serde_json::Value::Null,
+            "https://api.helcim.com/v2/payment/purchase".to_string(),
+            common_utils::request::Method::Post,
+            "pay_123".to_string(),
+            "merchant_123".to_string(),
+            None,
+            latency,
+            None,
+            None,
+            200,
+        )
+    }
+
+    //Authorizes a payment which Helcim took the latency (in milliseconds) to respond to, returning
+    //the number of warning level events logged while handling the response
+    fn authorize_with_latency(
+        latency: u128,
+        connector_meta_data: Option<serde_json::Value>,
+    ) -> usize {
+        let mut authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        authorize_router_data.connector_meta_data = connector_meta_data.map(Secret::new);
+        let mut connector_event = get_connector_event(latency);
+        let warn_events = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        router_env::tracing::subscriber::with_default(
+            EventCounter(router_env::tracing::Level::WARN, warn_events.clone()),
+            || {
+                types::PaymentsAuthorizeType::handle_response(
+                    &Helcim,
+                    &authorize_router_data,
+                    Some(&mut connector_event),
+                    get_mocked_response(serde_json::json!({
+                        "transactionId": 20163746,
+                        "status": "APPROVED",
+                        "type": "purchase"
+                    })),
+                )
+            },
+        )
+        .unwrap();
+        warn_events.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    #[test]
+    fn should_warn_when_helcim_responds_slower_than_the_threshold() {
+        assert_eq!(authorize_with_latency(4999, None), 0);
+        assert_eq!(authorize_with_latency(5001, None), 1);
+
+        // The threshold is taken from the connector metadata when it sets one
+        assert_eq!(
+            authorize_with_latency(
+                1500,
+                Some(serde_json::json!({ "slow_request_threshold_ms": 1000 }))
+            ),
+            1
+        );
+        assert_eq!(
+            authorize_with_latency(
+                5001,
+                Some(serde_json::json!({ "slow_request_threshold_ms": 10000 }))
+            ),
+            0
+        );
+
+        // Error responses are held to the default threshold
+        let mut connector_event = get_connector_event(5001);
+        let warn_events = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let error_response = router_env::tracing::subscriber::with_default(
+            EventCounter(router_env::tracing::Level::WARN, warn_events.clone()),
+            || {
+                types::PaymentsCaptureType::get_error_response(
+                    &Helcim,
+                    Response {
+                        headers: None,
+                        response: serde_json::json!({ "errors": "Transaction Declined" })
+                            .to_string()
+                            .into(),
+                        status_code: 400,
+                    },
+                    Some(&mut connector_event),
+                )
+            },
+        );
+        assert!(error_response.is_ok());
+        assert_eq!(warn_events.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    fn get_sync_metadata(
+        sync_router_data: &types::PaymentsSyncRouterData,
+    ) -> Option<serde_json::Value> {
+        match &sync_router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata.clone(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn should_keep_the_attempt_metadata_on_sync() {
+        let attempt_metadata = serde_json::json!({
+            "preauth_transaction_id": 20163745,
+            "capture_transaction_id": 20163746,
+            "captures": [{ "capture_transaction_id": 20163746, "amount": 1000 }],
+            "refunded_amount": 400
+        });
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "20163746".to_string(),
+                ),
+                connector_meta: Some(attempt_metadata.clone()),
+                currency: enums::Currency::USD,
+                amount: 1000,
+                ..Default::default()
+            });
+
+        let sync_router_data = types::PaymentsSyncType::handle_response(
+            &Helcim,
+            &sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163746,
+                "status": "APPROVED",
+                "type": "capture",
+                "amount": 10.0,
+                "currency": "USD",
+                "batchId": 4521,
+                "dateSettled": "2024-04-26"
+            })),
+        )
+        .unwrap();
+
+        // The references needed by later captures and refunds survive the sync, alongside the
+        // settlement details of the sync
+        let mut expected_metadata = attempt_metadata;
+        expected_metadata["batch_id"] = serde_json::json!(4521);
+        expected_metadata["date_settled"] = serde_json::json!("2024-04-26");
+        assert_eq!(
+            get_sync_metadata(&sync_router_data),
+            Some(expected_metadata)
+        );
+    }
+
+    #[test]
+    fn should_sync_silently_when_the_amount_matches() {
+        // Base unit amounts off by a minor unit due to rounding are not a mismatch
+        for amount in [10.0, 10.01, 9.99] {
+            let (sync_router_data, error_events) = sync_payment_of_ten_dollars(serde_json::json!({
+                "transactionId": 20163751,
+                "status": "APPROVED",
+                "type": "purchase",
+                "amount": amount,
+                "currency": "USD"
+            }));
+
+            assert_eq!(sync_router_data.status, enums::AttemptStatus::Charged);
+            assert_eq!(sync_router_data.amount_captured, None);
+            assert_eq!(get_sync_metadata(&sync_router_data), None);
+            assert_eq!(error_events, 0);
+        }
+    }
+
+    #[test]
+    fn should_sync_partially_approved_purchase_as_partially_charged() {
+        let (sync_router_data, error_events) = sync_payment_of_ten_dollars(serde_json::json!({
+            "transactionId": 20163751,
+            "status": "APPROVED",
+            "type": "purchase",
+            "amount": 10.0,
+            "approvedAmount": 6.29,
+            "currency": "USD"
+        }));
+
+        assert_eq!(
+            sync_router_data.status,
+            enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(sync_router_data.amount_captured, Some(629));
+        assert_eq!(
+            get_sync_metadata(&sync_router_data),
+            Some(serde_json::json!({
+                "amount_reconciliation": {
+                    "reason": "partial_approval",
+                    "expected_amount": 1000,
+                    "expected_currency": "USD",
+                    "connector_amount": 629,
+                    "connector_currency": "USD"
+                }
+            }))
+        );
+        assert_eq!(error_events, 0);
+    }
+
+    #[test]
+    fn should_flag_and_log_gross_amount_mismatch_on_sync() {
+        for (amount, currency) in [(25.0, "USD"), (10.0, "CAD")] {
+            let (sync_router_data, error_events) = sync_payment_of_ten_dollars(serde_json::json!({
+                "transactionId": 20163751,
+                "status": "APPROVED",
+                "type": "purchase",
+                "amount": amount,
+                "currency": currency
+            }));
+
+            // The status is left as Helcim reported it for the mismatch to be looked into
+            assert_eq!(sync_router_data.status, enums::AttemptStatus::Charged);
+            assert_eq!(sync_router_data.amount_captured, None);
+            let amount_reconciliation: transformers::HelcimAmountReconciliation =
+                serde_json::from_value(
+                    get_sync_metadata(&sync_router_data)
+                        .and_then(|metadata| metadata.get("amount_reconciliation").cloned())
+                        .unwrap(),
+                )
+                .unwrap();
+            assert_eq!(
+                amount_reconciliation.reason,
+                transformers::HelcimAmountMismatchReason::GrossMismatch
+            );
+            assert_eq!(
+                serde_json::json!(amount_reconciliation.connector_currency),
+                serde_json::json!(currency)
+            );
+            assert_eq!(error_events, 1);
+        }
+    }
+
+    #[test]
+    fn should_setup_mandate_through_verify_and_charge_the_card_token() {
+        let connectors = settings::Connectors::default();
+        let setup_mandate_router_data: types::SetupMandateRouterData = types::RouterData {
+            address: types::PaymentAddress::new(
+                None,
+                Some(api_models::payments::Address {
+                    address: Some(api_models::payments::AddressDetails {
+                        zip: Some(Secret::new("94122".to_string())),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                None,
+            ),
+            ..get_router_data(types::SetupMandateRequestData {
+                currency: enums::Currency::USD,
+                payment_method_data: domain::PaymentMethodData::Card(domain::Card {
+                    card_exp_month: Secret::new("12".to_string()),
+                    card_exp_year: Secret::new("2099".to_string()),
+                    ..Default::default()
+                }),
+                amount: Some(0),
+                confirm: true,
+                statement_descriptor_suffix: None,
+                customer_acceptance: None,
+                mandate_id: None,
+                setup_future_usage: Some(enums::FutureUsage::OffSession),
+                off_session: None,
+                setup_mandate_details: None,
+                router_return_url: None,
+                browser_info: get_browser_info(),
+                email: None,
+                customer_name: None,
+                return_url: None,
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                request_incremental_authorization: false,
+                metadata: None,
+            })
+        };
+
+        // Setup mandate: the card is verified without an amount
+        let verify_request = types::SetupMandateType::build_request(
+            &Helcim,
+            &setup_mandate_router_data,
+            &connectors,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(verify_request.url, "v2/payment/verify");
+        assert_eq!(verify_request.method, services::Method::Post);
+        let verify_request_body = get_request_body(verify_request.body.unwrap());
+        assert_eq!(verify_request_body["invoiceNumber"], "pay_123_1");
+        assert_eq!(verify_request_body["cardData"]["cardExpiry"], "1299");
+        assert!(verify_request_body.get("amount").is_none());
+
+        // The card token of the verify is the connector mandate id
+        let setup_mandate_router_data = types::SetupMandateType::handle_response(
+            &Helcim,
+            &setup_mandate_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163746,
+                "status": "APPROVED",
+                "type": "verify",
+                "invoiceNumber": "pay_123_1",
+                "cardToken": "27f2b2c8a3b7e5a1d2c9f0"
+            })),
+        )
+        .unwrap();
+        assert_eq!(
+            setup_mandate_router_data.status,
+            enums::AttemptStatus::Charged
+        );
+        let connector_mandate_id = match setup_mandate_router_data.response.unwrap() {
+            types::PaymentsResponseData::TransactionResponse {
+                mandate_reference, ..
+            } => mandate_reference
+                .and_then(|mandate_reference| mandate_reference.connector_mandate_id),
+            _ => None,
+        };
+        assert_eq!(
+            connector_mandate_id.as_deref(),
+            Some("27f2b2c8a3b7e5a1d2c9f0")
+        );
+
+        // Charge: subsequent mandate payments are made with the card token alone
+        let authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::MandatePayment,
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: None,
+                    mandate_reference_id: Some(
+                        api_models::payments::MandateReferenceId::ConnectorMandateId(
+                            api_models::payments::ConnectorMandateReferenceId {
+                                connector_mandate_id,
+                                payment_method_id: None,
+                                update_history: None,
+                            },
+                        ),
+                    ),
+                }),
+                off_session: Some(true),
+                ..get_authorize_data(enums::CaptureMethod::Automatic)
+            });
+        let authorize_request_body = get_request_body(
+            types::PaymentsAuthorizeType::get_request_body(
+                &Helcim,
+                &authorize_router_data,
+                &connectors,
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            authorize_request_body["cardData"],
+            serde_json::json!({ "cardToken": "27f2b2c8a3b7e5a1d2c9f0" })
+        );
+    }
+
+    fn get_mandate_revoke_router_data(
+        connector_customer: Option<&str>,
+    ) -> types::MandateRevokeRouterData {
+        types::MandateRevokeRouterData {
+            connector_customer: connector_customer.map(ToOwned::to_owned),
+            ..get_router_data(types::MandateRevokeRequestData {
+                mandate_id: None,
+                connector_mandate_id: Some("aB3cD4eF5gH6".to_string()),
+            })
+        }
+    }
+
+    #[test]
+    fn should_delete_card_token_saved_against_customer() {
+        let connectors = settings::Connectors::default();
+        let router_data = get_mandate_revoke_router_data(Some("CST1001"));
+
+        let request_body = get_request_body(
+            types::MandateRevokeType::get_request_body(&Helcim, &router_data, &connectors).unwrap(),
+        );
+        assert_eq!(
+            request_body,
+            serde_json::json!({
+                "customerCode": "CST1001",
+                "cardToken": "aB3cD4eF5gH6"
+            })
+        );
+        assert_eq!(
+            types::MandateRevokeType::get_http_method(&Helcim),
+            services::Method::Delete
+        );
+
+        let router_data = types::MandateRevokeType::handle_response(
+            &Helcim,
+            &router_data,
+            None,
+            Response {
+                headers: None,
+                response: "".into(),
+                status_code: 204,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            router_data.response.unwrap().mandate_status,
+            enums::MandateStatus::Revoked
+        );
+    }
+
+    #[test]
+    fn should_require_customer_code_to_delete_card_token() {
+        let connectors = settings::Connectors::default();
+        let router_data = get_mandate_revoke_router_data(None);
+
+        let error = types::MandateRevokeType::get_request_body(&Helcim, &router_data, &connectors)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_customer_id"
+            }
+        ));
+    }
+
+    fn get_incremental_authorization_router_data(
+    ) -> types::PaymentsIncrementalAuthorizationRouterData {
+        get_router_data(types::PaymentsIncrementalAuthorizationData {
+            total_amount: 1500,
+            additional_amount: 500,
+            currency: enums::Currency::USD,
+            reason: None,
+            connector_transaction_id: "20163745".to_string(),
+        })
+    }
+
+    #[test]
+    fn should_increment_the_preauth_to_the_new_total() {
+        let router_data = get_incremental_authorization_router_data();
+        let request_body = get_request_body(
+            types::IncrementalAuthorizationType::get_request_body(
+                &Helcim,
+                &router_data,
+                &settings::Connectors::default(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            request_body,
+            serde_json::json!({ "preAuthTransactionId": 20163745, "amount": 15.0 })
+        );
+
+        let router_data = types::IncrementalAuthorizationType::handle_response(
+            &Helcim,
+            &router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163790,
+                "status": "APPROVED",
+                "type": "preauth",
+                "invoiceNumber": "pay_123_1"
+            })),
+        )
+        .unwrap();
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status: common_enums::AuthorizationStatus::Success,
+                error_code: None,
+                error_message: None,
+                connector_authorization_id: Some(ref id),
+            }) if id == "20163790"
+        ));
+
+        // Once the router updates the payment amount, the increment can be captured even though
+        // the metadata still holds the amount of the original preauth
+        let authorize_metadata =
+            authorize_manually(get_authorize_data(enums::CaptureMethod::Manual));
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            get_router_data(types::PaymentsCaptureData {
+                amount_to_capture: 1500,
+                currency: enums::Currency::USD,
+                connector_transaction_id: "20163745".to_string(),
+                payment_amount: 1500,
+                connector_meta: authorize_metadata,
+                browser_info: get_browser_info(),
+                ..Default::default()
+            });
+        let capture_request = get_request_body(
+            types::PaymentsCaptureType::get_request_body(
+                &Helcim,
+                &capture_router_data,
+                &settings::Connectors::default(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(capture_request["amount"], 15.0);
+    }
+
+    #[test]
+    fn should_keep_the_authorized_amount_when_the_increment_is_declined() {
+        let router_data = types::IncrementalAuthorizationType::handle_response(
+            &Helcim,
+            &get_incremental_authorization_router_data(),
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163791,
+                "status": "DECLINED",
+                "type": "preauth",
+                "invoiceNumber": "pay_123_1",
+                "responseMessage": "INSUFFICIENT FUNDS"
+            })),
+        )
+        .unwrap();
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status: common_enums::AuthorizationStatus::Failure,
+                error_code: None,
+                error_message: Some(ref message),
+                connector_authorization_id: Some(_),
+            }) if message == "INSUFFICIENT FUNDS"
+        ));
+    }
+
+    fn settle_unanswered_authorize(
+        search_response: serde_json::Value,
+    ) -> types::PaymentsAuthorizeRouterData {
+        let mut authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        authorize_router_data.response = Err(ErrorResponse {
+            code: crate::consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
+            message: crate::consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
+            reason: None,
+            status_code: 504,
+            attempt_status: None,
+            connector_transaction_id: None,
+        });
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::NoResponseId,
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                sync_type: types::SyncRequestType::ReferenceSync,
+                currency: enums::Currency::USD,
+                amount: 1000,
+                ..Default::default()
+            });
+        assert_eq!(
+            types::PaymentsSyncType::get_url(
+                &Helcim,
+                &sync_router_data,
+                &settings::Connectors::default()
+            )
+            .unwrap(),
+            "v2/card-transactions?invoiceNumber=pay_123_1"
+        );
+        let lookup = types::PaymentsSyncType::handle_response(
+            &Helcim,
+            &sync_router_data,
+            None,
+            get_mocked_response(search_response),
+        )
+        .unwrap();
+        crate::core::payments::flows::authorize_flow::settle_unanswered_authorize(
+            authorize_router_data,
+            Some(lookup),
+        )
+    }
+
+    #[test]
+    fn should_settle_unanswered_authorize_from_the_transaction_found() {
+        let router_data = settle_unanswered_authorize(serde_json::json!([
+            {
+                "transactionId": 20163750,
+                "status": "DECLINED",
+                "type": "purchase",
+                "invoiceNumber": "pay_123_1"
+            },
+            {
+                "transactionId": 20163751,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "pay_123_1"
+            },
+            {
+                "transactionId": 20163752,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "pay_456_1"
+            }
+        ]));
+
+        assert_eq!(router_data.status, enums::AttemptStatus::Charged);
+        assert!(matches!(
+            router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(ref transaction_id),
+                ..
+            }) if transaction_id == "20163751"
+        ));
+    }
+
+    #[test]
+    fn should_leave_timed_out_authorize_pending_when_no_transaction_is_found() {
+        let router_data = settle_unanswered_authorize(serde_json::json!([]));
+
+        assert_eq!(router_data.status, enums::AttemptStatus::Pending);
+        let error = router_data.response.unwrap_err();
+        assert_eq!(error.code, crate::consts::REQUEST_TIMEOUT_ERROR_CODE);
+        assert_eq!(error.attempt_status, Some(enums::AttemptStatus::Pending));
+    }
+
+    #[test]
+    fn should_sync_payment_without_transaction_id_by_invoice_number() {
+        let sync_router_data: types::PaymentsSyncRouterData =
+            get_router_data(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::NoResponseId,
+                capture_method: Some(enums::CaptureMethod::Manual),
+                currency: enums::Currency::USD,
+                amount: 1100,
+                surcharge_amount: Some(100),
+                ..Default::default()
+            });
+        assert_eq!(
+            types::PaymentsSyncType::get_url(
+                &Helcim,
+                &sync_router_data,
+                &settings::Connectors::default()
+            )
+            .unwrap(),
+            "v2/card-transactions?invoiceNumber=pay_123_1"
+        );
+
+        let sync_router_data = types::PaymentsSyncType::handle_response(
+            &Helcim,
+            &sync_router_data,
+            None,
+            get_mocked_response(serde_json::json!([
+                {
+                    "transactionId": 20163751,
+                    "status": "APPROVED",
+                    "type": "preauth",
+                    "invoiceNumber": "pay_123_1",
+                    "amount": 11.0,
+                    "currency": "USD"
+                }
+            ])),
+        )
+        .unwrap();
+
+        // The preauth is stored as on authorize, with its id and surcharge in the connector metadata
+        assert_eq!(sync_router_data.status, enums::AttemptStatus::Authorized);
+        assert!(matches!(
+            sync_router_data.response,
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::NoResponseId,
+                ..
+            })
+        ));
+        assert_eq!(
+            get_sync_metadata(&sync_router_data),
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163751,
+                "authorized_amount": 1100,
+                "surcharge_amount": 100
+            }))
+        );
+    }
+
+    fn authorize_purchase_in_currency(currency: &str) -> Option<serde_json::Value> {
+        let router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        let router_data = types::PaymentsAuthorizeType::handle_response(
+            &Helcim,
+            &router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163745,
+                "status": "APPROVED",
+                "type": "purchase",
+                "currency": currency
+            })),
+        )
+        .unwrap();
+        assert_eq!(router_data.status, enums::AttemptStatus::Charged);
+        match router_data.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata,
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn should_not_flag_response_in_the_requested_currency() {
+        assert_eq!(authorize_purchase_in_currency("USD"), None);
+    }
+
+    #[test]
+    fn should_flag_response_in_another_currency() {
+        assert_eq!(
+            authorize_purchase_in_currency("EUR"),
+            Some(serde_json::json!({
+                "currency_mismatch": {
+                    "expected_currency": "USD",
+                    "connector_currency": "EUR"
+                }
+            }))
+        );
+        // Currencies which are not known are flagged as Helcim sent them
+        assert_eq!(
+            authorize_purchase_in_currency("XYZ"),
+            Some(serde_json::json!({
+                "currency_mismatch": {
+                    "expected_currency": "USD",
+                    "connector_currency": "XYZ"
+                }
+            }))
+        );
+    }
+
+    #[test]
+    fn should_keep_the_settlement_amount_of_converted_payments() {
+        let router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        let authorize = |currency: &str, amount: f64| {
+            types::PaymentsAuthorizeType::handle_response(
+                &Helcim,
+                &router_data,
+                None,
+                get_mocked_response(serde_json::json!({
+                    "transactionId": 20163745,
+                    "status": "APPROVED",
+                    "type": "purchase",
+                    "amount": amount,
+                    "currency": currency
+                })),
+            )
+            .unwrap()
+        };
+
+        // Payments processed in the requested currency are not converted
+        let router_data_in_usd = authorize("USD", 10.0);
+        assert_eq!(router_data_in_usd.status, enums::AttemptStatus::Charged);
+        assert!(router_data_in_usd.connector_response.is_none());
+
+        // Merchant accounts settling in CAD only return the amount settled in CAD
+        let router_data_in_cad = authorize("CAD", 13.62);
+        assert_eq!(router_data_in_cad.status, enums::AttemptStatus::Charged);
+        assert_eq!(router_data_in_cad.amount_captured, None);
+        let currency_conversion = match router_data_in_cad.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                connector_metadata, ..
+            }) => connector_metadata.and_then(|connector_metadata| {
+                connector_metadata.get("currency_conversion").cloned()
+            }),
+            _ => None,
+        };
+        assert_eq!(
+            currency_conversion,
+            Some(serde_json::json!({
+                "presentment_amount": 1000,
+                "presentment_currency": "USD",
+                "settlement_amount": 1362,
+                "settlement_currency": "CAD"
+            }))
+        );
+    }
+
+    #[test]
+    fn should_refund_converted_payments_in_the_settlement_currency() {
+        let connectors = settings::Connectors::default();
+        let connector_metadata = |refunded_amount: i64| {
+            serde_json::json!({
+                "refunded_amount": refunded_amount,
+                "currency_conversion": {
+                    "presentment_amount": 1000,
+                    "presentment_currency": "USD",
+                    "settlement_amount": 1362,
+                    "settlement_currency": "CAD"
+                }
+            })
+        };
+
+        // 400 of the 1000 USD payment is refunded as 5.45 of the 13.62 CAD settled
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(None, Some(connector_metadata(0))));
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["amount"], 5.45);
+
+        // Refunding the remaining 600 USD refunds the rest of the amount settled, without any
+        // rounding difference left behind
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(types::RefundsData {
+                refund_amount: 600,
+                ..get_refunds_data(None, Some(connector_metadata(400)))
+            });
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["amount"], 8.17);
+    }
+
+    fn get_authorize_request_with_mcc(
+        mcc: Option<&str>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let mut authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        authorize_router_data.connector_meta_data =
+            mcc.map(|mcc| Secret::new(serde_json::json!({ "mcc": mcc })));
+        types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_router_data,
+            &settings::Connectors::default(),
+        )
+        .map(get_request_body)
+    }
+
+    #[test]
+    fn should_send_mcc_from_connector_metadata() {
+        let authorize_request = get_authorize_request_with_mcc(Some("5411")).unwrap();
+        assert_eq!(authorize_request["mcc"], "5411");
+    }
+
+    #[test]
+    fn should_reject_invalid_mcc_in_connector_metadata() {
+        for mcc in ["541", "54111", "54a1", ""] {
+            let result = get_authorize_request_with_mcc(Some(mcc));
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ConnectorError::InvalidConnectorConfig {
+                    config: "metadata.mcc"
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn should_omit_mcc_without_connector_metadata() {
+        let authorize_request = get_authorize_request_with_mcc(None).unwrap();
+        assert!(authorize_request.get("mcc").is_none());
+    }
+
+    fn get_authorize_request_with_surcharge(
+        surcharge_amount: i64,
+        final_amount: i64,
+        max_surcharge_percentage: Option<&str>,
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        let mut authorize_router_data: types::PaymentsAuthorizeRouterData =
+            get_router_data(types::PaymentsAuthorizeData {
+                amount: 1000 + surcharge_amount,
+                surcharge_details: Some(crate::core::payments::types::SurchargeDetails {
+                    original_amount: 1000,
+                    surcharge: common_utils::types::Surcharge::Fixed(surcharge_amount),
+                    tax_on_surcharge: None,
+                    surcharge_amount,
+                    tax_on_surcharge_amount: 0,
+                    final_amount,
+                }),
+                ..get_authorize_data(enums::CaptureMethod::Automatic)
+            });
+        authorize_router_data.connector_meta_data =
+            max_surcharge_percentage.map(|max_surcharge_percentage| {
+                Secret::new(serde_json::json!({
+                    "max_surcharge_percentage": max_surcharge_percentage
+                }))
+            });
+        types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_router_data,
+            &settings::Connectors::default(),
+        )
+        .map(get_request_body)
+    }
+
+    #[test]
+    fn should_send_the_surcharge_within_the_cap_as_convenience_fee() {
+        for max_surcharge_percentage in [Some("3"), None] {
+            let authorize_request =
+                get_authorize_request_with_surcharge(30, 1030, max_surcharge_percentage).unwrap();
+            // The amount is the base amount and the surcharge the cardholder agreed to
+            assert_eq!(authorize_request["amount"], 10.30);
+            assert_eq!(authorize_request["convenienceFee"], 0.30);
+        }
+    }
+
+    #[test]
+    fn should_omit_convenience_fee_without_surcharge() {
+        let authorize_request =
+            get_router_data(get_authorize_data(enums::CaptureMethod::Automatic));
+        let authorize_request = types::PaymentsAuthorizeType::get_request_body(
+            &Helcim,
+            &authorize_request,
+            &settings::Connectors::default(),
+        )
+        .map(get_request_body)
+        .unwrap();
+        assert_eq!(authorize_request["amount"], 10.0);
+        assert!(authorize_request.get("convenienceFee").is_none());
+    }
+
+    #[test]
+    fn should_reject_surcharge_over_the_cap() {
+        let result = get_authorize_request_with_surcharge(31, 1031, Some("3"));
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Surcharge 31 exceeds the maximum surcharge 30, which is 3% of the amount 1000"
+        ));
+
+        let result = get_authorize_request_with_surcharge(30, 1030, Some("120"));
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.max_surcharge_percentage"
+            }
+        ));
+    }
+
+    #[test]
+    fn should_reject_amount_other_than_the_surcharged_amount() {
+        let result = get_authorize_request_with_surcharge(30, 1050, None);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason == "Amount 1030 does not match the surcharged amount 1050"
+        ));
+    }
+
+    #[test]
+    fn should_refund_bank_payments_through_the_ach_api() {
+        let connectors = settings::Connectors::default();
+
+        // Unsettled bank payments refunded in full are voided, and the void is the result of the
+        // refund
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(types::RefundsData {
+                refund_amount: 1000,
+                ..get_refunds_data(None, Some(serde_json::json!({ "bank_payment": {} })))
+            });
+        assert_eq!(
+            types::RefundExecuteType::get_url(&Helcim, &refund_router_data, &connectors).unwrap(),
+            "v2/ach/reverse"
+        );
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(
+            refund_request,
+            serde_json::json!({ "bankTransactionId": 20163746, "ipAddress": "127.0.0.1" })
+        );
+        let refund_router_data = types::RefundExecuteType::handle_response(
+            &Helcim,
+            &refund_router_data,
+            None,
+            get_mocked_response(serde_json::json!({
+                "transactionId": 20163747,
+                "status": "APPROVED",
+                "type": "void"
+            })),
+        )
+        .unwrap();
+        let refund_response = refund_router_data.response.unwrap();
+        assert_eq!(refund_response.connector_refund_id, "20163747");
+        assert_eq!(refund_response.refund_status, enums::RefundStatus::Success);
+
+        // Settled bank payments are refunded, and the refund is synced as an ACH transaction
+        let refund_router_data: types::RefundsRouterData<api::Execute> =
+            get_router_data(get_refunds_data(
+                Some("20163748".to_string()),
+                Some(serde_json::json!({
+                    "bank_payment": {},
+                    "date_settled": "2024-04-26"
+                })),
+            ));
+        assert_eq!(
+            types::RefundExecuteType::get_url(&Helcim, &refund_router_data, &connectors).unwrap(),
+            "v2/ach/refund"
+        );
+        let refund_request = get_request_body(
+            types::RefundExecuteType::get_request_body(&Helcim, &refund_router_data, &connectors)
+                .unwrap(),
+        );
+        assert_eq!(refund_request["bankTransactionId"], 20163746);
+        assert_eq!(refund_request["amount"], 4.0);
+        let refund_sync_router_data: types::RefundSyncRouterData =
+            get_router_data(refund_router_data.request.clone());
+        assert_eq!(
+            types::RefundSyncType::get_url(&Helcim, &refund_sync_router_data, &connectors).unwrap(),
+            "v2/ach/transactions/20163748"
+        );
+    }
+}
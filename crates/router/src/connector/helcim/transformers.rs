@@ -1,42 +1,69 @@
-use common_utils::pii::{Email, IpAddress};
-use error_stack::ResultExt;
-use masking::Secret;
+use std::{collections::HashMap, str::FromStr};
+
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    pii::{Email, IpAddress},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret, Strategy, WithType};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    collect_missing_value_keys,
     connector::utils::{
-        self, AddressDetailsData, BrowserInformationData, CardData, PaymentsAuthorizeRequestData,
+        self, BrowserInformationData, CardData, PaymentsAuthorizeRequestData,
         PaymentsCancelRequestData, PaymentsCaptureRequestData, PaymentsSetupMandateRequestData,
-        RefundsRequestData, RouterData,
+        RefundsRequestData, RevokeMandateRequestData, RouterData,
     },
-    core::errors,
+    consts,
+    core::{errors, payments::types::AuthenticationData},
     types::{self, api, domain, storage::enums},
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum HelcimAmount {
+    //Base unit of the currency, e.g. dollars for USD, accepted by v1 accounts
+    Major(f64),
+    //Minor unit of the currency, e.g. cents for USD, accepted by v2 accounts
+    Minor(i64),
+}
+
 #[derive(Debug, Serialize)]
 pub struct HelcimRouterData<T> {
-    pub amount: f64,
+    pub amount: HelcimAmount,
     pub router_data: T,
 }
 
-impl<T>
-    TryFrom<(
-        &types::api::CurrencyUnit,
-        types::storage::enums::Currency,
-        i64,
-        T,
-    )> for HelcimRouterData<T>
+impl<T> TryFrom<(&api::CurrencyUnit, types::storage::enums::Currency, i64, T)>
+    for HelcimRouterData<T>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         (currency_unit, currency, amount, item): (
-            &types::api::CurrencyUnit,
+            &api::CurrencyUnit,
             types::storage::enums::Currency,
             i64,
             T,
         ),
     ) -> Result<Self, Self::Error> {
-        let amount = utils::get_amount_as_f64(currency_unit, amount, currency)?;
+        let amount = match currency_unit {
+            api::CurrencyUnit::Base => {
+                HelcimAmount::Major(utils::get_amount_as_f64(currency_unit, amount, currency)?)
+            }
+            //Minor unit amounts are held to the same range as base unit amounts, so that an amount
+            //accepted for one api version is not rejected for the other
+            api::CurrencyUnit::Minor => {
+                HelcimAmount::Minor(u32::try_from(amount).map(i64::from).change_context_lazy(
+                    || {
+                        errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+                            "Amount {amount} can not be converted to the minor unit of {currency}"
+                        ))
+                    },
+                )?)
+            }
+        };
         Ok(Self {
             amount,
             router_data: item,
@@ -63,36 +90,604 @@ pub struct HelcimVerifyRequest {
     currency: enums::Currency,
     ip_address: Secret<String, IpAddress>,
     card_data: HelcimCard,
+    //Verify transactions carry no invoice, so the reference is sent as the invoice number for reconciliation
+    invoice_number: String,
     billing_address: HelcimBillingAddress,
     #[serde(skip_serializing_if = "Option::is_none")]
     ecommerce: Option<bool>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum HelcimPaymentsRequest {
+    Card(HelcimCardPaymentRequest),
+    Bank(HelcimBankPaymentRequest),
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct HelcimPaymentsRequest {
-    amount: f64,
+pub struct HelcimCardPaymentRequest {
+    amount: HelcimAmount,
     currency: enums::Currency,
     ip_address: Secret<String, IpAddress>,
-    card_data: HelcimCard,
+    card_data: HelcimCardData,
     invoice: HelcimInvoice,
     billing_address: HelcimBillingAddress,
     //The ecommerce field is an optional field in Connector Helcim.
     //Setting the ecommerce field to true activates the Helcim Fraud Defender.
     #[serde(skip_serializing_if = "Option::is_none")]
     ecommerce: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statement_descriptor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tip_amount: Option<HelcimAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    convenience_fee: Option<HelcimAmount>,
+    //3DS authentication results are passed through only when the payment was authenticated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    three_d_s: Option<HelcimThreeDsData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mcc: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimThreeDsData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eci: Option<String>,
+    cavv: Secret<String>,
+    xid: String,
+    version: String,
+}
+
+impl From<&AuthenticationData> for HelcimThreeDsData {
+    fn from(authentication_data: &AuthenticationData) -> Self {
+        Self {
+            eci: authentication_data.eci.clone(),
+            cavv: Secret::new(authentication_data.cavv.clone()),
+            xid: authentication_data.threeds_server_transaction_id.clone(),
+            version: authentication_data.message_version.clone(),
+        }
+    }
+}
+
+//ECI values for which the issuer has fully authenticated or attempted authentication,
+//shifting the fraud liability from the merchant to the issuer
+const HELCIM_LIABILITY_SHIFT_ECI_VALUES: [&str; 4] = ["01", "02", "05", "06"];
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct HelcimThreeDsResult {
+    pub eci: Option<String>,
+    pub liability_shift: bool,
+}
+
+impl From<&AuthenticationData> for HelcimThreeDsResult {
+    fn from(authentication_data: &AuthenticationData) -> Self {
+        let liability_shift = authentication_data
+            .eci
+            .as_deref()
+            .map(|eci| HELCIM_LIABILITY_SHIFT_ECI_VALUES.contains(&eci))
+            .unwrap_or(false);
+        Self {
+            eci: authentication_data.eci.clone(),
+            liability_shift,
+        }
+    }
+}
+
+//Helcim truncates billing descriptors longer than this on the cardholder's statement
+const HELCIM_STATEMENT_DESCRIPTOR_MAX_LENGTH: usize = 22;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HelcimConnectorMetadataObject {
+    pub statement_descriptor: Option<String>,
+    //Cards expiring within this many months, counting the current one, may expire before the
+    //payment is captured
+    pub card_expiry_horizon_months: Option<u8>,
+    //Helcim accounts set up for card present processing reject API captures and refunds without it
+    pub terminal_id: Option<String>,
+    #[serde(default)]
+    pub api_version: HelcimApiVersion,
+    //Helcim is North American, so the country of the billing address defaults to that of the merchant
+    pub default_country: Option<enums::CountryAlpha2>,
+    //Merchant category code, required on the transaction by certain interchange programs
+    pub mcc: Option<String>,
+    //Legal cap on surcharges in the region of the merchant, as a percentage of the amount before
+    //the surcharge
+    pub max_surcharge_percentage: Option<Decimal>,
+    //Helcim calls slower than this are logged as a warning
+    pub slow_request_threshold_ms: Option<u64>,
+}
+
+const HELCIM_SLOW_REQUEST_THRESHOLD_MS: u64 = 5000;
+
+//The default threshold applies when the metadata sets none or can not be read, as a malformed
+//metadata is reported by the flow itself
+pub fn get_slow_request_threshold_ms(
+    connector_meta_data: Option<&Secret<serde_json::Value>>,
+) -> u64 {
+    HelcimConnectorMetadataObject::try_from(connector_meta_data)
+        .ok()
+        .and_then(|connector_metadata| connector_metadata.slow_request_threshold_ms)
+        .unwrap_or(HELCIM_SLOW_REQUEST_THRESHOLD_MS)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HelcimApiVersion {
+    #[default]
+    V1,
+    V2,
+}
+
+impl HelcimApiVersion {
+    //v2 accounts accept integer amounts in the minor unit of the currency
+    pub fn get_currency_unit(&self) -> api::CurrencyUnit {
+        match self {
+            Self::V1 => super::Helcim::CURRENCY_UNIT,
+            Self::V2 => api::CurrencyUnit::Minor,
+        }
+    }
+}
+
+impl TryFrom<Option<&Secret<serde_json::Value>>> for HelcimConnectorMetadataObject {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: Option<&Secret<serde_json::Value>>) -> Result<Self, Self::Error> {
+        meta_data
+            .map(|meta_data| {
+                utils::to_connector_meta_from_secret::<Self>(Some(meta_data.to_owned()))
+                    .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                        config: "metadata",
+                    })
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+fn get_statement_descriptor(
+    statement_descriptor: Option<&String>,
+    connector_meta_data: Option<&Secret<serde_json::Value>>,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    let statement_descriptor = match statement_descriptor {
+        Some(statement_descriptor) => Some(statement_descriptor.to_owned()),
+        None => HelcimConnectorMetadataObject::try_from(connector_meta_data)?.statement_descriptor,
+    };
+    Ok(statement_descriptor
+        .map(|statement_descriptor| {
+            statement_descriptor
+                .trim()
+                .chars()
+                .take(HELCIM_STATEMENT_DESCRIPTOR_MAX_LENGTH)
+                .collect::<String>()
+        })
+        .filter(|statement_descriptor| !statement_descriptor.is_empty()))
+}
+
+//Merchant category codes are four digits, as assigned by the card networks
+fn get_mcc(
+    connector_meta_data: Option<&Secret<serde_json::Value>>,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    HelcimConnectorMetadataObject::try_from(connector_meta_data)?
+        .mcc
+        .map(|mcc| {
+            if mcc.len() == 4 && mcc.chars().all(|digit| digit.is_ascii_digit()) {
+                Ok(mcc)
+            } else {
+                Err(errors::ConnectorError::InvalidConnectorConfig {
+                    config: "metadata.mcc",
+                })
+                .attach_printable(format!("{mcc} is not a valid merchant category code"))
+            }
+        })
+        .transpose()
+}
+
+//Helcim rejects transaction notes longer than this
+const HELCIM_COMMENTS_MAX_LENGTH: usize = 255;
+
+//The note is taken from the `comments` key of the order metadata, falling back to the payment description
+fn get_comments(
+    metadata: Option<&Secret<serde_json::Value>>,
+    description: Option<&String>,
+) -> Option<String> {
+    metadata
+        .and_then(|metadata| {
+            metadata
+                .peek()
+                .get("comments")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_owned)
+        })
+        .or_else(|| description.cloned())
+        .map(|comments| {
+            comments
+                .trim()
+                .chars()
+                .take(HELCIM_COMMENTS_MAX_LENGTH)
+                .collect::<String>()
+        })
+        .filter(|comments| !comments.is_empty())
+}
+
+const HELCIM_TIP_AMOUNT_FIELD: &str = "tip_amount";
+const HELCIM_BASE_AMOUNT_FIELD: &str = "base_amount";
+const HELCIM_AMOUNT_BREAKDOWN_FIELD: &str = "amount_breakdown";
+//The breakdown may be composed from amounts rounded to the cent on the merchant's side
+const HELCIM_AMOUNT_BREAKDOWN_TOLERANCE: i64 = 1;
+
+//Tips and convenience fees settle separately from the base amount of the payment on Helcim.
+//The convenience fee is the surcharge of the payment. The unified request has no slot for tips,
+//so the tip and optionally the base amount are taken from the order metadata, in the minor unit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HelcimAmountBreakdown {
+    pub base_amount: i64,
+    pub tip_amount: i64,
+    pub convenience_fee: i64,
+}
+
+fn get_metadata_amount(
+    metadata: Option<&Secret<serde_json::Value>>,
+    key: &str,
+    field_name: &'static str,
+) -> Result<Option<i64>, error_stack::Report<errors::ConnectorError>> {
+    metadata
+        .and_then(|metadata| metadata.peek().get(key))
+        .map(|amount| {
+            amount
+                .as_i64()
+                .filter(|amount| *amount >= 0)
+                .ok_or(report!(errors::ConnectorError::InvalidDataFormat {
+                    field_name
+                }))
+                .attach_printable(format!(
+                    "Expected `{key}` in metadata to be a non-negative amount in the minor unit"
+                ))
+        })
+        .transpose()
+}
+
+impl HelcimAmountBreakdown {
+    //Payments without a tip or a convenience fee have no breakdown
+    pub fn try_from_authorize_data(
+        request: &types::PaymentsAuthorizeData,
+    ) -> Result<Option<Self>, error_stack::Report<errors::ConnectorError>> {
+        let tip_amount = get_metadata_amount(
+            request.metadata.as_ref(),
+            HELCIM_TIP_AMOUNT_FIELD,
+            "metadata.tip_amount",
+        )?
+        .filter(|tip_amount| *tip_amount > 0);
+        let convenience_fee = request
+            .get_total_surcharge_amount()
+            .filter(|convenience_fee| *convenience_fee > 0);
+        if tip_amount.is_none() && convenience_fee.is_none() {
+            return Ok(None);
+        }
+        let tip_amount = tip_amount.unwrap_or(0);
+        let convenience_fee = convenience_fee.unwrap_or(0);
+        let base_amount = get_metadata_amount(
+            request.metadata.as_ref(),
+            HELCIM_BASE_AMOUNT_FIELD,
+            "metadata.base_amount",
+        )?
+        .unwrap_or_else(|| {
+            request
+                .amount
+                .saturating_sub(tip_amount)
+                .saturating_sub(convenience_fee)
+        });
+
+        let breakdown_amount = base_amount
+            .saturating_add(tip_amount)
+            .saturating_add(convenience_fee);
+        if base_amount < 0
+            || breakdown_amount.saturating_sub(request.amount).abs()
+                > HELCIM_AMOUNT_BREAKDOWN_TOLERANCE
+        {
+            Err(errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+                "Amount {} does not match the base amount {base_amount}, tip {tip_amount} and convenience fee {convenience_fee} of the payment",
+                request.amount
+            )))?
+        }
+        Ok(Some(Self {
+            base_amount,
+            tip_amount,
+            convenience_fee,
+        }))
+    }
+}
+
+//The amount of the payment has to be the one the cardholder agreed to with the surcharge, and the
+//surcharge, excluding the tax on it, can not exceed the cap configured in the connector metadata
+fn validate_surcharge(
+    request: &types::PaymentsAuthorizeData,
+    max_surcharge_percentage: Option<Decimal>,
+) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+    let Some(surcharge_details) = request.surcharge_details.as_ref() else {
+        return Ok(());
+    };
+    let (amount, final_amount) = (request.amount, surcharge_details.final_amount);
+    if amount != final_amount {
+        Err(errors::ConnectorError::RequestEncodingFailedWithReason(
+            format!("Amount {amount} does not match the surcharged amount {final_amount}"),
+        ))?
+    }
+    let Some(max_surcharge_percentage) = max_surcharge_percentage else {
+        return Ok(());
+    };
+    let max_surcharge_amount = (max_surcharge_percentage >= Decimal::ZERO
+        && max_surcharge_percentage <= Decimal::ONE_HUNDRED)
+        .then(|| {
+            Decimal::from(surcharge_details.original_amount)
+                .checked_mul(max_surcharge_percentage)
+                .and_then(|amount| amount.checked_div(Decimal::ONE_HUNDRED))
+                .and_then(|amount| amount.floor().to_i64())
+        })
+        .flatten()
+        .ok_or(errors::ConnectorError::InvalidConnectorConfig {
+            config: "metadata.max_surcharge_percentage",
+        })
+        .attach_printable(format!(
+            "{max_surcharge_percentage} is not a percentage between 0 and 100"
+        ))?;
+    if surcharge_details.surcharge_amount > max_surcharge_amount {
+        Err(errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+            "Surcharge {} exceeds the maximum surcharge {max_surcharge_amount}, which is {max_surcharge_percentage}% of the amount {}",
+            surcharge_details.surcharge_amount, surcharge_details.original_amount
+        )))?
+    }
+    Ok(())
+}
+
+//Tip and convenience fee are sent in the same unit as the amount of the payment
+fn get_tip_amount_and_convenience_fee(
+    item: &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+) -> Result<(Option<HelcimAmount>, Option<HelcimAmount>), error_stack::Report<errors::ConnectorError>>
+{
+    let connector_metadata =
+        HelcimConnectorMetadataObject::try_from(item.router_data.connector_meta_data.as_ref())?;
+    validate_surcharge(
+        &item.router_data.request,
+        connector_metadata.max_surcharge_percentage,
+    )?;
+    let Some(amount_breakdown) =
+        HelcimAmountBreakdown::try_from_authorize_data(&item.router_data.request)?
+    else {
+        return Ok((None, None));
+    };
+    let currency_unit = connector_metadata.api_version.get_currency_unit();
+    let get_helcim_amount = |amount: i64| {
+        (amount > 0)
+            .then(|| {
+                HelcimRouterData::try_from((
+                    &currency_unit,
+                    item.router_data.request.currency,
+                    amount,
+                    (),
+                ))
+                .map(|router_data| router_data.amount)
+            })
+            .transpose()
+    };
+    Ok((
+        get_helcim_amount(amount_breakdown.tip_amount)?,
+        get_helcim_amount(amount_breakdown.convenience_fee)?,
+    ))
+}
+
+//Sets a field of the Helcim connector metadata, keeping the fields already stored in it
+fn insert_metadata_field(
+    connector_metadata: Option<serde_json::Value>,
+    key: &str,
+    value: serde_json::Value,
+) -> Option<serde_json::Value> {
+    let mut metadata = match connector_metadata {
+        Some(serde_json::Value::Object(metadata)) => metadata,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert(key.to_string(), value);
+    Some(serde_json::Value::Object(metadata))
+}
+
+pub fn add_amount_breakdown(
+    connector_metadata: Option<serde_json::Value>,
+    amount_breakdown: Option<HelcimAmountBreakdown>,
+) -> Option<serde_json::Value> {
+    let Some(amount_breakdown) = amount_breakdown else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_AMOUNT_BREAKDOWN_FIELD,
+        serde_json::json!(amount_breakdown),
+    )
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimBankPaymentRequest {
+    amount: HelcimAmount,
+    currency: enums::Currency,
+    ip_address: Secret<String, IpAddress>,
+    bank_data: HelcimBankData,
+    invoice: HelcimInvoice,
+    billing_address: HelcimBillingAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tip_amount: Option<HelcimAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    convenience_fee: Option<HelcimAmount>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimBankData {
+    bank_account_number: Secret<String>,
+    //For EFT payments in CA, the routing number is the institution number followed by the transit number
+    bank_routing_number: Secret<String>,
+    bank_account_holder: Secret<String>,
+    bank_account_type: HelcimBankAccountType,
+    bank_account_corporate: HelcimBankAccountCorporate,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HelcimBankAccountType {
+    Checking,
+    Savings,
+}
+
+impl From<common_enums::BankType> for HelcimBankAccountType {
+    fn from(bank_type: common_enums::BankType) -> Self {
+        match bank_type {
+            common_enums::BankType::Checking => Self::Checking,
+            common_enums::BankType::Savings => Self::Savings,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HelcimBankAccountCorporate {
+    Personal,
+    Corporate,
+}
+
+impl From<common_enums::BankHolderType> for HelcimBankAccountCorporate {
+    fn from(bank_holder_type: common_enums::BankHolderType) -> Self {
+        match bank_holder_type {
+            common_enums::BankHolderType::Personal => Self::Personal,
+            common_enums::BankHolderType::Business => Self::Corporate,
+        }
+    }
+}
+
+impl TryFrom<&domain::BankDebitData> for HelcimBankData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(bank_debit_data: &domain::BankDebitData) -> Result<Self, Self::Error> {
+        match bank_debit_data {
+            domain::BankDebitData::AchBankDebit {
+                billing_details,
+                account_number,
+                routing_number,
+                bank_account_holder_name,
+                bank_type,
+                bank_holder_type,
+                ..
+            } => Ok(Self {
+                bank_account_number: account_number.to_owned(),
+                bank_routing_number: routing_number.to_owned(),
+                bank_account_holder: bank_account_holder_name
+                    .clone()
+                    .unwrap_or(billing_details.name.clone()),
+                bank_account_type: bank_type
+                    .map(HelcimBankAccountType::from)
+                    .unwrap_or(HelcimBankAccountType::Checking),
+                bank_account_corporate: bank_holder_type
+                    .map(HelcimBankAccountCorporate::from)
+                    .unwrap_or(HelcimBankAccountCorporate::Personal),
+            }),
+            domain::BankDebitData::SepaBankDebit { .. }
+            | domain::BankDebitData::BecsBankDebit { .. }
+            | domain::BankDebitData::BacsBankDebit { .. } => {
+                Err(errors::ConnectorError::NotImplemented(
+                    utils::get_unimplemented_payment_method_error_message("Helcim"),
+                ))?
+            }
+        }
+    }
+}
+
+pub fn check_bank_debit_currency(
+    currency: types::storage::enums::Currency,
+) -> Result<types::storage::enums::Currency, errors::ConnectorError> {
+    //ACH payments are processed in USD and EFT payments in CAD
+    match currency {
+        types::storage::enums::Currency::USD | types::storage::enums::Currency::CAD => Ok(currency),
+        _ => Err(errors::ConnectorError::NotSupported {
+            message: format!("currency {currency} for bank debit payments"),
+            connector: "Helcim",
+        })?,
+    }
+}
+
+//Masking strategies used when request bodies are mask serialized for logs and connector events.
+//The request sent to Helcim is serialized with the values exposed, so it is not affected.
+#[derive(Debug)]
+pub enum HelcimCardNumberStrategy {}
+
+impl<T> Strategy<T> for HelcimCardNumberStrategy
+where
+    T: AsRef<str>,
+{
+    //Keeps the BIN and the last four digits of the card number
+    fn fmt(val: &T, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val_str: &str = val.as_ref();
+        let last_four_index = val_str.len().saturating_sub(4);
+        match (val_str.get(..6), val_str.get(last_four_index..)) {
+            (Some(bin), Some(last_four)) if (15..=19).contains(&val_str.len()) => write!(
+                f,
+                "{bin}{}{last_four}",
+                "*".repeat(last_four_index.saturating_sub(6))
+            ),
+            _ => WithType::fmt(val, f),
+        }
+    }
+}
+
+//The card number is serialized through the Helcim strategy, as the strategy of the card number
+//only keeps the BIN when it is mask serialized
+fn serialize_card_number<S>(
+    card_number: &cards::CardNumber,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    Secret::<String, HelcimCardNumberStrategy>::new(card_number.clone().get_card_no())
+        .serialize(serializer)
+}
+
+#[derive(Debug)]
+pub enum HelcimPartialMaskStrategy {}
+
+impl<T> Strategy<T> for HelcimPartialMaskStrategy
+where
+    T: AsRef<str>,
+{
+    //Keeps the first two characters, values too short to be partially masked are masked fully
+    fn fmt(val: &T, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val_str: &str = val.as_ref();
+        let length = val_str.chars().count();
+        if length <= 4 {
+            return WithType::fmt(val, f);
+        }
+        let visible: String = val_str.chars().take(2).collect();
+        write!(f, "{visible}{}", "*".repeat(length.saturating_sub(2)))
+    }
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HelcimBillingAddress {
-    name: Secret<String>,
-    street1: Secret<String>,
+    name: Secret<String, HelcimPartialMaskStrategy>,
+    street1: Secret<String, HelcimPartialMaskStrategy>,
     postal_code: Secret<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    street2: Option<Secret<String>>,
+    street2: Option<Secret<String, HelcimPartialMaskStrategy>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     city: Option<String>,
+    //Helcim expects the ISO 3166-1 alpha-3 code of the country, which improves AVS matching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<enums::CountryAlpha3>,
     #[serde(skip_serializing_if = "Option::is_none")]
     email: Option<Email>,
 }
@@ -109,46 +704,159 @@ pub struct HelcimInvoice {
 pub struct HelcimLineItems {
     description: String,
     quantity: u8,
-    price: f64,
-    total: f64,
+    price: HelcimAmount,
+    total: HelcimAmount,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HelcimCard {
+    #[serde(serialize_with = "serialize_card_number")]
     card_number: cards::CardNumber,
     card_expiry: Secret<String>,
     card_c_v_v: Secret<String>,
 }
 
+//Helcim documents the card expiry as MMYY, without any delimiter between month and year
+const HELCIM_CARD_EXPIRY_DELIMITER: &str = "";
+
+fn get_helcim_card_expiry(
+    expiry_month: &str,
+    expiry_year: &str,
+    delimiter: &str,
+) -> Result<Secret<String>, errors::ConnectorError> {
+    let expiry_month = expiry_month
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or(errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_exp_month",
+        })?;
+    let expiry_year = expiry_year.trim().parse::<u16>().map_err(|_| {
+        errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_exp_year",
+        }
+    })?;
+    Ok(Secret::new(format!(
+        "{expiry_month:02}{delimiter}{:02}",
+        expiry_year % 100
+    )))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum HelcimCardData {
+    Card(HelcimCard),
+    Token(HelcimCardToken),
+}
+
+//Card token returned by Helcim for a verified card, used to charge the card in subsequent mandate payments
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimCardToken {
+    card_token: Secret<String>,
+}
+
+impl From<HelcimCard> for HelcimCardData {
+    fn from(card: HelcimCard) -> Self {
+        Self::Card(card)
+    }
+}
+
+//Card tokenized on the client with HelcimPay.js, which is charged by its card token alone
+impl TryFrom<&domain::CardToken> for HelcimCardData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(card_token: &domain::CardToken) -> Result<Self, Self::Error> {
+        let connector_token = card_token.connector_token.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_method_data.card_token.connector_token",
+            },
+        )?;
+        if card_token.card_cvc.is_some() {
+            Err(errors::ConnectorError::InvalidDataFormat {
+                field_name: "payment_method_data.card_token.card_cvc",
+            })
+            .attach_printable(
+                "Card details cannot be passed along with a HelcimPay.js card token",
+            )?
+        }
+        Ok(Self::Token(HelcimCardToken {
+            card_token: connector_token,
+        }))
+    }
+}
+
+impl TryFrom<&domain::Card> for HelcimCard {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(req_card: &domain::Card) -> Result<Self, Self::Error> {
+        //Helcim rejects invalid cards with a generic error, so they are validated beforehand
+        req_card.validate_card_details()?;
+        Ok(Self {
+            card_expiry: get_helcim_card_expiry(
+                req_card.card_exp_month.peek(),
+                req_card.card_exp_year.peek(),
+                HELCIM_CARD_EXPIRY_DELIMITER,
+            )?,
+            card_number: req_card.card_number.clone(),
+            card_c_v_v: req_card.card_cvc.clone(),
+        })
+    }
+}
+
+//Manual captures of cards expiring within the horizon of the merchant are rejected, as the card may
+//expire before the capture. Automatic captures are captured along with the authorization, so they
+//are only logged.
+fn validate_card_expiry_horizon(
+    item: &types::PaymentsAuthorizeRouterData,
+    card: &domain::Card,
+) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+    let Some(horizon_months) =
+        HelcimConnectorMetadataObject::try_from(item.connector_meta_data.as_ref())?
+            .card_expiry_horizon_months
+    else {
+        return Ok(());
+    };
+    if !card.expires_within(horizon_months)? {
+        return Ok(());
+    }
+    if item.request.is_auto_capture()? {
+        router_env::logger::warn!(
+            card_expiry_horizon_months = horizon_months,
+            "Card expires within the card expiry horizon of the merchant"
+        );
+        Ok(())
+    } else {
+        Err(report!(errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_expiry",
+        }))
+        .attach_printable(format!(
+            "Card expires within {horizon_months} months, before the payment may be captured"
+        ))
+    }
+}
+
 impl TryFrom<(&types::SetupMandateRouterData, &domain::Card)> for HelcimVerifyRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         value: (&types::SetupMandateRouterData, &domain::Card),
     ) -> Result<Self, Self::Error> {
         let (item, req_card) = value;
-        let card_data = HelcimCard {
-            card_expiry: req_card
-                .get_card_expiry_month_year_2_digit_with_delimiter("".to_string())?,
-            card_number: req_card.card_number.clone(),
-            card_c_v_v: req_card.card_cvc.clone(),
-        };
-        let req_address = item.get_billing_address()?.to_owned();
-
-        let billing_address = HelcimBillingAddress {
-            name: req_address.get_full_name()?,
-            street1: req_address.get_line1()?.to_owned(),
-            postal_code: req_address.get_zip()?.to_owned(),
-            street2: req_address.line2,
-            city: req_address.city,
-            email: item.request.email.clone(),
-        };
+        let card_data = HelcimCard::try_from(req_card)?;
+        let billing_address = HelcimBillingAddress::try_from((
+            item.get_optional_billing()
+                .and_then(|billing| billing.address.as_ref()),
+            item.request.email.clone(),
+            HelcimConnectorMetadataObject::try_from(item.connector_meta_data.as_ref())?
+                .default_country,
+        ))?;
         let ip_address = item.request.get_browser_info()?.get_ip_address()?;
         let currency = check_currency(item.request.currency)?;
         Ok(Self {
             currency,
             ip_address,
             card_data,
+            invoice_number: item.connector_request_reference_id.clone(),
             billing_address,
             ecommerce: None,
         })
@@ -183,47 +891,225 @@ impl TryFrom<&types::SetupMandateRouterData> for HelcimVerifyRequest {
     }
 }
 
+//Helcim only mandates the postal code of the billing address, which it uses for AVS.
+//The name and street1 fields are still part of the request, so they fall back to a placeholder.
+const HELCIM_BILLING_FIELD_NOT_PROVIDED: &str = "Not Provided";
+
+//Helcim rejects billing names longer than this
+const HELCIM_BILLING_NAME_MAX_LENGTH: usize = 100;
+
+//Name on the billing address, split into the first and last names the issuer matches against
+#[derive(Debug, PartialEq)]
+struct HelcimBillingName {
+    first_name: String,
+    middle_names: Vec<String>,
+    last_name: Option<String>,
+}
+
+impl HelcimBillingName {
+    //Full names may be given entirely in the first name, in which case the last word is taken as
+    //the last name. Words without any letters or digits are dropped. Returns None when no name is
+    //given, and an error when none of the given name is usable.
+    fn try_from_names(
+        first_name: Option<&Secret<String>>,
+        last_name: Option<&Secret<String>>,
+    ) -> Result<Option<Self>, errors::ConnectorError> {
+        let is_given =
+            |name: Option<&Secret<String>>| name.is_some_and(|name| !name.peek().trim().is_empty());
+        if !is_given(first_name) && !is_given(last_name) {
+            return Ok(None);
+        }
+        let get_words = |name: Option<&Secret<String>>| {
+            name.map(|name| {
+                name.peek()
+                    .split_whitespace()
+                    .filter(|word| word.chars().any(char::is_alphanumeric))
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+        };
+        let mut given_names = get_words(first_name);
+        let mut last_names = get_words(last_name);
+        if last_names.is_empty() && given_names.len() > 1 {
+            last_names.extend(given_names.pop());
+        }
+        //A last name given alone is sent as the only name
+        if given_names.is_empty() && !last_names.is_empty() {
+            given_names.push(last_names.join(" "));
+            last_names.clear();
+        }
+        let mut given_names = given_names.into_iter();
+        let first_name = given_names
+            .next()
+            .ok_or(errors::ConnectorError::InvalidDataFormat {
+                field_name: "billing.address.first_name",
+            })?;
+        Ok(Some(Self {
+            first_name,
+            middle_names: given_names.collect(),
+            last_name: (!last_names.is_empty()).then(|| last_names.join(" ")),
+        }))
+    }
+
+    //Names over the length limit drop the middle names first and then shorten the first name, as
+    //the last name is what the issuer matches on
+    fn get_name(&self) -> String {
+        let full_name = std::iter::once(&self.first_name)
+            .chain(self.middle_names.iter())
+            .chain(self.last_name.iter())
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if full_name.chars().count() <= HELCIM_BILLING_NAME_MAX_LENGTH {
+            return full_name;
+        }
+        if let Some(last_name) = self.last_name.as_ref() {
+            let first_name_length = HELCIM_BILLING_NAME_MAX_LENGTH
+                .saturating_sub(last_name.chars().count())
+                .saturating_sub(1);
+            if first_name_length > 0 {
+                let first_name = self
+                    .first_name
+                    .chars()
+                    .take(first_name_length)
+                    .collect::<String>();
+                return format!("{first_name} {last_name}");
+            }
+        }
+        full_name
+            .chars()
+            .take(HELCIM_BILLING_NAME_MAX_LENGTH)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+}
+
+//Helcim rejects malformed US ZIP codes and Canadian postal codes, so they are validated and sent
+//as 12345, 12345-6789 or A1A 1A1. Postal codes of other countries are sent as they are.
+fn get_helcim_postal_code(
+    postal_code: &str,
+    country: Option<enums::CountryAlpha2>,
+) -> Result<Secret<String>, errors::ConnectorError> {
+    let postal_code = postal_code.trim();
+    let characters = postal_code
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .map(|character| character.to_ascii_uppercase())
+        .collect::<String>();
+    let postal_code = match country {
+        Some(enums::CountryAlpha2::US) => {
+            let is_numeric = characters
+                .chars()
+                .all(|character| character.is_ascii_digit());
+            match (is_numeric, characters.len()) {
+                (true, 5) => Some(characters),
+                (true, 9) => characters
+                    .get(..5)
+                    .zip(characters.get(5..))
+                    .map(|(zip, plus_four)| format!("{zip}-{plus_four}")),
+                _ => None,
+            }
+        }
+        Some(enums::CountryAlpha2::CA) => {
+            let is_valid = characters.len() == 6
+                && characters.chars().enumerate().all(|(index, character)| {
+                    if index % 2 == 0 {
+                        character.is_ascii_alphabetic()
+                    } else {
+                        character.is_ascii_digit()
+                    }
+                });
+            is_valid
+                .then(|| characters.get(..3).zip(characters.get(3..)))
+                .flatten()
+                .map(|(forward_sortation_area, local_delivery_unit)| {
+                    format!("{forward_sortation_area} {local_delivery_unit}")
+                })
+        }
+        _ => Some(postal_code.to_string()),
+    };
+    postal_code
+        .map(Secret::new)
+        .ok_or(errors::ConnectorError::InvalidDataFormat {
+            field_name: "billing.address.zip",
+        })
+}
+
 impl
     TryFrom<(
-        &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
-        &domain::Card,
-    )> for HelcimPaymentsRequest
+        Option<&api::AddressDetails>,
+        Option<Email>,
+        Option<enums::CountryAlpha2>,
+    )> for HelcimBillingAddress
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        value: (
-            &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
-            &domain::Card,
+        (billing_address, email, default_country): (
+            Option<&api::AddressDetails>,
+            Option<Email>,
+            Option<enums::CountryAlpha2>,
         ),
     ) -> Result<Self, Self::Error> {
-        let (item, req_card) = value;
-        let card_data = HelcimCard {
-            card_expiry: req_card
-                .get_card_expiry_month_year_2_digit_with_delimiter("".to_string())?,
-            card_number: req_card.card_number.clone(),
-            card_c_v_v: req_card.card_cvc.clone(),
-        };
-        let req_address = item
-            .router_data
-            .get_billing()?
-            .to_owned()
-            .address
-            .ok_or_else(utils::missing_field_err("billing.address"))?;
+        let postal_code = billing_address.and_then(|address| address.zip.clone());
+        let missing_fields = collect_missing_value_keys!(("billing.address.zip", postal_code));
 
-        let billing_address = HelcimBillingAddress {
-            name: req_address.get_full_name()?,
-            street1: req_address.get_line1()?.to_owned(),
-            postal_code: req_address.get_zip()?.to_owned(),
-            street2: req_address.line2,
-            city: req_address.city,
-            email: item.router_data.request.email.clone(),
-        };
+        match (billing_address, postal_code) {
+            (Some(address), Some(postal_code)) => {
+                let country = address.country.or(default_country);
+                Ok(Self {
+                    name: Secret::new(
+                        HelcimBillingName::try_from_names(
+                            address.first_name.as_ref(),
+                            address.last_name.as_ref(),
+                        )?
+                        .map(|billing_name| billing_name.get_name())
+                        .unwrap_or(HELCIM_BILLING_FIELD_NOT_PROVIDED.to_string()),
+                    ),
+                    street1: Secret::new(
+                        address
+                            .line1
+                            .clone()
+                            .map(ExposeInterface::expose)
+                            .unwrap_or(HELCIM_BILLING_FIELD_NOT_PROVIDED.to_string()),
+                    ),
+                    postal_code: get_helcim_postal_code(postal_code.peek(), country)?,
+                    street2: address
+                        .line2
+                        .clone()
+                        .map(|street2| Secret::new(street2.expose())),
+                    city: address.city.clone(),
+                    country: country.map(enums::CountryAlpha2::from_alpha2_to_alpha3),
+                    email,
+                })
+            }
+            _ => Err(errors::ConnectorError::MissingRequiredFields {
+                field_names: missing_fields,
+            }
+            .into()),
+        }
+    }
+}
 
-        let ip_address = item
-            .router_data
-            .request
-            .get_browser_info()?
-            .get_ip_address()?;
+impl TryFrom<&HelcimRouterData<&types::PaymentsAuthorizeRouterData>> for HelcimBillingAddress {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Self::try_from((
+            item.router_data
+                .get_optional_billing()
+                .and_then(|billing| billing.address.as_ref()),
+            item.router_data.request.email.clone(),
+            HelcimConnectorMetadataObject::try_from(item.router_data.connector_meta_data.as_ref())?
+                .default_country,
+        ))
+    }
+}
+
+impl From<&HelcimRouterData<&types::PaymentsAuthorizeRouterData>> for HelcimInvoice {
+    fn from(item: &HelcimRouterData<&types::PaymentsAuthorizeRouterData>) -> Self {
         let line_items = vec![
             (HelcimLineItems {
                 description: item
@@ -237,20 +1123,104 @@ impl
                 total: item.amount,
             }),
         ];
-        let invoice = HelcimInvoice {
+        Self {
             invoice_number: item.router_data.connector_request_reference_id.clone(),
             line_items,
-        };
-        let currency = check_currency(item.router_data.request.currency)?;
-        Ok(Self {
-            amount: item.amount,
-            currency,
-            ip_address,
-            card_data,
-            invoice,
-            billing_address,
+        }
+    }
+}
+
+impl
+    TryFrom<(
+        &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+        HelcimCardData,
+    )> for HelcimPaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        value: (
+            &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+            HelcimCardData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (item, card_data) = value;
+        let billing_address = HelcimBillingAddress::try_from(item)?;
+        let ip_address = item
+            .router_data
+            .request
+            .get_browser_info()?
+            .get_ip_address()?;
+        let currency = check_currency(item.router_data.request.currency)?;
+        let statement_descriptor = get_statement_descriptor(
+            item.router_data.request.statement_descriptor.as_ref(),
+            item.router_data.connector_meta_data.as_ref(),
+        )?;
+        let (tip_amount, convenience_fee) = get_tip_amount_and_convenience_fee(item)?;
+        Ok(Self::Card(HelcimCardPaymentRequest {
+            amount: item.amount,
+            currency,
+            ip_address,
+            card_data,
+            invoice: HelcimInvoice::from(item),
+            billing_address,
             ecommerce: None,
-        })
+            statement_descriptor,
+            customer_code: item.router_data.connector_customer.clone(),
+            comments: get_comments(
+                item.router_data.request.metadata.as_ref(),
+                item.router_data.description.as_ref(),
+            ),
+            tip_amount,
+            convenience_fee,
+            three_d_s: item
+                .router_data
+                .request
+                .authentication_data
+                .as_ref()
+                .map(HelcimThreeDsData::from),
+            mcc: get_mcc(item.router_data.connector_meta_data.as_ref())?,
+        }))
+    }
+}
+
+impl
+    TryFrom<(
+        &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+        &domain::BankDebitData,
+    )> for HelcimPaymentsRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        value: (
+            &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
+            &domain::BankDebitData,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (item, bank_debit_data) = value;
+        let bank_data = HelcimBankData::try_from(bank_debit_data)?;
+        let billing_address = HelcimBillingAddress::try_from(item)?;
+        let ip_address = item
+            .router_data
+            .request
+            .get_browser_info()?
+            .get_ip_address()?;
+        let currency = check_bank_debit_currency(item.router_data.request.currency)?;
+        let (tip_amount, convenience_fee) = get_tip_amount_and_convenience_fee(item)?;
+        Ok(Self::Bank(HelcimBankPaymentRequest {
+            amount: item.amount,
+            currency,
+            ip_address,
+            bank_data,
+            invoice: HelcimInvoice::from(item),
+            billing_address,
+            customer_code: item.router_data.connector_customer.clone(),
+            comments: get_comments(
+                item.router_data.request.metadata.as_ref(),
+                item.router_data.description.as_ref(),
+            ),
+            tip_amount,
+            convenience_fee,
+        }))
     }
 }
 
@@ -260,22 +1230,37 @@ impl TryFrom<&HelcimRouterData<&types::PaymentsAuthorizeRouterData>> for HelcimP
         item: &HelcimRouterData<&types::PaymentsAuthorizeRouterData>,
     ) -> Result<Self, Self::Error> {
         match item.router_data.request.payment_method_data.clone() {
-            domain::PaymentMethodData::Card(req_card) => Self::try_from((item, &req_card)),
+            domain::PaymentMethodData::Card(req_card) => {
+                let card = HelcimCard::try_from(&req_card)?;
+                validate_card_expiry_horizon(item.router_data, &req_card)?;
+                Self::try_from((item, card.into()))
+            }
+            domain::PaymentMethodData::MandatePayment => {
+                let card_token = HelcimCardToken {
+                    card_token: Secret::new(item.router_data.request.get_connector_mandate_id()?),
+                };
+                Self::try_from((item, HelcimCardData::Token(card_token)))
+            }
+            domain::PaymentMethodData::CardToken(card_token) => {
+                Self::try_from((item, HelcimCardData::try_from(&card_token)?))
+            }
+            domain::PaymentMethodData::BankDebit(bank_debit_data) => {
+                Self::try_from((item, &bank_debit_data))
+            }
             domain::PaymentMethodData::BankTransfer(_) => {
                 Err(errors::ConnectorError::NotImplemented("Payment Method".to_string()).into())
             }
+            //The card data of the Helcim api has no field for the cryptogram and ECI of a wallet
+            //token, and a device account number charged without them is declined
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Wallet(_)
             | domain::PaymentMethodData::PayLater(_)
             | domain::PaymentMethodData::BankRedirect(_)
-            | domain::PaymentMethodData::BankDebit(_)
             | domain::PaymentMethodData::Crypto(_)
-            | domain::PaymentMethodData::MandatePayment
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
-            | domain::PaymentMethodData::GiftCard(_)
-            | domain::PaymentMethodData::CardToken(_) => {
+            | domain::PaymentMethodData::GiftCard(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("Helcim"),
                 ))?
@@ -284,9 +1269,87 @@ impl TryFrom<&HelcimRouterData<&types::PaymentsAuthorizeRouterData>> for HelcimP
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimCustomerRequest {
+    contact_name: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cell_phone: Option<Secret<String>>,
+}
+
+impl TryFrom<&types::ConnectorCustomerData> for HelcimCustomerRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorCustomerData) -> Result<Self, Self::Error> {
+        //Helcim requires a contact name for a customer, the email is used when the name is absent
+        let contact_name = item
+            .name
+            .clone()
+            .or_else(|| {
+                item.email
+                    .as_ref()
+                    .map(|email| Secret::new(email.peek().to_owned()))
+            })
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "name or email",
+            })?;
+        Ok(Self {
+            contact_name,
+            email: item.email.clone(),
+            cell_phone: item.phone.clone(),
+        })
+    }
+}
+
+//Card tokens are saved against the Helcim customer, so the customer code is needed to delete them
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimDeleteCardRequest {
+    customer_code: String,
+    card_token: Secret<String>,
+}
+
+impl TryFrom<&types::MandateRevokeRouterData> for HelcimDeleteCardRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::MandateRevokeRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            customer_code: item.get_connector_customer_id()?,
+            card_token: Secret::new(item.request.get_connector_mandate_id()?),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimCustomerResponse {
+    id: u64,
+    //Payments are linked to a Helcim customer using the customer code
+    customer_code: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, HelcimCustomerResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, HelcimCustomerResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::ConnectorCustomerResponse {
+                connector_customer_id: item.response.customer_code,
+            }),
+            ..item.data
+        })
+    }
+}
+
 // Auth Struct
 pub struct HelcimAuthType {
     pub(super) api_key: Secret<String>,
+    /// Present only for merchants provisioned with an account id and api token pair
+    pub(super) account_id: Option<Secret<String>>,
 }
 
 impl TryFrom<&types::ConnectorAuthType> for HelcimAuthType {
@@ -295,6 +1358,11 @@ impl TryFrom<&types::ConnectorAuthType> for HelcimAuthType {
         match auth_type {
             types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
                 api_key: api_key.to_owned(),
+                account_id: None,
+            }),
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                api_key: api_key.to_owned(),
+                account_id: Some(key1.to_owned()),
             }),
             _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
         }
@@ -306,6 +1374,14 @@ impl TryFrom<&types::ConnectorAuthType> for HelcimAuthType {
 pub enum HelcimPaymentStatus {
     Approved,
     Declined,
+    //Bank payments are processed asynchronously and remain pending until they are settled
+    Pending,
+    //Transactions held for manual fraud review, which can still be approved or declined by Helcim
+    #[serde(alias = "PENDING REVIEW", alias = "PENDING_REVIEW")]
+    Held,
+    //Statuses introduced by Helcim which are not known yet
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -316,39 +1392,422 @@ pub enum HelcimTransactionType {
     Capture,
     Verify,
     Reverse,
+    //Transaction types introduced by Helcim which are not known yet, e.g. withdrawals
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+//Helcim adds transaction types and statuses without notice. Unknown values are logged so that
+//they can be mapped, and the transaction is left pending instead of failing the sync.
+fn log_unknown_helcim_value(field: &str, value: &str, transaction_id: HelcimTransactionId) {
+    router_env::logger::warn!(
+        helcim_field = field,
+        helcim_value = value,
+        %transaction_id,
+        "Unknown value received in Helcim response"
+    );
+}
+
+//Maps a Helcim transaction type and status to an attempt status. This only needs the stored type
+//and status of a transaction, so that it can also be used to reconcile transactions offline.
+pub fn get_attempt_status(
+    transaction_type: &HelcimTransactionType,
+    status: &HelcimPaymentStatus,
+) -> enums::AttemptStatus {
+    match transaction_type {
+        //A verify is only sent for mandate setup, which the core considers complete once the attempt is Charged.
+        //The card token returned with it is stored as the connector mandate id, so that the card can be charged later.
+        HelcimTransactionType::Purchase | HelcimTransactionType::Verify => match status {
+            HelcimPaymentStatus::Approved => enums::AttemptStatus::Charged,
+            HelcimPaymentStatus::Declined => enums::AttemptStatus::Failure,
+            HelcimPaymentStatus::Pending | HelcimPaymentStatus::Unknown(_) => {
+                enums::AttemptStatus::Pending
+            }
+            HelcimPaymentStatus::Held => enums::AttemptStatus::Unresolved,
+        },
+        HelcimTransactionType::PreAuth => match status {
+            HelcimPaymentStatus::Approved => enums::AttemptStatus::Authorized,
+            HelcimPaymentStatus::Declined => enums::AttemptStatus::AuthorizationFailed,
+            HelcimPaymentStatus::Pending | HelcimPaymentStatus::Unknown(_) => {
+                enums::AttemptStatus::Pending
+            }
+            HelcimPaymentStatus::Held => enums::AttemptStatus::Unresolved,
+        },
+        //Declined captures are treated as declined by the issuer, unless the response tells that the
+        //authorization expired, see `HelcimCaptureDeclineReason`
+        HelcimTransactionType::Capture => match status {
+            HelcimPaymentStatus::Approved => enums::AttemptStatus::Charged,
+            HelcimPaymentStatus::Declined => enums::AttemptStatus::CaptureFailed,
+            HelcimPaymentStatus::Pending | HelcimPaymentStatus::Held => {
+                enums::AttemptStatus::CaptureInitiated
+            }
+            HelcimPaymentStatus::Unknown(_) => enums::AttemptStatus::Pending,
+        },
+        HelcimTransactionType::Reverse => match status {
+            HelcimPaymentStatus::Approved => enums::AttemptStatus::Voided,
+            HelcimPaymentStatus::Declined => enums::AttemptStatus::VoidFailed,
+            HelcimPaymentStatus::Pending | HelcimPaymentStatus::Held => {
+                enums::AttemptStatus::VoidInitiated
+            }
+            HelcimPaymentStatus::Unknown(_) => enums::AttemptStatus::Pending,
+        },
+        HelcimTransactionType::Unknown(_) => enums::AttemptStatus::Pending,
+    }
 }
 
 impl From<HelcimPaymentsResponse> for enums::AttemptStatus {
     fn from(item: HelcimPaymentsResponse) -> Self {
-        match item.transaction_type {
-            HelcimTransactionType::Purchase | HelcimTransactionType::Verify => match item.status {
-                HelcimPaymentStatus::Approved => Self::Charged,
-                HelcimPaymentStatus::Declined => Self::Failure,
-            },
-            HelcimTransactionType::PreAuth => match item.status {
-                HelcimPaymentStatus::Approved => Self::Authorized,
-                HelcimPaymentStatus::Declined => Self::AuthorizationFailed,
-            },
-            HelcimTransactionType::Capture => match item.status {
-                HelcimPaymentStatus::Approved => Self::Charged,
-                HelcimPaymentStatus::Declined => Self::CaptureFailed,
-            },
-            HelcimTransactionType::Reverse => match item.status {
-                HelcimPaymentStatus::Approved => Self::Voided,
-                HelcimPaymentStatus::Declined => Self::VoidFailed,
-            },
+        if let HelcimPaymentStatus::Unknown(status) = &item.status {
+            log_unknown_helcim_value("status", status, item.transaction_id);
+        }
+        if let HelcimTransactionType::Unknown(transaction_type) = &item.transaction_type {
+            log_unknown_helcim_value("type", transaction_type, item.transaction_id);
+        }
+        match item.get_capture_decline_reason() {
+            Some(capture_decline_reason) => capture_decline_reason.get_attempt_status(),
+            None => get_attempt_status(&item.transaction_type, &item.status),
+        }
+    }
+}
+
+//Helcim declines a capture either because the issuer declined it, in which case the capture can be
+//retried later, or because the authorization expired, in which case the payment has to be
+//authorized again before it can be captured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HelcimCaptureDeclineReason {
+    IssuerDeclined,
+    AuthorizationExpired,
+}
+
+//Key under which Helcim reports the error of a preauth which can no longer be captured
+const HELCIM_PREAUTH_ERROR_FIELD: &str = "preAuthTransactionId";
+
+impl HelcimCaptureDeclineReason {
+    //Helcim reports an expired authorization under the field of the capture request naming the
+    //preauth, any other decline is the issuer's. The message is not matched, as its wording is not
+    //stable and an expired card is worded much like an expired authorization.
+    fn from_response_errors(errors: Option<&serde_json::Value>) -> Self {
+        match errors.and_then(|errors| errors.get(HELCIM_PREAUTH_ERROR_FIELD)) {
+            Some(_) => Self::AuthorizationExpired,
+            None => Self::IssuerDeclined,
+        }
+    }
+
+    //The attempt of an expired authorization is failed, so that the orchestrator authorizes the
+    //payment again instead of retrying the capture
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match self {
+            Self::IssuerDeclined => enums::AttemptStatus::CaptureFailed,
+            Self::AuthorizationExpired => enums::AttemptStatus::Failure,
         }
     }
 }
 
+//Identifier Helcim assigns to every transaction (purchase, preauth, capture, refund, reverse)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct HelcimTransactionId(u64);
+
+impl std::fmt::Display for HelcimTransactionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for HelcimTransactionId {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u64>().map(Self)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HelcimPaymentsResponse {
     status: HelcimPaymentStatus,
-    transaction_id: u64,
+    transaction_id: HelcimTransactionId,
     invoice_number: Option<String>,
     #[serde(rename = "type")]
     transaction_type: HelcimTransactionType,
+    card_token: Option<Secret<String>>,
+    //Customer code is only returned when the card is saved against a Helcim customer
+    customer_code: Option<String>,
+    //Network transaction id is only returned for card transactions routed through networks which issue one
+    network_transaction_id: Option<String>,
+    //Batch and settlement date are only returned once the transaction is settled
+    batch_id: Option<u64>,
+    date_settled: Option<String>,
+    //Card details are only returned for card transactions
+    card_type: Option<HelcimCardBrand>,
+    card_funding: Option<HelcimCardFunding>,
+    //Amount approved by the issuer, in the unit the amount was sent in. It is less than the
+    //requested amount when the card has insufficient funds for the full amount.
+    approved_amount: Option<f64>,
+    //Amount Helcim recorded for the transaction, returned on lookup of the transaction
+    amount: Option<f64>,
+    //Currency the transaction was processed in, which differs from the requested currency when
+    //the cardholder chose to pay in their own currency through dynamic currency conversion
+    currency: Option<HelcimCurrency>,
+    //Message of the issuer or of Helcim, explaining why a transaction was declined
+    response_message: Option<String>,
+    //Errors of a declined transaction, keyed by the field of the request they were reported for
+    errors: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub enum HelcimCardBrand {
+    #[serde(alias = "VI")]
+    Visa,
+    #[serde(alias = "MC")]
+    Mastercard,
+    #[serde(alias = "AX")]
+    AmericanExpress,
+    #[serde(alias = "DI")]
+    Discover,
+    #[serde(alias = "JC")]
+    Jcb,
+    Interac,
+    #[serde(other)]
+    Other,
+}
+
+impl HelcimCardBrand {
+    fn get_card_network(&self) -> Option<enums::CardNetwork> {
+        match self {
+            Self::Visa => Some(enums::CardNetwork::Visa),
+            Self::Mastercard => Some(enums::CardNetwork::Mastercard),
+            Self::AmericanExpress => Some(enums::CardNetwork::AmericanExpress),
+            Self::Discover => Some(enums::CardNetwork::Discover),
+            Self::Jcb => Some(enums::CardNetwork::JCB),
+            Self::Interac => Some(enums::CardNetwork::Interac),
+            Self::Other => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HelcimCardFunding {
+    Credit,
+    Debit,
+    Prepaid,
+    #[serde(other)]
+    Unknown,
+}
+
+impl HelcimCardFunding {
+    fn get_card_type(&self) -> Option<String> {
+        match self {
+            Self::Credit => Some("credit".to_string()),
+            Self::Debit => Some("debit".to_string()),
+            Self::Prepaid | Self::Unknown => None,
+        }
+    }
+}
+
+impl HelcimPaymentsResponse {
+    //Invoice number is preferred as it is what the Helcim dashboard lists transactions by
+    fn get_connector_response_reference_id(&self) -> Option<String> {
+        Some(
+            self.invoice_number
+                .clone()
+                .unwrap_or_else(|| self.transaction_id.to_string()),
+        )
+    }
+
+    //Network transaction id is used for subsequent merchant initiated payments, so only approved auths carry it
+    fn get_network_txn_id(&self) -> Option<String> {
+        match self.status {
+            HelcimPaymentStatus::Approved => self.network_transaction_id.clone(),
+            HelcimPaymentStatus::Declined
+            | HelcimPaymentStatus::Pending
+            | HelcimPaymentStatus::Held
+            | HelcimPaymentStatus::Unknown(_) => None,
+        }
+    }
+
+    //Helcim returns the card token on every approved card transaction, so it is kept whether or not
+    //a mandate is set up
+    fn get_card_token_metadata(&self) -> Option<HelcimCardTokenMetaData> {
+        match self.status {
+            HelcimPaymentStatus::Approved => {
+                self.card_token
+                    .clone()
+                    .map(|card_token| HelcimCardTokenMetaData {
+                        card_token,
+                        customer_code: self.customer_code.clone(),
+                    })
+            }
+            HelcimPaymentStatus::Declined
+            | HelcimPaymentStatus::Pending
+            | HelcimPaymentStatus::Held
+            | HelcimPaymentStatus::Unknown(_) => None,
+        }
+    }
+
+    fn get_mandate_reference(&self) -> Option<types::MandateReference> {
+        self.card_token
+            .as_ref()
+            .map(|card_token| types::MandateReference {
+                connector_mandate_id: Some(card_token.peek().to_owned()),
+                payment_method_id: None,
+            })
+    }
+
+    //Card brand and funding are surfaced for the merchant's surcharging and routing rules,
+    //along with the liability shift of 3DS authenticated payments
+    fn get_additional_payment_method_data(
+        &self,
+        authentication_data: Option<&AuthenticationData>,
+    ) -> Option<types::AdditionalPaymentMethodConnectorResponse> {
+        if self.card_type.is_none() && self.card_funding.is_none() && authentication_data.is_none()
+        {
+            return None;
+        }
+        Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+            authentication_data: authentication_data.map(|authentication_data| {
+                serde_json::json!(HelcimThreeDsResult::from(authentication_data))
+            }),
+            payment_checks: None,
+            card_network: self
+                .card_type
+                .as_ref()
+                .and_then(HelcimCardBrand::get_card_network),
+            card_type: self
+                .card_funding
+                .as_ref()
+                .and_then(HelcimCardFunding::get_card_type),
+        })
+    }
+
+    //Returns the approved amount, in the minor unit of the currency, if the transaction was
+    //approved for less than the requested amount
+    fn get_partially_approved_amount(
+        &self,
+        requested_amount: i64,
+        currency_unit: &api::CurrencyUnit,
+        currency: enums::Currency,
+    ) -> Result<Option<i64>, error_stack::Report<errors::ConnectorError>> {
+        match (&self.status, self.approved_amount) {
+            (HelcimPaymentStatus::Approved, Some(approved_amount)) => {
+                let approved_amount =
+                    get_amount_in_minor_unit(approved_amount, currency_unit, currency)?;
+                Ok((approved_amount < requested_amount).then_some(approved_amount))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    //Only approved transactions are reconciled, the amount of other transactions is not final
+    fn get_amount_reconciliation(
+        &self,
+        expected_amount: i64,
+        expected_currency: enums::Currency,
+        currency_unit: &api::CurrencyUnit,
+    ) -> Result<Option<HelcimAmountReconciliation>, error_stack::Report<errors::ConnectorError>>
+    {
+        let connector_amount = match (&self.status, self.approved_amount.or(self.amount)) {
+            (HelcimPaymentStatus::Approved, Some(connector_amount)) => {
+                get_amount_in_minor_unit(connector_amount, currency_unit, expected_currency)?
+            }
+            _ => return Ok(None),
+        };
+        let is_same_currency = self
+            .currency
+            .as_ref()
+            .map_or(true, |currency| currency.is(expected_currency));
+        let difference = connector_amount.saturating_sub(expected_amount);
+        let reason =
+            if is_same_currency && difference.abs() <= HELCIM_AMOUNT_RECONCILIATION_TOLERANCE {
+                return Ok(None);
+            } else if is_same_currency && difference < 0 {
+                HelcimAmountMismatchReason::PartialApproval
+            } else {
+                HelcimAmountMismatchReason::GrossMismatch
+            };
+        Ok(Some(HelcimAmountReconciliation {
+            reason,
+            expected_amount,
+            expected_currency,
+            connector_amount,
+            connector_currency: self.currency.clone(),
+        }))
+    }
+
+    fn get_currency_mismatch(
+        &self,
+        expected_currency: enums::Currency,
+    ) -> Option<HelcimCurrencyMismatch> {
+        let currency_mismatch = self
+            .currency
+            .as_ref()
+            .filter(|currency| !currency.is(expected_currency))
+            .map(|connector_currency| HelcimCurrencyMismatch {
+                expected_currency,
+                connector_currency: connector_currency.clone(),
+            });
+        if let Some(currency_mismatch) = currency_mismatch.as_ref() {
+            router_env::logger::warn!(
+                transaction_id = %self.transaction_id,
+                expected_currency = %currency_mismatch.expected_currency,
+                connector_currency = ?currency_mismatch.connector_currency,
+                "Currency of Helcim transaction does not match the currency of the payment"
+            );
+        }
+        currency_mismatch
+    }
+
+    //Merchant accounts settling in CAD only convert approved transactions into CAD, the amount
+    //Helcim returns is then the amount settled in CAD rather than the amount the customer was charged
+    fn get_currency_conversion(
+        &self,
+        presentment_amount: i64,
+        presentment_currency: enums::Currency,
+        currency_unit: &api::CurrencyUnit,
+    ) -> Result<Option<HelcimCurrencyConversion>, error_stack::Report<errors::ConnectorError>> {
+        match (
+            &self.status,
+            self.currency.as_ref(),
+            self.approved_amount.or(self.amount),
+        ) {
+            (
+                HelcimPaymentStatus::Approved,
+                Some(HelcimCurrency::Known(settlement_currency)),
+                Some(settlement_amount),
+            ) if *settlement_currency != presentment_currency => {
+                Ok(Some(HelcimCurrencyConversion {
+                    presentment_amount,
+                    presentment_currency,
+                    settlement_amount: get_amount_in_minor_unit(
+                        settlement_amount,
+                        currency_unit,
+                        *settlement_currency,
+                    )?,
+                    settlement_currency: *settlement_currency,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn get_capture_decline_reason(&self) -> Option<HelcimCaptureDeclineReason> {
+        match (&self.transaction_type, &self.status) {
+            (HelcimTransactionType::Capture, HelcimPaymentStatus::Declined) => Some(
+                HelcimCaptureDeclineReason::from_response_errors(self.errors.as_ref()),
+            ),
+            _ => None,
+        }
+    }
+
+    fn get_settlement_metadata(&self) -> Option<HelcimSettlementMetaData> {
+        self.date_settled
+            .as_ref()
+            .map(|date_settled| HelcimSettlementMetaData {
+                batch_id: self.batch_id,
+                date_settled: date_settled.to_owned(),
+            })
+    }
 }
 
 impl<F>
@@ -376,10 +1835,12 @@ impl<F>
                     item.response.transaction_id.to_string(),
                 ),
                 redirection_data: None,
-                mandate_reference: None,
+                mandate_reference: item.response.get_mandate_reference(),
                 connector_metadata: None,
-                network_txn_id: None,
-                connector_response_reference_id: item.response.invoice_number.clone(),
+                network_txn_id: item.response.get_network_txn_id(),
+                connector_response_reference_id: item
+                    .response
+                    .get_connector_response_reference_id(),
                 incremental_authorization_allowed: None,
             }),
             status: enums::AttemptStatus::from(item.response),
@@ -390,73 +1851,599 @@ impl<F>
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct HelcimMetaData {
-    pub preauth_transaction_id: u64,
+    pub preauth_transaction_id: HelcimTransactionId,
+    #[serde(flatten)]
+    pub authorization: HelcimAuthorizationMetaData,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub captures: Vec<HelcimCaptureMetaData>,
 }
 
-impl<F>
-    TryFrom<
-        types::ResponseRouterData<
-            F,
-            HelcimPaymentsResponse,
-            types::PaymentsAuthorizeData,
-            types::PaymentsResponseData,
-        >,
-    > for types::RouterData<F, types::PaymentsAuthorizeData, types::PaymentsResponseData>
-{
-    type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(
-        item: types::ResponseRouterData<
-            F,
-            HelcimPaymentsResponse,
-            types::PaymentsAuthorizeData,
-            types::PaymentsResponseData,
-        >,
-    ) -> Result<Self, Self::Error> {
-        //PreAuth Transaction ID is stored in connector metadata
-        //Initially resource_id is stored as NoResponseID for manual capture
-        //After Capture Transaction is completed it is updated to store the Capture ID
-        let resource_id = if item.data.request.is_auto_capture()? {
-            types::ResponseId::ConnectorTransactionId(item.response.transaction_id.to_string())
-        } else {
-            types::ResponseId::NoResponseId
-        };
-        let connector_metadata = if !item.data.request.is_auto_capture()? {
-            Some(serde_json::json!(HelcimMetaData {
-                preauth_transaction_id: item.response.transaction_id,
-            }))
-        } else {
-            None
-        };
-        Ok(Self {
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id,
-                redirection_data: None,
-                mandate_reference: None,
-                connector_metadata,
-                network_txn_id: None,
-                connector_response_reference_id: item.response.invoice_number.clone(),
-                incremental_authorization_allowed: None,
-            }),
-            status: enums::AttemptStatus::from(item.response),
-            ..item.data
-        })
+//A pre-authorization captured incrementally has a capture transaction per capture. They are kept
+//in the order they were made, along with the amount refunded against each of them, as Helcim
+//refunds a single transaction per refund.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimCaptureMetaData {
+    pub capture_transaction_id: HelcimTransactionId,
+    pub amount: i64,
+    #[serde(default)]
+    pub refunded_amount: i64,
+}
+
+impl HelcimCaptureMetaData {
+    fn get_refundable_amount(&self) -> i64 {
+        self.amount.saturating_sub(self.refunded_amount)
     }
 }
 
-// impl utils::MultipleCaptureSyncResponse for HelcimPaymentsResponse {
-//     fn get_connector_capture_id(&self) -> String {
-//         self.transaction_id.to_string()
-//     }
+pub fn get_captures(
+    connector_metadata: &Option<serde_json::Value>,
+) -> Result<Vec<HelcimCaptureMetaData>, error_stack::Report<errors::ConnectorError>> {
+    match connector_metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(HELCIM_CAPTURES_FIELD))
+    {
+        None | Some(serde_json::Value::Null) => Ok(Vec::new()),
+        Some(captures) => serde_json::from_value(captures.clone()).change_context(
+            errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_metadata.captures",
+            },
+        ),
+    }
+}
 
-//     fn get_capture_attempt_status(&self) -> diesel_models::enums::AttemptStatus {
-//         enums::AttemptStatus::from(self.to_owned())
-//     }
+//Amounts a manually captured payment was authorized for, so that captures can be derived from
+//and validated against them. The authorized amount includes the surcharge of the payment.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HelcimAuthorizationMetaData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_amount: Option<i64>,
+    #[serde(default)]
+    pub surcharge_amount: i64,
+    //Only present when the preauth was approved for less than the authorized amount, which
+    //limits the captures of the payment to the amount approved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_amount: Option<i64>,
+}
 
-//     fn is_capture_response(&self) -> bool {
-//         true
-//     }
+impl HelcimAuthorizationMetaData {
+    pub fn try_from_connector_metadata(
+        connector_metadata: &Option<serde_json::Value>,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        Ok(connector_metadata
+            .clone()
+            .filter(|metadata| !metadata.is_null())
+            .map(serde_json::from_value)
+            .transpose()
+            .change_context(errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_metadata",
+            })?
+            .unwrap_or_default())
+    }
+}
 
-//     fn get_amount_captured(&self) -> Option<i64> {
+//The surcharge of the payment is captured in proportion to the share of the payment amount being
+//captured, the same way the authorized amount was composed, so that partial captures summing up
+//to the payment amount capture the whole surcharge, give or take the rounding of each share.
+//Incremental authorizations raise the payment amount without updating the connector metadata,
+//so the larger of the two is taken as the authorized amount, unless the preauth was partially
+//approved.
+pub fn get_capture_amount(
+    request: &types::PaymentsCaptureData,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    let authorization =
+        HelcimAuthorizationMetaData::try_from_connector_metadata(&request.connector_meta)?;
+    let payment_amount = request
+        .payment_amount
+        .saturating_add(authorization.surcharge_amount);
+    let authorized_amount = match authorization.approved_amount {
+        Some(approved_amount) => approved_amount,
+        None => authorization
+            .authorized_amount
+            .map_or(payment_amount, |authorized_amount| {
+                authorized_amount.max(payment_amount)
+            }),
+    };
+    let capture_amount = request
+        .amount_to_capture
+        .saturating_add(get_capture_surcharge(
+            authorization.surcharge_amount,
+            request.amount_to_capture,
+            request.payment_amount,
+        ));
+    if capture_amount > authorized_amount {
+        Err(errors::ConnectorError::RequestEncodingFailedWithReason(
+            format!(
+                "Capture amount {capture_amount} exceeds the authorized amount {authorized_amount}"
+            ),
+        ))?
+    }
+    Ok(capture_amount)
+}
+
+//Share of the surcharge for the amount to capture, rounded half up to the lowest denomination
+fn get_capture_surcharge(
+    surcharge_amount: i64,
+    amount_to_capture: i64,
+    payment_amount: i64,
+) -> i64 {
+    if payment_amount <= 0 {
+        return surcharge_amount;
+    }
+    let surcharge_share = (i128::from(surcharge_amount) * i128::from(amount_to_capture) * 2
+        + i128::from(payment_amount))
+        / (i128::from(payment_amount) * 2);
+    i64::try_from(surcharge_share).unwrap_or(surcharge_amount)
+}
+
+impl HelcimMetaData {
+    //The metadata is validated explicitly so that a malformed blob names the expected field.
+    //Payments captured at authorization store no preauth transaction id, so the connector
+    //transaction id is used when the field is absent.
+    pub fn try_from_connector_metadata(
+        connector_metadata: &Option<serde_json::Value>,
+        connector_transaction_id: Option<&str>,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        let preauth_transaction_id = match connector_metadata {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::Object(metadata)) => {
+                metadata.get(HELCIM_PREAUTH_TRANSACTION_ID_FIELD).cloned()
+            }
+            Some(_) => {
+                return Err(report!(errors::ConnectorError::InvalidDataFormat {
+                    field_name: "connector_metadata",
+                })
+                .attach_printable(format!(
+                    "Expected connector metadata to be an object with a numeric `{HELCIM_PREAUTH_TRANSACTION_ID_FIELD}` field"
+                )));
+            }
+        };
+
+        let preauth_transaction_id = match preauth_transaction_id {
+            Some(preauth_transaction_id) => serde_json::from_value(preauth_transaction_id)
+                .change_context(errors::ConnectorError::InvalidDataFormat {
+                    field_name: "connector_metadata.preauth_transaction_id",
+                })
+                .attach_printable(format!(
+                    "Expected `{HELCIM_PREAUTH_TRANSACTION_ID_FIELD}` in connector metadata to be a numeric Helcim transaction id"
+                ))?,
+            None => connector_transaction_id
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "connector_metadata.preauth_transaction_id",
+                })?
+                .parse::<HelcimTransactionId>()
+                .change_context(errors::ConnectorError::InvalidDataFormat {
+                    field_name: "connector_transaction_id",
+                })?,
+        };
+
+        Ok(Self {
+            preauth_transaction_id,
+            authorization: HelcimAuthorizationMetaData::try_from_connector_metadata(
+                connector_metadata,
+            )?,
+            captures: get_captures(connector_metadata)?,
+        })
+    }
+}
+
+const HELCIM_PREAUTH_TRANSACTION_ID_FIELD: &str = "preauth_transaction_id";
+const HELCIM_CAPTURES_FIELD: &str = "captures";
+const HELCIM_BATCH_ID_FIELD: &str = "batch_id";
+const HELCIM_CAPTURE_DECLINE_REASON_FIELD: &str = "capture_decline_reason";
+
+//Amounts Helcim returns in the base unit of the currency are converted to the minor unit
+fn get_amount_in_minor_unit(
+    amount: f64,
+    currency_unit: &api::CurrencyUnit,
+    currency: enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    let amount = match currency_unit {
+        api::CurrencyUnit::Base => utils::to_currency_lower_unit(amount.to_string(), currency)?,
+        api::CurrencyUnit::Minor => amount.to_string(),
+    };
+    Decimal::from_str(&amount)
+        .ok()
+        .and_then(|amount| amount.round().to_i64())
+        .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+        .attach_printable("Invalid amount in Helcim response")
+}
+
+//Base unit amounts of Helcim may be off by a minor unit due to rounding, which is not a mismatch
+const HELCIM_AMOUNT_RECONCILIATION_TOLERANCE: i64 = 1;
+const HELCIM_AMOUNT_RECONCILIATION_FIELD: &str = "amount_reconciliation";
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HelcimAmountMismatchReason {
+    //The issuer approved less than the amount of the payment
+    PartialApproval,
+    //Helcim recorded more than the amount of the payment, or an amount in another currency
+    GrossMismatch,
+}
+
+//Note kept in the connector metadata when the amount Helcim recorded for a transaction differs
+//from the amount of the payment attempt, so that merchants can reconcile the payment
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimAmountReconciliation {
+    pub reason: HelcimAmountMismatchReason,
+    pub expected_amount: i64,
+    pub expected_currency: enums::Currency,
+    pub connector_amount: i64,
+    pub connector_currency: Option<HelcimCurrency>,
+}
+
+impl HelcimAmountReconciliation {
+    //Gross mismatches are not explained by the issuer and need to be looked into
+    fn log(&self, transaction_id: HelcimTransactionId) {
+        match self.reason {
+            HelcimAmountMismatchReason::PartialApproval => router_env::logger::info!(
+                %transaction_id,
+                expected_amount = self.expected_amount,
+                connector_amount = self.connector_amount,
+                "Helcim transaction partially approved"
+            ),
+            HelcimAmountMismatchReason::GrossMismatch => router_env::logger::error!(
+                %transaction_id,
+                expected_amount = self.expected_amount,
+                expected_currency = %self.expected_currency,
+                connector_amount = self.connector_amount,
+                connector_currency = ?self.connector_currency,
+                "Amount of Helcim transaction does not match the amount of the payment"
+            ),
+        }
+    }
+}
+
+pub fn add_amount_reconciliation(
+    connector_metadata: Option<serde_json::Value>,
+    amount_reconciliation: Option<&HelcimAmountReconciliation>,
+) -> Option<serde_json::Value> {
+    let Some(amount_reconciliation) = amount_reconciliation else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_AMOUNT_RECONCILIATION_FIELD,
+        serde_json::json!(amount_reconciliation),
+    )
+}
+
+//Currency of a Helcim transaction. Currencies which are not known are kept as Helcim sent them,
+//so that they are still flagged as a mismatch.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum HelcimCurrency {
+    Known(enums::Currency),
+    Unknown(String),
+}
+
+impl HelcimCurrency {
+    fn is(&self, currency: enums::Currency) -> bool {
+        match self {
+            Self::Known(known_currency) => *known_currency == currency,
+            Self::Unknown(unknown_currency) => {
+                unknown_currency.eq_ignore_ascii_case(&currency.to_string())
+            }
+        }
+    }
+}
+
+const HELCIM_CURRENCY_MISMATCH_FIELD: &str = "currency_mismatch";
+
+//Note kept in the connector metadata when Helcim processed a transaction in another currency
+//than the currency of the payment attempt
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimCurrencyMismatch {
+    pub expected_currency: enums::Currency,
+    pub connector_currency: HelcimCurrency,
+}
+
+pub fn add_currency_mismatch(
+    connector_metadata: Option<serde_json::Value>,
+    currency_mismatch: Option<&HelcimCurrencyMismatch>,
+) -> Option<serde_json::Value> {
+    let Some(currency_mismatch) = currency_mismatch else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_CURRENCY_MISMATCH_FIELD,
+        serde_json::json!(currency_mismatch),
+    )
+}
+
+const HELCIM_CURRENCY_CONVERSION_FIELD: &str = "currency_conversion";
+
+//Note kept in the connector metadata when Helcim settled a transaction in another currency than
+//the currency the customer was charged in, so that refunds are sent in the settlement currency
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimCurrencyConversion {
+    pub presentment_amount: i64,
+    pub presentment_currency: enums::Currency,
+    pub settlement_amount: i64,
+    pub settlement_currency: enums::Currency,
+}
+
+impl HelcimCurrencyConversion {
+    //Refunds are converted at the rate of the payment. The amount refunded so far is converted
+    //along with the refund, so that refunds of the whole payment add up to the amount settled.
+    pub fn get_settlement_refund_amount(
+        &self,
+        refunded_amount: i64,
+        refund_amount: i64,
+    ) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+        let to_settlement_amount = |amount: i64| {
+            Decimal::from(self.settlement_amount)
+                .checked_mul(Decimal::from(amount))
+                .and_then(|amount| amount.checked_div(Decimal::from(self.presentment_amount)))
+                .and_then(|amount| amount.round().to_i64())
+                .ok_or(errors::ConnectorError::RequestEncodingFailedWithReason(
+                    format!(
+                        "Refund amount {refund_amount} can not be converted to {}",
+                        self.settlement_currency
+                    ),
+                ))
+        };
+        let settled_refunded_amount = to_settlement_amount(refunded_amount)?;
+        let settled_total_amount =
+            to_settlement_amount(refunded_amount.saturating_add(refund_amount))?;
+        Ok(settled_total_amount.saturating_sub(settled_refunded_amount))
+    }
+}
+
+pub fn add_currency_conversion(
+    connector_metadata: Option<serde_json::Value>,
+    currency_conversion: Option<&HelcimCurrencyConversion>,
+) -> Option<serde_json::Value> {
+    let Some(currency_conversion) = currency_conversion else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_CURRENCY_CONVERSION_FIELD,
+        serde_json::json!(currency_conversion),
+    )
+}
+
+const HELCIM_BANK_PAYMENT_FIELD: &str = "bank_payment";
+
+//Kept for bank payments, which are refunded through the ACH api. Whether they are voided or
+//refunded is decided by their settlement, as for card payments.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimBankPaymentMetaData {}
+
+pub fn add_bank_payment(
+    connector_metadata: Option<serde_json::Value>,
+    bank_payment: Option<&HelcimBankPaymentMetaData>,
+) -> Option<serde_json::Value> {
+    let Some(bank_payment) = bank_payment else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_BANK_PAYMENT_FIELD,
+        serde_json::json!(bank_payment),
+    )
+}
+
+const HELCIM_CARD_TOKEN_FIELD: &str = "card_token";
+
+//Card token of the payment, kept for subsequent operations which reference the card rather than
+//the transaction, such as purchases with the saved card. Captures, reverses and refunds are
+//referenced by transaction id only in the Helcim api, so they do not send the token.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimCardTokenMetaData {
+    pub card_token: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_code: Option<String>,
+}
+
+impl HelcimCardTokenMetaData {
+    pub fn from_connector_metadata(connector_metadata: Option<&serde_json::Value>) -> Option<Self> {
+        connector_metadata
+            .and_then(|connector_metadata| connector_metadata.get(HELCIM_CARD_TOKEN_FIELD))
+            .cloned()
+            .and_then(|card_token| serde_json::from_value(card_token).ok())
+    }
+}
+
+pub fn add_card_token(
+    connector_metadata: Option<serde_json::Value>,
+    card_token: Option<&HelcimCardTokenMetaData>,
+) -> Option<serde_json::Value> {
+    let Some(card_token) = card_token else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_CARD_TOKEN_FIELD,
+        serde_json::json!(card_token),
+    )
+}
+
+//Settlement details of a transaction, used by merchants to reconcile deposits
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct HelcimSettlementMetaData {
+    pub batch_id: Option<u64>,
+    pub date_settled: String,
+}
+
+const HELCIM_DATE_SETTLED_FIELD: &str = "date_settled";
+
+pub fn add_settlement(
+    connector_metadata: Option<serde_json::Value>,
+    settlement_metadata: Option<&HelcimSettlementMetaData>,
+) -> Option<serde_json::Value> {
+    let Some(settlement_metadata) = settlement_metadata else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        add_batch_id(connector_metadata, settlement_metadata.batch_id),
+        HELCIM_DATE_SETTLED_FIELD,
+        serde_json::Value::from(settlement_metadata.date_settled.clone()),
+    )
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsAuthorizeData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        //PreAuth Transaction ID is stored in connector metadata
+        //Initially resource_id is stored as NoResponseID for manual capture
+        //After Capture Transaction is completed it is updated to store the Capture ID
+        let resource_id = if item.data.request.is_auto_capture()? {
+            types::ResponseId::ConnectorTransactionId(item.response.transaction_id.to_string())
+        } else {
+            types::ResponseId::NoResponseId
+        };
+        let currency_unit =
+            HelcimConnectorMetadataObject::try_from(item.data.connector_meta_data.as_ref())?
+                .api_version
+                .get_currency_unit();
+        let currency_conversion = item.response.get_currency_conversion(
+            item.data.request.amount,
+            item.data.request.currency,
+            &currency_unit,
+        )?;
+        //Partially approved purchases are surfaced with the approved amount as the amount captured,
+        //so that the merchant can collect the remainder in another payment. Partially approved
+        //preauths are authorized with captures limited to the approved amount. The amount of
+        //converted transactions is in another currency and can not be compared.
+        let partially_approved_amount = match item.response.transaction_type {
+            HelcimTransactionType::Purchase | HelcimTransactionType::PreAuth
+                if currency_conversion.is_none() =>
+            {
+                item.response.get_partially_approved_amount(
+                    item.data.request.amount,
+                    &currency_unit,
+                    item.data.request.currency,
+                )?
+            }
+            HelcimTransactionType::Purchase
+            | HelcimTransactionType::PreAuth
+            | HelcimTransactionType::Capture
+            | HelcimTransactionType::Verify
+            | HelcimTransactionType::Reverse
+            | HelcimTransactionType::Unknown(_) => None,
+        };
+        let connector_metadata = if !item.data.request.is_auto_capture()? {
+            Some(serde_json::json!(HelcimMetaData {
+                preauth_transaction_id: item.response.transaction_id,
+                authorization: HelcimAuthorizationMetaData {
+                    authorized_amount: Some(item.data.request.amount),
+                    surcharge_amount: item.data.request.get_total_surcharge_amount().unwrap_or(0),
+                    approved_amount: partially_approved_amount,
+                },
+                captures: Vec::new(),
+            }))
+        } else {
+            None
+        };
+        let connector_metadata = add_batch_id(connector_metadata, item.response.batch_id);
+        //The breakdown of the amount into base amount, tip and convenience fee is kept for reconciliation
+        let connector_metadata = add_amount_breakdown(
+            connector_metadata,
+            HelcimAmountBreakdown::try_from_authorize_data(&item.data.request)?,
+        );
+        let connector_metadata = add_currency_mismatch(
+            connector_metadata,
+            item.response
+                .get_currency_mismatch(item.data.request.currency)
+                .as_ref(),
+        );
+        let connector_metadata =
+            add_currency_conversion(connector_metadata, currency_conversion.as_ref());
+        let bank_payment = matches!(
+            item.data.request.payment_method_data,
+            domain::PaymentMethodData::BankDebit(_)
+        )
+        .then_some(HelcimBankPaymentMetaData {});
+        let connector_metadata = add_bank_payment(connector_metadata, bank_payment.as_ref());
+        let connector_metadata = add_card_token(
+            connector_metadata,
+            item.response.get_card_token_metadata().as_ref(),
+        );
+        //Card token is stored as mandate reference only when the payment sets up a mandate
+        let mandate_reference = item
+            .data
+            .request
+            .setup_mandate_details
+            .as_ref()
+            .and_then(|_| item.response.get_mandate_reference());
+        //Liability shift of 3DS authenticated payments is surfaced for the merchant's dispute handling
+        let additional_payment_method_data = item
+            .response
+            .get_additional_payment_method_data(item.data.request.authentication_data.as_ref());
+        let connector_response = additional_payment_method_data
+            .map(types::ConnectorResponseData::with_additional_payment_method_data);
+        let partially_captured_amount = match item.response.transaction_type {
+            HelcimTransactionType::Purchase => partially_approved_amount,
+            HelcimTransactionType::PreAuth
+            | HelcimTransactionType::Capture
+            | HelcimTransactionType::Verify
+            | HelcimTransactionType::Reverse
+            | HelcimTransactionType::Unknown(_) => None,
+        };
+        //Only approved preauths can be incremented, and only when the merchant requested it
+        let incremental_authorization_allowed =
+            match (&item.response.transaction_type, &item.response.status) {
+                (HelcimTransactionType::PreAuth, HelcimPaymentStatus::Approved) => {
+                    Some(item.data.request.request_incremental_authorization)
+                }
+                _ => None,
+            };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data: None,
+                mandate_reference,
+                connector_metadata,
+                network_txn_id: item.response.get_network_txn_id(),
+                connector_response_reference_id: item
+                    .response
+                    .get_connector_response_reference_id(),
+                incremental_authorization_allowed,
+            }),
+            status: match partially_captured_amount {
+                Some(_) => enums::AttemptStatus::PartialCharged,
+                None => enums::AttemptStatus::from(item.response),
+            },
+            amount_captured: partially_captured_amount.or(item.data.amount_captured),
+            connector_response,
+            ..item.data
+        })
+    }
+}
+
+// impl utils::MultipleCaptureSyncResponse for HelcimPaymentsResponse {
+//     fn get_connector_capture_id(&self) -> String {
+//         self.transaction_id.to_string()
+//     }
+
+//     fn get_capture_attempt_status(&self) -> diesel_models::enums::AttemptStatus {
+//         enums::AttemptStatus::from(self.to_owned())
+//     }
+
+//     fn is_capture_response(&self) -> bool {
+//         true
+//     }
+
+//     fn get_amount_captured(&self) -> Option<i64> {
 //         Some(self.amount)
 //     }
 //     fn get_connector_reference_id(&self) -> Option<String> {
@@ -484,21 +2471,96 @@ impl<F>
         >,
     ) -> Result<Self, Self::Error> {
         match item.data.request.sync_type {
-            types::SyncRequestType::SinglePaymentSync => Ok(Self {
-                response: Ok(types::PaymentsResponseData::TransactionResponse {
-                    resource_id: types::ResponseId::ConnectorTransactionId(
-                        item.response.transaction_id.to_string(),
-                    ),
-                    redirection_data: None,
-                    mandate_reference: None,
-                    connector_metadata: None,
-                    network_txn_id: None,
-                    connector_response_reference_id: item.response.invoice_number.clone(),
-                    incremental_authorization_allowed: None,
-                }),
-                status: enums::AttemptStatus::from(item.response),
-                ..item.data
-            }),
+            types::SyncRequestType::SinglePaymentSync => {
+                //Settled transactions carry their batch details for reconciliation
+                let settlement_metadata = item.response.get_settlement_metadata();
+                let currency_unit = HelcimConnectorMetadataObject::try_from(
+                    item.data.connector_meta_data.as_ref(),
+                )?
+                .api_version
+                .get_currency_unit();
+                let currency_conversion = item.response.get_currency_conversion(
+                    item.data.request.amount,
+                    item.data.request.currency,
+                    &currency_unit,
+                )?;
+                let additional_payment_method_data =
+                    item.response.get_additional_payment_method_data(None);
+                let connector_response = additional_payment_method_data
+                    .map(types::ConnectorResponseData::with_additional_payment_method_data);
+                //The details of the sync are merged into the metadata of the attempt, as the
+                //metadata returned replaces it and later captures and refunds need the
+                //references stored on authorize and capture
+                let connector_metadata = add_settlement(
+                    item.data.request.connector_meta.clone(),
+                    settlement_metadata.as_ref(),
+                );
+                //The amount Helcim recorded is reconciled against the amount of the attempt, as it
+                //differs on partial approvals
+                let amount_reconciliation = item.response.get_amount_reconciliation(
+                    item.data.request.amount,
+                    item.data.request.currency,
+                    &currency_unit,
+                )?;
+                if let Some(amount_reconciliation) = amount_reconciliation.as_ref() {
+                    amount_reconciliation.log(item.response.transaction_id);
+                }
+                let connector_metadata =
+                    add_amount_reconciliation(connector_metadata, amount_reconciliation.as_ref());
+                let connector_metadata = add_currency_mismatch(
+                    connector_metadata,
+                    item.response
+                        .get_currency_mismatch(item.data.request.currency)
+                        .as_ref(),
+                );
+                let connector_metadata =
+                    add_currency_conversion(connector_metadata, currency_conversion.as_ref());
+                let connector_metadata = add_card_token(
+                    connector_metadata,
+                    item.response.get_card_token_metadata().as_ref(),
+                );
+                //Partially approved purchases and captures are surfaced with the approved amount
+                //as the amount captured
+                let partially_captured_amount = match (
+                    &item.response.transaction_type,
+                    amount_reconciliation.as_ref(),
+                ) {
+                    (
+                        HelcimTransactionType::Purchase | HelcimTransactionType::Capture,
+                        Some(HelcimAmountReconciliation {
+                            reason: HelcimAmountMismatchReason::PartialApproval,
+                            connector_amount,
+                            ..
+                        }),
+                    ) => Some(*connector_amount),
+                    _ => None,
+                };
+                Ok(Self {
+                    response: Ok(types::PaymentsResponseData::TransactionResponse {
+                        resource_id: types::ResponseId::ConnectorTransactionId(
+                            item.response.transaction_id.to_string(),
+                        ),
+                        redirection_data: None,
+                        mandate_reference: None,
+                        connector_metadata,
+                        network_txn_id: item.response.get_network_txn_id(),
+                        connector_response_reference_id: item
+                            .response
+                            .get_connector_response_reference_id(),
+                        incremental_authorization_allowed: None,
+                    }),
+                    status: match partially_captured_amount {
+                        Some(_) => enums::AttemptStatus::PartialCharged,
+                        None => enums::AttemptStatus::from(item.response),
+                    },
+                    amount_captured: partially_captured_amount.or(item.data.amount_captured),
+                    connector_response,
+                    ..item.data
+                })
+            }
+            types::SyncRequestType::DateRangeSync(_) | types::SyncRequestType::ReferenceSync => {
+                Err(errors::ConnectorError::ResponseHandlingFailed.into())
+            }
             types::SyncRequestType::MultipleCaptureSync(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     "manual multiple capture sync".to_string(),
@@ -519,35 +2581,50 @@ impl<F>
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct HelcimCaptureRequest {
-    pre_auth_transaction_id: u64,
-    amount: f64,
-    ip_address: Secret<String, IpAddress>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ecommerce: Option<bool>,
+pub struct HelcimDateRangeSyncRequest {
+    date_from: String,
+    date_to: String,
 }
 
-impl TryFrom<&HelcimRouterData<&types::PaymentsCaptureRouterData>> for HelcimCaptureRequest {
-    type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(
-        item: &HelcimRouterData<&types::PaymentsCaptureRouterData>,
-    ) -> Result<Self, Self::Error> {
-        let ip_address = item
-            .router_data
-            .request
-            .get_browser_info()?
-            .get_ip_address()?;
-        Ok(Self {
-            pre_auth_transaction_id: item
-                .router_data
-                .request
-                .connector_transaction_id
-                .parse::<u64>()
-                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
-            amount: item.amount,
-            ip_address,
-            ecommerce: None,
-        })
+impl From<&types::TransactionDateRange> for HelcimDateRangeSyncRequest {
+    fn from(date_range: &types::TransactionDateRange) -> Self {
+        // Helcim filters transactions by date in YYYY-MM-DD format
+        Self {
+            date_from: date_range.start_time.date().to_string(),
+            date_to: date_range.end_time.date().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HelcimTransactionListItem {
+    Payment(HelcimPaymentsResponse),
+    // Refunds and other transactions which do not map to a payment status
+    Other(serde_json::Value),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct HelcimTransactionListResponse(Vec<HelcimTransactionListItem>);
+
+impl HelcimTransactionListResponse {
+    pub fn get_transaction_status_list(self) -> HashMap<String, enums::AttemptStatus> {
+        self.0
+            .into_iter()
+            .filter_map(|transaction| match transaction {
+                // Refunds and transaction types not known yet do not map to a payment status
+                HelcimTransactionListItem::Payment(HelcimPaymentsResponse {
+                    transaction_type: HelcimTransactionType::Unknown(_),
+                    ..
+                }) => None,
+                HelcimTransactionListItem::Payment(payment) => Some((
+                    payment.transaction_id.to_string(),
+                    enums::AttemptStatus::from(payment),
+                )),
+                HelcimTransactionListItem::Other(_) => None,
+            })
+            .collect()
     }
 }
 
@@ -555,61 +2632,91 @@ impl<F>
     TryFrom<
         types::ResponseRouterData<
             F,
-            HelcimPaymentsResponse,
-            types::PaymentsCaptureData,
+            HelcimTransactionListResponse,
+            types::PaymentsSyncData,
             types::PaymentsResponseData,
         >,
-    > for types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>
+    > for types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         item: types::ResponseRouterData<
             F,
-            HelcimPaymentsResponse,
-            types::PaymentsCaptureData,
+            HelcimTransactionListResponse,
+            types::PaymentsSyncData,
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction_id.to_string(),
-                ),
-                redirection_data: None,
-                mandate_reference: None,
-                connector_metadata: None,
-                network_txn_id: None,
-                connector_response_reference_id: item.response.invoice_number.clone(),
-                incremental_authorization_allowed: None,
+            response: Ok(types::PaymentsResponseData::TransactionListSyncResponse {
+                transaction_status_list: item.response.get_transaction_status_list(),
             }),
-            status: enums::AttemptStatus::from(item.response),
             ..item.data
         })
     }
 }
 
+//A payment whose authorize timed out has no transaction id yet, so the transaction is looked up by
+//the invoice number sent with it
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct HelcimVoidRequest {
-    card_transaction_id: u64,
-    ip_address: Secret<String, IpAddress>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ecommerce: Option<bool>,
+pub struct HelcimTransactionSearchRequest {
+    invoice_number: String,
 }
 
-impl TryFrom<&types::PaymentsCancelRouterData> for HelcimVoidRequest {
-    type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
-        let ip_address = item.request.get_browser_info()?.get_ip_address()?;
-        Ok(Self {
-            card_transaction_id: item
-                .request
-                .connector_transaction_id
-                .parse::<u64>()
-                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
-            ip_address,
-            ecommerce: None,
-        })
+impl From<&types::PaymentsSyncRouterData> for HelcimTransactionSearchRequest {
+    fn from(item: &types::PaymentsSyncRouterData) -> Self {
+        Self {
+            invoice_number: item.connector_request_reference_id.clone(),
+        }
+    }
+}
+
+//Card payments which never received a transaction id, e.g. because the authorize timed out, are
+//synced by their invoice number instead
+pub fn is_reference_sync(request: &types::PaymentsSyncData) -> bool {
+    match request.sync_type {
+        types::SyncRequestType::ReferenceSync => true,
+        types::SyncRequestType::SinglePaymentSync => {
+            matches!(
+                request.connector_transaction_id,
+                types::ResponseId::NoResponseId
+            ) && request.payment_method_type != Some(enums::PaymentMethodType::Ach)
+        }
+        types::SyncRequestType::MultipleCaptureSync(_)
+        | types::SyncRequestType::DateRangeSync(_) => false,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct HelcimTransactionSearchResponse(Vec<HelcimTransactionListItem>);
+
+impl HelcimTransactionSearchResponse {
+    //Only a purchase or preauth can have been created by the authorize. An approved one is
+    //preferred, as a declined attempt may have been followed by another with the same invoice.
+    fn get_payment_transaction(self, invoice_number: &str) -> Option<HelcimPaymentsResponse> {
+        let mut transactions: Vec<HelcimPaymentsResponse> = self
+            .0
+            .into_iter()
+            .filter_map(|transaction| match transaction {
+                HelcimTransactionListItem::Payment(payment) => Some(payment),
+                HelcimTransactionListItem::Other(_) => None,
+            })
+            .filter(|payment| {
+                matches!(
+                    payment.transaction_type,
+                    HelcimTransactionType::Purchase | HelcimTransactionType::PreAuth
+                ) && payment.invoice_number.as_deref() == Some(invoice_number)
+            })
+            .collect();
+        let approved_transaction = transactions
+            .iter()
+            .position(|payment| matches!(payment.status, HelcimPaymentStatus::Approved));
+        match approved_transaction {
+            Some(position) => Some(transactions.swap_remove(position)),
+            None => transactions.pop(),
+        }
     }
 }
 
@@ -617,151 +2724,3370 @@ impl<F>
     TryFrom<
         types::ResponseRouterData<
             F,
-            HelcimPaymentsResponse,
-            types::PaymentsCancelData,
+            HelcimTransactionSearchResponse,
+            types::PaymentsSyncData,
             types::PaymentsResponseData,
         >,
-    > for types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>
+    > for types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         item: types::ResponseRouterData<
             F,
-            HelcimPaymentsResponse,
-            types::PaymentsCancelData,
+            HelcimTransactionSearchResponse,
+            types::PaymentsSyncData,
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
+        let transaction = item
+            .response
+            .get_payment_transaction(&item.data.connector_request_reference_id);
+        let transaction = match transaction {
+            Some(transaction) => transaction,
+            //The authorize may not have reached Helcim, or may not be searchable yet. The attempt is
+            //left pending so that it is synced again instead of being failed.
+            None => {
+                return Ok(Self {
+                    response: Err(types::ErrorResponse {
+                        code: consts::NO_ERROR_CODE.to_string(),
+                        message: "No transaction found for the invoice number".to_string(),
+                        reason: None,
+                        status_code: item.http_code,
+                        attempt_status: Some(enums::AttemptStatus::Pending),
+                        connector_transaction_id: None,
+                    }),
+                    ..item.data
+                })
+            }
+        };
+        //The transaction is stored the same way as in the response of the authorize
+        let (resource_id, connector_metadata) = match transaction.transaction_type {
+            HelcimTransactionType::PreAuth => (
+                types::ResponseId::NoResponseId,
+                Some(serde_json::json!(HelcimMetaData {
+                    preauth_transaction_id: transaction.transaction_id,
+                    authorization: HelcimAuthorizationMetaData {
+                        authorized_amount: Some(item.data.request.amount),
+                        surcharge_amount: item.data.request.surcharge_amount.unwrap_or(0),
+                        approved_amount: None,
+                    },
+                    captures: Vec::new(),
+                })),
+            ),
+            _ => (
+                types::ResponseId::ConnectorTransactionId(transaction.transaction_id.to_string()),
+                None,
+            ),
+        };
+        let connector_metadata = add_batch_id(connector_metadata, transaction.batch_id);
+        let connector_metadata = add_card_token(
+            connector_metadata,
+            transaction.get_card_token_metadata().as_ref(),
+        );
         Ok(Self {
             response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction_id.to_string(),
-                ),
+                resource_id,
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: None,
-                network_txn_id: None,
-                connector_response_reference_id: item.response.invoice_number.clone(),
+                connector_metadata,
+                network_txn_id: transaction.get_network_txn_id(),
+                connector_response_reference_id: transaction.get_connector_response_reference_id(),
                 incremental_authorization_allowed: None,
             }),
-            status: enums::AttemptStatus::from(item.response),
+            status: enums::AttemptStatus::from(transaction),
             ..item.data
         })
     }
 }
 
-// REFUND :
-// Type definition for RefundRequest
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct HelcimRefundRequest {
-    amount: f64,
-    original_transaction_id: u64,
+pub struct HelcimCaptureRequest {
+    pre_auth_transaction_id: HelcimTransactionId,
+    amount: HelcimAmount,
     ip_address: Secret<String, IpAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     ecommerce: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    terminal_id: Option<String>,
 }
 
-impl<F> TryFrom<&HelcimRouterData<&types::RefundsRouterData<F>>> for HelcimRefundRequest {
+impl TryFrom<&HelcimRouterData<&types::PaymentsCaptureRouterData>> for HelcimCaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: &HelcimRouterData<&types::RefundsRouterData<F>>,
+        item: &HelcimRouterData<&types::PaymentsCaptureRouterData>,
     ) -> Result<Self, Self::Error> {
-        let original_transaction_id = item
-            .router_data
-            .request
-            .connector_transaction_id
-            .parse::<u64>()
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-
         let ip_address = item
             .router_data
             .request
             .get_browser_info()?
             .get_ip_address()?;
+        let connector_metadata =
+            HelcimConnectorMetadataObject::try_from(item.router_data.connector_meta_data.as_ref())?;
         Ok(Self {
+            pre_auth_transaction_id: item
+                .router_data
+                .request
+                .connector_transaction_id
+                .parse::<HelcimTransactionId>()
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
             amount: item.amount,
-            original_transaction_id,
             ip_address,
             ecommerce: None,
+            terminal_id: connector_metadata.terminal_id,
         })
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum HelcimRefundTransactionType {
-    Refund,
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        //The capture is appended to the captures of the preauth so that refunds reference it.
+        //Declined captures hold no funds to refund.
+        let connector_metadata = match item.response.status {
+            HelcimPaymentStatus::Declined => item.data.request.connector_meta.clone(),
+            _ => add_capture(
+                item.data.request.connector_meta.clone(),
+                HelcimCaptureMetaData {
+                    capture_transaction_id: item.response.transaction_id,
+                    amount: get_capture_amount(&item.data.request)?,
+                    refunded_amount: 0,
+                },
+            )?,
+        };
+        let connector_metadata = add_batch_id(connector_metadata, item.response.batch_id);
+        //The reason of a declined capture is kept so that the orchestrator can decide whether to
+        //retry the capture or to authorize the payment again
+        let connector_metadata = add_capture_decline_reason(
+            connector_metadata,
+            item.response.get_capture_decline_reason(),
+        );
+        //The card token of the preauth is carried over in the metadata of the payment, and is
+        //refreshed with the token returned on the capture
+        let connector_metadata = add_card_token(
+            connector_metadata,
+            item.response.get_card_token_metadata().as_ref(),
+        );
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.transaction_id.to_string(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                connector_response_reference_id: item
+                    .response
+                    .get_connector_response_reference_id(),
+                incremental_authorization_allowed: None,
+            }),
+            status: enums::AttemptStatus::from(item.response),
+            ..item.data
+        })
+    }
 }
-#[derive(Debug, Deserialize, Serialize)]
+
+const HELCIM_INCREMENT_DECLINED_MESSAGE: &str = "Incremental authorization declined by Helcim";
+
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RefundResponse {
-    status: HelcimPaymentStatus,
-    transaction_id: u64,
-    #[serde(rename = "type")]
-    transaction_type: HelcimRefundTransactionType,
+pub struct HelcimIncrementalAuthRequest {
+    pre_auth_transaction_id: HelcimTransactionId,
+    //New total amount of the preauth, not the amount it is incremented by
+    amount: HelcimAmount,
 }
 
-impl From<RefundResponse> for enums::RefundStatus {
-    fn from(item: RefundResponse) -> Self {
-        match item.transaction_type {
-            HelcimRefundTransactionType::Refund => match item.status {
-                HelcimPaymentStatus::Approved => Self::Success,
-                HelcimPaymentStatus::Declined => Self::Failure,
-            },
+impl TryFrom<&HelcimRouterData<&types::PaymentsIncrementalAuthorizationRouterData>>
+    for HelcimIncrementalAuthRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &HelcimRouterData<&types::PaymentsIncrementalAuthorizationRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pre_auth_transaction_id: item
+                .router_data
+                .request
+                .connector_transaction_id
+                .parse::<HelcimTransactionId>()
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+            amount: item.amount,
+        })
+    }
+}
+
+impl From<&HelcimPaymentStatus> for common_enums::AuthorizationStatus {
+    fn from(status: &HelcimPaymentStatus) -> Self {
+        match status {
+            HelcimPaymentStatus::Approved => Self::Success,
+            HelcimPaymentStatus::Declined => Self::Failure,
+            HelcimPaymentStatus::Pending => Self::Processing,
+            HelcimPaymentStatus::Held | HelcimPaymentStatus::Unknown(_) => Self::Unresolved,
         }
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
-    for types::RefundsRouterData<api::Execute>
+//The router updates the authorized amount of the payment to the new total once the increment
+//is approved, so the response only carries the outcome of the increment
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+    >
+    for types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
+        item: types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
     ) -> Result<Self, Self::Error> {
+        let status = common_enums::AuthorizationStatus::from(&item.response.status);
+        let error_message = match status {
+            common_enums::AuthorizationStatus::Failure => item
+                .response
+                .response_message
+                .clone()
+                .or(Some(HELCIM_INCREMENT_DECLINED_MESSAGE.to_string())),
+            common_enums::AuthorizationStatus::Success
+            | common_enums::AuthorizationStatus::Processing
+            | common_enums::AuthorizationStatus::Unresolved => None,
+        };
         Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.transaction_id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response),
-            }),
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    error_code: None,
+                    error_message,
+                    connector_authorization_id: Some(item.response.transaction_id.to_string()),
+                },
+            ),
             ..item.data
         })
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
-    for types::RefundsRouterData<api::RSync>
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimVoidRequest {
+    card_transaction_id: HelcimTransactionId,
+    ip_address: Secret<String, IpAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ecommerce: Option<bool>,
+}
+
+impl TryFrom<&types::PaymentsCancelRouterData> for HelcimVoidRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        let ip_address = item.request.get_browser_info()?.get_ip_address()?;
+        Ok(Self {
+            card_transaction_id: item
+                .request
+                .connector_transaction_id
+                .parse::<HelcimTransactionId>()
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+            ip_address,
+            ecommerce: None,
+        })
+    }
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsCancelData, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
+        item: types::ResponseRouterData<
+            F,
+            HelcimPaymentsResponse,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.transaction_id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.transaction_id.to_string(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: item
+                    .response
+                    .get_connector_response_reference_id(),
+                incremental_authorization_allowed: None,
             }),
+            status: enums::AttemptStatus::from(item.response),
             ..item.data
         })
     }
 }
 
-#[derive(Debug, strum::Display, Deserialize, Serialize)]
+// REFUND :
+// Type definition for RefundRequest
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimRefundTransactionRequest {
+    amount: HelcimAmount,
+    original_transaction_id: HelcimTransactionId,
+    ip_address: Secret<String, IpAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ecommerce: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    terminal_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimBankRefundRequest {
+    amount: HelcimAmount,
+    bank_transaction_id: HelcimTransactionId,
+    ip_address: Secret<String, IpAddress>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimBankVoidRequest {
+    bank_transaction_id: HelcimTransactionId,
+    ip_address: Secret<String, IpAddress>,
+}
+
+#[derive(Debug, Serialize)]
 #[serde(untagged)]
-pub enum HelcimErrorTypes {
-    StringType(String),
-    JsonType(serde_json::Value),
+pub enum HelcimRefundRequest {
+    Refund(HelcimRefundTransactionRequest),
+    Void(HelcimVoidRequest),
+    BankRefund(HelcimBankRefundRequest),
+    BankVoid(HelcimBankVoidRequest),
+}
+
+//Helcim only refunds settled transactions, so a full refund of an unsettled transaction is sent
+//as a reverse instead. Partial refunds can not be reversed and are always sent as refunds.
+//Bank payments are refunded through the ACH api, which only voids them until they are settled.
+#[derive(Debug, PartialEq)]
+pub enum HelcimRefundType {
+    Refund,
+    Void,
+    BankRefund,
+    BankVoid,
+}
+
+impl HelcimRefundType {
+    pub fn try_from_refund_metadata(
+        refund_metadata: &HelcimRefundMetaData,
+        payment_amount: i64,
+        refund_amount: i64,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        //Refunding the preauth itself is declined by Helcim, its hold is released by a void
+        if refund_metadata.is_uncaptured_authorization() {
+            Err(errors::ConnectorError::NotSupported {
+                message:
+                    "Refunding an authorization that was never captured (void the payment instead)"
+                        .to_string(),
+                connector: "Helcim",
+            })?
+        }
+        match refund_metadata.bank_payment.as_ref() {
+            Some(_) => Self::get_bank_refund_type(refund_metadata, payment_amount, refund_amount),
+            None => Ok(Self::get_refund_type(
+                refund_metadata,
+                payment_amount,
+                refund_amount,
+            )),
+        }
+    }
+
+    //Settled bank payments are refunded, unsettled ones are still in the open batch of Helcim and
+    //can only be voided in full
+    pub fn get_bank_refund_type(
+        refund_metadata: &HelcimRefundMetaData,
+        payment_amount: i64,
+        refund_amount: i64,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        if refund_metadata.date_settled.is_some() {
+            return Ok(Self::BankRefund);
+        }
+        if refund_metadata.refunded_amount != 0 || refund_amount != payment_amount {
+            Err(errors::ConnectorError::NotSupported {
+                message: "Partial refunds of bank payments before settlement".to_string(),
+                connector: "Helcim",
+            })?
+        }
+        Ok(Self::BankVoid)
+    }
+
+    pub fn get_refund_type(
+        refund_metadata: &HelcimRefundMetaData,
+        payment_amount: i64,
+        refund_amount: i64,
+    ) -> Self {
+        let is_full_refund = match refund_metadata.get_capture_to_refund(refund_amount) {
+            //A capture of an incrementally captured payment is reversed on its own
+            Some(capture) if refund_metadata.captures.len() > 1 => {
+                capture.refunded_amount == 0 && refund_amount == capture.amount
+            }
+            _ => refund_metadata.refunded_amount == 0 && refund_amount == payment_amount,
+        };
+        if refund_metadata.date_settled.is_none() && is_full_refund {
+            Self::Void
+        } else {
+            Self::Refund
+        }
+    }
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for HelcimRefundType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        let refund_metadata =
+            HelcimRefundMetaData::try_from_connector_metadata(&item.request.connector_metadata)?;
+        Self::try_from_refund_metadata(
+            &refund_metadata,
+            item.request.payment_amount,
+            item.request.refund_amount,
+        )
+    }
+}
+
+impl<F> TryFrom<&HelcimRouterData<&types::RefundsRouterData<F>>> for HelcimRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &HelcimRouterData<&types::RefundsRouterData<F>>,
+    ) -> Result<Self, Self::Error> {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(
+            &item.router_data.request.connector_metadata,
+        )?;
+        //Manually captured payments are refunded against a capture transaction,
+        //payments captured at authorization against the purchase itself
+        let original_transaction_id = if refund_metadata.captures.is_empty() {
+            match refund_metadata.capture_transaction_id {
+                Some(capture_transaction_id) => capture_transaction_id,
+                None => item
+                    .router_data
+                    .request
+                    .connector_transaction_id
+                    .parse::<HelcimTransactionId>()
+                    .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+            }
+        } else {
+            refund_metadata
+                .get_capture_to_refund(item.router_data.request.refund_amount)
+                .map(|capture| capture.capture_transaction_id)
+                .ok_or(errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+                    "Refund amount {} exceeds the refundable amount of every capture of the payment, refund the captures separately",
+                    item.router_data.request.refund_amount
+                )))?
+        };
+        validate_refund_amount(
+            item.router_data.request.payment_amount,
+            refund_metadata.refunded_amount,
+            item.router_data.request.refund_amount,
+        )?;
+
+        let ip_address = item
+            .router_data
+            .request
+            .get_browser_info()?
+            .get_ip_address()?;
+        match HelcimRefundType::try_from(item.router_data)? {
+            HelcimRefundType::Refund => {
+                let connector_metadata = HelcimConnectorMetadataObject::try_from(
+                    item.router_data.connector_meta_data.as_ref(),
+                )?;
+                //Helcim holds the settled amount of converted payments, so they are refunded in
+                //the settlement currency
+                let amount = match refund_metadata.currency_conversion.as_ref() {
+                    Some(currency_conversion) => {
+                        HelcimRouterData::try_from((
+                            &connector_metadata.api_version.get_currency_unit(),
+                            currency_conversion.settlement_currency,
+                            currency_conversion.get_settlement_refund_amount(
+                                refund_metadata.refunded_amount,
+                                item.router_data.request.refund_amount,
+                            )?,
+                            (),
+                        ))?
+                        .amount
+                    }
+                    None => item.amount,
+                };
+                Ok(Self::Refund(HelcimRefundTransactionRequest {
+                    amount,
+                    original_transaction_id,
+                    ip_address,
+                    ecommerce: None,
+                    terminal_id: connector_metadata.terminal_id,
+                }))
+            }
+            HelcimRefundType::Void => Ok(Self::Void(HelcimVoidRequest {
+                card_transaction_id: original_transaction_id,
+                ip_address,
+                ecommerce: None,
+            })),
+            HelcimRefundType::BankRefund => Ok(Self::BankRefund(HelcimBankRefundRequest {
+                amount: item.amount,
+                bank_transaction_id: original_transaction_id,
+                ip_address,
+            })),
+            HelcimRefundType::BankVoid => Ok(Self::BankVoid(HelcimBankVoidRequest {
+                bank_transaction_id: original_transaction_id,
+                ip_address,
+            })),
+        }
+    }
+}
+
+//Running total of the amount refunded against the payment, stored alongside
+//any other Helcim metadata so that partial refunds can not exceed the payment
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HelcimRefundMetaData {
+    #[serde(default)]
+    pub refunded_amount: i64,
+    //Only present once the payment has been synced after settlement
+    pub date_settled: Option<String>,
+    //Only present for manually captured payments
+    #[serde(default)]
+    pub captures: Vec<HelcimCaptureMetaData>,
+    //Single capture of payments captured before captures were kept as a list
+    pub capture_transaction_id: Option<HelcimTransactionId>,
+    //Only present for manually captured payments, captured or not
+    pub preauth_transaction_id: Option<HelcimTransactionId>,
+    //Only present for payments Helcim settled in another currency
+    pub currency_conversion: Option<HelcimCurrencyConversion>,
+    //Only present for bank payments
+    pub bank_payment: Option<HelcimBankPaymentMetaData>,
+}
+
+impl HelcimRefundMetaData {
+    //A manually captured payment without any capture is an authorization holding no funds
+    pub fn is_uncaptured_authorization(&self) -> bool {
+        self.preauth_transaction_id.is_some()
+            && self.captures.is_empty()
+            && self.capture_transaction_id.is_none()
+    }
+
+    //A refund is sent against the first capture with enough left to refund
+    pub fn get_capture_to_refund(&self, refund_amount: i64) -> Option<&HelcimCaptureMetaData> {
+        self.captures
+            .iter()
+            .find(|capture| capture.get_refundable_amount() >= refund_amount)
+    }
+
+    pub fn try_from_connector_metadata(
+        connector_metadata: &Option<serde_json::Value>,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        Ok(connector_metadata
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .change_context(errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_metadata",
+            })?
+            .unwrap_or_default())
+    }
+}
+
+pub fn validate_refund_amount(
+    payment_amount: i64,
+    refunded_amount: i64,
+    refund_amount: i64,
+) -> Result<(), error_stack::Report<errors::ConnectorError>> {
+    let remaining_amount = payment_amount.saturating_sub(refunded_amount);
+    if refund_amount > remaining_amount {
+        Err(errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+            "Refund amount {refund_amount} exceeds the remaining refundable amount {remaining_amount}"
+        )))?
+    }
+    Ok(())
+}
+
+//A capture processed again, when its response is handled twice, is not appended twice
+pub fn add_capture(
+    connector_metadata: Option<serde_json::Value>,
+    capture: HelcimCaptureMetaData,
+) -> Result<Option<serde_json::Value>, error_stack::Report<errors::ConnectorError>> {
+    let mut captures = get_captures(&connector_metadata)?;
+    if !captures
+        .iter()
+        .any(|existing| existing.capture_transaction_id == capture.capture_transaction_id)
+    {
+        captures.push(capture);
+    }
+    Ok(insert_metadata_field(
+        connector_metadata,
+        HELCIM_CAPTURES_FIELD,
+        serde_json::json!(captures),
+    ))
+}
+
+//The settlement batch of the transaction is kept alongside any other Helcim metadata so that
+//merchants can reconcile deposits. The metadata is left as is when Helcim returns no batch.
+pub fn add_batch_id(
+    connector_metadata: Option<serde_json::Value>,
+    batch_id: Option<u64>,
+) -> Option<serde_json::Value> {
+    let Some(batch_id) = batch_id else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_BATCH_ID_FIELD,
+        serde_json::Value::from(batch_id),
+    )
+}
+
+pub fn add_capture_decline_reason(
+    connector_metadata: Option<serde_json::Value>,
+    capture_decline_reason: Option<HelcimCaptureDeclineReason>,
+) -> Option<serde_json::Value> {
+    let Some(capture_decline_reason) = capture_decline_reason else {
+        return connector_metadata;
+    };
+    insert_metadata_field(
+        connector_metadata,
+        HELCIM_CAPTURE_DECLINE_REASON_FIELD,
+        serde_json::json!(capture_decline_reason),
+    )
+}
+
+//Helcim requires an Idempotency Key of length 25. Refunds derive it from the refund id instead
+//of generating a random one, so that a retried refund is recognised by Helcim as the same one.
+const HELCIM_REFUND_IDEMPOTENCY_KEY_HASH_LENGTH: usize = 22;
+
+pub fn get_refund_idempotency_key(
+    refund_id: &str,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    let digest = crypto::Sha256
+        .generate_digest(refund_id.as_bytes())
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failed to derive the idempotency key of the refund")?;
+    let refund_hash: String = hex::encode(digest)
+        .chars()
+        .take(HELCIM_REFUND_IDEMPOTENCY_KEY_HASH_LENGTH)
+        .collect();
+    Ok(format!("HS_{refund_hash}"))
+}
+
+pub fn update_refunded_amount(
+    connector_metadata: Option<serde_json::Value>,
+    refund_amount: i64,
+) -> Result<Option<serde_json::Value>, error_stack::Report<errors::ConnectorError>> {
+    let mut refund_metadata =
+        HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata)?;
+    let refunded_amount = refund_metadata
+        .refunded_amount
+        .saturating_add(refund_amount);
+    //The refund is also counted against the capture it was sent against
+    if let Some(capture) = refund_metadata
+        .captures
+        .iter_mut()
+        .find(|capture| capture.get_refundable_amount() >= refund_amount)
+    {
+        capture.refunded_amount = capture.refunded_amount.saturating_add(refund_amount);
+    }
+    let connector_metadata = insert_metadata_field(
+        connector_metadata,
+        "refunded_amount",
+        serde_json::Value::from(refunded_amount),
+    );
+    if refund_metadata.captures.is_empty() {
+        return Ok(connector_metadata);
+    }
+    Ok(insert_metadata_field(
+        connector_metadata,
+        HELCIM_CAPTURES_FIELD,
+        serde_json::json!(refund_metadata.captures),
+    ))
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-pub struct HelcimPaymentsErrorResponse {
-    pub errors: HelcimErrorTypes,
+#[serde(rename_all = "lowercase")]
+pub enum HelcimRefundTransactionType {
+    Refund,
+    Reverse,
+    //Transaction types introduced by Helcim which are not known yet
+    #[serde(untagged)]
+    Unknown(String),
+}
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundResponse {
+    status: HelcimPaymentStatus,
+    transaction_id: HelcimTransactionId,
+    #[serde(rename = "type")]
+    transaction_type: HelcimRefundTransactionType,
+}
+
+//Returned instead of the refund when a refund with the same idempotency key was already processed
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelcimDuplicateRefundResponse {
+    transaction_id: HelcimTransactionId,
+    notice: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
-pub enum HelcimErrorResponse {
+pub enum HelcimRefundResponse {
+    Refund(RefundResponse),
+    AlreadyExists(HelcimDuplicateRefundResponse),
+}
+
+impl From<RefundResponse> for enums::RefundStatus {
+    fn from(item: RefundResponse) -> Self {
+        if let HelcimPaymentStatus::Unknown(status) = &item.status {
+            log_unknown_helcim_value("status", status, item.transaction_id);
+        }
+        match item.transaction_type {
+            HelcimRefundTransactionType::Refund => match item.status {
+                HelcimPaymentStatus::Approved => Self::Success,
+                HelcimPaymentStatus::Declined => Self::Failure,
+                HelcimPaymentStatus::Pending | HelcimPaymentStatus::Unknown(_) => Self::Pending,
+                HelcimPaymentStatus::Held => Self::ManualReview,
+            },
+            HelcimRefundTransactionType::Reverse => match item.status {
+                HelcimPaymentStatus::Approved => Self::Success,
+                HelcimPaymentStatus::Declined => Self::Failure,
+                HelcimPaymentStatus::Pending
+                | HelcimPaymentStatus::Held
+                | HelcimPaymentStatus::Unknown(_) => Self::Pending,
+            },
+            //The refund could not be identified, so it is left for the merchant to review
+            HelcimRefundTransactionType::Unknown(transaction_type) => {
+                log_unknown_helcim_value("type", &transaction_type, item.transaction_id);
+                Self::ManualReview
+            }
+        }
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, HelcimRefundResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, HelcimRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_type = HelcimRefundType::try_from(&item.data)?;
+        let (connector_refund_id, refund_status) = match item.response {
+            //Bank payments voided on the day of the payment are refunded in full
+            HelcimRefundResponse::Refund(response) if refund_type == HelcimRefundType::BankVoid => {
+                (
+                    response.transaction_id.to_string(),
+                    match response.status {
+                        HelcimPaymentStatus::Approved => enums::RefundStatus::Success,
+                        HelcimPaymentStatus::Declined => enums::RefundStatus::Failure,
+                        HelcimPaymentStatus::Pending
+                        | HelcimPaymentStatus::Held
+                        | HelcimPaymentStatus::Unknown(_) => enums::RefundStatus::Pending,
+                    },
+                )
+            }
+            HelcimRefundResponse::Refund(response) => (
+                response.transaction_id.to_string(),
+                enums::RefundStatus::from(response),
+            ),
+            //The refund was already processed by an earlier attempt, whose result is reused
+            HelcimRefundResponse::AlreadyExists(response) => {
+                router_env::logger::info!(
+                    "Helcim refund {} already exists: {}",
+                    response.transaction_id,
+                    response.notice
+                );
+                (
+                    response.transaction_id.to_string(),
+                    enums::RefundStatus::Success,
+                )
+            }
+        };
+        //Only successful refunds count towards the refunded amount of the payment
+        let connector_metadata = if refund_status == enums::RefundStatus::Success {
+            update_refunded_amount(
+                item.data.request.connector_metadata.clone(),
+                item.data.request.refund_amount,
+            )?
+        } else {
+            item.data.request.connector_metadata.clone()
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id,
+                refund_status,
+            }),
+            request: types::RefundsData {
+                connector_metadata,
+                ..item.data.request
+            },
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.transaction_id.to_string(),
+                refund_status: enums::RefundStatus::from(item.response),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, strum::Display, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HelcimErrorTypes {
+    StringType(String),
+    JsonType(serde_json::Value),
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct HelcimPaymentsErrorResponse {
+    pub errors: HelcimErrorTypes,
+}
+
+//Helcim reports errors either as a single message or as messages keyed by the invalid field,
+//other fields of the error body are ignored
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HelcimErrorResponse {
     Payment(HelcimPaymentsErrorResponse),
     General(String),
+    //Body of an error which is not JSON, e.g. an HTML page returned by Helcim's edge
+    #[serde(skip_deserializing)]
+    Unstructured(String),
+}
+
+const HELCIM_RATE_LIMIT_MESSAGE: &str = "too many requests";
+//Key under which Helcim reports the error of a card token which can no longer be used
+const HELCIM_CARD_TOKEN_ERROR_FIELD: &str = "cardToken";
+pub const HELCIM_CARD_TOKEN_EXPIRED_CODE: &str = "CARD_TOKEN_EXPIRED";
+pub const HELCIM_CARD_TOKEN_EXPIRED_MESSAGE: &str =
+    "The card token has expired, tokenize the card again with HelcimPay.js and retry the payment";
+pub const HELCIM_GATEWAY_ERROR_CODE: &str = "GATEWAY_ERROR";
+pub const HELCIM_GATEWAY_ERROR_MESSAGE: &str =
+    "Helcim is temporarily unable to process the request, retry the payment";
+//Number of characters of an unstructured error body which are kept in the error reason
+const HELCIM_ERROR_BODY_PREVIEW_LENGTH: usize = 256;
+
+impl HelcimErrorResponse {
+    // Helcim's edge returns an HTML page or plain text on gateway errors, such bodies are kept as
+    // text along with the status code so that the error never fails to deserialize
+    pub fn from_response_body(body: &[u8], status_code: u16) -> Self {
+        serde_json::from_slice(body).unwrap_or_else(|_| {
+            let body = String::from_utf8_lossy(body);
+            let body = body.trim();
+            Self::Unstructured(if body.is_empty() {
+                format!("Helcim responded with status code {status_code} and an empty body")
+            } else {
+                format!(
+                    "Helcim responded with status code {status_code}: {}",
+                    body.chars()
+                        .take(HELCIM_ERROR_BODY_PREVIEW_LENGTH)
+                        .collect::<String>()
+                )
+            })
+        })
+    }
+
+    pub fn get_error_message(&self) -> String {
+        match self {
+            Self::Payment(response) => match &response.errors {
+                HelcimErrorTypes::StringType(error) => error.clone(),
+                HelcimErrorTypes::JsonType(error) => error.to_string(),
+            },
+            Self::General(error_string) | Self::Unstructured(error_string) => error_string.clone(),
+        }
+    }
+
+    // Helcim can report throttling in the error body without a 429 status
+    pub fn is_rate_limited(&self) -> bool {
+        self.get_error_message()
+            .to_lowercase()
+            .contains(HELCIM_RATE_LIMIT_MESSAGE)
+    }
+
+    // HelcimPay.js card tokens are short lived, payments made with an expired one can be retried
+    // once the customer re-enters the card. The error is identified by the field Helcim reports it
+    // under, as the wording of the message is not stable.
+    pub fn is_card_token_expired(&self) -> bool {
+        match self {
+            Self::Payment(HelcimPaymentsErrorResponse {
+                errors: HelcimErrorTypes::JsonType(errors),
+            }) => errors.get(HELCIM_CARD_TOKEN_ERROR_FIELD).is_some(),
+            Self::Payment(HelcimPaymentsErrorResponse {
+                errors: HelcimErrorTypes::StringType(_),
+            })
+            | Self::General(_)
+            | Self::Unstructured(_) => false,
+        }
+    }
+
+    // Helcim reports the decline reason only in the error text, so it is matched against known phrases
+    pub fn get_decline_category(&self) -> Option<HelcimDeclineCategory> {
+        let error_message = self.get_error_message().to_lowercase();
+        HELCIM_DECLINE_CATEGORIES
+            .iter()
+            .find(|(phrase, _)| error_message.contains(phrase))
+            .map(|(_, category)| *category)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, strum::Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum HelcimDeclineCategory {
+    IssuerUnavailable,
+    DoNotHonor,
+    InsufficientFunds,
+    NetworkDecline,
+}
+
+// Ordered from the most to the least specific, generic declines are matched last
+const HELCIM_DECLINE_CATEGORIES: [(&str, HelcimDeclineCategory); 7] = [
+    (
+        "issuer unavailable",
+        HelcimDeclineCategory::IssuerUnavailable,
+    ),
+    (
+        "issuer or switch inoperative",
+        HelcimDeclineCategory::IssuerUnavailable,
+    ),
+    (
+        "issuer not available",
+        HelcimDeclineCategory::IssuerUnavailable,
+    ),
+    ("do not honor", HelcimDeclineCategory::DoNotHonor),
+    (
+        "insufficient funds",
+        HelcimDeclineCategory::InsufficientFunds,
+    ),
+    (
+        "not sufficient funds",
+        HelcimDeclineCategory::InsufficientFunds,
+    ),
+    ("declined", HelcimDeclineCategory::NetworkDecline),
+];
+
+pub fn get_retry_after(headers: Option<&http::HeaderMap>) -> Option<u64> {
+    headers
+        .and_then(|headers| headers.get(http::header::RETRY_AFTER))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_auth_header(auth_type: &types::ConnectorAuthType) -> Vec<(String, String)> {
+        use crate::types::api::ConnectorCommon;
+
+        crate::connector::Helcim
+            .get_auth_header(auth_type)
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_inner()))
+            .collect()
+    }
+
+    #[test]
+    fn should_emit_api_token_header_for_header_key_auth() {
+        let auth_type = types::ConnectorAuthType::HeaderKey {
+            api_key: Secret::new("helcim_api_token".to_string()),
+        };
+
+        assert_eq!(
+            get_auth_header(&auth_type),
+            vec![(
+                crate::headers::API_TOKEN.to_string(),
+                "helcim_api_token".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn should_emit_api_token_and_account_id_headers_for_body_key_auth() {
+        let auth_type = types::ConnectorAuthType::BodyKey {
+            api_key: Secret::new("helcim_api_token".to_string()),
+            key1: Secret::new("helcim_account_id".to_string()),
+        };
+
+        assert_eq!(
+            get_auth_header(&auth_type),
+            vec![
+                (
+                    crate::headers::API_TOKEN.to_string(),
+                    "helcim_api_token".to_string()
+                ),
+                (
+                    crate::headers::ACCOUNT_ID.to_string(),
+                    "helcim_account_id".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_unsupported_auth_type() {
+        let auth_type = types::ConnectorAuthType::SignatureKey {
+            api_key: Secret::new("helcim_api_token".to_string()),
+            key1: Secret::new("helcim_account_id".to_string()),
+            api_secret: Secret::new("helcim_secret".to_string()),
+        };
+
+        let result = HelcimAuthType::try_from(&auth_type);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ConnectorError::FailedToObtainAuthType
+        ));
+    }
+
+    fn ach_bank_debit_data(bank_account_holder_name: Option<&str>) -> domain::BankDebitData {
+        domain::BankDebitData::AchBankDebit {
+            billing_details: domain::BankDebitBilling {
+                name: Secret::new("John Doe".to_string()),
+                email: Email::try_from("john.doe@example.com".to_string()).unwrap(),
+                address: None,
+            },
+            account_number: Secret::new("000123456789".to_string()),
+            routing_number: Secret::new("110000000".to_string()),
+            card_holder_name: None,
+            bank_account_holder_name: bank_account_holder_name
+                .map(|name| Secret::new(name.to_string())),
+            bank_name: None,
+            bank_type: Some(common_enums::BankType::Savings),
+            bank_holder_type: Some(common_enums::BankHolderType::Business),
+        }
+    }
+
+    #[test]
+    fn should_serialize_bank_payment_request() {
+        let request = HelcimPaymentsRequest::Bank(HelcimBankPaymentRequest {
+            amount: HelcimAmount::Major(10.5),
+            currency: enums::Currency::CAD,
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            bank_data: HelcimBankData::try_from(&ach_bank_debit_data(Some("Acme Corp"))).unwrap(),
+            invoice: HelcimInvoice {
+                invoice_number: "INV1001".to_string(),
+                line_items: vec![],
+            },
+            billing_address: HelcimBillingAddress {
+                name: Secret::new("John Doe".to_string()),
+                street1: Secret::new("1 Main Street".to_string()),
+                postal_code: Secret::new("T2P 1J9".to_string()),
+                street2: None,
+                city: None,
+                country: None,
+                email: None,
+            },
+            customer_code: None,
+            comments: None,
+            tip_amount: None,
+            convenience_fee: None,
+        });
+
+        let request = serde_json::to_value(request).unwrap();
+
+        assert_eq!(request["amount"], 10.5);
+        assert_eq!(request["currency"], "CAD");
+        assert_eq!(
+            request["bankData"],
+            serde_json::json!({
+                "bankAccountNumber": "000123456789",
+                "bankRoutingNumber": "110000000",
+                "bankAccountHolder": "Acme Corp",
+                "bankAccountType": "SAVINGS",
+                "bankAccountCorporate": "CORPORATE"
+            })
+        );
+        assert!(request.get("cardData").is_none());
+    }
+
+    #[test]
+    fn should_default_bank_account_holder_to_billing_name() {
+        let bank_data =
+            serde_json::to_value(HelcimBankData::try_from(&ach_bank_debit_data(None)).unwrap())
+                .unwrap();
+
+        assert_eq!(bank_data["bankAccountHolder"], "John Doe");
+    }
+
+    #[test]
+    fn should_reject_unsupported_bank_debits() {
+        let bank_debit_data = domain::BankDebitData::SepaBankDebit {
+            billing_details: domain::BankDebitBilling {
+                name: Secret::new("John Doe".to_string()),
+                email: Email::try_from("john.doe@example.com".to_string()).unwrap(),
+                address: None,
+            },
+            iban: Secret::new("DE89370400440532013000".to_string()),
+            bank_account_holder_name: None,
+        };
+
+        assert!(matches!(
+            HelcimBankData::try_from(&bank_debit_data)
+                .unwrap_err()
+                .current_context(),
+            errors::ConnectorError::NotImplemented(_)
+        ));
+    }
+
+    #[test]
+    fn should_map_transaction_type_and_status_to_attempt_status() {
+        let combinations = [
+            (
+                HelcimTransactionType::Purchase,
+                HelcimPaymentStatus::Approved,
+                enums::AttemptStatus::Charged,
+            ),
+            (
+                HelcimTransactionType::Purchase,
+                HelcimPaymentStatus::Declined,
+                enums::AttemptStatus::Failure,
+            ),
+            (
+                HelcimTransactionType::PreAuth,
+                HelcimPaymentStatus::Approved,
+                enums::AttemptStatus::Authorized,
+            ),
+            (
+                HelcimTransactionType::PreAuth,
+                HelcimPaymentStatus::Declined,
+                enums::AttemptStatus::AuthorizationFailed,
+            ),
+            (
+                HelcimTransactionType::Capture,
+                HelcimPaymentStatus::Approved,
+                enums::AttemptStatus::Charged,
+            ),
+            (
+                HelcimTransactionType::Capture,
+                HelcimPaymentStatus::Declined,
+                enums::AttemptStatus::CaptureFailed,
+            ),
+            (
+                HelcimTransactionType::Reverse,
+                HelcimPaymentStatus::Approved,
+                enums::AttemptStatus::Voided,
+            ),
+            (
+                HelcimTransactionType::Reverse,
+                HelcimPaymentStatus::Declined,
+                enums::AttemptStatus::VoidFailed,
+            ),
+        ];
+
+        for (transaction_type, status, expected_status) in combinations {
+            assert_eq!(
+                get_attempt_status(&transaction_type, &status),
+                expected_status
+            );
+
+            // The response conversion delegates to the same mapping
+            let response = HelcimPaymentsResponse {
+                transaction_type,
+                status,
+                ..serde_json::from_value(serde_json::json!({
+                    "transactionId": 20163745,
+                    "status": "APPROVED",
+                    "type": "purchase",
+                    "invoiceNumber": "INV1001"
+                }))
+                .unwrap()
+            };
+            assert_eq!(enums::AttemptStatus::from(response), expected_status);
+        }
+    }
+
+    #[test]
+    fn should_retry_capture_declined_by_issuer() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163747,
+            "status": "DECLINED",
+            "type": "capture",
+            "invoiceNumber": "INV1001",
+            "responseMessage": "DECLINED - Insufficient Funds"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.get_capture_decline_reason(),
+            Some(HelcimCaptureDeclineReason::IssuerDeclined)
+        );
+        assert_eq!(
+            add_capture_decline_reason(None, response.get_capture_decline_reason()),
+            Some(serde_json::json!({ "capture_decline_reason": "issuer_declined" }))
+        );
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::CaptureFailed
+        );
+
+        // An expired card is declined by the issuer, not an expired authorization
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163747,
+            "status": "DECLINED",
+            "type": "capture",
+            "invoiceNumber": "INV1001",
+            "responseMessage": "DECLINED - Expired Card",
+            "errors": { "cardNumber": "Card is expired" }
+        }))
+        .unwrap();
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::CaptureFailed
+        );
+    }
+
+    #[test]
+    fn should_treat_capture_declined_with_unrecognized_message_as_declined_by_issuer() {
+        // The message alone does not tell that the authorization expired
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163749,
+            "status": "DECLINED",
+            "type": "capture",
+            "invoiceNumber": "INV1001",
+            "responseMessage": "Preauthorization expired or already captured"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.get_capture_decline_reason(),
+            Some(HelcimCaptureDeclineReason::IssuerDeclined)
+        );
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::CaptureFailed
+        );
+    }
+
+    #[test]
+    fn should_authorize_again_when_capture_declined_for_expired_authorization() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163748,
+            "status": "DECLINED",
+            "type": "capture",
+            "invoiceNumber": "INV1001",
+            "responseMessage": "Transaction declined",
+            "errors": { "preAuthTransactionId": "Preauth has expired" }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.get_capture_decline_reason(),
+            Some(HelcimCaptureDeclineReason::AuthorizationExpired)
+        );
+        assert_eq!(
+            add_capture_decline_reason(
+                Some(serde_json::json!({ "preauth_transaction_id": 20163745 })),
+                response.get_capture_decline_reason()
+            ),
+            Some(serde_json::json!({
+                "preauth_transaction_id": 20163745,
+                "capture_decline_reason": "authorization_expired"
+            }))
+        );
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::Failure
+        );
+    }
+
+    #[test]
+    fn should_move_bank_payment_from_pending_to_charged_on_settlement() {
+        let get_status = |status: &str| {
+            let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+                "transactionId": 20163745,
+                "status": status,
+                "type": "purchase",
+                "invoiceNumber": "INV1001"
+            }))
+            .unwrap();
+            enums::AttemptStatus::from(response)
+        };
+
+        assert_eq!(get_status("PENDING"), enums::AttemptStatus::Pending);
+        assert_eq!(get_status("APPROVED"), enums::AttemptStatus::Charged);
+        assert_eq!(get_status("DECLINED"), enums::AttemptStatus::Failure);
+    }
+
+    #[test]
+    fn should_map_held_transactions_to_awaiting_review() {
+        let get_status = |status: &str, transaction_type: &str| {
+            let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+                "transactionId": 20163745,
+                "status": status,
+                "type": transaction_type,
+                "invoiceNumber": "INV1001"
+            }))
+            .unwrap();
+            enums::AttemptStatus::from(response)
+        };
+
+        for status in ["HELD", "PENDING REVIEW"] {
+            assert_eq!(
+                get_status(status, "purchase"),
+                enums::AttemptStatus::Unresolved
+            );
+            assert_eq!(
+                get_status(status, "preauth"),
+                enums::AttemptStatus::Unresolved
+            );
+            assert_ne!(
+                get_status(status, "purchase"),
+                enums::AttemptStatus::Charged
+            );
+        }
+    }
+
+    #[test]
+    fn should_map_held_refund_to_manual_review() {
+        let response: RefundResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163746,
+            "status": "HELD",
+            "type": "refund"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            enums::RefundStatus::from(response),
+            enums::RefundStatus::ManualReview
+        );
+    }
+
+    #[test]
+    fn should_store_card_token_from_verify_as_mandate_reference() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163746,
+            "status": "APPROVED",
+            "type": "verify",
+            "invoiceNumber": null,
+            "cardToken": "27f2b2c8a3b7e5a1d2c9f0"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response
+                .get_mandate_reference()
+                .and_then(|mandate_reference| mandate_reference.connector_mandate_id),
+            Some("27f2b2c8a3b7e5a1d2c9f0".to_string())
+        );
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::Charged
+        );
+    }
+
+    #[test]
+    fn should_keep_card_token_of_approved_transactions_only() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163746,
+            "status": "APPROVED",
+            "type": "purchase",
+            "cardToken": "27f2b2c8a3b7e5a1d2c9f0",
+            "customerCode": "CST1000"
+        }))
+        .unwrap();
+        let card_token = response.get_card_token_metadata().unwrap();
+        assert_eq!(card_token.customer_code, Some("CST1000".to_string()));
+        assert!(!format!("{response:?}").contains("27f2b2c8a3b7e5a1d2c9f0"));
+        assert!(!format!("{card_token:?}").contains("27f2b2c8a3b7e5a1d2c9f0"));
+
+        let metadata = add_card_token(
+            Some(serde_json::json!({ "batch_id": 4521 })),
+            Some(&card_token),
+        );
+        assert_eq!(
+            metadata.as_ref().and_then(|m| m.get("batch_id")),
+            Some(&4521.into())
+        );
+        assert_eq!(
+            HelcimCardTokenMetaData::from_connector_metadata(metadata.as_ref()),
+            Some(card_token)
+        );
+
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163747,
+            "status": "DECLINED",
+            "type": "purchase",
+            "cardToken": "27f2b2c8a3b7e5a1d2c9f0"
+        }))
+        .unwrap();
+        assert_eq!(response.get_card_token_metadata(), None);
+    }
+
+    fn get_amount(
+        api_version: HelcimApiVersion,
+        currency: enums::Currency,
+        amount: i64,
+    ) -> HelcimAmount {
+        HelcimRouterData::try_from((&api_version.get_currency_unit(), currency, amount, ()))
+            .unwrap()
+            .amount
+    }
+
+    #[test]
+    fn should_scale_amount_to_declared_currency_unit() {
+        use crate::types::api::ConnectorCommon;
+
+        assert!(matches!(
+            crate::connector::Helcim.get_currency_unit(),
+            types::api::CurrencyUnit::Base
+        ));
+        assert_eq!(
+            HelcimConnectorMetadataObject::try_from(None)
+                .unwrap()
+                .api_version,
+            HelcimApiVersion::V1
+        );
+
+        assert_eq!(
+            get_amount(HelcimApiVersion::V1, enums::Currency::USD, 1050),
+            HelcimAmount::Major(10.5)
+        );
+        assert_eq!(
+            get_amount(HelcimApiVersion::V1, enums::Currency::JPY, 1050),
+            HelcimAmount::Major(1050.0)
+        );
+        assert_eq!(
+            get_amount(HelcimApiVersion::V1, enums::Currency::USD, 0),
+            HelcimAmount::Major(0.0)
+        );
+    }
+
+    #[test]
+    fn should_use_minor_unit_amounts_for_v2_accounts() {
+        let connector_meta_data = Secret::new(serde_json::json!({ "api_version": "v2" }));
+        let connector_metadata =
+            HelcimConnectorMetadataObject::try_from(Some(&connector_meta_data)).unwrap();
+
+        assert_eq!(connector_metadata.api_version, HelcimApiVersion::V2);
+        assert!(matches!(
+            connector_metadata.api_version.get_currency_unit(),
+            types::api::CurrencyUnit::Minor
+        ));
+        assert_eq!(
+            get_amount(HelcimApiVersion::V2, enums::Currency::USD, 1050),
+            HelcimAmount::Minor(1050)
+        );
+        assert_eq!(
+            get_amount(HelcimApiVersion::V2, enums::Currency::JPY, 1050),
+            HelcimAmount::Minor(1050)
+        );
+        assert_eq!(
+            get_amount(HelcimApiVersion::V2, enums::Currency::USD, 0),
+            HelcimAmount::Minor(0)
+        );
+    }
+
+    fn get_decimal_amount(amount: HelcimAmount, exponent: u32) -> Option<Decimal> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        match amount {
+            HelcimAmount::Major(amount) => {
+                Decimal::from_f64(amount).map(|amount| amount.round_dp(exponent))
+            }
+            HelcimAmount::Minor(amount) => Some(Decimal::new(amount, exponent)),
+        }
+    }
+
+    #[test]
+    fn should_convert_same_amount_alike_in_base_and_minor_units() {
+        for currency in [
+            enums::Currency::USD,
+            enums::Currency::CAD,
+            enums::Currency::EUR,
+            enums::Currency::JPY,
+            enums::Currency::KRW,
+            enums::Currency::BHD,
+            enums::Currency::KWD,
+        ] {
+            let exponent = utils::get_currency_exponent(currency);
+            for amount in [0, 1, 5, 629, 1050, 999_999, i64::from(u32::MAX)] {
+                let base_amount = get_amount(HelcimApiVersion::V1, currency, amount);
+                let minor_amount = get_amount(HelcimApiVersion::V2, currency, amount);
+
+                assert_eq!(minor_amount, HelcimAmount::Minor(amount));
+                assert_eq!(
+                    get_decimal_amount(base_amount, exponent),
+                    Some(Decimal::new(amount, exponent)),
+                    "{amount} minor units of {currency} converted to {base_amount:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_reject_same_amounts_in_base_and_minor_units() {
+        for amount in [-1, i64::from(u32::MAX) + 1] {
+            for api_version in [HelcimApiVersion::V1, HelcimApiVersion::V2] {
+                let result = HelcimRouterData::try_from((
+                    &api_version.get_currency_unit(),
+                    enums::Currency::USD,
+                    amount,
+                    (),
+                ));
+
+                assert!(matches!(
+                    result.unwrap_err().current_context(),
+                    errors::ConnectorError::RequestEncodingFailedWithReason(_)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn should_serialize_amounts_for_each_api_version() {
+        for (api_version, currency, expected_amount) in [
+            (
+                HelcimApiVersion::V1,
+                enums::Currency::USD,
+                serde_json::json!(10.5),
+            ),
+            (
+                HelcimApiVersion::V1,
+                enums::Currency::JPY,
+                serde_json::json!(1050.0),
+            ),
+            (
+                HelcimApiVersion::V2,
+                enums::Currency::USD,
+                serde_json::json!(1050),
+            ),
+            (
+                HelcimApiVersion::V2,
+                enums::Currency::JPY,
+                serde_json::json!(1050),
+            ),
+        ] {
+            let amount = get_amount(api_version, currency, 1050);
+            let authorize_request = get_card_payment_request_with_amount(None, currency, amount);
+            let capture_request = HelcimCaptureRequest {
+                pre_auth_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+                amount,
+                ip_address: Secret::new("127.0.0.1".to_string()),
+                ecommerce: None,
+                terminal_id: None,
+            };
+            let refund_request = HelcimRefundTransactionRequest {
+                amount,
+                original_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+                ip_address: Secret::new("127.0.0.1".to_string()),
+                ecommerce: None,
+                terminal_id: None,
+            };
+
+            for request in [
+                serde_json::to_value(authorize_request).unwrap(),
+                serde_json::to_value(capture_request).unwrap(),
+                serde_json::to_value(refund_request).unwrap(),
+            ] {
+                assert_eq!(request["amount"], expected_amount);
+            }
+        }
+    }
+
+    #[test]
+    fn should_map_settlement_details_of_settled_transaction() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163747,
+            "status": "APPROVED",
+            "type": "purchase",
+            "invoiceNumber": "INV1003",
+            "batchId": 4521,
+            "dateSettled": "2024-04-26"
+        }))
+        .unwrap();
+
+        let settlement_metadata = response.get_settlement_metadata().unwrap();
+        assert_eq!(
+            settlement_metadata,
+            HelcimSettlementMetaData {
+                batch_id: Some(4521),
+                date_settled: "2024-04-26".to_string(),
+            }
+        );
+        assert_eq!(
+            serde_json::json!(settlement_metadata),
+            serde_json::json!({
+                "batch_id": 4521,
+                "date_settled": "2024-04-26"
+            })
+        );
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::Charged
+        );
+    }
+
+    #[test]
+    fn should_omit_settlement_details_of_unsettled_transaction() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163748,
+            "status": "APPROVED",
+            "type": "purchase",
+            "invoiceNumber": "INV1004"
+        }))
+        .unwrap();
+
+        assert_eq!(response.batch_id, None);
+        assert_eq!(response.date_settled, None);
+        assert_eq!(response.get_settlement_metadata(), None);
+    }
+
+    #[test]
+    fn should_populate_network_txn_id_for_approved_auth() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163749,
+            "status": "APPROVED",
+            "type": "preauth",
+            "invoiceNumber": "INV1006",
+            "approvalCode": "T3E5ST",
+            "networkTransactionId": "MCC8D3K4L0522"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.get_network_txn_id(),
+            Some("MCC8D3K4L0522".to_string())
+        );
+        assert_eq!(
+            response.get_connector_response_reference_id(),
+            Some("INV1006".to_string())
+        );
+    }
+
+    #[test]
+    fn should_omit_network_txn_id_for_declined_auth() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163750,
+            "status": "DECLINED",
+            "type": "purchase",
+            "invoiceNumber": "INV1007",
+            "networkTransactionId": "MCC8D3K4L0523"
+        }))
+        .unwrap();
+
+        assert_eq!(response.get_network_txn_id(), None);
+        assert_eq!(
+            response.get_connector_response_reference_id(),
+            Some("INV1007".to_string())
+        );
+    }
+
+    #[test]
+    fn should_send_and_match_reference_id_on_verify() {
+        let request = HelcimVerifyRequest {
+            currency: enums::Currency::USD,
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            card_data: HelcimCard {
+                card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+                card_expiry: Secret::new("1230".to_string()),
+                card_c_v_v: Secret::new("737".to_string()),
+            },
+            invoice_number: "pay_mbabizu24mvu3mela5njyhpit4_1".to_string(),
+            billing_address: HelcimBillingAddress {
+                name: Secret::new("John Doe".to_string()),
+                street1: Secret::new("1 Main Street".to_string()),
+                postal_code: Secret::new("94122".to_string()),
+                street2: None,
+                city: None,
+                country: None,
+                email: None,
+            },
+            ecommerce: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["invoiceNumber"],
+            "pay_mbabizu24mvu3mela5njyhpit4_1"
+        );
+
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163752,
+            "status": "APPROVED",
+            "type": "verify",
+            "invoiceNumber": "pay_mbabizu24mvu3mela5njyhpit4_1",
+            "cardToken": "27f2b2c8a3b7e5a1d2c9f0"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.get_connector_response_reference_id(),
+            Some("pay_mbabizu24mvu3mela5njyhpit4_1".to_string())
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_transaction_id_as_reference_for_capture_and_sync() {
+        for transaction_type in ["capture", "purchase"] {
+            let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+                "transactionId": 20163751,
+                "status": "APPROVED",
+                "type": transaction_type
+            }))
+            .unwrap();
+
+            assert_eq!(response.get_network_txn_id(), None);
+            assert_eq!(
+                response.get_connector_response_reference_id(),
+                Some("20163751".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_charge_verified_card_with_card_token() {
+        let request = HelcimPaymentsRequest::Card(HelcimCardPaymentRequest {
+            amount: HelcimAmount::Major(10.5),
+            currency: enums::Currency::USD,
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            card_data: HelcimCardData::Token(HelcimCardToken {
+                card_token: Secret::new("27f2b2c8a3b7e5a1d2c9f0".to_string()),
+            }),
+            invoice: HelcimInvoice {
+                invoice_number: "INV1002".to_string(),
+                line_items: vec![],
+            },
+            billing_address: HelcimBillingAddress {
+                name: Secret::new("John Doe".to_string()),
+                street1: Secret::new("1 Main Street".to_string()),
+                postal_code: Secret::new("94122".to_string()),
+                street2: None,
+                city: None,
+                country: None,
+                email: None,
+            },
+            ecommerce: None,
+            statement_descriptor: None,
+            customer_code: None,
+            comments: None,
+            tip_amount: None,
+            convenience_fee: None,
+            three_d_s: None,
+            mcc: None,
+        });
+
+        let request = serde_json::to_value(request).unwrap();
+
+        assert_eq!(
+            request["cardData"],
+            serde_json::json!({ "cardToken": "27f2b2c8a3b7e5a1d2c9f0" })
+        );
+        assert!(request.get("statementDescriptor").is_none());
+
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163747,
+            "status": "APPROVED",
+            "type": "purchase",
+            "invoiceNumber": "INV1002"
+        }))
+        .unwrap();
+
+        assert!(response.get_mandate_reference().is_none());
+        assert_eq!(
+            enums::AttemptStatus::from(response),
+            enums::AttemptStatus::Charged
+        );
+    }
+
+    fn helcim_pay_card_token(
+        connector_token: Option<&str>,
+        card_cvc: Option<&str>,
+    ) -> domain::CardToken {
+        domain::CardToken {
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            card_cvc: card_cvc.map(|card_cvc| Secret::new(card_cvc.to_string())),
+            connector_token: connector_token
+                .map(|connector_token| Secret::new(connector_token.to_string())),
+        }
+    }
+
+    #[test]
+    fn should_charge_card_with_helcim_pay_card_token() {
+        let card_data =
+            HelcimCardData::try_from(&helcim_pay_card_token(Some("f3c0a8e1d2b9c7a6"), None))
+                .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(card_data).unwrap(),
+            serde_json::json!({ "cardToken": "f3c0a8e1d2b9c7a6" })
+        );
+    }
+
+    #[test]
+    fn should_reject_card_token_without_helcim_pay_token() {
+        let result = HelcimCardData::try_from(&helcim_pay_card_token(None, None));
+
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_method_data.card_token.connector_token"
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_card_details_along_with_helcim_pay_token() {
+        let result = HelcimCardData::try_from(&helcim_pay_card_token(
+            Some("f3c0a8e1d2b9c7a6"),
+            Some("123"),
+        ));
+
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "payment_method_data.card_token.card_cvc"
+            }
+        );
+    }
+
+    fn get_card_payment_request(three_d_s: Option<HelcimThreeDsData>) -> HelcimPaymentsRequest {
+        get_card_payment_request_with_amount(
+            three_d_s,
+            enums::Currency::USD,
+            HelcimAmount::Major(10.5),
+        )
+    }
+
+    fn get_card_payment_request_with_amount(
+        three_d_s: Option<HelcimThreeDsData>,
+        currency: enums::Currency,
+        amount: HelcimAmount,
+    ) -> HelcimPaymentsRequest {
+        HelcimPaymentsRequest::Card(HelcimCardPaymentRequest {
+            amount,
+            currency,
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            card_data: HelcimCardData::Token(HelcimCardToken {
+                card_token: Secret::new("27f2b2c8a3b7e5a1d2c9f0".to_string()),
+            }),
+            invoice: HelcimInvoice {
+                invoice_number: "INV1005".to_string(),
+                line_items: vec![],
+            },
+            billing_address: HelcimBillingAddress {
+                name: Secret::new("John Doe".to_string()),
+                street1: Secret::new("1 Main Street".to_string()),
+                postal_code: Secret::new("94122".to_string()),
+                street2: None,
+                city: None,
+                country: None,
+                email: None,
+            },
+            ecommerce: None,
+            statement_descriptor: None,
+            customer_code: None,
+            comments: None,
+            tip_amount: None,
+            convenience_fee: None,
+            three_d_s,
+            mcc: None,
+        })
+    }
+
+    #[test]
+    fn should_pass_through_three_ds_authentication_data() {
+        let authentication_data = AuthenticationData {
+            eci: Some("05".to_string()),
+            cavv: "AAABBEg0VhI0VniQEjRWAAAAAAA=".to_string(),
+            threeds_server_transaction_id: "8a880dc0-d2d2-4067-bcb1-b08d1690b26e".to_string(),
+            message_version: "2.2.0".to_string(),
+        };
+
+        let request = serde_json::to_value(get_card_payment_request(Some(
+            HelcimThreeDsData::from(&authentication_data),
+        )))
+        .unwrap();
+
+        assert_eq!(
+            request["threeDS"],
+            serde_json::json!({
+                "eci": "05",
+                "cavv": "AAABBEg0VhI0VniQEjRWAAAAAAA=",
+                "xid": "8a880dc0-d2d2-4067-bcb1-b08d1690b26e",
+                "version": "2.2.0"
+            })
+        );
+
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163749,
+            "status": "APPROVED",
+            "type": "purchase"
+        }))
+        .unwrap();
+        assert!(matches!(
+            response.get_additional_payment_method_data(Some(&authentication_data)),
+            Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+                authentication_data: Some(authentication_result),
+                payment_checks: None,
+                ..
+            }) if authentication_result == serde_json::json!({ "eci": "05", "liability_shift": true })
+        ));
+    }
+
+    #[test]
+    fn should_serialize_comments_when_present() {
+        let metadata = Secret::new(serde_json::json!({ "comments": "  Gift wrap order 1005  " }));
+        let comments = get_comments(Some(&metadata), Some(&"Order 1005".to_string()));
+        let mut request = get_card_payment_request(None);
+        if let HelcimPaymentsRequest::Card(card_request) = &mut request {
+            card_request.comments = comments;
+        }
+
+        let request = serde_json::to_value(request).unwrap();
+
+        assert_eq!(request["comments"], "Gift wrap order 1005");
+        assert_eq!(
+            get_comments(None, Some(&"x".repeat(300))).map(|comments| comments.len()),
+            Some(HELCIM_COMMENTS_MAX_LENGTH)
+        );
+        assert_eq!(get_comments(None, Some(&"   ".to_string())), None);
+        assert!(serde_json::to_value(get_card_payment_request(None))
+            .unwrap()
+            .get("comments")
+            .is_none());
+    }
+
+    #[test]
+    fn should_map_card_brand_and_funding_of_visa_credit_transaction() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163750,
+            "status": "APPROVED",
+            "type": "purchase",
+            "cardType": "VI",
+            "cardFunding": "credit"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            response.get_additional_payment_method_data(None),
+            Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+                authentication_data: None,
+                payment_checks: None,
+                card_network: Some(enums::CardNetwork::Visa),
+                card_type: Some(card_type),
+            }) if card_type == "credit"
+        ));
+    }
+
+    #[test]
+    fn should_map_card_brand_and_funding_of_mastercard_debit_transaction() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163751,
+            "status": "APPROVED",
+            "type": "preauth",
+            "cardType": "Mastercard",
+            "cardFunding": "debit"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            response.get_additional_payment_method_data(None),
+            Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+                card_network: Some(enums::CardNetwork::Mastercard),
+                card_type: Some(card_type),
+                ..
+            }) if card_type == "debit"
+        ));
+    }
+
+    #[test]
+    fn should_omit_card_details_when_card_info_is_missing_or_unknown() {
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163752,
+            "status": "APPROVED",
+            "type": "purchase"
+        }))
+        .unwrap();
+        assert!(response.get_additional_payment_method_data(None).is_none());
+
+        let response: HelcimPaymentsResponse = serde_json::from_value(serde_json::json!({
+            "transactionId": 20163753,
+            "status": "APPROVED",
+            "type": "purchase",
+            "cardType": "UnionPay",
+            "cardFunding": "charge"
+        }))
+        .unwrap();
+        assert_eq!(response.card_type, Some(HelcimCardBrand::Other));
+        assert_eq!(response.card_funding, Some(HelcimCardFunding::Unknown));
+        assert!(matches!(
+            response.get_additional_payment_method_data(None),
+            Some(types::AdditionalPaymentMethodConnectorResponse::Card {
+                card_network: None,
+                card_type: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn should_omit_three_ds_data_for_non_authenticated_payment() {
+        let request = serde_json::to_value(get_card_payment_request(None)).unwrap();
+
+        assert!(request.get("threeDS").is_none());
+
+        let authentication_data = AuthenticationData {
+            eci: Some("07".to_string()),
+            cavv: "AAABBEg0VhI0VniQEjRWAAAAAAA=".to_string(),
+            threeds_server_transaction_id: "8a880dc0-d2d2-4067-bcb1-b08d1690b26e".to_string(),
+            message_version: "2.2.0".to_string(),
+        };
+        assert_eq!(
+            HelcimThreeDsResult::from(&authentication_data),
+            HelcimThreeDsResult {
+                eci: Some("07".to_string()),
+                liability_shift: false,
+            }
+        );
+    }
+
+    #[test]
+    fn should_truncate_long_statement_descriptor() {
+        let statement_descriptor = "HYPERSWITCH ONLINE STORE PURCHASE".to_string();
+
+        assert_eq!(
+            get_statement_descriptor(Some(&statement_descriptor), None).unwrap(),
+            Some("HYPERSWITCH ONLINE STO".to_string())
+        );
+        assert_eq!(
+            get_statement_descriptor(Some(&statement_descriptor), None)
+                .unwrap()
+                .map(|statement_descriptor| statement_descriptor.len()),
+            Some(HELCIM_STATEMENT_DESCRIPTOR_MAX_LENGTH)
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_statement_descriptor_from_connector_metadata() {
+        let connector_meta_data = Secret::new(serde_json::json!({
+            "statement_descriptor": "HELCIM STORE"
+        }));
+        let statement_descriptor = "REQUEST STORE".to_string();
+
+        assert_eq!(
+            get_statement_descriptor(None, Some(&connector_meta_data)).unwrap(),
+            Some("HELCIM STORE".to_string())
+        );
+        assert_eq!(
+            get_statement_descriptor(Some(&statement_descriptor), Some(&connector_meta_data))
+                .unwrap(),
+            Some("REQUEST STORE".to_string())
+        );
+        assert_eq!(get_statement_descriptor(None, None).unwrap(), None);
+    }
+
+    #[test]
+    fn should_send_terminal_id_from_connector_metadata() {
+        let connector_meta_data = Secret::new(serde_json::json!({
+            "statement_descriptor": "HELCIM STORE",
+            "terminal_id": "74213"
+        }));
+        let connector_metadata =
+            HelcimConnectorMetadataObject::try_from(Some(&connector_meta_data)).unwrap();
+        let refund_request = HelcimRefundTransactionRequest {
+            amount: HelcimAmount::Major(10.5),
+            original_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+            terminal_id: connector_metadata.terminal_id,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&refund_request).unwrap()["terminalId"],
+            "74213"
+        );
+    }
+
+    #[test]
+    fn should_omit_terminal_id_without_connector_metadata() {
+        let connector_metadata = HelcimConnectorMetadataObject::try_from(None).unwrap();
+        let capture_request = HelcimCaptureRequest {
+            pre_auth_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+            amount: HelcimAmount::Major(10.5),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+            terminal_id: connector_metadata.terminal_id,
+        };
+
+        assert!(serde_json::to_value(&capture_request)
+            .unwrap()
+            .get("terminalId")
+            .is_none());
+    }
+
+    #[test]
+    fn should_reject_malformed_connector_metadata() {
+        let connector_meta_data = Secret::new(serde_json::json!({
+            "terminal_id": ["74213"]
+        }));
+
+        assert!(matches!(
+            HelcimConnectorMetadataObject::try_from(Some(&connector_meta_data))
+                .unwrap_err()
+                .current_context(),
+            errors::ConnectorError::InvalidConnectorConfig { config: "metadata" }
+        ));
+    }
+
+    fn connector_customer_data(
+        name: Option<&str>,
+        email: Option<&str>,
+    ) -> types::ConnectorCustomerData {
+        types::ConnectorCustomerData {
+            description: None,
+            email: email.map(|email| Email::try_from(email.to_string()).unwrap()),
+            phone: None,
+            name: name.map(|name| Secret::new(name.to_string())),
+            preprocessing_id: None,
+            payment_method_data: domain::PaymentMethodData::MandatePayment,
+        }
+    }
+
+    #[test]
+    fn should_build_customer_request() {
+        let request = serde_json::to_value(
+            HelcimCustomerRequest::try_from(&connector_customer_data(
+                Some("John Doe"),
+                Some("john.doe@example.com"),
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            request,
+            serde_json::json!({
+                "contactName": "John Doe",
+                "email": "john.doe@example.com"
+            })
+        );
+
+        let request = serde_json::to_value(
+            HelcimCustomerRequest::try_from(&connector_customer_data(
+                None,
+                Some("john.doe@example.com"),
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(request["contactName"], "john.doe@example.com");
+        assert!(HelcimCustomerRequest::try_from(&connector_customer_data(None, None)).is_err());
+    }
+
+    #[test]
+    fn should_link_payment_to_created_customer() {
+        let response: HelcimCustomerResponse = serde_json::from_value(serde_json::json!({
+            "id": 1234567,
+            "customerCode": "CST1001",
+            "contactName": "John Doe"
+        }))
+        .unwrap();
+
+        let request = HelcimPaymentsRequest::Card(HelcimCardPaymentRequest {
+            amount: HelcimAmount::Major(10.5),
+            currency: enums::Currency::USD,
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            card_data: HelcimCardData::Token(HelcimCardToken {
+                card_token: Secret::new("27f2b2c8a3b7e5a1d2c9f0".to_string()),
+            }),
+            invoice: HelcimInvoice {
+                invoice_number: "INV1003".to_string(),
+                line_items: vec![],
+            },
+            billing_address: HelcimBillingAddress {
+                name: Secret::new("John Doe".to_string()),
+                street1: Secret::new("1 Main Street".to_string()),
+                postal_code: Secret::new("94122".to_string()),
+                street2: None,
+                city: None,
+                country: None,
+                email: None,
+            },
+            ecommerce: None,
+            statement_descriptor: None,
+            customer_code: Some(response.customer_code),
+            comments: None,
+            tip_amount: None,
+            convenience_fee: None,
+            three_d_s: None,
+            mcc: None,
+        });
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["customerCode"],
+            "CST1001"
+        );
+    }
+
+    #[test]
+    fn should_parse_transaction_list_response_for_date_range_sync() {
+        let response: HelcimTransactionListResponse = serde_json::from_value(serde_json::json!([
+            {
+                "transactionId": 20163741,
+                "status": "APPROVED",
+                "type": "purchase",
+                "invoiceNumber": "INV1001"
+            },
+            {
+                "transactionId": 20163742,
+                "status": "DECLINED",
+                "type": "preauth",
+                "invoiceNumber": null
+            },
+            {
+                "transactionId": 20163743,
+                "status": "APPROVED",
+                "type": "capture",
+                "invoiceNumber": "INV1003"
+            },
+            {
+                "transactionId": 20163744,
+                "status": "APPROVED",
+                "type": "refund",
+                "invoiceNumber": "INV1001"
+            }
+        ]))
+        .unwrap();
+
+        let transaction_status_list = response.get_transaction_status_list();
+
+        assert_eq!(transaction_status_list.len(), 3);
+        assert_eq!(
+            transaction_status_list.get("20163741"),
+            Some(&enums::AttemptStatus::Charged)
+        );
+        assert_eq!(
+            transaction_status_list.get("20163742"),
+            Some(&enums::AttemptStatus::AuthorizationFailed)
+        );
+        assert_eq!(
+            transaction_status_list.get("20163743"),
+            Some(&enums::AttemptStatus::Charged)
+        );
+        assert!(!transaction_status_list.contains_key("20163744"));
+    }
+
+    fn build_error_response(
+        status_code: u16,
+        headers: Option<http::HeaderMap>,
+        body: &str,
+    ) -> errors::CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        use crate::types::api::ConnectorCommon;
+
+        crate::connector::Helcim.build_error_response(
+            types::Response {
+                headers,
+                response: body.to_string().into(),
+                status_code,
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn should_classify_too_many_requests_as_retryable() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::RETRY_AFTER,
+            http::HeaderValue::from_static("30"),
+        );
+
+        let error = build_error_response(429, Some(headers), r#"{"errors":"Too Many Requests"}"#)
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::RateLimitReceived {
+                retry_after: Some(30)
+            }
+        );
+        assert!(error.current_context().is_retryable());
+    }
+
+    #[test]
+    fn should_classify_rate_limit_error_body_as_retryable() {
+        let error =
+            build_error_response(400, None, r#"{"errors":"Too many requests"}"#).unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::RateLimitReceived { retry_after: None }
+        );
+        assert!(error.current_context().is_retryable());
+    }
+
+    #[test]
+    fn should_classify_html_gateway_error_as_retryable() {
+        let response = build_error_response(
+            502,
+            None,
+            "<html><head><title>502 Bad Gateway</title></head><body>Bad Gateway</body></html>",
+        )
+        .unwrap();
+
+        assert_eq!(response.status_code, 502);
+        assert_eq!(response.code, HELCIM_GATEWAY_ERROR_CODE);
+        assert_eq!(response.message, HELCIM_GATEWAY_ERROR_MESSAGE);
+        assert_eq!(
+            response.reason,
+            Some("Helcim responded with status code 502: <html><head><title>502 Bad Gateway</title></head><body>Bad Gateway</body></html>".to_string())
+        );
+
+        let body = "x".repeat(1000);
+        let response = build_error_response(503, None, &body).unwrap();
+        assert_eq!(
+            response.reason.map(|reason| reason.len()),
+            Some(
+                "Helcim responded with status code 503: ".len() + HELCIM_ERROR_BODY_PREVIEW_LENGTH
+            )
+        );
+    }
+
+    #[test]
+    fn should_keep_status_code_of_empty_error_body() {
+        let response = build_error_response(504, None, "").unwrap();
+
+        assert_eq!(response.status_code, 504);
+        assert_eq!(response.code, HELCIM_GATEWAY_ERROR_CODE);
+        assert_eq!(
+            response.reason,
+            Some("Helcim responded with status code 504 and an empty body".to_string())
+        );
+    }
+
+    #[test]
+    fn should_keep_truncated_json_error_body_as_text() {
+        let response = build_error_response(400, None, r#"{"errors":"Invalid card nu"#).unwrap();
+
+        assert_eq!(response.status_code, 400);
+        assert_eq!(response.code, crate::consts::NO_ERROR_CODE);
+        assert_eq!(
+            response.reason,
+            Some(
+                r#"Helcim responded with status code 400: {"errors":"Invalid card nu"#.to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_deserialize_minimal_error_body() {
+        let body = r#"{"errors":"Invalid card number"}"#;
+        let response: HelcimErrorResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(
+            response,
+            HelcimErrorResponse::Payment(HelcimPaymentsErrorResponse {
+                errors: HelcimErrorTypes::StringType("Invalid card number".to_string()),
+            })
+        );
+        assert_eq!(serde_json::to_string(&response).unwrap(), body);
+
+        let response: HelcimErrorResponse = serde_json::from_str(r#""Unauthorized""#).unwrap();
+        assert_eq!(
+            response,
+            HelcimErrorResponse::General("Unauthorized".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<HelcimErrorResponse>(&serde_json::to_string(&response).unwrap())
+                .unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn should_deserialize_error_body_with_field_errors() {
+        let body = serde_json::json!({
+            "errors": {
+                "cardNumber": "Invalid card number",
+                "cardExpiry": "Card has expired"
+            },
+            "transactionId": null
+        });
+        let response: HelcimErrorResponse = serde_json::from_value(body.clone()).unwrap();
+
+        assert_eq!(
+            response,
+            HelcimErrorResponse::Payment(HelcimPaymentsErrorResponse {
+                errors: HelcimErrorTypes::JsonType(body["errors"].clone()),
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<HelcimErrorResponse>(serde_json::to_value(&response).unwrap())
+                .unwrap(),
+            response
+        );
+
+        // The field errors are kept as the reason of the error, along with the status code
+        let response = build_error_response(422, None, &body.to_string()).unwrap();
+        assert_eq!(response.status_code, 422);
+        assert_eq!(response.code, crate::consts::NO_ERROR_CODE);
+        assert_eq!(response.reason, Some(body["errors"].to_string()));
+        assert_eq!(Some(response.message), response.reason);
+    }
+
+    #[test]
+    fn should_keep_status_code_of_error_body_of_unknown_shape() {
+        let body = r#"{"message":"Unauthenticated."}"#;
+
+        assert!(serde_json::from_str::<HelcimErrorResponse>(body).is_err());
+
+        let response = build_error_response(401, None, body).unwrap();
+        assert_eq!(response.status_code, 401);
+        assert_eq!(
+            response.reason,
+            Some(format!("Helcim responded with status code 401: {body}"))
+        );
+    }
+
+    #[test]
+    fn should_not_classify_declines_as_retryable() {
+        let response = build_error_response(400, None, r#"{"errors":"Card declined"}"#).unwrap();
+
+        assert_eq!(response.message, "Card declined");
+    }
+
+    #[test]
+    fn should_map_expired_card_token_to_retryable_error_code() {
+        let response = build_error_response(
+            400,
+            None,
+            r#"{"errors":{"cardToken":"Card Token has expired"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.code, HELCIM_CARD_TOKEN_EXPIRED_CODE);
+        assert_eq!(response.message, HELCIM_CARD_TOKEN_EXPIRED_MESSAGE);
+        assert_eq!(
+            response.reason,
+            Some(r#"{"cardToken":"Card Token has expired"}"#.to_string())
+        );
+
+        // Only the error of the card token is matched, not messages which mention an expiry
+        for body in [
+            r#"{"errors":"Card declined"}"#,
+            r#"{"errors":"Card Token has expired"}"#,
+            r#"{"errors":{"cardExpiry":"Card has expired"}}"#,
+        ] {
+            let response = build_error_response(400, None, body).unwrap();
+
+            assert_ne!(response.code, HELCIM_CARD_TOKEN_EXPIRED_CODE);
+        }
+    }
+
+    #[test]
+    fn should_classify_decline_categories() {
+        let get_decline_category = |error: &str| {
+            HelcimErrorResponse::Payment(HelcimPaymentsErrorResponse {
+                errors: HelcimErrorTypes::StringType(error.to_string()),
+            })
+            .get_decline_category()
+        };
+
+        assert_eq!(
+            get_decline_category("Transaction Declined: Issuer or Switch Inoperative"),
+            Some(HelcimDeclineCategory::IssuerUnavailable)
+        );
+        assert_eq!(
+            get_decline_category("Transaction Declined: DO NOT HONOR"),
+            Some(HelcimDeclineCategory::DoNotHonor)
+        );
+        assert_eq!(
+            get_decline_category("Transaction Declined: Insufficient Funds"),
+            Some(HelcimDeclineCategory::InsufficientFunds)
+        );
+        assert_eq!(
+            get_decline_category("Card declined"),
+            Some(HelcimDeclineCategory::NetworkDecline)
+        );
+        assert_eq!(get_decline_category("Invalid card number"), None);
+    }
+
+    #[test]
+    fn should_surface_decline_category_as_error_code() {
+        let response = build_error_response(
+            400,
+            None,
+            r#"{"errors":"Transaction Declined: Issuer Unavailable"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.code, "ISSUER_UNAVAILABLE");
+        assert_eq!(response.message, "Transaction Declined: Issuer Unavailable");
+
+        let response = build_error_response(
+            400,
+            None,
+            r#"{"errors":"Transaction Declined: DO NOT HONOR"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.code, "DO_NOT_HONOR");
+
+        let response =
+            build_error_response(400, None, r#"{"errors":"Invalid card number"}"#).unwrap();
+
+        assert_eq!(response.code, crate::consts::NO_ERROR_CODE);
+    }
+
+    #[test]
+    fn should_zero_pad_single_digit_card_expiry_month() {
+        let card_expiry =
+            get_helcim_card_expiry("3", "2025", HELCIM_CARD_EXPIRY_DELIMITER).unwrap();
+        assert_eq!(card_expiry.peek(), "0325");
+
+        let card_expiry = get_helcim_card_expiry("03", "25", HELCIM_CARD_EXPIRY_DELIMITER).unwrap();
+        assert_eq!(card_expiry.peek(), "0325");
+    }
+
+    #[test]
+    fn should_format_december_card_expiry() {
+        let card_expiry =
+            get_helcim_card_expiry("12", "2030", HELCIM_CARD_EXPIRY_DELIMITER).unwrap();
+        assert_eq!(card_expiry.peek(), "1230");
+
+        let card_expiry = get_helcim_card_expiry("12", "2030", "/").unwrap();
+        assert_eq!(card_expiry.peek(), "12/30");
+    }
+
+    #[test]
+    fn should_reject_invalid_card_expiry_month() {
+        assert_eq!(
+            get_helcim_card_expiry("13", "2030", HELCIM_CARD_EXPIRY_DELIMITER).unwrap_err(),
+            errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_exp_month"
+            }
+        );
+    }
+
+    #[test]
+    fn should_build_billing_address_from_full_address() {
+        let address = api::AddressDetails {
+            first_name: Some(Secret::new("John".to_string())),
+            last_name: Some(Secret::new("Doe".to_string())),
+            line1: Some(Secret::new("1 Main Street".to_string())),
+            line2: Some(Secret::new("Suite 200".to_string())),
+            city: Some("Calgary".to_string()),
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+
+        let billing_address = serde_json::to_value(
+            HelcimBillingAddress::try_from((
+                Some(&address),
+                Some(Email::try_from("john.doe@example.com".to_string()).unwrap()),
+                None,
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            billing_address,
+            serde_json::json!({
+                "name": "John Doe",
+                "street1": "1 Main Street",
+                "postalCode": "T2P 1J9",
+                "street2": "Suite 200",
+                "city": "Calgary",
+                "email": "john.doe@example.com"
+            })
+        );
+    }
+
+    #[test]
+    fn should_build_billing_address_from_zip_only() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+
+        let billing_address = serde_json::to_value(
+            HelcimBillingAddress::try_from((Some(&address), None, None)).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            billing_address,
+            serde_json::json!({
+                "name": "Not Provided",
+                "street1": "Not Provided",
+                "postalCode": "T2P 1J9"
+            })
+        );
+    }
+
+    fn get_billing_name(first_name: Option<&str>, last_name: Option<&str>) -> Option<String> {
+        HelcimBillingName::try_from_names(
+            first_name
+                .map(|name| Secret::new(name.to_string()))
+                .as_ref(),
+            last_name.map(|name| Secret::new(name.to_string())).as_ref(),
+        )
+        .unwrap()
+        .map(|billing_name| billing_name.get_name())
+    }
+
+    #[test]
+    fn should_split_multi_word_billing_names() {
+        assert_eq!(
+            HelcimBillingName::try_from_names(
+                Some(&Secret::new("John Michael Doe".to_string())),
+                None
+            ),
+            Ok(Some(HelcimBillingName {
+                first_name: "John".to_string(),
+                middle_names: vec!["Michael".to_string()],
+                last_name: Some("Doe".to_string()),
+            }))
+        );
+        assert_eq!(
+            HelcimBillingName::try_from_names(
+                Some(&Secret::new(" Mary  Ann ".to_string())),
+                Some(&Secret::new("van der Berg".to_string()))
+            ),
+            Ok(Some(HelcimBillingName {
+                first_name: "Mary".to_string(),
+                middle_names: vec!["Ann".to_string()],
+                last_name: Some("van der Berg".to_string()),
+            }))
+        );
+        assert_eq!(
+            get_billing_name(Some("John  Michael"), Some("Doe")),
+            Some("John Michael Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn should_send_single_word_billing_names_as_they_are() {
+        assert_eq!(
+            get_billing_name(Some("Madonna"), None),
+            Some("Madonna".to_string())
+        );
+        assert_eq!(
+            get_billing_name(None, Some("Madonna")),
+            Some("Madonna".to_string())
+        );
+        assert_eq!(
+            get_billing_name(Some("- Madonna ."), Some("  ")),
+            Some("Madonna".to_string())
+        );
+    }
+
+    #[test]
+    fn should_shorten_over_length_billing_names() {
+        let long_first_name = "A".repeat(HELCIM_BILLING_NAME_MAX_LENGTH);
+        let long_last_name = "B".repeat(HELCIM_BILLING_NAME_MAX_LENGTH.saturating_add(10));
+
+        // Middle names are dropped before the first name is shortened
+        let middle_names = "Middle ".repeat(20);
+        assert_eq!(
+            get_billing_name(Some(&format!("John {middle_names}")), Some("Doe")),
+            Some("John Doe".to_string())
+        );
+
+        let billing_name = get_billing_name(Some(&long_first_name), Some("Doe")).unwrap();
+        assert_eq!(billing_name.chars().count(), HELCIM_BILLING_NAME_MAX_LENGTH);
+        assert!(billing_name.ends_with(" Doe"));
+
+        let billing_name = get_billing_name(Some("John"), Some(&long_last_name)).unwrap();
+        assert_eq!(billing_name.chars().count(), HELCIM_BILLING_NAME_MAX_LENGTH);
+        assert!(billing_name.starts_with("John B"));
+
+        // Multi byte characters are not split
+        let billing_name = get_billing_name(Some(&"É".repeat(150)), None).unwrap();
+        assert_eq!(billing_name, "É".repeat(HELCIM_BILLING_NAME_MAX_LENGTH));
+    }
+
+    #[test]
+    fn should_reject_billing_names_without_usable_words() {
+        assert_eq!(get_billing_name(None, None), None);
+        assert_eq!(get_billing_name(Some(" "), Some("")), None);
+        assert_eq!(
+            HelcimBillingName::try_from_names(
+                Some(&Secret::new("- .".to_string())),
+                Some(&Secret::new("?".to_string()))
+            ),
+            Err(errors::ConnectorError::InvalidDataFormat {
+                field_name: "billing.address.first_name"
+            })
+        );
+
+        let address = api::AddressDetails {
+            first_name: Some(Secret::new("...".to_string())),
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+        assert_eq!(
+            HelcimBillingAddress::try_from((Some(&address), None, None))
+                .unwrap_err()
+                .current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "billing.address.first_name"
+            }
+        );
+    }
+
+    #[test]
+    fn should_list_missing_fields_for_empty_billing_address() {
+        for address in [None, Some(&api::AddressDetails::default())] {
+            assert_eq!(
+                HelcimBillingAddress::try_from((address, None, None))
+                    .unwrap_err()
+                    .current_context(),
+                &errors::ConnectorError::MissingRequiredFields {
+                    field_names: vec!["billing.address.zip"]
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn should_send_country_of_billing_address_over_default_country() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("94122".to_string())),
+            country: Some(enums::CountryAlpha2::US),
+            ..Default::default()
+        };
+
+        let billing_address = serde_json::to_value(
+            HelcimBillingAddress::try_from((Some(&address), None, Some(enums::CountryAlpha2::CA)))
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(billing_address["country"], "USA");
+    }
+
+    #[test]
+    fn should_default_country_of_billing_address_from_connector_metadata() {
+        let connector_meta_data = Secret::new(serde_json::json!({ "default_country": "CA" }));
+        let default_country = HelcimConnectorMetadataObject::try_from(Some(&connector_meta_data))
+            .unwrap()
+            .default_country;
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+
+        let billing_address = serde_json::to_value(
+            HelcimBillingAddress::try_from((Some(&address), None, default_country)).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(default_country, Some(enums::CountryAlpha2::CA));
+        assert_eq!(billing_address["country"], "CAN");
+    }
+
+    #[test]
+    fn should_omit_country_without_billing_or_default_country() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+        let default_country = HelcimConnectorMetadataObject::try_from(None)
+            .unwrap()
+            .default_country;
+
+        let billing_address = serde_json::to_value(
+            HelcimBillingAddress::try_from((Some(&address), None, default_country)).unwrap(),
+        )
+        .unwrap();
+
+        assert!(billing_address.get("country").is_none());
+    }
+
+    #[test]
+    fn should_validate_and_normalize_us_postal_codes() {
+        let get_postal_code = |postal_code: &str| {
+            get_helcim_postal_code(postal_code, Some(enums::CountryAlpha2::US))
+                .map(|postal_code| postal_code.expose())
+        };
+
+        assert_eq!(get_postal_code("94122"), Ok("94122".to_string()));
+        assert_eq!(get_postal_code(" 94122 "), Ok("94122".to_string()));
+        assert_eq!(get_postal_code("94122-1234"), Ok("94122-1234".to_string()));
+        assert_eq!(get_postal_code("941221234"), Ok("94122-1234".to_string()));
+
+        for postal_code in ["9412", "941223", "94122-12", "9412A", "T2P 1J9", ""] {
+            assert_eq!(
+                get_postal_code(postal_code),
+                Err(errors::ConnectorError::InvalidDataFormat {
+                    field_name: "billing.address.zip"
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn should_validate_and_normalize_ca_postal_codes() {
+        let get_postal_code = |postal_code: &str| {
+            get_helcim_postal_code(postal_code, Some(enums::CountryAlpha2::CA))
+                .map(|postal_code| postal_code.expose())
+        };
+
+        assert_eq!(get_postal_code("T2P 1J9"), Ok("T2P 1J9".to_string()));
+        assert_eq!(get_postal_code("t2p1j9"), Ok("T2P 1J9".to_string()));
+        assert_eq!(get_postal_code("T2P-1J9"), Ok("T2P 1J9".to_string()));
+
+        for postal_code in ["T2P 1J", "T2P 1J99", "22P 1J9", "TTP 1J9", "94122", ""] {
+            assert_eq!(
+                get_postal_code(postal_code),
+                Err(errors::ConnectorError::InvalidDataFormat {
+                    field_name: "billing.address.zip"
+                })
+            );
+        }
+
+        // Postal codes of other countries are sent as they are
+        assert_eq!(
+            get_helcim_postal_code("SW1A 1AA", Some(enums::CountryAlpha2::GB))
+                .map(|postal_code| postal_code.expose()),
+            Ok("SW1A 1AA".to_string())
+        );
+    }
+
+    #[test]
+    fn should_reject_billing_address_with_malformed_postal_code() {
+        let address = api::AddressDetails {
+            zip: Some(Secret::new("9412".to_string())),
+            country: Some(enums::CountryAlpha2::US),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            HelcimBillingAddress::try_from((Some(&address), None, None))
+                .unwrap_err()
+                .current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "billing.address.zip"
+            }
+        );
+    }
+
+    #[test]
+    fn should_redact_card_number_and_cvv_in_debug_output() {
+        let card = HelcimCard {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            card_expiry: Secret::new("1230".to_string()),
+            card_c_v_v: Secret::new("737".to_string()),
+        };
+
+        for output in [
+            format!("{card:?}"),
+            format!("{card:#?}"),
+            format!("{:?}", HelcimCardData::from(card)),
+        ] {
+            assert!(!output.contains("4111111111111111"));
+            assert!(!output.contains("737"));
+            assert!(!output.contains("1230"));
+        }
+    }
+
+    #[test]
+    fn should_mask_card_fields_in_masked_request_body() {
+        let card = HelcimCard {
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            card_expiry: Secret::new("1230".to_string()),
+            card_c_v_v: Secret::new("737".to_string()),
+        };
+
+        let masked_card = masking::masked_serialize(&card).unwrap();
+
+        assert_eq!(masked_card["cardNumber"], "411111******1111");
+        assert!(!masked_card.to_string().contains("4111111111111111"));
+        assert!(!masked_card.to_string().contains("737"));
+        assert_eq!(
+            serde_json::to_value(&card).unwrap()["cardNumber"],
+            "4111111111111111"
+        );
+    }
+
+    #[test]
+    fn should_partially_mask_billing_address_in_masked_request_body() {
+        let address = api::AddressDetails {
+            first_name: Some(Secret::new("John".to_string())),
+            last_name: Some(Secret::new("Doe".to_string())),
+            line1: Some(Secret::new("1 Main Street".to_string())),
+            line2: Some(Secret::new("Suite 200".to_string())),
+            zip: Some(Secret::new("T2P 1J9".to_string())),
+            ..Default::default()
+        };
+
+        let billing_address = HelcimBillingAddress::try_from((Some(&address), None, None)).unwrap();
+        let masked_billing_address = masking::masked_serialize(&billing_address).unwrap();
+
+        assert_eq!(masked_billing_address["name"], "Jo******");
+        assert_eq!(masked_billing_address["street1"], "1 ***********");
+        assert_eq!(masked_billing_address["street2"], "Su*******");
+        assert!(!masked_billing_address.to_string().contains("T2P 1J9"));
+    }
+
+    #[test]
+    fn should_mask_ip_address_in_masked_capture_and_refund_bodies() {
+        let capture_request = HelcimCaptureRequest {
+            pre_auth_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+            amount: HelcimAmount::Major(10.5),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+            terminal_id: None,
+        };
+        let refund_request = HelcimRefundTransactionRequest {
+            amount: HelcimAmount::Major(10.5),
+            original_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+            terminal_id: None,
+        };
+
+        for masked_body in [
+            masking::masked_serialize(&capture_request).unwrap(),
+            masking::masked_serialize(&refund_request).unwrap(),
+        ] {
+            assert_eq!(masked_body["ipAddress"], "127.**.**.**");
+        }
+    }
+
+    #[test]
+    fn should_allow_sequential_partial_refunds_up_to_payment_amount() {
+        let connector_metadata = Some(serde_json::json!({ "preauth_transaction_id": 1234 }));
+
+        validate_refund_amount(1000, 0, 400).unwrap();
+        let connector_metadata = update_refunded_amount(connector_metadata, 400).unwrap();
+        let refunded_amount =
+            HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata)
+                .unwrap()
+                .refunded_amount;
+        assert_eq!(refunded_amount, 400);
+
+        validate_refund_amount(1000, refunded_amount, 600).unwrap();
+        let connector_metadata = update_refunded_amount(connector_metadata, 600).unwrap();
+        let refunded_amount =
+            HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata)
+                .unwrap()
+                .refunded_amount;
+        assert_eq!(refunded_amount, 1000);
+
+        let preauth_metadata: HelcimMetaData =
+            serde_json::from_value(connector_metadata.unwrap()).unwrap();
+        assert_eq!(
+            preauth_metadata.preauth_transaction_id,
+            HelcimTransactionId(1234)
+        );
+        assert!(validate_refund_amount(1000, refunded_amount, 1).is_err());
+    }
+
+    fn get_capture(capture_transaction_id: u64, amount: i64) -> HelcimCaptureMetaData {
+        HelcimCaptureMetaData {
+            capture_transaction_id: HelcimTransactionId(capture_transaction_id),
+            amount,
+            refunded_amount: 0,
+        }
+    }
+
+    #[test]
+    fn should_accumulate_captures_of_incrementally_captured_preauth() {
+        let connector_metadata = Some(serde_json::json!({
+            "preauth_transaction_id": 1234,
+            "authorized_amount": 1000,
+            "surcharge_amount": 0
+        }));
+
+        let connector_metadata = add_capture(connector_metadata, get_capture(1235, 300)).unwrap();
+        let connector_metadata = add_capture(connector_metadata, get_capture(1236, 500)).unwrap();
+        // The response of the second capture handled again does not add it twice
+        let connector_metadata = add_capture(connector_metadata, get_capture(1236, 500)).unwrap();
+
+        let metadata =
+            HelcimMetaData::try_from_connector_metadata(&connector_metadata, None).unwrap();
+        assert_eq!(metadata.preauth_transaction_id, HelcimTransactionId(1234));
+        assert_eq!(metadata.authorization.authorized_amount, Some(1000));
+        assert_eq!(
+            metadata.captures,
+            vec![get_capture(1235, 300), get_capture(1236, 500)]
+        );
+    }
+
+    #[test]
+    fn should_refund_against_the_capture_with_enough_left_to_refund() {
+        let connector_metadata = add_capture(None, get_capture(1235, 300)).unwrap();
+        let connector_metadata = add_capture(connector_metadata, get_capture(1236, 500)).unwrap();
+
+        let refund_metadata =
+            HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata).unwrap();
+        assert_eq!(
+            refund_metadata
+                .get_capture_to_refund(200)
+                .map(|capture| capture.capture_transaction_id),
+            Some(HelcimTransactionId(1235))
+        );
+        assert_eq!(
+            refund_metadata
+                .get_capture_to_refund(400)
+                .map(|capture| capture.capture_transaction_id),
+            Some(HelcimTransactionId(1236))
+        );
+        // No single capture can be refunded for more than it captured
+        assert_eq!(refund_metadata.get_capture_to_refund(600), None);
+
+        // Once the first capture is refunded in part, the rest of the refunds go to the other
+        let connector_metadata = update_refunded_amount(connector_metadata, 200).unwrap();
+        let refund_metadata =
+            HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata).unwrap();
+        assert_eq!(refund_metadata.refunded_amount, 200);
+        assert_eq!(
+            refund_metadata
+                .captures
+                .first()
+                .map(|capture| capture.refunded_amount),
+            Some(200)
+        );
+        assert_eq!(
+            refund_metadata
+                .get_capture_to_refund(150)
+                .map(|capture| capture.capture_transaction_id),
+            Some(HelcimTransactionId(1236))
+        );
+
+        // A capture refunded in full before settlement is reversed on its own
+        assert_eq!(
+            HelcimRefundType::get_refund_type(&refund_metadata, 800, 500),
+            HelcimRefundType::Void
+        );
+        assert_eq!(
+            HelcimRefundType::get_refund_type(&refund_metadata, 800, 100),
+            HelcimRefundType::Refund
+        );
+    }
+
+    #[test]
+    fn should_reject_partial_refund_exceeding_remaining_amount() {
+        let connector_metadata = update_refunded_amount(None, 400).unwrap();
+        let refunded_amount =
+            HelcimRefundMetaData::try_from_connector_metadata(&connector_metadata)
+                .unwrap()
+                .refunded_amount;
+
+        let error = validate_refund_amount(1000, refunded_amount, 700).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::RequestEncodingFailedWithReason(reason)
+                if reason.contains("remaining refundable amount 600")
+        ));
+    }
+
+    #[test]
+    fn should_refund_settled_payment() {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(&Some(
+            serde_json::json!({ "batch_id": 118, "date_settled": "2024-04-26" }),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            HelcimRefundType::get_refund_type(&refund_metadata, 1000, 1000),
+            HelcimRefundType::Refund
+        );
+    }
+
+    #[test]
+    fn should_void_full_refund_of_unsettled_payment() {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(&Some(
+            serde_json::json!({ "preauth_transaction_id": 1234 }),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            HelcimRefundType::get_refund_type(&refund_metadata, 1000, 1000),
+            HelcimRefundType::Void
+        );
+        assert_eq!(
+            HelcimRefundType::get_refund_type(&refund_metadata, 1000, 400),
+            HelcimRefundType::Refund
+        );
+
+        let void_request = HelcimRefundRequest::Void(HelcimVoidRequest {
+            card_transaction_id: HelcimTransactionId(20163745),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+        });
+        assert_eq!(
+            serde_json::to_value(&void_request).unwrap(),
+            serde_json::json!({
+                "cardTransactionId": 20163745,
+                "ipAddress": "127.0.0.1"
+            })
+        );
+    }
+
+    #[test]
+    fn should_keep_card_refunds_on_the_card_path() {
+        let refund_metadata =
+            HelcimRefundMetaData::try_from_connector_metadata(&Some(serde_json::json!({
+                "preauth_transaction_id": 1234,
+                "captures": [{ "capture_transaction_id": 1235, "amount": 1000 }]
+            })))
+            .unwrap();
+
+        assert_eq!(
+            HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 1000).unwrap(),
+            HelcimRefundType::Void
+        );
+        assert_eq!(
+            HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 400).unwrap(),
+            HelcimRefundType::Refund
+        );
+    }
+
+    #[test]
+    fn should_reject_refund_of_uncaptured_authorization() {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(&Some(
+            serde_json::json!({ "preauth_transaction_id": 1234, "authorized_amount": 1000 }),
+        ))
+        .unwrap();
+        assert!(refund_metadata.is_uncaptured_authorization());
+
+        let err =
+            HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 1000).unwrap_err();
+        assert!(matches!(
+            err.current_context(),
+            errors::ConnectorError::NotSupported {
+                connector: "Helcim",
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("void the payment instead"));
+    }
+
+    #[test]
+    fn should_refund_captured_authorization_and_purchase() {
+        // Captured authorizations, whether the capture is kept in the list or as a single
+        // transaction, and purchases captured at authorization are refundable
+        for connector_metadata in [
+            serde_json::json!({
+                "preauth_transaction_id": 1234,
+                "captures": [{ "capture_transaction_id": 1235, "amount": 1000 }]
+            }),
+            serde_json::json!({ "preauth_transaction_id": 1234, "capture_transaction_id": 1235 }),
+            serde_json::json!({ "batch_id": 118 }),
+        ] {
+            let refund_metadata =
+                HelcimRefundMetaData::try_from_connector_metadata(&Some(connector_metadata))
+                    .unwrap();
+            assert!(!refund_metadata.is_uncaptured_authorization());
+            assert_eq!(
+                HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 400).unwrap(),
+                HelcimRefundType::Refund
+            );
+        }
+    }
+
+    #[test]
+    fn should_refund_settled_bank_payment() {
+        let refund_metadata =
+            HelcimRefundMetaData::try_from_connector_metadata(&Some(serde_json::json!({
+                "bank_payment": {},
+                "date_settled": "2024-04-26"
+            })))
+            .unwrap();
+
+        // Settled bank payments are refunded in part or in full
+        for refund_amount in [400, 1000] {
+            assert_eq!(
+                HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, refund_amount)
+                    .unwrap(),
+                HelcimRefundType::BankRefund
+            );
+        }
+
+        let refund_request = HelcimRefundRequest::BankRefund(HelcimBankRefundRequest {
+            amount: HelcimAmount::Major(4.0),
+            bank_transaction_id: HelcimTransactionId(20163745),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+        });
+        assert_eq!(
+            serde_json::to_value(&refund_request).unwrap(),
+            serde_json::json!({
+                "amount": 4.0,
+                "bankTransactionId": 20163745,
+                "ipAddress": "127.0.0.1"
+            })
+        );
+    }
+
+    #[test]
+    fn should_void_unsettled_bank_payment_refunded_in_full() {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(&Some(
+            serde_json::json!({ "bank_payment": {} }),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 1000).unwrap(),
+            HelcimRefundType::BankVoid
+        );
+
+        let void_request = HelcimRefundRequest::BankVoid(HelcimBankVoidRequest {
+            bank_transaction_id: HelcimTransactionId(20163745),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+        });
+        assert_eq!(
+            serde_json::to_value(&void_request).unwrap(),
+            serde_json::json!({
+                "bankTransactionId": 20163745,
+                "ipAddress": "127.0.0.1"
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_support_partial_refunds_of_unsettled_bank_payments() {
+        let refund_metadata = HelcimRefundMetaData::try_from_connector_metadata(&Some(
+            serde_json::json!({ "bank_payment": {} }),
+        ))
+        .unwrap();
+
+        let error =
+            HelcimRefundType::try_from_refund_metadata(&refund_metadata, 1000, 400).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::NotSupported { message, connector: "Helcim" }
+                if message == "Partial refunds of bank payments before settlement"
+        ));
+    }
+
+    #[test]
+    fn should_serialize_transaction_id_as_plain_number() {
+        let capture_request = HelcimCaptureRequest {
+            pre_auth_transaction_id: "20163745".parse::<HelcimTransactionId>().unwrap(),
+            amount: HelcimAmount::Major(10.5),
+            ip_address: Secret::new("127.0.0.1".to_string()),
+            ecommerce: None,
+            terminal_id: None,
+        };
+
+        let capture_request = serde_json::to_value(capture_request).unwrap();
+
+        assert_eq!(capture_request["preAuthTransactionId"], 20163745);
+        let metadata: HelcimMetaData =
+            serde_json::from_value(serde_json::json!({ "preauth_transaction_id": 20163745 }))
+                .unwrap();
+        assert_eq!(metadata.preauth_transaction_id.to_string(), "20163745");
+    }
+
+    #[test]
+    fn should_read_preauth_transaction_id_from_valid_metadata() {
+        let metadata = HelcimMetaData::try_from_connector_metadata(
+            &Some(serde_json::json!({ "preauth_transaction_id": 20163745, "refunded_amount": 0 })),
+            Some("20163746"),
+        )
+        .unwrap();
+
+        assert_eq!(metadata.preauth_transaction_id.to_string(), "20163745");
+    }
+
+    #[test]
+    fn should_fall_back_to_connector_transaction_id_for_missing_metadata() {
+        for connector_metadata in [
+            None,
+            Some(serde_json::Value::Null),
+            Some(serde_json::json!({ "refunded_amount": 500 })),
+        ] {
+            let metadata =
+                HelcimMetaData::try_from_connector_metadata(&connector_metadata, Some("20163746"))
+                    .unwrap();
+            assert_eq!(metadata.preauth_transaction_id.to_string(), "20163746");
+        }
+
+        let err = HelcimMetaData::try_from_connector_metadata(&None, None).unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_metadata.preauth_transaction_id"
+            }
+        );
+    }
+
+    #[test]
+    fn should_name_expected_field_for_malformed_metadata() {
+        for connector_metadata in [
+            serde_json::json!({ "preauth_transaction_id": "txn_20163745" }),
+            serde_json::json!({ "preauth_transaction_id": null }),
+        ] {
+            let err = HelcimMetaData::try_from_connector_metadata(
+                &Some(connector_metadata),
+                Some("20163746"),
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.current_context(),
+                &errors::ConnectorError::InvalidDataFormat {
+                    field_name: "connector_metadata.preauth_transaction_id"
+                }
+            );
+        }
+
+        let err = HelcimMetaData::try_from_connector_metadata(
+            &Some(serde_json::json!("20163745")),
+            Some("20163746"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "connector_metadata"
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_non_numeric_transaction_id() {
+        assert!("txn_20163745".parse::<HelcimTransactionId>().is_err());
+        assert!("".parse::<HelcimTransactionId>().is_err());
+    }
 }
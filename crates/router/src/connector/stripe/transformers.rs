@@ -2366,6 +2366,8 @@ impl From<AdditionalPaymentMethodDetails> for types::AdditionalPaymentMethodConn
         Self::Card {
             authentication_data: item.authentication_details,
             payment_checks: item.payment_checks,
+            card_network: None,
+            card_type: None,
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{
     enums::{CanadaStatesAbbreviation, UsStatesAbbreviation},
@@ -16,6 +16,10 @@ use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, Secret};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 use serde::Serializer;
 use time::PrimitiveDateTime;
 
@@ -25,7 +29,7 @@ use crate::{
     consts,
     core::{
         errors::{self, ApiErrorResponse, CustomResult},
-        payments::{types::AuthenticationData, PaymentData, RecurringMandatePaymentData},
+        payments::{helpers, types::AuthenticationData, PaymentData, RecurringMandatePaymentData},
     },
     pii::PeekInterface,
     types::{
@@ -975,6 +979,21 @@ pub enum CardIssuer {
     CarteBlanche,
 }
 
+impl CardIssuer {
+    fn get_card_cvc_length(&self) -> usize {
+        match self {
+            Self::AmericanExpress => 4,
+            Self::Master
+            | Self::Maestro
+            | Self::Visa
+            | Self::Discover
+            | Self::DinersClub
+            | Self::JCB
+            | Self::CarteBlanche => 3,
+        }
+    }
+}
+
 pub trait CardData {
     fn get_card_expiry_year_2_digit(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_card_issuer(&self) -> Result<CardIssuer, Error>;
@@ -988,6 +1007,8 @@ pub trait CardData {
     fn get_expiry_date_as_yymm(&self) -> Result<Secret<String>, errors::ConnectorError>;
     fn get_expiry_month_as_i8(&self) -> Result<Secret<i8>, Error>;
     fn get_expiry_year_as_i32(&self) -> Result<Secret<i32>, Error>;
+    fn validate_card_details(&self) -> Result<(), Error>;
+    fn expires_within(&self, months: u8) -> Result<bool, Error>;
 }
 
 impl CardData for domain::Card {
@@ -1061,6 +1082,83 @@ impl CardData for domain::Card {
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
             .map(Secret::new)
     }
+    /// Validates the CVC length, the expiry and the card number against the card network, so that
+    /// invalid cards are rejected before reaching the connector. All failures are reported at
+    /// once, the first one being the context of the report. Cards of networks which can not be
+    /// classified are left for the connector to validate, as is the CVC of saved cards, which is
+    /// not stored.
+    fn validate_card_details(&self) -> Result<(), Error> {
+        let Ok(card_issuer) = self.get_card_issuer() else {
+            return Ok(());
+        };
+        let mut validation_errors = Vec::new();
+        let card_cvc = self.card_cvc.peek();
+        if !card_cvc.is_empty()
+            && (card_cvc.len() != card_issuer.get_card_cvc_length()
+                || !card_cvc.chars().all(|c| c.is_ascii_digit()))
+        {
+            validation_errors.push(errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_cvc",
+            });
+        }
+        if helpers::validate_card_expiry(&self.card_exp_month, &self.card_exp_year).is_err() {
+            validation_errors.push(errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_expiry",
+            });
+        }
+        if cards::CardNumber::from_str(self.card_number.peek()).is_err() {
+            validation_errors.push(errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_number",
+            });
+        }
+        let mut validation_errors = validation_errors.into_iter().map(|error| report!(error));
+        match validation_errors.next() {
+            None => Ok(()),
+            Some(mut report) => {
+                validation_errors.for_each(|error| report.extend_one(error));
+                Err(report)
+            }
+        }
+    }
+    fn expires_within(&self, months: u8) -> Result<bool, Error> {
+        card_expires_within(
+            self.card_exp_month.peek(),
+            self.get_expiry_year_4_digit().peek(),
+            months,
+            date_time::now(),
+        )
+    }
+}
+
+/// Whether a card expires before the end of the given number of months from the current month,
+/// a card being valid until the end of its expiry month. `0` months only matches expired cards.
+fn card_expires_within(
+    expiry_month: &str,
+    expiry_year: &str,
+    months: u8,
+    current_date_time: PrimitiveDateTime,
+) -> Result<bool, Error> {
+    let expiry_month = expiry_month
+        .trim()
+        .parse::<u8>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or(errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_exp_month",
+        })?;
+    let expiry_year = expiry_year.trim().parse::<i32>().change_context(
+        errors::ConnectorError::InvalidDataFormat {
+            field_name: "card_exp_year",
+        },
+    )?;
+    let get_month_index =
+        |year: i32, month: u8| year.saturating_mul(12).saturating_add(i32::from(month));
+    Ok(get_month_index(expiry_year, expiry_month)
+        < get_month_index(
+            current_date_time.year(),
+            u8::from(current_date_time.month()),
+        )
+        .saturating_add(i32::from(months)))
 }
 
 #[track_caller]
@@ -1548,13 +1646,36 @@ pub fn to_currency_base_unit_with_zero_decimal_check(
         .change_context(errors::ConnectorError::RequestEncodingFailed)
 }
 
+/// Number of digits after the decimal point in the base unit of the currency, ie. 0 for zero
+/// decimal currencies like JPY, 3 for three decimal currencies like KWD and 2 for the rest
+pub fn get_currency_exponent(currency: diesel_models::enums::Currency) -> u32 {
+    u32::from(currency.number_of_digits_after_decimal_point())
+}
+
+/// Converts the amount in the lowest denomination of the currency to its base unit. Negative
+/// amounts, amounts beyond the supported range and amounts which do not scale back to the same
+/// number of minor units with the exponent of the currency are rejected.
 pub fn to_currency_base_unit_asf64(
     amount: i64,
     currency: diesel_models::enums::Currency,
 ) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
-    currency
-        .to_currency_base_unit_asf64(amount)
-        .change_context(errors::ConnectorError::ParsingFailed)
+    let conversion_error = || {
+        errors::ConnectorError::RequestEncodingFailedWithReason(format!(
+            "Amount {amount} can not be converted to the base unit of {currency}"
+        ))
+    };
+    let exponent = get_currency_exponent(currency);
+    let base_amount = u32::try_from(amount)
+        .map(|amount| Decimal::new(i64::from(amount), exponent))
+        .change_context_lazy(conversion_error)?;
+
+    base_amount
+        .to_f64()
+        .filter(|float_amount| {
+            Decimal::from_f64(*float_amount)
+                .is_some_and(|converted| converted.round_dp(exponent) == base_amount)
+        })
+        .ok_or_else(|| report!(conversion_error()))
 }
 
 pub fn str_to_f32<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
@@ -2123,3 +2244,188 @@ mod error_code_error_message_tests {
         assert_eq!(error_code_error_message_none, None);
     }
 }
+
+#[cfg(test)]
+mod currency_base_unit_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn should_look_up_currency_exponents() {
+        assert_eq!(get_currency_exponent(enums::Currency::JPY), 0);
+        assert_eq!(get_currency_exponent(enums::Currency::USD), 2);
+        assert_eq!(get_currency_exponent(enums::Currency::KWD), 3);
+    }
+
+    #[test]
+    fn should_convert_zero_decimal_currency_amounts() {
+        let currency_unit = api::CurrencyUnit::Base;
+
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 1000, enums::Currency::JPY).unwrap(),
+            1000.0
+        );
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 1, enums::Currency::KRW).unwrap(),
+            1.0
+        );
+    }
+
+    #[test]
+    fn should_convert_two_decimal_currency_amounts() {
+        let currency_unit = api::CurrencyUnit::Base;
+
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 1050, enums::Currency::USD).unwrap(),
+            10.5
+        );
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 629, enums::Currency::CAD).unwrap(),
+            6.29
+        );
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 1, enums::Currency::EUR).unwrap(),
+            0.01
+        );
+    }
+
+    #[test]
+    fn should_convert_three_decimal_currency_amounts() {
+        let currency_unit = api::CurrencyUnit::Base;
+
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 1234, enums::Currency::KWD).unwrap(),
+            1.234
+        );
+        assert_eq!(
+            get_amount_as_f64(&currency_unit, 5, enums::Currency::BHD).unwrap(),
+            0.005
+        );
+    }
+
+    #[test]
+    fn should_reject_amounts_which_can_not_be_converted() {
+        for (amount, currency) in [
+            (-100, enums::Currency::USD),
+            (-1, enums::Currency::JPY),
+            (i64::from(u32::MAX) + 1, enums::Currency::KWD),
+        ] {
+            let result = get_amount_as_f64(&api::CurrencyUnit::Base, amount, currency);
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ConnectorError::RequestEncodingFailedWithReason(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn should_pass_through_minor_unit_amounts() {
+        assert_eq!(
+            get_amount_as_f64(&api::CurrencyUnit::Minor, 1234, enums::Currency::KWD).unwrap(),
+            1234.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod card_data_tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_card(card_number: &str, card_cvc: &str, card_exp_year: &str) -> domain::Card {
+        domain::Card {
+            card_number: cards::CardNumber::from_str(card_number).unwrap(),
+            card_exp_month: Secret::new("12".to_string()),
+            card_exp_year: Secret::new(card_exp_year.to_string()),
+            card_cvc: Secret::new(card_cvc.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn get_invalid_fields(error: &Error) -> Vec<&'static str> {
+        error
+            .frames()
+            .filter_map(
+                |frame| match frame.downcast_ref::<errors::ConnectorError>() {
+                    Some(errors::ConnectorError::InvalidDataFormat { field_name }) => {
+                        Some(*field_name)
+                    }
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_valid_visa_card() {
+        let card = get_card("4111111111111111", "123", "2099");
+
+        assert!(card.validate_card_details().is_ok());
+    }
+
+    #[test]
+    fn should_reject_amex_card_with_3_digit_cvc() {
+        let card = get_card("378282246310005", "123", "2099");
+        let error = card.validate_card_details().unwrap_err();
+
+        assert_eq!(get_invalid_fields(&error), vec!["card_cvc"]);
+        assert!(get_card("378282246310005", "1234", "2099")
+            .validate_card_details()
+            .is_ok());
+    }
+
+    #[test]
+    fn should_reject_expired_card() {
+        let card = get_card("4111111111111111", "123", "20");
+        let error = card.validate_card_details().unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::InvalidDataFormat {
+                field_name: "card_expiry"
+            }
+        );
+    }
+
+    #[test]
+    fn should_accept_saved_card_without_cvc() {
+        let card = get_card("4111111111111111", "", "2099");
+
+        assert!(card.validate_card_details().is_ok());
+    }
+
+    #[test]
+    fn should_report_all_card_validation_failures_at_once() {
+        let card = domain::Card {
+            card_exp_month: Secret::new("13".to_string()),
+            ..get_card("378282246310005", "12", "2099")
+        };
+        let error = card.validate_card_details().unwrap_err();
+
+        assert_eq!(get_invalid_fields(&error), vec!["card_cvc", "card_expiry"]);
+    }
+
+    #[test]
+    fn should_detect_card_expiring_within_months() {
+        let current_date_time = time::macros::datetime!(2024-11-15 10:00);
+
+        // A card is valid until the end of its expiry month
+        assert!(!card_expires_within("11", "2024", 0, current_date_time).unwrap());
+        assert!(card_expires_within("11", "2024", 1, current_date_time).unwrap());
+        assert!(card_expires_within("01", "2025", 3, current_date_time).unwrap());
+        assert!(!card_expires_within("02", "2025", 3, current_date_time).unwrap());
+        assert!(card_expires_within("10", "2024", 0, current_date_time).unwrap());
+    }
+
+    #[test]
+    fn should_not_expire_far_future_card_within_months() {
+        let card = get_card("4111111111111111", "123", "2099");
+
+        assert!(!card.expires_within(12).unwrap());
+        assert!(get_card("4111111111111111", "123", "20")
+            .expires_within(0)
+            .unwrap());
+    }
+}
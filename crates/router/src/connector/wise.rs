@@ -223,8 +223,49 @@ impl
 {
 }
 
+#[cfg(not(feature = "payouts"))]
 impl api::Payouts for Wise {}
 #[cfg(feature = "payouts")]
+impl api::Payouts for Wise {
+    // The balances of the profile are read, which needs both the API key and the profile id
+    fn build_payout_credentials_verification_request(
+        &self,
+        connector_auth_type: &types::ConnectorAuthType,
+        _connector_meta_data: &Option<common_utils::pii::SecretSerdeValue>,
+        _test_mode: Option<bool>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&get_balances_url(connector_auth_type, connectors)?)
+            .attach_default_headers()
+            .headers(self.get_auth_header(connector_auth_type)?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    fn supports_payout_sync(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "payouts")]
+fn get_balances_url(
+    connector_auth_type: &types::ConnectorAuthType,
+    connectors: &settings::Connectors,
+) -> CustomResult<String, errors::ConnectorError> {
+    let auth = wise::WiseAuthType::try_from(connector_auth_type)
+        .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+    Ok(format!(
+        "{}v4/profiles/{}/balances?types=STANDARD",
+        connectors.wise.base_url,
+        auth.profile_id.peek()
+    ))
+}
+#[cfg(feature = "payouts")]
+impl api::PayoutBalance for Wise {}
+#[cfg(feature = "payouts")]
 impl api::PayoutCancel for Wise {}
 #[cfg(feature = "payouts")]
 impl api::PayoutCreate for Wise {}
@@ -236,6 +277,80 @@ impl api::PayoutQuote for Wise {}
 impl api::PayoutRecipient for Wise {}
 #[cfg(feature = "payouts")]
 impl api::PayoutFulfill for Wise {}
+#[cfg(feature = "payouts")]
+impl api::PayoutSync for Wise {}
+
+#[cfg(feature = "payouts")]
+impl
+    services::ConnectorIntegration<
+        api::PoBalance,
+        types::PayoutsData,
+        types::PayoutsBalanceResponseData,
+    > for Wise
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutsBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        get_balances_url(&req.connector_auth_type, connectors)
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutsBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutBalanceType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutBalanceType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsBalanceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsBalanceRouterData, errors::ConnectorError> {
+        let response: wise::WiseBalanceResponse = res
+            .response
+            .parse_struct("WiseBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
 
 #[cfg(feature = "payouts")]
 impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -728,6 +843,80 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
     }
 }
 
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Wise
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let transfer_id = req.request.connector_payout_id.to_owned().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "transfer_id",
+            },
+        )?;
+        Ok(format!(
+            "{}v1/transfers/{}",
+            connectors.wise.base_url, transfer_id
+        ))
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutSyncType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutSyncType::get_headers(self, req, connectors)?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoSync>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
+        let response: wise::WisePayoutSyncResponse = res
+            .response
+            .parse_struct("WisePayoutSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl api::Refund for Wise {}
 impl api::RefundExecute for Wise {}
 impl api::RefundSync for Wise {}
@@ -2,6 +2,8 @@
 use api_models::payouts::PayoutMethodData;
 #[cfg(feature = "payouts")]
 use common_utils::pii::Email;
+#[cfg(feature = "payouts")]
+use error_stack::ResultExt;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
@@ -243,6 +245,12 @@ pub struct WisePayoutResponse {
     customer_transaction_id: Option<String>,
 }
 
+/// Transfer fetched on sync, whose status is reported as is unlike on transfer creation
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct WisePayoutSyncResponse(WisePayoutResponse);
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -522,6 +530,108 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutResponse>>
     }
 }
 
+// Payouts transfer sync response
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        let WisePayoutSyncResponse(response) = item.response;
+
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(storage_enums::PayoutStatus::foreign_from(response.status)),
+                connector_payout_id: response.id.to_string(),
+                payout_eligible: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WiseBalanceResponse(Vec<WiseBalance>);
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseBalance {
+    id: i64,
+    currency: String,
+    amount: WiseBalanceAmount,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WiseBalanceAmount {
+    value: f64,
+    currency: String,
+}
+
+#[cfg(feature = "payouts")]
+impl WiseBalanceResponse {
+    /// Balance of the profile in the currency, in minor units. A profile without a balance
+    /// account in the currency has nothing to pay out from.
+    pub fn get_available_balance(&self, currency: storage_enums::Currency) -> Result<i64, Error> {
+        match self
+            .0
+            .iter()
+            .find(|balance| balance.currency == currency.to_string())
+        {
+            Some(balance) => {
+                // Scaling the decimal balance can leave float noise behind, so it is rounded off
+                let amount =
+                    utils::to_currency_lower_unit(balance.amount.value.to_string(), currency)?
+                        .parse::<f64>()
+                        .change_context(errors::ConnectorError::ParsingFailed)?;
+                format!("{amount:.0}")
+                    .parse::<i64>()
+                    .change_context(errors::ConnectorError::ParsingFailed)
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+// Payouts balance response transform
+#[cfg(feature = "payouts")]
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            WiseBalanceResponse,
+            types::PayoutsData,
+            types::PayoutsBalanceResponseData,
+        >,
+    > for types::RouterData<F, types::PayoutsData, types::PayoutsBalanceResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            WiseBalanceResponse,
+            types::PayoutsData,
+            types::PayoutsBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let available_balance = item
+            .response
+            .get_available_balance(item.data.request.source_currency)?;
+
+        Ok(Self {
+            response: Ok(types::PayoutsBalanceResponseData {
+                available_balance: Some(available_balance),
+            }),
+            ..item.data
+        })
+    }
+}
+
 // Payouts fulfill request transform
 #[cfg(feature = "payouts")]
 impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutFulfillRequest {
@@ -607,3 +717,55 @@ impl TryFrom<PayoutMethodData> for RecipientType {
         }
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_balance_response() -> WiseBalanceResponse {
+        serde_json::from_value(serde_json::json!([
+            {
+                "id": 200001,
+                "currency": "EUR",
+                "amount": { "value": 1234.56, "currency": "EUR" },
+                "type": "STANDARD"
+            },
+            {
+                "id": 200002,
+                "currency": "JPY",
+                "amount": { "value": 15000, "currency": "JPY" },
+                "type": "STANDARD"
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_balance_in_the_currency_is_converted_to_minor_units() {
+        let balance_response = get_balance_response();
+
+        assert_eq!(
+            balance_response
+                .get_available_balance(storage_enums::Currency::EUR)
+                .unwrap(),
+            123456
+        );
+        assert_eq!(
+            balance_response
+                .get_available_balance(storage_enums::Currency::JPY)
+                .unwrap(),
+            15000
+        );
+    }
+
+    #[test]
+    fn test_balance_without_account_in_the_currency_is_zero() {
+        assert_eq!(
+            get_balance_response()
+                .get_available_balance(storage_enums::Currency::USD)
+                .unwrap(),
+            0
+        );
+    }
+}
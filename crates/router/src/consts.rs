@@ -17,10 +17,21 @@ pub(crate) const ALPHABETS: [char; 62] = [
 pub const REQUEST_TIME_OUT: u64 = 30;
 pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time";
+pub const CONNECTION_ERROR_CODE: &str = "CONNECTION_ERROR";
+pub const CONNECTION_ERROR_MESSAGE: &str = "Connection to the connector failed before a response";
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
 
+/// Error code of payout attempts failed because no routed connector could process the payout
+pub const PAYOUT_ROUTING_FAILED_ERROR_CODE: &str = "ROUTING_FAILED";
+
+/// Error code of payout attempts held until the KYC of the recipient is verified
+pub const PAYOUT_RECIPIENT_KYC_PENDING_ERROR_CODE: &str = "RECIPIENT_KYC_PENDING";
+
+/// Number of payouts read from the database at a time when exporting payouts
+pub const PAYOUTS_EXPORT_PAGE_SIZE: u32 = 500;
+
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 
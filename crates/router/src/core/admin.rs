@@ -17,7 +17,10 @@ use masking::{PeekInterface, Secret};
 use pm_auth::connector::plaid::transformers::PlaidAuthType;
 use uuid::Uuid;
 
+#[cfg(all(feature = "olap", feature = "payouts"))]
+use crate::core::verify_connector;
 use crate::{
+    configs::settings,
     consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
@@ -27,7 +30,7 @@ use crate::{
     },
     db::StorageInterface,
     routes::{metrics, AppState},
-    services::{self, api as service_api},
+    services::{self, api as service_api, ConnectorValidation},
     types::{
         self, api,
         domain::{
@@ -216,6 +219,7 @@ pub async fn create_merchant_account(
             default_profile: None,
             recon_status: diesel_models::enums::ReconStatus::NotRequested,
             payment_link_config: None,
+            kyc_status: None,
         })
     }
     .await
@@ -439,6 +443,12 @@ pub async fn update_business_profile_cascade(
             payment_link_config: None,
             session_expiry: None,
             authentication_connector_details: None,
+            #[cfg(feature = "payouts")]
+            check_balance_before_fulfill: None,
+            #[cfg(feature = "payouts")]
+            payout_blocked_countries: None,
+            require_recipient_kyc: None,
+            default_payout_method: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -613,6 +623,45 @@ pub async fn merchant_account_update(
     ))
 }
 
+/// Updates the KYC status of the merchant. The KYC status is set by the admin of the platform
+/// once the merchant is verified, and cannot be updated by the merchant itself.
+pub async fn merchant_account_kyc_status_update(
+    state: AppState,
+    req: api::MerchantKycStatusUpdate,
+) -> RouterResponse<api::MerchantAccountResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let updated_merchant_account = db
+        .update_merchant(
+            merchant_account,
+            storage::MerchantAccountUpdate::KycStatusUpdate {
+                kyc_status: req.kyc_status,
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the KYC status of the merchant")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantAccountResponse::try_from(updated_merchant_account)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while generating response")?,
+    ))
+}
+
 pub async fn merchant_account_delete(
     state: AppState,
     merchant_id: String,
@@ -828,6 +877,14 @@ pub async fn create_payment_connector(
             req.connector_name, business_profile.profile_name
         ));
 
+    if let Some(payment_methods_enabled) = req.payment_methods_enabled.as_ref() {
+        validate_payment_methods_enabled_for_connector(
+            &state.conf.connectors,
+            &req.connector_name.to_string(),
+            payment_methods_enabled,
+        )?;
+    }
+
     let mut vec = Vec::new();
     let payment_methods_enabled = match req.payment_methods_enabled {
         Some(val) => {
@@ -877,6 +934,20 @@ pub async fn create_payment_connector(
         }
     })?;
 
+    #[cfg(all(feature = "olap", feature = "payouts"))]
+    if req.connector_type == api_enums::ConnectorType::PayoutProcessor
+        && req.verify_credentials.unwrap_or(false)
+    {
+        verify_connector::verify_payout_connector_credentials(
+            &state,
+            req.connector_name,
+            &auth,
+            &req.metadata,
+            req.test_mode,
+        )
+        .await?;
+    }
+
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
 
     // The purpose of this merchant account update is just to update the
@@ -1178,6 +1249,14 @@ pub async fn update_payment_connector(
             id: merchant_connector_id.to_string(),
         })?;
 
+    if let Some(payment_methods_enabled) = req.payment_methods_enabled.as_ref() {
+        validate_payment_methods_enabled_for_connector(
+            &state.conf.connectors,
+            &mca.connector_name,
+            payment_methods_enabled,
+        )?;
+    }
+
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -1225,6 +1304,20 @@ pub async fn update_payment_connector(
         }),
     })?;
 
+    #[cfg(all(feature = "olap", feature = "payouts"))]
+    if req.connector_type == api_enums::ConnectorType::PayoutProcessor
+        && req.verify_credentials.unwrap_or(false)
+    {
+        verify_connector::verify_payout_connector_credentials(
+            &state,
+            connector_enum,
+            &auth,
+            &metadata,
+            req.test_mode.or(mca.test_mode),
+        )
+        .await?;
+    }
+
     let (connector_status, disabled) =
         validate_status_and_disabled(req.status, req.disabled, auth, mca.status)?;
 
@@ -1676,6 +1769,36 @@ pub async fn update_business_profile(
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "authentication_connector_details",
             })?,
+        #[cfg(feature = "payouts")]
+        check_balance_before_fulfill: request.check_balance_before_fulfill,
+        #[cfg(not(feature = "payouts"))]
+        check_balance_before_fulfill: None,
+        #[cfg(feature = "payouts")]
+        payout_blocked_countries: request
+            .payout_blocked_countries
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payout_blocked_countries",
+            })?,
+        #[cfg(not(feature = "payouts"))]
+        payout_blocked_countries: None,
+        #[cfg(feature = "payouts")]
+        require_recipient_kyc: request.require_recipient_kyc,
+        #[cfg(not(feature = "payouts"))]
+        require_recipient_kyc: None,
+        #[cfg(feature = "payouts")]
+        default_payout_method: request
+            .default_payout_method
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "default_payout_method",
+            })?,
+        #[cfg(not(feature = "payouts"))]
+        default_payout_method: None,
     };
 
     let updated_business_profile = db
@@ -1724,6 +1847,26 @@ pub async fn extended_card_info_toggle(
     Ok(service_api::ApplicationResponse::Json(ext_card_info_choice))
 }
 
+/// Rejects payment methods that the connector does not support, for connectors describing their
+/// supported features. Connectors that are not payment processors are not validated.
+fn validate_payment_methods_enabled_for_connector(
+    connectors: &settings::Connectors,
+    connector_name: &str,
+    payment_methods_enabled: &[admin_types::PaymentMethodsEnabled],
+) -> RouterResult<()> {
+    match api::ConnectorData::convert_connector(connectors, connector_name) {
+        Ok(connector) => {
+            connector
+                .get_connector_specifications()
+                .map_or(Ok(()), |connector_specifications| {
+                    connector_specifications
+                        .validate_payment_methods_enabled(connector_name, payment_methods_enabled)
+                })
+        }
+        Err(_) => Ok(()),
+    }
+}
+
 pub(crate) fn validate_auth_and_metadata_type(
     connector_name: api_models::enums::Connector,
     val: &types::ConnectorAuthType,
@@ -4,8 +4,12 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
+#[cfg(all(feature = "payouts", feature = "olap"))]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
+#[cfg(all(feature = "payouts", feature = "olap"))]
+use crate::core::payouts;
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
@@ -116,6 +120,7 @@ pub async fn create_customer(
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
             default_payment_method_id: None,
+            kyc_status: customer_data.kyc_status,
         })
     }
     .await
@@ -318,6 +323,7 @@ pub async fn delete_customer(
         metadata: None,
         connector_customer: None,
         address_id: None,
+        kyc_status: None,
     };
     db.update_customer_by_customer_id_merchant_id(
         req.customer_id.clone(),
@@ -413,6 +419,10 @@ pub async fn update_customer(
         }
     };
 
+    #[cfg(all(feature = "payouts", feature = "olap"))]
+    let is_kyc_verified = customer.kyc_status != Some(enums::KycStatus::Verified)
+        && update_customer.kyc_status == Some(enums::KycStatus::Verified);
+
     let response = db
         .update_customer_by_customer_id_merchant_id(
             update_customer.customer_id.to_owned(),
@@ -441,6 +451,7 @@ pub async fn update_customer(
                     description: update_customer.description,
                     connector_customer: None,
                     address_id: address.clone().map(|addr| addr.address_id),
+                    kyc_status: update_customer.kyc_status,
                 })
             }
             .await
@@ -452,6 +463,24 @@ pub async fn update_customer(
         .await
         .switch()?;
 
+    // Payouts held for the KYC of the customer are fulfilled once the customer is verified
+    #[cfg(all(feature = "payouts", feature = "olap"))]
+    if is_kyc_verified {
+        if let Err(error) = payouts::release_held_payouts(
+            &state,
+            &merchant_account,
+            &key_store,
+            &response.customer_id,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to release the payouts held for customer KYC"
+            );
+        }
+    }
+
     Ok(services::ApplicationResponse::Json(
         customers::CustomerResponse::from((response, update_customer.address)),
     ))
@@ -218,6 +218,8 @@ pub enum ConnectorError {
     },
     #[error("Invalid Configuration")]
     InvalidConnectorConfig { config: &'static str },
+    #[error("Server responded with Too Many Requests, retry after {retry_after:?} seconds")]
+    RateLimitReceived { retry_after: Option<u64> },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -336,6 +338,14 @@ impl ConnectorError {
     pub fn is_connector_timeout(&self) -> bool {
         self == &Self::RequestTimeoutReceived
     }
+
+    /// Errors after which the same request can be retried with the connector after backing off
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RequestTimeoutReceived | Self::RateLimitReceived { .. }
+        )
+    }
 }
 
 #[cfg(feature = "detailed_errors")]
@@ -127,6 +127,12 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Insufficient balance in the connector account to fulfill the payout. Available: {available_amount}, required: {required_amount} {currency}")]
+    InsufficientConnectorBalance {
+        available_amount: i64,
+        required_amount: i64,
+        currency: String,
+    },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
     #[error(error_type = ErrorType::LockTimeout, code = "HE_00", message = "Resource is busy. Please try again later.")]
@@ -180,6 +186,8 @@ pub enum ApiErrorResponse {
     ApiKeyNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
     PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout schedule does not exist in our records")]
+    PayoutScheduleNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
     EventNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Invalid mandate id passed from connector")]
@@ -203,6 +211,12 @@ pub enum ApiErrorResponse {
         status: String,
         reason: String,
     },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Payouts to {country} are blocked for compliance reasons")]
+    PayoutCountryBlocked { country: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Payouts to accounts owned by the merchant are not allowed")]
+    SelfPayoutBlocked,
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "{payout_type} payouts are not allowed until the KYC of the merchant is verified")]
+    MerchantKycIncomplete { payout_type: String },
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
     SuccessfulPaymentNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "The connector provided in the request is incorrect or not available")]
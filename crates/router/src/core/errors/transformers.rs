@@ -135,6 +135,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 4, "Payout failed while processing with connector.", Some(Extra { data: data.clone(), ..Default::default()})))
             },
+            Self::InsufficientConnectorBalance { available_amount, required_amount, currency } => {
+                AER::BadRequest(ApiError::new("CE", 9, format!("Insufficient balance in the connector account to fulfill the payout. Available: {available_amount}, required: {required_amount} {currency}"), None))
+            },
             Self::DuplicateRefundRequest => AER::BadRequest(ApiError::new("HE", 1, "Duplicate refund request. Refund already attempted with the refund ID", None)),
             Self::DuplicateMandate => AER::BadRequest(ApiError::new("HE", 1, "Duplicate mandate request. Mandate already attempted with the Mandate ID", None)),
             Self::DuplicateMerchantAccount => AER::BadRequest(ApiError::new("HE", 1, "The merchant account with the specified details already exists in our records", None)),
@@ -187,6 +190,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
             }
+            Self::PayoutScheduleNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout schedule does not exist in our records", None))
+            }
             Self::EventNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
             }
@@ -203,6 +209,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
                 reason,
                 ..
             } => AER::DomainError(ApiError::new("HE", 3, message, Some(Extra { reason: Some(reason.clone()), ..Default::default() }))),
+            Self::PayoutCountryBlocked { country } => {
+                AER::BadRequest(ApiError::new("HE", 3, format!("Payouts to {country} are blocked for compliance reasons"), None))
+            }
+            Self::SelfPayoutBlocked => {
+                AER::BadRequest(ApiError::new("HE", 3, "Payouts to accounts owned by the merchant are not allowed", None))
+            }
+            Self::MerchantKycIncomplete { payout_type } => {
+                AER::BadRequest(ApiError::new("HE", 3, format!("{payout_type} payouts are not allowed until the KYC of the merchant is verified"), None))
+            }
             Self::SuccessfulPaymentNotFound => {
                 AER::NotFound(ApiError::new("HE", 4, "Successful payment not found for the given payment id", None))
             }
@@ -212,6 +212,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
             | errors::ConnectorError::MissingPaymentMethodType
             | errors::ConnectorError::InSufficientBalanceInPaymentMethod
             | errors::ConnectorError::RequestTimeoutReceived
+            | errors::ConnectorError::RateLimitReceived { .. }
             | errors::ConnectorError::CurrencyNotSupported { .. }
             | errors::ConnectorError::InvalidConnectorConfig { .. } => {
                 err.change_context(errors::ApiErrorResponse::RefundFailed { data: None })
@@ -269,6 +270,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::CurrencyNotSupported { message, connector} => errors::ApiErrorResponse::CurrencyNotSupported { message: format!("Credentials for the currency {message} are not configured with the connector {connector}/hyperswitch") },
                 errors::ConnectorError::FailedToObtainAuthType =>  errors::ApiErrorResponse::InvalidConnectorConfiguration {config: "connector_account_details".to_string()},
                 errors::ConnectorError::InvalidConnectorConfig { config }  => errors::ApiErrorResponse::InvalidConnectorConfiguration { config: config.to_string() },
+                errors::ConnectorError::RateLimitReceived { .. } => errors::ApiErrorResponse::PaymentAuthorizationFailed { data: None },
                 errors::ConnectorError::FailedToObtainIntegrationUrl |
                 errors::ConnectorError::RequestEncodingFailed |
                 errors::ConnectorError::RequestEncodingFailedWithReason(_) |
@@ -396,6 +398,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 | errors::ConnectorError::MissingPaymentMethodType
                 | errors::ConnectorError::InSufficientBalanceInPaymentMethod
                 | errors::ConnectorError::RequestTimeoutReceived
+                | errors::ConnectorError::RateLimitReceived { .. }
                 | errors::ConnectorError::CurrencyNotSupported { .. }
                 | errors::ConnectorError::ProcessingStepFailed(None) => {
                     logger::error!(%error,"Setup Mandate flow failed");
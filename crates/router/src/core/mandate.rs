@@ -7,7 +7,7 @@ use error_stack::{report, ResultExt};
 use futures::future;
 use router_env::{instrument, logger, tracing};
 
-use super::payments::helpers as payment_helper;
+use super::payments::{customers as payment_customers, helpers as payment_helper};
 use crate::{
     core::{
         errors::{self, RouterResponse, StorageErrorExt},
@@ -106,10 +106,33 @@ pub async fn revoke_mandate(
                 types::MandateRevokeResponseData,
             > = connector_data.connector.get_connector_integration();
 
+            let customer = db
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &mandate.customer_id,
+                    &merchant_account.merchant_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the customer of the mandate")?;
+            let connector_customer = customer.as_ref().and_then(|customer| {
+                merchant_connector_account
+                    .get_mca_id()
+                    .and_then(|merchant_connector_id| {
+                        payment_customers::get_connector_customer_details_if_present(
+                            customer,
+                            &merchant_connector_id,
+                        )
+                        .map(ToOwned::to_owned)
+                    })
+            });
+
             let router_data = utils::construct_mandate_revoke_router_data(
                 merchant_connector_account,
                 &merchant_account,
                 mandate.clone(),
+                connector_customer,
             )
             .await?;
 
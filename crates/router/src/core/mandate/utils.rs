@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use common_utils::{errors::CustomResult, ext_traits::ValueExt};
-use diesel_models::Mandate;
+use diesel_models::{Mandate, PaymentMethod};
 use error_stack::ResultExt;
 
 use crate::{
@@ -21,6 +21,54 @@ pub async fn construct_mandate_revoke_router_data(
     merchant_connector_account: helpers::MerchantConnectorAccountType,
     merchant_account: &domain::MerchantAccount,
     mandate: Mandate,
+    connector_customer: Option<String>,
+) -> CustomResult<types::MandateRevokeRouterData, errors::ApiErrorResponse> {
+    construct_revoke_router_data(
+        merchant_connector_account,
+        merchant_account,
+        mandate.connector,
+        mandate.customer_id,
+        connector_customer,
+        mandate.original_payment_id,
+        types::MandateRevokeRequestData {
+            mandate_id: Some(mandate.mandate_id),
+            connector_mandate_id: mandate.connector_mandate_id,
+        },
+    )
+}
+
+/// Constructs the router data for revoking the token of a payment method saved at the connector,
+/// which is not backed by a mandate
+pub fn construct_payment_method_token_revoke_router_data(
+    merchant_connector_account: helpers::MerchantConnectorAccountType,
+    merchant_account: &domain::MerchantAccount,
+    connector: String,
+    payment_method: &PaymentMethod,
+    connector_customer: Option<String>,
+    connector_mandate_id: String,
+) -> CustomResult<types::MandateRevokeRouterData, errors::ApiErrorResponse> {
+    construct_revoke_router_data(
+        merchant_connector_account,
+        merchant_account,
+        connector,
+        payment_method.customer_id.clone(),
+        connector_customer,
+        None,
+        types::MandateRevokeRequestData {
+            mandate_id: None,
+            connector_mandate_id: Some(connector_mandate_id),
+        },
+    )
+}
+
+fn construct_revoke_router_data(
+    merchant_connector_account: helpers::MerchantConnectorAccountType,
+    merchant_account: &domain::MerchantAccount,
+    connector: String,
+    customer_id: String,
+    connector_customer: Option<String>,
+    payment_id: Option<String>,
+    request: types::MandateRevokeRequestData,
 ) -> CustomResult<types::MandateRevokeRouterData, errors::ApiErrorResponse> {
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .get_connector_account_details()
@@ -29,11 +77,10 @@ pub async fn construct_mandate_revoke_router_data(
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.merchant_id.clone(),
-        customer_id: Some(mandate.customer_id),
-        connector_customer: None,
-        connector: mandate.connector,
-        payment_id: mandate
-            .original_payment_id
+        customer_id: Some(customer_id),
+        connector_customer,
+        connector,
+        payment_id: payment_id
             .unwrap_or_else(|| IRRELEVANT_PAYMENT_ID_IN_MANDATE_REVOKE_FLOW.to_string()),
         attempt_id: IRRELEVANT_ATTEMPT_ID_IN_MANDATE_REVOKE_FLOW.to_string(),
         status: diesel_models::enums::AttemptStatus::default(),
@@ -54,10 +101,7 @@ pub async fn construct_mandate_revoke_router_data(
         payment_method_balance: None,
         connector_api_version: None,
         payment_method_status: None,
-        request: types::MandateRevokeRequestData {
-            mandate_id: mandate.mandate_id,
-            connector_mandate_id: mandate.connector_mandate_id,
-        },
+        request,
         response: Err(types::ErrorResponse::get_not_implemented()),
         payment_method_id: None,
         connector_request_reference_id:
@@ -73,6 +73,10 @@ impl PaymentMethodRetrieve for Oss {
             pm @ Some(api::PaymentMethodData::Reward) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::CardRedirect(_)) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::GiftCard(_)) => Ok((pm.to_owned(), None)),
+            pm @ Some(api::PaymentMethodData::CardToken(CardToken {
+                connector_token: Some(_),
+                ..
+            })) => Ok((pm.to_owned(), None)),
             pm_opt @ Some(pm @ api::PaymentMethodData::BankTransfer(_)) => {
                 let payment_token = helpers::store_payment_method_data_in_vault(
                     state,
@@ -44,9 +44,10 @@ use crate::{
     configs::settings,
     core::{
         errors::{self, StorageErrorExt},
+        mandate::utils as mandate_utils,
         payment_methods::{transformers as payment_methods, vault},
         payments::{
-            helpers,
+            self as payments_core, customers as payment_customers, helpers,
             routing::{self, SessionFlowRoutingInput},
         },
         utils as core_utils,
@@ -58,7 +59,7 @@ use crate::{
         metrics::{self, request},
         payment_methods::ParentPaymentMethodToken,
     },
-    services,
+    services::{self, ConnectorValidation},
     types::{
         api::{self, routing as routing_types, PaymentMethodCreateExt},
         domain::{
@@ -3994,6 +3995,128 @@ pub async fn retrieve_payment_method(
     ))
 }
 
+/// Revokes the tokens of the payment method saved at the connectors that support it, so that the
+/// payment method can no longer be charged through the connectors once it is deleted
+#[instrument(skip_all)]
+pub async fn revoke_connector_payment_method_tokens(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &storage::PaymentMethod,
+    customer: &domain::Customer,
+) -> errors::RouterResult<()> {
+    let connector_mandate_details = match payment_method.connector_mandate_details.clone() {
+        Some(connector_mandate_details) => connector_mandate_details
+            .parse_value::<storage::PaymentsMandateReference>("PaymentsMandateReference")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the connector mandate details of payment method")?,
+        None => return Ok(()),
+    };
+
+    let db = state.store.as_ref();
+    for (merchant_connector_id, mandate_reference_record) in connector_mandate_details.0 {
+        let merchant_connector_account = match db
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &merchant_account.merchant_id,
+                &merchant_connector_id,
+                key_store,
+            )
+            .await
+        {
+            Ok(merchant_connector_account) => merchant_connector_account,
+            Err(error) if error.current_context().is_db_not_found() => {
+                logger::warn!(
+                    "Skipping the token revocation of payment method {} as the merchant connector account {merchant_connector_id} no longer exists",
+                    payment_method.payment_method_id
+                );
+                continue;
+            }
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the merchant connector account"))
+            }
+        };
+
+        let connector_name = merchant_connector_account.connector_name.clone();
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+            Some(merchant_connector_id.clone()),
+        )?;
+        let supports_token_revocation = connector_data
+            .connector
+            .get_connector_specifications()
+            .is_some_and(|specifications| specifications.supports_payment_method_token_revocation);
+        if !supports_token_revocation {
+            continue;
+        }
+
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::MandateRevoke,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        > = connector_data.connector.get_connector_integration();
+
+        let connector_customer = payment_customers::get_connector_customer_details_if_present(
+            customer,
+            &merchant_connector_id,
+        )
+        .map(ToOwned::to_owned);
+        let router_data = mandate_utils::construct_payment_method_token_revoke_router_data(
+            helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account),
+            merchant_account,
+            connector_name.clone(),
+            payment_method,
+            connector_customer,
+            mandate_reference_record.connector_mandate_id,
+        )?;
+
+        let response = services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments_core::CallConnectorAction::Trigger,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke the payment method token at the connector")?;
+
+        handle_payment_method_token_revoke_response(response.response, connector_name)?;
+    }
+
+    Ok(())
+}
+
+/// Connectors respond with not found when the token was already deleted at their end, which is
+/// treated as a successful revocation
+fn handle_payment_method_token_revoke_response(
+    response: Result<types::MandateRevokeResponseData, types::ErrorResponse>,
+    connector: String,
+) -> errors::RouterResult<()> {
+    match response {
+        Ok(_) => Ok(()),
+        Err(err) if err.status_code == 404 => {
+            logger::info!(
+                "Payment method token was already deleted at {connector}: {}",
+                err.message
+            );
+            Ok(())
+        }
+        Err(err) => Err(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector,
+            status_code: err.status_code,
+            reason: err.reason,
+        }
+        .into()),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
     state: routes::AppState,
@@ -4037,6 +4160,9 @@ pub async fn delete_payment_method(
         || Err(errors::ApiErrorResponse::PaymentMethodDeleteFailed),
     )?;
 
+    revoke_connector_payment_method_tokens(&state, &merchant_account, &key_store, &key, &customer)
+        .await?;
+
     if key.payment_method == Some(enums::PaymentMethod::Card) {
         let response = delete_card_from_locker(
             &state,
@@ -4172,3 +4298,57 @@ pub async fn list_countries_currencies_for_connector_payment_method_util(
             .collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_error_response(status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: "NO_ERROR_CODE".to_string(),
+            message: "Card not found".to_string(),
+            reason: None,
+            status_code,
+            attempt_status: None,
+            connector_transaction_id: None,
+        }
+    }
+
+    #[test]
+    fn test_payment_method_token_revoked_at_connector() {
+        let response = Ok(types::MandateRevokeResponseData {
+            mandate_status: enums::MandateStatus::Revoked,
+        });
+
+        assert!(
+            handle_payment_method_token_revoke_response(response, "helcim".to_string()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_payment_method_token_already_deleted_at_connector() {
+        let response = Err(get_error_response(404));
+
+        assert!(
+            handle_payment_method_token_revoke_response(response, "helcim".to_string()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_payment_method_token_revoke_error_is_propagated() {
+        let response = Err(get_error_response(400));
+
+        let error = handle_payment_method_token_revoke_response(response, "helcim".to_string())
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::ExternalConnectorError {
+                connector,
+                status_code: 400,
+                ..
+            } if connector == "helcim"
+        ));
+    }
+}
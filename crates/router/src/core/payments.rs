@@ -1045,6 +1045,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentRedirectSyn
             }),
             client_secret: None,
             expand_attempts: None,
+            expand_payouts: None,
             expand_captures: None,
         };
         let response = Box::pin(payments_core::<
@@ -1220,6 +1221,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentAuthenticat
                 }),
                 client_secret: None,
                 expand_attempts: None,
+                expand_payouts: None,
                 expand_captures: None,
             };
             Box::pin(payments_core::<
@@ -2480,6 +2482,7 @@ where
     pub refunds: Vec<storage::Refund>,
     pub disputes: Vec<storage::Dispute>,
     pub attempts: Option<Vec<storage::PaymentAttempt>>,
+    pub linked_payouts: Option<Vec<api_models::payments::PaymentLinkedPayoutResponse>>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<Secret<String>>,
     pub email: Option<pii::Email>,
@@ -2807,6 +2810,104 @@ pub async fn get_filters_for_payments(
     ))
 }
 
+/// Syncs the status of all the transactions processed through a merchant connector account in the
+/// time range, so that a day of transactions can be reconciled at once rather than one payment at
+/// a time
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn reconcile_payments(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsReconcileRequest,
+) -> RouterResponse<api::PaymentsReconcileResponse> {
+    common_utils::fp_utils::when(req.start_time > req.end_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must not be later than end_time".to_string(),
+        })
+    })?;
+
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            &req.merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: req.merchant_connector_id.clone(),
+        })?;
+    let connector_name = merchant_connector_account.connector_name.clone();
+
+    // Transactions can only be synced by date range with connectors which list them by date
+    if !matches!(
+        connector_name.parse::<enums::Connector>(),
+        Ok(enums::Connector::Helcim)
+    ) {
+        return Err(errors::ApiErrorResponse::FlowNotSupported {
+            flow: "Reconcile payments".to_string(),
+            connector: connector_name,
+        }
+        .into());
+    }
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        Some(req.merchant_connector_id.clone()),
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PSync,
+        router_types::PaymentsSyncData,
+        router_types::PaymentsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = utils::construct_date_range_sync_router_data(
+        &merchant_account,
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account),
+        connector_name.clone(),
+        router_types::TransactionDateRange {
+            start_time: req.start_time,
+            end_time: req.end_time,
+        },
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to sync the transactions in the date range")?;
+
+    match response.response {
+        Ok(router_types::PaymentsResponseData::TransactionListSyncResponse {
+            transaction_status_list,
+        }) => Ok(services::ApplicationResponse::Json(
+            api::PaymentsReconcileResponse {
+                connector: connector_name,
+                transactions: transaction_status_list,
+            },
+        )),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response for the date range sync"),
+        Err(err) => Err(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector_name,
+            status_code: err.status_code,
+            reason: err.reason,
+        }
+        .into()),
+    }
+}
+
 #[cfg(feature = "olap")]
 pub async fn get_payment_filters(
     state: AppState,
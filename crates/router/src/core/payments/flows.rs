@@ -321,7 +321,6 @@ default_imp_for_create_customer!(
     connector::Forte,
     connector::Globalpay,
     connector::Globepay,
-    connector::Helcim,
     connector::Iatapay,
     connector::Klarna,
     connector::Mollie,
@@ -958,9 +957,6 @@ macro_rules! default_imp_for_payouts {
     };
 }
 
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::Payouts for connector::DummyConnector<T> {}
-
 default_imp_for_payouts!(
     connector::Aci,
     connector::Airwallex,
@@ -1036,17 +1032,6 @@ macro_rules! default_imp_for_payouts_create {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutCreate for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_create!(
     connector::Aci,
@@ -1213,20 +1198,100 @@ macro_rules! default_imp_for_payouts_fulfill {
     };
 }
 
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_fulfill!(
+    connector::Aci,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Cryptopay,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stax,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutBalance for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoBalance,
+            types::PayoutsData,
+            types::PayoutsBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
 #[cfg(feature = "payouts")]
 #[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutFulfill for connector::DummyConnector<T> {}
+impl<const T: u8> api::PayoutBalance for connector::DummyConnector<T> {}
 #[cfg(feature = "payouts")]
 #[cfg(feature = "dummy_connector")]
 impl<const T: u8>
-    services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
+    services::ConnectorIntegration<
+        api::PoBalance,
+        types::PayoutsData,
+        types::PayoutsBalanceResponseData,
+    > for connector::DummyConnector<T>
 {
 }
 
 #[cfg(feature = "payouts")]
-default_imp_for_payouts_fulfill!(
+default_imp_for_payouts_balance!(
     connector::Aci,
+    connector::Adyen,
     connector::Airwallex,
     connector::Authorizedotnet,
     connector::Bambora,
@@ -1285,13 +1350,13 @@ default_imp_for_payouts_fulfill!(
 );
 
 #[cfg(feature = "payouts")]
-macro_rules! default_imp_for_payouts_cancel {
+macro_rules! default_imp_for_payouts_sync {
     ($($path:ident::$connector:ident),*) => {
         $(
-            impl api::PayoutCancel for $path::$connector {}
+            impl api::PayoutSync for $path::$connector {}
             impl
             services::ConnectorIntegration<
-            api::PoCancel,
+            api::PoSync,
             types::PayoutsData,
             types::PayoutsResponseData,
         > for $path::$connector
@@ -1301,14 +1366,80 @@ macro_rules! default_imp_for_payouts_cancel {
 }
 
 #[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutCancel for connector::DummyConnector<T> {}
+default_imp_for_payouts_sync!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Cryptopay,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stax,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl
+);
+
 #[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
+macro_rules! default_imp_for_payouts_cancel {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutCancel for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoCancel,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
 }
 
 #[cfg(feature = "payouts")]
@@ -2283,7 +2414,6 @@ default_imp_for_incremental_authorization!(
     connector::Globalpay,
     connector::Globepay,
     connector::Gocardless,
-    connector::Helcim,
     connector::Iatapay,
     connector::Klarna,
     connector::Mollie,
@@ -2368,7 +2498,6 @@ default_imp_for_revoking_mandates!(
     connector::Globalpay,
     connector::Globepay,
     connector::Gocardless,
-    connector::Helcim,
     connector::Iatapay,
     connector::Klarna,
     connector::Mollie,
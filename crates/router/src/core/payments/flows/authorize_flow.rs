@@ -4,8 +4,9 @@ use error_stack;
 // use router_env::tracing::Instrument;
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    consts,
     core::{
-        errors::{self, ConnectorErrorExt, RouterResult},
+        errors::{self, ConnectorErrorExt, CustomResult, RouterResult},
         mandate,
         payments::{
             self, access_token, customers, helpers, tokenization, transformers, PaymentData,
@@ -14,7 +15,7 @@ use crate::{
     logger,
     routes::{metrics, AppState},
     services,
-    types::{self, api, domain},
+    types::{self, api, domain, storage::enums as storage_enums},
 };
 
 #[async_trait]
@@ -81,8 +82,10 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 call_connector_action,
                 connector_request,
             )
-            .await
-            .to_payment_failed_response()?;
+            .await;
+            let resp = recover_unanswered_authorize(state, connector, &self, resp)
+                .await
+                .to_payment_failed_response()?;
 
             metrics::PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
             Ok(resp)
@@ -218,6 +221,142 @@ impl types::PaymentsAuthorizeRouterData {
     }
 }
 
+/// An authorize which timed out, or whose connection failed, may still have been processed by the
+/// connector. Retrying it could charge the customer twice, so the transaction is looked up by its
+/// reference instead, for connectors which support it.
+async fn recover_unanswered_authorize(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    authorize_router_data: &types::PaymentsAuthorizeRouterData,
+    result: CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError>,
+) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+    let supports_reference_sync = connector
+        .connector
+        .get_connector_specifications()
+        .is_some_and(|specifications| specifications.supports_reference_sync);
+    if !supports_reference_sync {
+        return result;
+    }
+    let router_data = match result {
+        Ok(router_data)
+            if matches!(
+                &router_data.response,
+                Err(error) if error.code == consts::REQUEST_TIMEOUT_ERROR_CODE
+            ) =>
+        {
+            router_data
+        }
+        Err(error) if is_connection_error(&error) => {
+            logger::error!(unanswered_authorize_error=?error);
+            types::RouterData {
+                response: Err(types::ErrorResponse {
+                    code: consts::CONNECTION_ERROR_CODE.to_string(),
+                    message: consts::CONNECTION_ERROR_MESSAGE.to_string(),
+                    reason: Some(error.to_string()),
+                    status_code: 503,
+                    attempt_status: None,
+                    connector_transaction_id: None,
+                }),
+                ..authorize_router_data.clone()
+            }
+        }
+        result => return result,
+    };
+
+    let sync_request = types::PaymentsSyncData {
+        connector_transaction_id: types::ResponseId::NoResponseId,
+        encoded_data: None,
+        capture_method: router_data.request.capture_method,
+        connector_meta: None,
+        sync_type: types::SyncRequestType::ReferenceSync,
+        mandate_id: router_data.request.mandate_id.clone(),
+        payment_method_type: router_data.request.payment_method_type,
+        currency: router_data.request.currency,
+        amount: router_data.request.amount,
+        surcharge_amount: router_data
+            .request
+            .surcharge_details
+            .as_ref()
+            .map(|surcharge_details| surcharge_details.get_total_surcharge_amount()),
+    };
+    let sync_router_data = helpers::router_data_type_conversion::<_, api::PSync, _, _, _, _>(
+        router_data.clone(),
+        sync_request,
+        Err(types::ErrorResponse::default()),
+    );
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PSync,
+        types::PaymentsSyncData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+    // A failed lookup is inconclusive, so it leaves the attempt pending instead of failing it
+    let lookup = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &sync_router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .map_err(|error| logger::error!(unanswered_authorize_lookup_error=?error))
+    .ok();
+
+    Ok(settle_unanswered_authorize(router_data, lookup))
+}
+
+/// Whether the request failed on the connection to the connector, after which it may or may not
+/// have been received
+fn is_connection_error(error: &error_stack::Report<errors::ConnectorError>) -> bool {
+    error
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<errors::ApiClientError>())
+        .any(|error| {
+            matches!(
+                error,
+                errors::ApiClientError::ConnectionClosedIncompleteMessage
+                    | errors::ApiClientError::RequestNotSent(_)
+            )
+        })
+}
+
+/// Settles the status of an authorize which got no response, from the transaction found by
+/// looking it up. The attempt is only left pending, not failed, when no transaction could be found.
+pub fn settle_unanswered_authorize(
+    router_data: types::PaymentsAuthorizeRouterData,
+    lookup: Option<types::PaymentsSyncRouterData>,
+) -> types::PaymentsAuthorizeRouterData {
+    match lookup {
+        Some(lookup) if lookup.response.is_ok() => {
+            let status = lookup.status;
+            let request = router_data.request.clone();
+            let response = lookup.response.clone();
+            types::RouterData {
+                status,
+                ..helpers::router_data_type_conversion::<_, api::Authorize, _, _, _, _>(
+                    router_data,
+                    request,
+                    response,
+                )
+            }
+        }
+        _ => {
+            let response = router_data
+                .response
+                .clone()
+                .map_err(|error| types::ErrorResponse {
+                    attempt_status: Some(storage_enums::AttemptStatus::Pending),
+                    ..error
+                });
+            types::RouterData {
+                status: storage_enums::AttemptStatus::Pending,
+                response,
+                ..router_data
+            }
+        }
+    }
+}
+
 impl mandate::MandateBehaviour for types::PaymentsAuthorizeData {
     fn get_amount(&self) -> i64 {
         self.amount
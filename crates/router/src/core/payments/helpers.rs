@@ -1533,6 +1533,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                                     connector_customer: None,
                                     metadata: None,
                                     address_id: None,
+                                    kyc_status: None,
                                 },
                             )
                         }
@@ -1590,6 +1591,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                                 connector_customer: None,
                                 address_id: None,
                                 default_payment_method_id: None,
+                                kyc_status: None,
                             },
                         )
                     }
@@ -4135,11 +4137,15 @@ pub fn add_connector_response_to_additional_payment_data(
             core_types::AdditionalPaymentMethodConnectorResponse::Card {
                 authentication_data,
                 payment_checks,
+                card_network,
+                card_type,
             },
         ) => api_models::payments::AdditionalPaymentData::Card(Box::new(
             api_models::payments::AdditionalCardInfo {
                 payment_checks,
                 authentication_data,
+                card_network: card_network.or(additional_card_data.card_network.clone()),
+                card_type: card_type.or(additional_card_data.card_type.clone()),
                 ..*additional_card_data.clone()
             },
         )),
@@ -214,6 +214,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            linked_payouts: None,
             sessions_token: vec![],
             card_cvc: None,
             creds_identifier,
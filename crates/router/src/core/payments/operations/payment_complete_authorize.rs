@@ -290,6 +290,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            linked_payouts: None,
             sessions_token: vec![],
             card_cvc: request.card_cvc.clone(),
             creds_identifier: None,
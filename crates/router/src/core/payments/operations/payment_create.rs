@@ -429,6 +429,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             refunds: vec![],
             disputes: vec![],
             attempts: None,
+            linked_payouts: None,
             force_sync: None,
             sessions_token: vec![],
             card_cvc: request.card_cvc.clone(),
@@ -983,6 +983,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 types::PaymentsResponseData::IncrementalAuthorizationResponse { .. } => {
                     (None, None)
                 }
+                types::PaymentsResponseData::TransactionListSyncResponse { .. } => (None, None),
                 types::PaymentsResponseData::MultipleCaptureResponse {
                     capture_sync_response_list,
                 } => match payment_data.multiple_capture_data {
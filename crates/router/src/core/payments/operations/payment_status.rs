@@ -304,6 +304,33 @@ async fn get_tracker_for_sync<
         _ => None,
     };
 
+    #[cfg(feature = "payouts")]
+    let linked_payouts = match request.expand_payouts {
+        Some(true) => Some(
+            db.find_payouts_by_merchant_id_source_payment_id(
+                &merchant_account.merchant_id,
+                &payment_id_str,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Error while retrieving linked payouts for, merchant_id: {}, payment_id: {payment_id_str}", &merchant_account.merchant_id)
+            })?
+            .into_iter()
+            .map(|payout| api_models::payments::PaymentLinkedPayoutResponse {
+                payout_id: payout.payout_id,
+                amount: payout.amount,
+                currency: payout.destination_currency,
+                status: payout.status,
+            })
+            .collect(),
+        ),
+        _ => None,
+    };
+    #[cfg(not(feature = "payouts"))]
+    let linked_payouts = None;
+
     let multiple_capture_data = if payment_attempt.multiple_capture_count > Some(0) {
         let captures = db
             .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
@@ -473,6 +500,7 @@ async fn get_tracker_for_sync<
         refunds,
         disputes,
         attempts,
+        linked_payouts,
         sessions_token: vec![],
         card_cvc: None,
         creds_identifier,
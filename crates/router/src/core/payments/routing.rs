@@ -12,7 +12,10 @@ use api_models::{
     payments::Address,
     routing::ConnectorSelection,
 };
-use common_utils::static_cache::StaticCache;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    static_cache::StaticCache,
+};
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
 use euclid::{
@@ -318,8 +321,15 @@ pub async fn perform_static_routing_v1<F: Clone>(
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => match transaction_data {
+            routing::TransactionData::Payment(_) => perform_volume_split(splits.to_vec(), None),
+            // Payouts are split by their id, so that a payout is always routed the same way
+            #[cfg(feature = "payouts")]
+            routing::TransactionData::Payout(payout_data) => {
+                perform_volume_split_with_fallback(splits.to_vec(), &payout_data.payouts.payout_id)
+            }
+        }
+        .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
@@ -536,6 +546,44 @@ pub fn perform_volume_split(
     Ok(splits.into_iter().map(|sp| sp.connector).collect())
 }
 
+/// Orders all the connectors of a volume split by picking each one out of the remaining ones by
+/// their splits, so that the connectors falling back to the first one also follow the configured
+/// volumes. Connectors with no volume are ordered last. The generator is seeded with the SHA-256
+/// digest of the seed, so the order is the same for a given seed across processes and releases.
+pub fn perform_volume_split_with_fallback(
+    splits: Vec<routing_types::ConnectorVolumeSplit>,
+    rng_seed: &str,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let seed: [u8; 32] = crypto::Sha256
+        .generate_digest(rng_seed.as_bytes())
+        .change_context(errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Failed to hash the seed of the volume split")?
+        .try_into()
+        .map_err(|_| errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Unexpected length of the hashed seed of the volume split")?;
+    let mut rng = rand_chacha::ChaCha8Rng::from_seed(seed);
+
+    let (mut remaining, unweighted): (Vec<_>, Vec<_>) =
+        splits.into_iter().partition(|sp| sp.split > 0);
+    let mut connectors = Vec::with_capacity(remaining.len() + unweighted.len());
+    while !remaining.is_empty() {
+        let weighted_index = distributions::WeightedIndex::new(remaining.iter().map(|sp| sp.split))
+            .change_context(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Error creating weighted distribution for volume split")?;
+        let idx = weighted_index.sample(&mut rng);
+
+        remaining
+            .get(idx)
+            .ok_or(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Volume split index lookup failed")?;
+        // Panic Safety: The index is checked to be present just above
+        connectors.push(remaining.remove(idx).connector);
+    }
+    connectors.extend(unweighted.into_iter().map(|sp| sp.connector));
+
+    Ok(connectors)
+}
+
 pub async fn get_merchant_kgraph<'a>(
     state: &AppState,
     key_store: &domain::MerchantKeyStore,
@@ -8,7 +8,7 @@ use common_utils::{consts::X_HS_LATENCY, fp_utils};
 use diesel_models::ephemeral_key;
 use error_stack::{report, ResultExt};
 use masking::Maskable;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use super::{flows::Feature, types::AuthenticationData, PaymentData};
 use crate::{
@@ -705,6 +705,7 @@ where
                 .set_disputes(disputes_response)
                 .set_attempts(attempts_response)
                 .set_captures(captures_response)
+                .set_linked_payouts(payment_data.linked_payouts)
                 .set_payment_method(
                     payment_attempt.payment_method,
                     auth_flow == services::AuthFlow::Merchant,
@@ -884,27 +885,41 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
 }
 
 #[cfg(feature = "payouts")]
-impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
-    for api::PayoutCreateResponse
-{
-    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt, domain::Customer)) -> Self {
-        let (payout, payout_attempt, customer) = item;
-        let attempt = PayoutAttemptResponse {
+impl ForeignFrom<(&storage::Payouts, storage::PayoutAttempt)> for PayoutAttemptResponse {
+    fn foreign_from(item: (&storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        Self {
             attempt_id: payout_attempt.payout_attempt_id,
             status: payout_attempt.status,
-            amount: payout.amount,
+            amount: payout_attempt.amount.unwrap_or(payout.amount),
             currency: Some(payout.destination_currency),
-            connector: payout_attempt.connector.clone(),
-            error_code: payout_attempt.error_code.clone(),
-            error_message: payout_attempt.error_message.clone(),
+            connector: payout_attempt.connector,
+            error_code: payout_attempt.error_code,
+            error_message: payout_attempt.error_message,
             payment_method: Some(payout.payout_type),
             payout_method_type: None,
             connector_transaction_id: Some(payout_attempt.connector_payout_id),
             cancellation_reason: None,
             unified_code: None,
             unified_message: None,
-        };
-        let attempts = vec![attempt];
+            created_at: Some(payout_attempt.created_at),
+            last_modified_at: Some(payout_attempt.last_modified_at),
+            manual_routing_override: payout_attempt.manual_routing_override,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
+    for api::PayoutCreateResponse
+{
+    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt, domain::Customer)) -> Self {
+        let (payout, payout_attempt, customer) = item;
+        let attempts = vec![PayoutAttemptResponse::foreign_from((
+            &payout,
+            payout_attempt.clone(),
+        ))];
+        let converted_amount = crate::core::payouts::fx::get_converted_amount(&payout);
         Self {
             payout_id: payout.payout_id,
             merchant_id: payout.merchant_id,
@@ -925,14 +940,25 @@ impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt, domain::Customer)>
             entity_type: payout.entity_type,
             recurring: payout.recurring,
             metadata: payout.metadata,
-            status: payout_attempt.status,
+            status: crate::core::payouts::helpers::get_payout_status(&payout, &payout_attempt),
             error_message: payout_attempt.error_message,
             error_code: payout_attempt.error_code,
             profile_id: payout.profile_id,
             created: Some(payout.created_at),
+            scheduled_at: payout.scheduled_at,
+            source_currency: payout.source_currency,
+            converted_amount,
+            fx_rate: payout.fx_rate,
+            fx_quote_expires_at: payout.fx_quote_expires_at,
+            priority: payout.priority,
+            fulfilled_amount: payout.fulfilled_amount,
+            payout_schedule_id: payout.payout_schedule_id,
+            source_payment_id: payout.source_payment_id,
             attempts: Some(attempts),
             billing: None,
             client_secret: None,
+            connector_sync_supported: None,
+            routing: None,
         }
     }
 }
@@ -1221,6 +1247,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsSyncData
             },
             payment_method_type: payment_data.payment_attempt.payment_method_type,
             currency: payment_data.currency,
+            amount: payment_data.payment_attempt.get_total_amount(),
+            surcharge_amount: payment_data.payment_attempt.get_total_surcharge_amount(),
         })
     }
 }
@@ -1274,13 +1302,15 @@ impl api::ConnectorTransactionId for Helcim {
         &self,
         payment_attempt: storage::PaymentAttempt,
     ) -> Result<Option<String>, errors::ApiErrorResponse> {
-        if payment_attempt.connector_transaction_id.is_none() {
-            let metadata =
-                Self::connector_transaction_id(self, &payment_attempt.connector_metadata);
-            metadata.map_err(|_| errors::ApiErrorResponse::ResourceIdNotFound)
-        } else {
-            Ok(payment_attempt.connector_transaction_id)
-        }
+        Self::connector_transaction_id(
+            self,
+            &payment_attempt.connector_metadata,
+            payment_attempt.connector_transaction_id.as_deref(),
+        )
+        .map_err(|error| {
+            logger::error!(error=?error, "Failed to get the Helcim transaction id of the payment attempt");
+            errors::ApiErrorResponse::ResourceIdNotFound
+        })
     }
 }
 
@@ -1,30 +1,37 @@
 pub mod access_token;
+pub mod audit;
+pub mod fx;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
+pub mod schedules;
 pub mod validator;
 
-use std::vec::IntoIter;
+use std::{str::FromStr, vec::IntoIter};
 
 use api_models::enums as api_enums;
-use common_utils::{crypto::Encryptable, ext_traits::ValueExt, pii};
+use common_utils::{
+    crypto::Encryptable,
+    ext_traits::{Encode, ValueExt},
+    pii,
+};
 #[cfg(feature = "olap")]
 use data_models::errors::StorageError;
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 #[cfg(feature = "olap")]
 use futures::future::join_all;
-#[cfg(feature = "olap")]
-use router_env::logger;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use serde_json;
 
 use super::errors::{ConnectorErrorExt, StorageErrorExt};
 #[cfg(feature = "olap")]
 use crate::types::{domain::behaviour::Conversion, transformers::ForeignFrom};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResponse, RouterResult},
+        payment_methods,
         payments::{self, helpers as payment_helpers},
         utils as core_utils,
     },
@@ -32,7 +39,7 @@ use crate::{
     services,
     types::{
         self,
-        api::{self, payouts},
+        api::{self, payouts, Payouts},
         domain,
         storage::{self, PaymentRoutingInfo},
     },
@@ -50,6 +57,17 @@ pub struct PayoutData {
     pub payout_attempt: storage::PayoutAttempt,
     pub payout_method_data: Option<payouts::PayoutMethodData>,
     pub profile_id: String,
+    /// Amount of the tranche being fulfilled, the whole unfulfilled amount is fulfilled when not set
+    pub fulfillment_amount: Option<i64>,
+    /// Who triggered the operation on the payout, recorded in the audit trail of its status transitions
+    pub audit_actor: payouts::PayoutEventActor,
+}
+
+impl PayoutData {
+    pub fn get_fulfillment_amount(&self) -> i64 {
+        self.fulfillment_amount
+            .unwrap_or_else(|| helpers::get_unfulfilled_amount(&self.payouts))
+    }
 }
 
 // ********************************************** CORE FLOWS **********************************************
@@ -79,7 +97,9 @@ pub async fn get_connector_choice(
             .collect()
     });
     let connector_choice = helpers::get_default_payout_connector(state, routing_algorithm).await?;
-    match connector_choice {
+    // Connectors are routed only if the attempt is not yet bound to a connector
+    let is_routed = payout_data.payout_attempt.connector.is_none();
+    let connector_call_type = match connector_choice {
         api::ConnectorChoice::SessionMultiple(_) => {
             Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Invalid connector choice - SessionMultiple")?
@@ -140,6 +160,19 @@ pub async fn get_connector_choice(
             )
             .await
         }
+    }?;
+
+    if is_routed {
+        helpers::apply_payout_routing_checks(
+            state,
+            merchant_account,
+            key_store,
+            connector_call_type,
+            payout_data,
+        )
+        .await
+    } else {
+        Ok(connector_call_type)
     }
 }
 
@@ -260,22 +293,49 @@ pub async fn payouts_create_core(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutCreateRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
-    // Validate create request
-    let (payout_id, payout_method_data, profile_id) =
-        validator::validate_create_request(&state, &merchant_account, &req, &key_store).await?;
-
-    // Create DB entries
-    let mut payout_data = payout_create_db_entries(
+    // Payouts funded by a payment are validated and created under a lock on the payment, so that
+    // concurrent payouts and refunds of it are validated against each other
+    let source_payment_locking_action =
+        helpers::get_source_payment_locking_action(req.source_payment_id.as_deref());
+    source_payment_locking_action
+        .clone()
+        .perform_locking_action(&state, merchant_account.merchant_id.clone())
+        .await?;
+    let result = Box::pin(validate_and_create_payout(
         &state,
         &merchant_account,
         &key_store,
-        &req,
-        &payout_id,
-        &profile_id,
-        payout_method_data.as_ref(),
-    )
-    .await?;
+        req,
+        audit_actor,
+    ))
+    .await;
+    source_payment_locking_action
+        .free_lock_action(&state, merchant_account.merchant_id.clone())
+        .await?;
+    let (req, mut payout_data) = result?;
+    audit::record_status_transition(&state, &payout_data, None);
+
+    // Defer connector calls for payouts scheduled to be processed later
+    if let Some(scheduled_at) = payout_data.payouts.scheduled_at {
+        schedule_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &mut payout_data,
+            scheduled_at,
+        )
+        .await?;
+
+        return response_handler(
+            &state,
+            &merchant_account,
+            &payouts::PayoutRequest::PayoutCreateRequest(req.to_owned()),
+            &payout_data,
+        )
+        .await;
+    }
 
     let connector_call_type = get_connector_choice(
         &state,
@@ -307,11 +367,100 @@ pub async fn payouts_create_core(
     .await
 }
 
+/// Validates the create request and creates the DB entries of the payout, returning the request
+/// with the values resolved during validation
+async fn validate_and_create_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: payouts::PayoutCreateRequest,
+    audit_actor: payouts::PayoutEventActor,
+) -> RouterResult<(payouts::PayoutCreateRequest, PayoutData)> {
+    // Validate create request
+    let validator::ValidatedPayoutCreateRequest {
+        payout_id,
+        payout_method_data,
+        profile_id,
+        fx_quote,
+        recipient_contact_details,
+        request_payout_method_data,
+        payout_type,
+        source_payment_amount,
+    } = validator::validate_create_request(state, merchant_account, &req, key_store).await?;
+    let req = payouts::PayoutCreateRequest {
+        amount: source_payment_amount.map(api::Amount::from).or(req.amount),
+        payout_type: Some(payout_type),
+        payout_method_data: request_payout_method_data,
+        email: recipient_contact_details.email,
+        phone: recipient_contact_details.phone,
+        phone_country_code: recipient_contact_details.phone_country_code,
+        ..req
+    };
+
+    // Create DB entries
+    let payout_data = payout_create_db_entries(
+        state,
+        merchant_account,
+        key_store,
+        &req,
+        &payout_id,
+        &profile_id,
+        payout_method_data.as_ref(),
+        fx_quote.as_ref(),
+        audit_actor,
+    )
+    .await?;
+
+    Ok((req, payout_data))
+}
+
+/// Runs the validations of payout creation on the request without creating any DB entries or
+/// storing the payout method in the locker
+#[instrument(skip_all)]
+pub async fn payouts_validate_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutValidateResponse> {
+    let profile_id =
+        match validator::validate_create_request(&state, &merchant_account, &req, &key_store).await
+        {
+            Ok(validated_request) => validated_request.profile_id,
+            Err(error) => {
+                let validation_error =
+                    helpers::get_payout_validation_error(error.current_context()).ok_or(error)?;
+                return Ok(services::ApplicationResponse::Json(
+                    payouts::PayoutValidateResponse {
+                        valid: false,
+                        connector: None,
+                        errors: vec![validation_error],
+                    },
+                ));
+            }
+        };
+
+    let configured_connectors =
+        helpers::get_configured_payout_connectors(&state, &key_store, &profile_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutValidateResponse {
+            valid: true,
+            connector: helpers::resolve_payout_connector(
+                req.connector.as_deref(),
+                &configured_connectors,
+            ),
+            errors: vec![],
+        },
+    ))
+}
+
 pub async fn payouts_update_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutCreateRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
     let mut payout_data = make_payout_data(
@@ -319,28 +468,58 @@ pub async fn payouts_update_core(
         &merchant_account,
         &key_store,
         &payouts::PayoutRequest::PayoutCreateRequest(req.to_owned()),
+        audit_actor,
     )
     .await?;
 
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let status = payout_attempt.status;
 
+    // A confirm which waited on a concurrent confirm of the payout returns its result
+    if req.confirm.unwrap_or(false) && helpers::is_payout_fulfillment_started(status) {
+        return response_handler(
+            &state,
+            &merchant_account,
+            &payouts::PayoutRequest::PayoutCreateRequest(req),
+            &payout_data,
+        )
+        .await;
+    }
+
     // Verify update feasibility
-    if helpers::is_payout_terminal_state(status) || helpers::is_payout_initiated(status) {
-        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-            message: format!(
-                "Payout {} cannot be updated for status {}",
-                payout_id, status
-            ),
-        }));
+    validator::validate_payout_status_for_update(&payout_id, status)?;
+    validator::validate_source_payment_for_update(&payout_data.payouts, &req)?;
+    validator::validate_payout_metadata(req.metadata.as_ref())?;
+
+    // Validate bank details passed for update
+    if let Some(payout_method_data) = req.payout_method_data.as_ref() {
+        validator::validate_payout_method_data(payout_method_data)?;
+        validator::validate_bank_payout_compatibility(
+            payout_method_data,
+            req.currency
+                .unwrap_or(payout_data.payouts.destination_currency),
+            payout_data
+                .billing_address
+                .as_ref()
+                .and_then(|address| address.country),
+        )?;
     }
 
     // Update DB with new data
     let payouts = payout_data.payouts.to_owned();
+    let destination_currency = req.currency.unwrap_or(payouts.destination_currency);
+    // Same currency payouts keep following the payout currency unless a source currency is passed
+    let source_currency =
+        req.source_currency
+            .unwrap_or(if payouts.source_currency == payouts.destination_currency {
+                destination_currency
+            } else {
+                payouts.source_currency
+            });
     let updated_payouts = storage::PayoutsUpdate::Update {
         amount: req.amount.unwrap_or(payouts.amount.into()).into(),
-        destination_currency: req.currency.unwrap_or(payouts.destination_currency),
-        source_currency: req.currency.unwrap_or(payouts.source_currency),
+        destination_currency,
+        source_currency,
         description: req.description.clone().or(payouts.description.clone()),
         recurring: req.recurring.unwrap_or(payouts.recurring),
         auto_fulfill: req.auto_fulfill.unwrap_or(payouts.auto_fulfill),
@@ -363,6 +542,42 @@ pub async fn payouts_update_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error updating payouts")?;
 
+    // Re-quote when the currencies change or a source currency is passed explicitly
+    if req.source_currency.is_some()
+        || source_currency != payouts.source_currency
+        || destination_currency != payouts.destination_currency
+    {
+        let fx_quote = fx::get_fx_quote(
+            &fx::ForexApiQuoteProvider::new(&state),
+            source_currency,
+            destination_currency,
+            state.conf.payouts.fx_quote_validity_in_seconds,
+            common_utils::date_time::now(),
+        )
+        .await?;
+        let fx_quote_update = storage::PayoutsUpdate::FxQuoteUpdate {
+            fx_rate: fx_quote.as_ref().map(|fx_quote| fx_quote.rate.to_string()),
+            fx_quote_expires_at: fx_quote.as_ref().map(|fx_quote| fx_quote.expires_at),
+        };
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                fx_quote_update,
+                &payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating FX quote in payouts")?;
+    }
+    if let Some(true) = req.confirm {
+        validator::validate_fx_quote_expiry(
+            &payout_id,
+            payout_data.payouts.fx_quote_expires_at,
+            common_utils::date_time::now(),
+        )?;
+    }
+
     let updated_business_country =
         payout_attempt
             .business_country
@@ -425,6 +640,7 @@ pub async fn payouts_update_core(
             .await?
             .get_required_value("payout_method_data")?,
         );
+        validator::validate_payout_data_compatibility(&payout_data)?;
     };
 
     let connector_call_type = get_connector_choice(
@@ -457,28 +673,120 @@ pub async fn payouts_update_core(
     .await
 }
 
-#[instrument(skip_all)]
-pub async fn payouts_retrieve_core(
+pub async fn payouts_update_details_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    req: payouts::PayoutRetrieveRequest,
+    req: payouts::PayoutUpdateRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
-    let payout_data = make_payout_data(
+    let payout_request =
+        payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.to_owned(),
+            connector: None,
+            amount: None,
+        });
+    let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
-        &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
+        &payout_request,
+        audit_actor,
     )
     .await?;
 
-    response_handler(
+    // Verify that only non-monetary fields are updated on an updatable payout
+    validator::validate_update_request(&req, payout_data.payout_attempt.status)?;
+
+    let db = &*state.store;
+    if req.description.is_some() || req.metadata.is_some() {
+        let updated_payouts = storage::PayoutsUpdate::MetadataUpdate {
+            description: req.description.to_owned(),
+            metadata: req.metadata.to_owned(),
+        };
+        payout_data.payouts = db
+            .update_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &req.payout_id,
+                updated_payouts,
+                &payout_data.payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+            .attach_printable("Error updating payouts")?;
+    }
+
+    // Recipient contact details are stored on the customer linked to the payout
+    if req.email.is_some() || req.phone.is_some() || req.phone_country_code.is_some() {
+        let customer = payout_data
+            .customer_details
+            .take()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Customer not found for payout")?;
+        payout_data.customer_details = Some(
+            helpers::update_customer_contact_details(
+                &state,
+                customer,
+                &req,
+                &merchant_account,
+                &key_store,
+            )
+            .await?,
+        );
+    }
+
+    response_handler(&state, &merchant_account, &payout_request, &payout_data).await
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutRetrieveRequest,
+    audit_actor: payouts::PayoutEventActor,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let payout_request = payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned());
+    let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
-        &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
-        &payout_data,
+        &key_store,
+        &payout_request,
+        audit_actor,
     )
-    .await
+    .await?;
+
+    // Sync with the connector only if the payout was created with it and can still change status
+    let connector_sync_supported = if req.force_sync.unwrap_or(false)
+        && !helpers::is_payout_terminal_state(payout_data.payout_attempt.status)
+        && validator::validate_sync_request(&payout_data.payout_attempt).is_ok()
+    {
+        Some(
+            sync_payout(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payout_request,
+                &mut payout_data,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    let response =
+        response_handler(&state, &merchant_account, &payout_request, &payout_data).await?;
+    Ok(match response {
+        services::ApplicationResponse::Json(response) => {
+            services::ApplicationResponse::Json(payouts::PayoutCreateResponse {
+                connector_sync_supported,
+                ..response
+            })
+        }
+        response => response,
+    })
 }
 
 #[instrument(skip_all)]
@@ -487,12 +795,14 @@ pub async fn payouts_cancel_core(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutActionRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
         &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+        audit_actor,
     )
     .await?;
 
@@ -511,6 +821,7 @@ pub async fn payouts_cancel_core(
 
     // Make local cancellation
     } else if helpers::is_eligible_for_local_payout_cancellation(status) {
+        let previous_status = payout_data.payouts.status;
         let status = storage_enums::PayoutStatus::Cancelled;
         let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
             connector_payout_id: connector_payout_id.to_owned(),
@@ -541,6 +852,7 @@ pub async fn payouts_cancel_core(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error updating payouts in db")?;
+        audit::record_status_transition(&state, &payout_data, Some(previous_status));
 
     // Trigger connector's cancellation
     } else {
@@ -590,15 +902,71 @@ pub async fn payouts_fulfill_core(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutActionRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
         &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+        audit_actor,
     )
     .await?;
 
+    // Payouts are fulfilled under a lock, a request which waited on a concurrent fulfillment
+    // returns its result instead of sending the payout to the connector again, even when it
+    // forces the payout through another connector
+    if helpers::is_payout_fulfillment_started(payout_data.payouts.status) {
+        return response_handler(
+            &state,
+            &merchant_account,
+            &payouts::PayoutRequest::PayoutActionRequest(req),
+            &payout_data,
+        )
+        .await;
+    }
+    let fulfillment_amount =
+        validator::validate_payout_fulfillment(&payout_data.payouts, req.amount)?;
+    let connector = req.connector.map(api_enums::Connector::from).or_else(|| {
+        payout_data
+            .payout_attempt
+            .connector
+            .as_deref()
+            .and_then(|connector| api_enums::Connector::from_str(connector).ok())
+    });
+    validator::validate_payout_tranche(
+        &payout_data.payouts,
+        &payout_data.payout_attempt,
+        fulfillment_amount,
+        connector,
+    )?;
+    payout_data.fulfillment_amount = Some(fulfillment_amount);
+
+    // Force the payout through the connector passed in the request
+    if let Some(connector) = req.connector {
+        return Box::pin(fulfill_payout_with_connector_override(
+            &state,
+            &merchant_account,
+            &key_store,
+            &req,
+            connector,
+            payout_data,
+        ))
+        .await;
+    }
+
+    // Fulfill the remaining amount of a partially fulfilled payout through a new attempt
+    if payout_data.payouts.status == storage_enums::PayoutStatus::PartiallyFulfilled {
+        return Box::pin(fulfill_payout_tranche(
+            &state,
+            &merchant_account,
+            &key_store,
+            &req,
+            payout_data,
+        ))
+        .await;
+    }
+
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let status = payout_attempt.status;
 
@@ -649,6 +1017,7 @@ pub async fn payouts_fulfill_core(
         .await?
         .get_required_value("payout_method_data")?,
     );
+    validator::validate_payout_data_compatibility(&payout_data)?;
     payout_data = fulfill_payout(
         &state,
         &merchant_account,
@@ -677,6 +1046,231 @@ pub async fn payouts_fulfill_core(
     .await
 }
 
+/// Fulfills the payout through the connector forced in the fulfill request, bypassing routing.
+/// A new attempt is created for the connector, which is run through payout creation and fulfillment.
+async fn fulfill_payout_with_connector_override(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutActionRequest,
+    connector: api_enums::PayoutConnectors,
+    mut payout_data: PayoutData,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    validator::validate_connector_override(&payout_data.payout_attempt)?;
+    // The connector is resolved the same way as for validating a payout request
+    let configured_connectors =
+        helpers::get_configured_payout_connectors(state, key_store, &payout_data.profile_id)
+            .await?;
+    let connector = helpers::resolve_payout_connector(Some(&[connector]), &configured_connectors)
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Connector {connector} is not configured for payouts"),
+        })?;
+    let routing_trail = payouts::PayoutRoutingTrail {
+        candidates: vec![connector.to_string()],
+        chosen_connector: Some(connector.to_string()),
+        skipped: vec![],
+        routing_algorithm: None,
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode payout routing trail")?;
+
+    // Form connector data
+    let connector_data = api::ConnectorData::get_payout_connector_by_name(
+        &state.conf.connectors,
+        &connector.to_string(),
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector data")?;
+    helpers::create_new_payout_attempt(
+        state,
+        &connector_data,
+        merchant_account,
+        &mut payout_data,
+        true,
+        Some(routing_trail),
+    )
+    .await?;
+
+    // Create payout with the connector
+    let create_request = payouts::PayoutCreateRequest {
+        payout_id: Some(req.payout_id.to_owned()),
+        confirm: Some(true),
+        ..Default::default()
+    };
+    payout_data = call_connector_payout(
+        state,
+        merchant_account,
+        key_store,
+        &create_request,
+        &connector_data,
+        payout_data,
+    )
+    .await?;
+
+    // Trigger fulfillment
+    if payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresFulfillment {
+        payout_data = fulfill_payout(
+            state,
+            merchant_account,
+            key_store,
+            &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable("Payout fulfillment failed for given Payout request")?;
+    }
+
+    let payout_attempt = &payout_data.payout_attempt;
+    if helpers::is_payout_err_state(payout_attempt.status) {
+        return Err(report!(errors::ApiErrorResponse::PayoutFailed {
+            data: Some(
+                serde_json::json!({"payout_status": payout_attempt.status.to_string(), "error_message": payout_attempt.error_message, "error_code": payout_attempt.error_code})
+            ),
+        }));
+    }
+
+    response_handler(
+        state,
+        merchant_account,
+        &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+        &payout_data,
+    )
+    .await
+}
+
+/// Fulfills a tranche of a partially fulfilled payout. A new attempt is created for the connector
+/// of the previous tranche, which is run through payout creation and fulfillment.
+async fn fulfill_payout_tranche(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutActionRequest,
+    mut payout_data: PayoutData,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    // Form connector data
+    let connector_data = api::ConnectorData::get_payout_connector_by_name(
+        &state.conf.connectors,
+        payout_data
+            .payout_attempt
+            .connector
+            .as_ref()
+            .get_required_value("connector")?,
+        api::GetToken::Connector,
+        payout_data.payout_attempt.merchant_connector_id.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector data")?;
+    let manual_routing_override = payout_data.payout_attempt.manual_routing_override;
+    let routing_trail = payout_data.payout_attempt.routing_trail.clone();
+    helpers::create_new_payout_attempt(
+        state,
+        &connector_data,
+        merchant_account,
+        &mut payout_data,
+        manual_routing_override,
+        routing_trail,
+    )
+    .await?;
+
+    // Create payout with the connector
+    let create_request = payouts::PayoutCreateRequest {
+        payout_id: Some(req.payout_id.to_owned()),
+        confirm: Some(true),
+        ..Default::default()
+    };
+    payout_data = call_connector_payout(
+        state,
+        merchant_account,
+        key_store,
+        &create_request,
+        &connector_data,
+        payout_data,
+    )
+    .await?;
+
+    // Trigger fulfillment
+    if payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresFulfillment {
+        payout_data = fulfill_payout(
+            state,
+            merchant_account,
+            key_store,
+            &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable("Payout fulfillment failed for given Payout request")?;
+    }
+
+    let payout_attempt = &payout_data.payout_attempt;
+    if helpers::is_payout_err_state(payout_attempt.status) {
+        // The tranches fulfilled earlier are retained, so that the remaining amount can be fulfilled again
+        if payout_data.payouts.status != storage_enums::PayoutStatus::PartiallyFulfilled {
+            let previous_status = payout_data.payouts.status;
+            payout_data.payouts = state
+                .store
+                .update_payout(
+                    &payout_data.payouts,
+                    storage::PayoutsUpdate::StatusUpdate {
+                        status: storage_enums::PayoutStatus::PartiallyFulfilled,
+                    },
+                    payout_attempt,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, &payout_data, Some(previous_status));
+        }
+        return Err(report!(errors::ApiErrorResponse::PayoutFailed {
+            data: Some(
+                serde_json::json!({"payout_status": payout_attempt.status.to_string(), "error_message": payout_attempt.error_message, "error_code": payout_attempt.error_code})
+            ),
+        }));
+    }
+
+    response_handler(
+        state,
+        merchant_account,
+        &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+        &payout_data,
+    )
+    .await
+}
+
+/// Lists the audit trail of the status transitions of the payout, in the order they happened
+#[instrument(skip_all)]
+pub async fn payouts_list_events_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutEventsRequest,
+) -> RouterResponse<payouts::PayoutEventsResponse> {
+    let db = &*state.store;
+    let payouts = db
+        .find_payout_by_merchant_id_payout_id(
+            &merchant_account.merchant_id,
+            &req.payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let events =
+        audit::list_payout_events(db, &merchant_account.merchant_id, &payouts.payout_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutEventsResponse {
+            payout_id: payouts.payout_id,
+            events,
+        },
+    ))
+}
+
 #[cfg(feature = "olap")]
 pub async fn payouts_list_core(
     state: AppState,
@@ -791,68 +1385,403 @@ pub async fn payouts_filtered_list_core(
             &filters.clone().into(),
             merchant_account.storage_scheme,
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let data: Vec<api::PayoutCreateResponse> = join_all(list.into_iter().map(|(p, pa, c)| async {
+        match domain::Customer::convert_back(c, &key_store.key).await {
+            Ok(domain_cust) => Some((p, pa, domain_cust)),
+            Err(err) => {
+                logger::warn!(?err, "failed to convert customer for id: {}", p.customer_id);
+                None
+            }
+        }
+    }))
+    .await
+    .into_iter()
+    .flatten()
+    .map(ForeignFrom::foreign_from)
+    .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+pub async fn payouts_list_available_filters_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api::TimeRange,
+) -> RouterResponse<api::PayoutListFilters> {
+    let db = state.store.as_ref();
+    let payout = db
+        .filter_payouts_by_time_range_constraints(
+            &merchant_account.merchant_id,
+            &time_range,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let filters = db
+        .get_filters_for_payouts(
+            payout.as_slice(),
+            &merchant_account.merchant_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PayoutListFilters {
+            connector: filters.connector,
+            currency: filters.currency,
+            status: filters.status,
+            payout_method: filters.payout_method,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+pub async fn payouts_export_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api::TimeRange,
+) -> RouterResponse<()> {
+    utils::when(
+        time_range
+            .end_time
+            .is_some_and(|end_time| end_time < time_range.start_time),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "end_time should not be earlier than start_time".to_string(),
+            }))
+        },
+    )?;
+
+    let csv = helpers::export_payouts_as_csv(
+        state.store.clone(),
+        merchant_account.merchant_id,
+        time_range,
+        consts::PAYOUTS_EXPORT_PAGE_SIZE,
+        merchant_account.storage_scheme,
+    );
+
+    Ok(services::ApplicationResponse::FileDataStream((
+        services::FileDataStream(csv),
+        mime::TEXT_CSV,
+    )))
+}
+
+pub async fn payouts_required_fields_core(
+    state: AppState,
+    req: payouts::PayoutRequiredFieldsRequest,
+) -> RouterResponse<payouts::PayoutRequiredFieldsResponse> {
+    Ok(services::ApplicationResponse::Json(
+        validator::get_payout_required_fields(&state.conf.payouts.required_fields, &req),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_list_saved_payout_methods_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: String,
+) -> RouterResponse<payouts::CustomerPayoutMethodsListResponse> {
+    let db = &*state.store;
+    db.find_customer_by_customer_id_merchant_id(
+        &customer_id,
+        &merchant_account.merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let saved_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+            &customer_id,
+            &merchant_account.merchant_id,
+            storage_enums::PaymentMethodStatus::Active,
+            helpers::PAYOUT_CAPABLE_PAYMENT_METHODS.to_vec(),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch saved payout methods for customer")?;
+
+    let mut customer_payout_methods = Vec::with_capacity(saved_payment_methods.len());
+    for payment_method in saved_payment_methods.iter() {
+        // Entries which were not saved as payout methods cannot be parsed, skip them
+        match helpers::get_saved_payout_method_data(&state, payment_method, &key_store).await {
+            Ok(payout_method_data) => customer_payout_methods.push(
+                helpers::get_customer_payout_method(payment_method, &payout_method_data),
+            ),
+            Err(err) => logger::warn!(
+                payment_method_id = %payment_method.payment_method_id,
+                saved_payout_method_fetch_error=?err
+            ),
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::CustomerPayoutMethodsListResponse {
+            customer_payout_methods,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payouts_delete_saved_payout_method_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::CustomerPayoutMethodId,
+) -> RouterResponse<payouts::PayoutMethodDeleteResponse> {
+    let db = &*state.store;
+    let payment_method = helpers::find_saved_payout_method(
+        db,
+        &merchant_account.merchant_id,
+        &req.customer_id,
+        &req.payout_method_id,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    payment_methods::cards::delete_card_from_hs_locker(
+        &state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await
+    .attach_printable("Failed to delete saved payout method from locker")?;
+
+    db.delete_payment_method_by_merchant_id_payment_method_id(
+        &merchant_account.merchant_id,
+        &payment_method.payment_method_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutMethodDeleteResponse {
+            payout_method_id: payment_method.payment_method_id,
+            deleted: true,
+        },
+    ))
+}
+
+// ********************************************** HELPERS **********************************************
+pub async fn schedule_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &mut PayoutData,
+    scheduled_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    // Store payout_method_data in locker, to be fetched by the scheduled task
+    if let (Some(payout_method_data), None) = (
+        payout_data.payout_method_data.to_owned(),
+        payout_data.payout_attempt.payout_token.as_ref(),
+    ) {
+        let payout_attempt = payout_data.payout_attempt.to_owned();
+        let payout_type = payout_data.payouts.payout_type;
+        helpers::make_payout_method_data(
+            state,
+            Some(&payout_method_data),
+            None,
+            &payout_attempt.customer_id,
+            &payout_attempt.merchant_id,
+            Some(&payout_type),
+            key_store,
+            Some(payout_data),
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    }
+
+    helpers::add_scheduled_payout_task(&*state.store, &payout_data.payouts, scheduled_at).await
+}
+
+/// Re-drives the payouts held for the KYC of the customer through fulfillment, once the customer
+/// is verified. Returns the ids of the payouts which were fulfilled.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn release_held_payouts(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+) -> RouterResult<Vec<String>> {
+    let held_payouts = helpers::filter_payouts_held_for_recipient_kyc(
+        &*state.store,
+        customer_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the payouts held for the KYC of the customer")?;
+
+    let release_actor = payouts::PayoutEventActor::InternalJob {
+        job_name: "release_held_payouts".to_string(),
+    };
+    let mut released_payout_ids = Vec::new();
+    for held_payout in held_payouts {
+        let req = payouts::PayoutActionRequest {
+            payout_id: held_payout.payout_id,
+            connector: None,
+            amount: None,
+        };
+        let mut payout_data = make_payout_data(
+            state,
+            merchant_account,
+            key_store,
+            &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
+            release_actor.clone(),
+        )
+        .await?;
+        if payout_data.payout_attempt.status
+            != storage_enums::PayoutStatus::RequiresVendorAccountCreation
+        {
+            continue;
+        }
 
-    let data: Vec<api::PayoutCreateResponse> = join_all(list.into_iter().map(|(p, pa, c)| async {
-        match domain::Customer::convert_back(c, &key_store.key).await {
-            Ok(domain_cust) => Some((p, pa, domain_cust)),
-            Err(err) => {
-                logger::warn!(?err, "failed to convert customer for id: {}", p.customer_id);
-                None
-            }
+        helpers::update_payout_and_attempt_status(
+            state,
+            merchant_account,
+            &mut payout_data,
+            storage_enums::PayoutStatus::RequiresFulfillment,
+            None,
+            None,
+        )
+        .await?;
+        match Box::pin(payouts_fulfill_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            req.to_owned(),
+            release_actor.clone(),
+        ))
+        .await
+        {
+            Ok(_) => released_payout_ids.push(req.payout_id),
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to fulfill payout {} released after KYC verification",
+                req.payout_id
+            ),
         }
-    }))
-    .await
-    .into_iter()
-    .flatten()
-    .map(ForeignFrom::foreign_from)
-    .collect();
+    }
 
-    Ok(services::ApplicationResponse::Json(
-        api::PayoutListResponse {
-            size: data.len(),
-            data,
-        },
-    ))
+    Ok(released_payout_ids)
 }
 
-#[cfg(feature = "olap")]
-pub async fn payouts_list_available_filters_core(
-    state: AppState,
-    merchant_account: domain::MerchantAccount,
-    time_range: api::TimeRange,
-) -> RouterResponse<api::PayoutListFilters> {
-    let db = state.store.as_ref();
-    let payout = db
-        .filter_payouts_by_time_range_constraints(
-            &merchant_account.merchant_id,
-            &time_range,
+#[instrument(skip_all)]
+pub async fn process_scheduled_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_id: &str,
+) -> RouterResult<PayoutData> {
+    let mut payout_data = make_payout_data(
+        state,
+        merchant_account,
+        key_store,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: payout_id.to_owned(),
+            connector: None,
+            amount: None,
+        }),
+        payouts::PayoutEventActor::InternalJob {
+            job_name: "scheduled_payouts".to_string(),
+        },
+    )
+    .await?;
+
+    // Payouts cancelled before the scheduled time are not processed
+    if payout_data.payout_attempt.status != storage_enums::PayoutStatus::Scheduled {
+        logger::info!(
+            "Skipping scheduled payout {} in status {}",
+            payout_id,
+            payout_data.payout_attempt.status
+        );
+        return Ok(payout_data);
+    }
+
+    let previous_status = payout_data.payouts.status;
+    let status = storage_enums::PayoutStatus::RequiresCreation;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_message: None,
+        error_code: None,
+        is_eligible: None,
+    };
+    payout_data.payout_attempt = state
+        .store
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
             merchant_account.storage_scheme,
         )
         .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
-
-    let filters = db
-        .get_filters_for_payouts(
-            payout.as_slice(),
-            &merchant_account.merchant_id,
-            storage_enums::MerchantStorageScheme::PostgresOnly,
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = state
+        .store
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
         )
         .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    audit::record_status_transition(state, &payout_data, Some(previous_status));
+
+    let req = payouts::PayoutCreateRequest {
+        payout_id: Some(payout_id.to_owned()),
+        merchant_id: Some(merchant_account.merchant_id.to_owned()),
+        confirm: Some(true),
+        auto_fulfill: Some(payout_data.payouts.auto_fulfill),
+        payout_type: Some(payout_data.payouts.payout_type),
+        payout_token: payout_data.payout_attempt.payout_token.to_owned(),
+        ..Default::default()
+    };
 
-    Ok(services::ApplicationResponse::Json(
-        api::PayoutListFilters {
-            connector: filters.connector,
-            currency: filters.currency,
-            status: filters.status,
-            payout_method: filters.payout_method,
-        },
+    let connector_call_type = get_connector_choice(
+        state,
+        merchant_account,
+        key_store,
+        None,
+        payout_data.payout_attempt.routing_info.clone(),
+        &mut payout_data,
+        None,
+    )
+    .await?;
+
+    Box::pin(make_connector_decision(
+        state,
+        merchant_account,
+        key_store,
+        &req,
+        connector_call_type,
+        payout_data,
     ))
+    .await
 }
 
-// ********************************************** HELPERS **********************************************
 pub async fn call_connector_payout(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -864,6 +1793,13 @@ pub async fn call_connector_payout(
     let payout_attempt = &payout_data.payout_attempt.to_owned();
     let payouts = &payout_data.payouts.to_owned();
 
+    // Payout priority
+    validator::validate_payout_priority(
+        payouts.priority,
+        payouts.payout_type,
+        &[connector_data.connector_name],
+    )?;
+
     // update connector_name
     if payout_data.payout_attempt.connector.is_none()
         || payout_data.payout_attempt.connector != Some(connector_data.connector_name.to_string())
@@ -872,6 +1808,7 @@ pub async fn call_connector_payout(
         let updated_payout_attempt = storage::PayoutAttemptUpdate::UpdateRouting {
             connector: connector_data.connector_name.to_string(),
             routing_info: payout_data.payout_attempt.routing_info.clone(),
+            routing_trail: payout_data.payout_attempt.routing_trail.clone(),
         };
         let db = &*state.store;
         payout_data.payout_attempt = db
@@ -888,10 +1825,15 @@ pub async fn call_connector_payout(
 
     // Fetch / store payout_method_data
     if payout_data.payout_method_data.is_none() || payout_attempt.payout_token.is_none() {
+        // Saved payout methods are not passed in the request, but fetched while validating it
+        let payout_method_data = req
+            .payout_method_data
+            .clone()
+            .or(payout_data.payout_method_data.clone());
         payout_data.payout_method_data = Some(
             helpers::make_payout_method_data(
                 state,
-                req.payout_method_data.as_ref(),
+                payout_method_data.as_ref(),
                 payout_attempt.payout_token.as_deref(),
                 &payout_attempt.customer_id,
                 &payout_attempt.merchant_id,
@@ -903,6 +1845,20 @@ pub async fn call_connector_payout(
             .await?
             .get_required_value("payout_method_data")?,
         );
+        validator::validate_payout_data_compatibility(&payout_data)?;
+    }
+
+    // Fields required by the connector for the payout method
+    if let (Some(payout_method_data), Ok(connector)) = (
+        payout_data.payout_method_data.as_ref(),
+        api_enums::PayoutConnectors::try_from(connector_data.connector_name),
+    ) {
+        validator::validate_payout_required_fields(
+            &state.conf.payouts.required_fields,
+            connector,
+            payout_method_data,
+            payout_data.billing_address.as_ref(),
+        )?;
     }
 
     if let Some(true) = req.confirm {
@@ -1156,6 +2112,7 @@ pub async fn check_payout_eligibility(
 
     // 4. Process data returned by the connector
     let db = &*state.store;
+    let previous_status = payout_data.payouts.status;
     match router_data_resp.response {
         Ok(payout_response_data) => {
             let payout_attempt = &payout_data.payout_attempt;
@@ -1189,6 +2146,7 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1226,6 +2184,7 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
         }
     };
 
@@ -1247,6 +2206,7 @@ pub async fn complete_create_payout(
         {
             // create payout_object only in router
             let db = &*state.store;
+            let previous_status = payout_data.payouts.status;
             let payout_attempt = &payout_data.payout_attempt;
             let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
                 connector_payout_id: "".to_string(),
@@ -1277,6 +2237,7 @@ pub async fn complete_create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, &payout_data, Some(previous_status));
         } else {
             // create payout_object in connector as well as router
             payout_data = create_payout(
@@ -1350,6 +2311,7 @@ pub async fn create_payout(
 
     // 6. Process data returned by the connector
     let db = &*state.store;
+    let previous_status = payout_data.payouts.status;
     match router_data_resp.response {
         Ok(payout_response_data) => {
             let payout_attempt = &payout_data.payout_attempt;
@@ -1383,6 +2345,7 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1420,6 +2383,7 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
         }
     };
 
@@ -1466,6 +2430,7 @@ pub async fn cancel_payout(
 
     // 4. Process data returned by the connector
     let db = &*state.store;
+    let previous_status = payout_data.payouts.status;
     match router_data_resp.response {
         Ok(payout_response_data) => {
             let status = payout_response_data
@@ -1498,6 +2463,7 @@ pub async fn cancel_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
         }
         Err(err) => {
             let status = storage_enums::PayoutStatus::Failed;
@@ -1528,6 +2494,7 @@ pub async fn cancel_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
         }
     };
 
@@ -1542,6 +2509,33 @@ pub async fn fulfill_payout(
     connector_data: &api::ConnectorData,
     payout_data: &mut PayoutData,
 ) -> RouterResult<PayoutData> {
+    // Hold the payout without calling the connector until the KYC of the recipient is verified
+    if let Some(hold_reason) = validator::get_recipient_kyc_hold_reason(
+        payout_data
+            .business_profile
+            .require_recipient_kyc
+            .unwrap_or(false),
+        payout_data
+            .customer_details
+            .as_ref()
+            .and_then(|customer| customer.kyc_status),
+    ) {
+        logger::info!(
+            "Holding payout {} for the KYC of the recipient",
+            payout_data.payouts.payout_id
+        );
+        helpers::update_payout_and_attempt_status(
+            state,
+            merchant_account,
+            payout_data,
+            storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+            Some(consts::PAYOUT_RECIPIENT_KYC_PENDING_ERROR_CODE.to_string()),
+            Some(hold_reason),
+        )
+        .await?;
+        return Ok(payout_data.clone());
+    }
+
     // 1. Form Router data
     let mut router_data = core_utils::construct_payout_router_data(
         state,
@@ -1563,7 +2557,40 @@ pub async fn fulfill_payout(
     )
     .await?;
 
-    // 3. Fetch connector integration details
+    // 3. Validate the connector balance, payout stays in its current state on failure
+    if payout_data
+        .business_profile
+        .check_balance_before_fulfill
+        .unwrap_or(false)
+    {
+        let available_balance = get_payout_balance(state, connector_data, &router_data).await;
+        helpers::validate_connector_balance(
+            available_balance,
+            payout_data.get_fulfillment_amount(),
+            payout_data.payouts.source_currency,
+        )?;
+    }
+
+    // 4. Record the amount paid out by the attempt, it is counted towards the fulfilled amount of
+    // the payout only once the connector reports it fulfilled
+    let db = &*state.store;
+    let fulfillment_amount = payout_data.get_fulfillment_amount();
+    if payout_data.payout_attempt.amount != Some(fulfillment_amount) {
+        payout_data.payout_attempt = db
+            .update_payout_attempt(
+                &payout_data.payout_attempt,
+                storage::PayoutAttemptUpdate::AmountUpdate {
+                    amount: fulfillment_amount,
+                },
+                &payout_data.payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payout_attempt in db")?;
+    }
+
+    // 5. Fetch connector integration details
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         api::PoFulfill,
@@ -1571,7 +2598,7 @@ pub async fn fulfill_payout(
         types::PayoutsResponseData,
     > = connector_data.connector.get_connector_integration();
 
-    // 4. Call connector service
+    // 6. Call connector service
     let router_data_resp = services::execute_connector_processing_step(
         state,
         connector_integration,
@@ -1582,8 +2609,9 @@ pub async fn fulfill_payout(
     .await
     .to_payout_failed_response()?;
 
-    // 5. Process data returned by the connector
-    let db = &*state.store;
+    // 7. Process data returned by the connector
+    let previous_status = payout_data.payouts.status;
+    let previous_tranche_status = payout_data.payout_attempt.status;
     match router_data_resp.response {
         Ok(payout_response_data) => {
             let status = payout_response_data
@@ -1623,16 +2651,26 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            let (fulfilled_amount, payout_status) = helpers::get_payout_fulfillment_status(
+                &payout_data.payouts,
+                fulfillment_amount,
+                previous_tranche_status,
+                status,
+            );
             payout_data.payouts = db
                 .update_payout(
                     &payout_data.payouts,
-                    storage::PayoutsUpdate::StatusUpdate { status },
+                    storage::PayoutsUpdate::FulfillmentUpdate {
+                        status: payout_status,
+                        fulfilled_amount,
+                    },
                     &payout_data.payout_attempt,
                     merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1660,32 +2698,224 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            let (fulfilled_amount, payout_status) = helpers::get_payout_fulfillment_status(
+                &payout_data.payouts,
+                fulfillment_amount,
+                previous_tranche_status,
+                status,
+            );
             payout_data.payouts = db
                 .update_payout(
                     &payout_data.payouts,
-                    storage::PayoutsUpdate::StatusUpdate { status },
+                    storage::PayoutsUpdate::FulfillmentUpdate {
+                        status: payout_status,
+                        fulfilled_amount,
+                    },
                     &payout_data.payout_attempt,
                     merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            audit::record_status_transition(state, payout_data, Some(previous_status));
         }
     };
 
     Ok(payout_data.clone())
 }
 
+/// Syncs the payout status with the connector, updating the payout and its attempt with the
+/// reported status or error. Returns `false` when the connector does not support payout sync.
+#[instrument(skip_all)]
+pub async fn sync_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutRequest,
+    payout_data: &mut PayoutData,
+) -> RouterResult<bool> {
+    // 1. Form connector data
+    let connector_data = api::ConnectorData::get_payout_connector_by_name(
+        &state.conf.connectors,
+        payout_data
+            .payout_attempt
+            .connector
+            .as_ref()
+            .get_required_value("connector")?,
+        api::GetToken::Connector,
+        payout_data.payout_attempt.merchant_connector_id.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector data")?;
+    if !connector_data.connector.supports_payout_sync() {
+        return Ok(false);
+    }
+
+    // 2. Form Router data
+    let mut router_data = core_utils::construct_payout_router_data(
+        state,
+        &connector_data.connector_name.to_string(),
+        merchant_account,
+        key_store,
+        req,
+        payout_data,
+    )
+    .await?;
+
+    // 3. Get/Create access token
+    access_token::create_access_token(
+        state,
+        &connector_data,
+        merchant_account,
+        &mut router_data,
+        payout_data.payouts.payout_type.to_owned(),
+    )
+    .await?;
+
+    // 4. Fetch connector integration details
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PoSync,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 5. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payout_failed_response()?;
+
+    // 6. Process data returned by the connector
+    let updated_payout_attempt = match helpers::get_payout_sync_result(
+        &payout_data.payout_attempt,
+        router_data_resp.response,
+    ) {
+        helpers::PayoutSyncResult::Unchanged => return Ok(true),
+        helpers::PayoutSyncResult::Updated(updated_payout_attempt) => updated_payout_attempt,
+    };
+    let db = &*state.store;
+    let previous_status = payout_data.payouts.status;
+    let previous_tranche_status = payout_data.payout_attempt.status;
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    let (fulfilled_amount, payout_status) = helpers::get_payout_fulfillment_status(
+        &payout_data.payouts,
+        helpers::get_tranche_amount(&payout_data.payouts, &payout_data.payout_attempt),
+        previous_tranche_status,
+        payout_data.payout_attempt.status,
+    );
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::FulfillmentUpdate {
+                status: payout_status,
+                fulfilled_amount,
+            },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    audit::record_status_transition(state, payout_data, Some(previous_status));
+
+    Ok(true)
+}
+
+/// Fetches the available balance of the connector account for the payout's source currency.
+/// Returns `None` when the connector does not expose a balance API or the balance could not be fetched.
+pub async fn get_payout_balance(
+    state: &AppState,
+    connector_data: &api::ConnectorData,
+    router_data: &types::PayoutsRouterData<api::PoFulfill>,
+) -> Option<i64> {
+    let balance_router_data: types::PayoutsBalanceRouterData =
+        payment_helpers::router_data_type_conversion(
+            router_data.clone(),
+            router_data.request.clone(),
+            Ok(types::PayoutsBalanceResponseData::default()),
+        );
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PoBalance,
+        types::PayoutsData,
+        types::PayoutsBalanceResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &balance_router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .map_err(|error| logger::warn!(?error, "Failed to fetch payout balance from connector"))
+    .ok()?;
+
+    router_data_resp
+        .response
+        .map_err(|error| {
+            logger::warn!(
+                error_code = %error.code,
+                error_message = %error.message,
+                "Connector returned an error while fetching payout balance"
+            )
+        })
+        .ok()
+        .and_then(|balance_response| balance_response.available_balance)
+}
+
 pub async fn response_handler(
-    _state: &AppState,
+    state: &AppState,
     merchant_account: &domain::MerchantAccount,
-    _req: &payouts::PayoutRequest,
+    req: &payouts::PayoutRequest,
     payout_data: &PayoutData,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let payouts = payout_data.payouts.to_owned();
+
+    let attempts = match req {
+        payouts::PayoutRequest::PayoutRetrieveRequest(payouts::PayoutRetrieveRequest {
+            expand: Some(payouts::PayoutExpandableField::Attempts),
+            ..
+        }) => Some(
+            helpers::get_payout_attempts(&*state.store, &payouts, merchant_account.storage_scheme)
+                .await?,
+        ),
+        _ => None,
+    };
+    let routing = match req {
+        payouts::PayoutRequest::PayoutRetrieveRequest(payouts::PayoutRetrieveRequest {
+            expand: Some(payouts::PayoutExpandableField::Routing),
+            ..
+        }) => payout_attempt
+            .routing_trail
+            .clone()
+            .map(|routing_trail| routing_trail.parse_value("PayoutRoutingTrail"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout routing trail")?,
+        _ => None,
+    };
     let billing_address = payout_data.billing_address.to_owned();
     let customer_details = payout_data.customer_details.to_owned();
+    let converted_amount = fx::get_converted_amount(&payouts);
     let customer_id = payouts.customer_id;
 
     let (email, name, phone, phone_country_code) = customer_details
@@ -1738,12 +2968,23 @@ pub async fn response_handler(
         entity_type: payouts.entity_type.to_owned(),
         recurring: payouts.recurring,
         metadata: payouts.metadata,
-        status: payout_attempt.status.to_owned(),
+        status: helpers::get_payout_status(&payouts, &payout_attempt),
         error_message: payout_attempt.error_message.to_owned(),
         error_code: payout_attempt.error_code,
         profile_id: payout_attempt.profile_id,
         created: Some(payouts.created_at),
-        attempts: None,
+        scheduled_at: payouts.scheduled_at,
+        source_currency: payouts.source_currency,
+        fx_rate: payouts.fx_rate.to_owned(),
+        converted_amount,
+        fx_quote_expires_at: payouts.fx_quote_expires_at,
+        priority: payouts.priority,
+        fulfilled_amount: payouts.fulfilled_amount,
+        payout_schedule_id: payouts.payout_schedule_id,
+        source_payment_id: payouts.source_payment_id,
+        attempts,
+        connector_sync_supported: None,
+        routing,
     };
     Ok(services::ApplicationResponse::Json(response))
 }
@@ -1758,6 +2999,8 @@ pub async fn payout_create_db_entries(
     payout_id: &String,
     profile_id: &String,
     stored_payout_method_data: Option<&payouts::PayoutMethodData>,
+    fx_quote: Option<&fx::FxQuote>,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResult<PayoutData> {
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
@@ -1815,11 +3058,24 @@ pub async fn payout_create_db_entries(
         .get_required_value("payout_type")?;
 
     let payout_method_id = if stored_payout_method_data.is_some() {
-        req.payout_token.to_owned()
+        req.payout_method_id
+            .to_owned()
+            .or(req.payout_token.to_owned())
     } else {
         None
     };
 
+    let status = if req.scheduled_at.is_some() {
+        storage_enums::PayoutStatus::Scheduled
+    } else if req.payout_method_data.is_some()
+        || req.payout_token.is_some()
+        || stored_payout_method_data.is_some()
+    {
+        storage_enums::PayoutStatus::RequiresCreation
+    } else {
+        storage_enums::PayoutStatus::RequiresPayoutMethodData
+    };
+
     let payouts_req = storage::PayoutsNew {
         payout_id: payout_id.to_string(),
         merchant_id: merchant_id.to_string(),
@@ -1828,7 +3084,7 @@ pub async fn payout_create_db_entries(
         payout_type,
         amount: req.amount.unwrap_or(api::Amount::Zero).into(),
         destination_currency: currency,
-        source_currency: currency,
+        source_currency: req.source_currency.unwrap_or(currency),
         description: req.description.to_owned(),
         recurring: req.recurring.unwrap_or(false),
         auto_fulfill: req.auto_fulfill.unwrap_or(false),
@@ -1836,7 +3092,14 @@ pub async fn payout_create_db_entries(
         entity_type: req.entity_type.unwrap_or_default(),
         payout_method_id,
         profile_id: profile_id.to_string(),
+        status,
         attempt_count: 1,
+        scheduled_at: req.scheduled_at,
+        fx_rate: fx_quote.map(|fx_quote| fx_quote.rate.to_string()),
+        fx_quote_expires_at: fx_quote.map(|fx_quote| fx_quote.expires_at),
+        priority: req.priority.unwrap_or_default(),
+        payout_schedule_id: req.payout_schedule_id.to_owned(),
+        source_payment_id: req.source_payment_id.to_owned(),
         ..Default::default()
     };
     let payouts = db
@@ -1848,14 +3111,6 @@ pub async fn payout_create_db_entries(
         .attach_printable("Error inserting payouts in db")?;
 
     // Make payout_attempt entry
-    let status = if req.payout_method_data.is_some()
-        || req.payout_token.is_some()
-        || stored_payout_method_data.is_some()
-    {
-        storage_enums::PayoutStatus::RequiresCreation
-    } else {
-        storage_enums::PayoutStatus::RequiresPayoutMethodData
-    };
     let payout_attempt_id = utils::get_payment_attempt_id(payout_id, 1);
 
     let payout_attempt_req = storage::PayoutAttemptNew {
@@ -1869,6 +3124,7 @@ pub async fn payout_create_db_entries(
         business_label: req.business_label.to_owned(),
         payout_token: req.payout_token.to_owned(),
         profile_id: profile_id.to_string(),
+        routing_info: req.routing.to_owned(),
         ..Default::default()
     };
     let payout_attempt = db
@@ -1901,6 +3157,8 @@ pub async fn payout_create_db_entries(
             .cloned()
             .or(stored_payout_method_data.cloned()),
         profile_id: profile_id.to_owned(),
+        fulfillment_amount: None,
+        audit_actor,
     })
 }
 
@@ -1909,6 +3167,7 @@ pub async fn make_payout_data(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
     req: &payouts::PayoutRequest,
+    audit_actor: payouts::PayoutEventActor,
 ) -> RouterResult<PayoutData> {
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
@@ -1975,6 +3234,8 @@ pub async fn make_payout_data(
         payout_method_data: None,
         merchant_connector_account: None,
         profile_id,
+        fulfillment_amount: None,
+        audit_actor,
     })
 }
 
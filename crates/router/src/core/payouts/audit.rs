@@ -0,0 +1,420 @@
+use api_models::payouts::{PayoutEvent, PayoutEventActor};
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+use super::{helpers, PayoutData};
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::{app::ReqState, AppState},
+    services::authentication::AuthenticationType,
+    types::storage::{self, enums as storage_enums},
+};
+
+/// Details of a status transition of a payout, stored as a row of the payout audit trail
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PayoutAuditEventDetails {
+    pub actor: PayoutEventActor,
+    pub previous_status: Option<storage_enums::PayoutStatus>,
+    pub new_status: storage_enums::PayoutStatus,
+    pub reason: Option<String>,
+}
+
+/// Returns who the status transitions made while serving the request are attributed to
+pub fn get_request_actor(req_state: &ReqState) -> PayoutEventActor {
+    match &req_state.auth_type {
+        Some(AuthenticationType::ApiKey { key_id, .. }) => PayoutEventActor::ApiKey {
+            key_id: key_id.to_owned(),
+        },
+        Some(AuthenticationType::AdminApiKey) => PayoutEventActor::AdminApiKey,
+        Some(AuthenticationType::MerchantJwt { user_id, .. }) => PayoutEventActor::User {
+            user_id: user_id.to_owned(),
+        },
+        Some(AuthenticationType::UserJwt { user_id }) => PayoutEventActor::User {
+            user_id: Some(user_id.to_owned()),
+        },
+        Some(
+            AuthenticationType::MerchantId { merchant_id }
+            | AuthenticationType::PublishableKey { merchant_id }
+            | AuthenticationType::WebhookAuth { merchant_id },
+        ) => PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_owned(),
+        },
+        Some(AuthenticationType::NoAuth) | None => PayoutEventActor::Unauthenticated,
+    }
+}
+
+/// Returns the type of the event recording the transition of a payout between the statuses.
+/// A payout without a previous status was just created.
+pub fn get_event_type(
+    previous_status: Option<storage_enums::PayoutStatus>,
+    new_status: storage_enums::PayoutStatus,
+) -> storage_enums::PayoutAuditEventType {
+    match (previous_status, new_status) {
+        (None, _) => storage_enums::PayoutAuditEventType::Created,
+        (
+            Some(_),
+            storage_enums::PayoutStatus::Success | storage_enums::PayoutStatus::PartiallyFulfilled,
+        ) => storage_enums::PayoutAuditEventType::Fulfilled,
+        (
+            Some(_),
+            storage_enums::PayoutStatus::Failed | storage_enums::PayoutStatus::Ineligible,
+        ) => storage_enums::PayoutAuditEventType::Failed,
+        (Some(_), storage_enums::PayoutStatus::Cancelled) => {
+            storage_enums::PayoutAuditEventType::Cancelled
+        }
+        (
+            Some(_),
+            storage_enums::PayoutStatus::Pending
+            | storage_enums::PayoutStatus::RequiresCreation
+            | storage_enums::PayoutStatus::RequiresPayoutMethodData
+            | storage_enums::PayoutStatus::RequiresFulfillment
+            | storage_enums::PayoutStatus::Scheduled
+            | storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+        ) => storage_enums::PayoutAuditEventType::Confirmed,
+    }
+}
+
+/// Records the transition of the payout from `previous_status` to its current status, nothing is
+/// recorded if the status did not change. The event is stored in the background, so failing to
+/// store it never fails or delays the payout.
+pub fn record_status_transition(
+    state: &AppState,
+    payout_data: &PayoutData,
+    previous_status: Option<storage_enums::PayoutStatus>,
+) {
+    let new_status = payout_data.payouts.status;
+    if previous_status == Some(new_status) {
+        return;
+    }
+    // The error returned by the connector is the reason for failing the payout
+    let reason = helpers::is_payout_err_state(new_status)
+        .then(|| {
+            payout_data
+                .payout_attempt
+                .error_message
+                .clone()
+                .or(payout_data.payout_attempt.error_code.clone())
+        })
+        .flatten();
+    let details = PayoutAuditEventDetails {
+        actor: payout_data.audit_actor.clone(),
+        previous_status,
+        new_status,
+        reason,
+    };
+    spawn_audit_event(
+        state,
+        &payout_data.payouts,
+        get_event_type(previous_status, new_status),
+        details,
+    );
+}
+
+/// Records the retry of the payout with a new attempt. The event is stored in the background, so
+/// failing to store it never fails or delays the payout.
+pub fn record_retry(
+    state: &AppState,
+    payout_data: &PayoutData,
+    previous_status: storage_enums::PayoutStatus,
+) {
+    let details = PayoutAuditEventDetails {
+        actor: payout_data.audit_actor.clone(),
+        previous_status: Some(previous_status),
+        new_status: payout_data.payout_attempt.status,
+        reason: payout_data
+            .payout_attempt
+            .connector
+            .as_ref()
+            .map(|connector| format!("Retrying the payout with {connector}")),
+    };
+    spawn_audit_event(
+        state,
+        &payout_data.payouts,
+        storage_enums::PayoutAuditEventType::Retried,
+        details,
+    );
+}
+
+// The time of the transition is taken before spawning, so that events stored out of order are
+// still listed in the order the transitions occurred
+fn spawn_audit_event(
+    state: &AppState,
+    payouts: &storage::Payouts,
+    event_type: storage_enums::PayoutAuditEventType,
+    details: PayoutAuditEventDetails,
+) {
+    let audit_event = match get_audit_event_new(payouts, event_type, details) {
+        Ok(audit_event) => audit_event,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                payout_id = %payouts.payout_id,
+                %event_type,
+                "Failed to build the audit event of the payout"
+            );
+            return;
+        }
+    };
+    let db = state.store.clone();
+    // Using a tokio spawn here and not arbiter because not all callers of this function may have
+    // an actix arbiter
+    tokio::spawn(
+        async move {
+            store_audit_event(&*db, audit_event).await;
+        }
+        .in_current_span(),
+    );
+}
+
+/// Builds the row recording a status transition of the payout
+fn get_audit_event_new(
+    payouts: &storage::Payouts,
+    event_type: storage_enums::PayoutAuditEventType,
+    details: PayoutAuditEventDetails,
+) -> RouterResult<storage::PayoutAuditEventNew> {
+    let actor = details
+        .actor
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the actor of the payout audit event")?;
+    Ok(storage::PayoutAuditEventNew {
+        event_id: common_utils::generate_time_ordered_id("evt"),
+        merchant_id: payouts.merchant_id.clone(),
+        payout_id: payouts.payout_id.clone(),
+        event_type,
+        actor,
+        previous_status: details.previous_status,
+        new_status: details.new_status,
+        reason: details.reason,
+        created_at: common_utils::date_time::now(),
+    })
+}
+
+/// Stores the row recording a status transition of the payout, logging the failure to do so
+#[instrument(skip_all)]
+async fn store_audit_event(db: &dyn StorageInterface, audit_event: storage::PayoutAuditEventNew) {
+    let payout_id = audit_event.payout_id.clone();
+    let event_type = audit_event.event_type;
+    if let Err(error) = db.insert_payout_audit_event(audit_event).await {
+        logger::error!(
+            ?error,
+            %payout_id,
+            %event_type,
+            "Failed to store the audit event of the payout"
+        );
+    }
+}
+
+/// Lists the status transitions of the payout, ordered by the time they occurred
+#[instrument(skip_all)]
+pub async fn list_payout_events(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payout_id: &str,
+) -> RouterResult<Vec<PayoutEvent>> {
+    db.list_payout_audit_events_by_merchant_id_payout_id(merchant_id, payout_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the audit events of the payout")?
+        .into_iter()
+        .map(|event| {
+            let actor = event
+                .actor
+                .parse_value("PayoutEventActor")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the actor of the payout audit event")?;
+            Ok(PayoutEvent {
+                event_id: event.event_id,
+                event_type: event.event_type,
+                actor,
+                previous_status: event.previous_status,
+                new_status: event.new_status,
+                reason: event.reason,
+                created_at: event.created_at,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::db::MockDb;
+
+    fn get_payouts(status: storage_enums::PayoutStatus) -> storage::Payouts {
+        storage::Payouts {
+            payout_id: "payout_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            address_id: "addr_123".to_string(),
+            payout_type: storage_enums::PayoutType::Bank,
+            payout_method_id: None,
+            amount: 1000,
+            destination_currency: storage_enums::Currency::EUR,
+            source_currency: storage_enums::Currency::EUR,
+            description: None,
+            recurring: false,
+            auto_fulfill: true,
+            return_url: None,
+            entity_type: storage_enums::PayoutEntityType::Individual,
+            metadata: None,
+            created_at: datetime!(2024-05-10 10:00:00),
+            last_modified_at: datetime!(2024-05-10 10:00:00),
+            profile_id: "pro_123".to_string(),
+            status,
+            attempt_count: 1,
+            scheduled_at: None,
+            fx_rate: None,
+            fx_quote_expires_at: None,
+            priority: storage_enums::PayoutPriority::Standard,
+            fulfilled_amount: None,
+            payout_schedule_id: None,
+            source_payment_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_payout_audit_trail_is_listed_in_order() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let api_key_actor = PayoutEventActor::ApiKey {
+            key_id: "dev_key_123".to_string(),
+        };
+        let job_actor = PayoutEventActor::InternalJob {
+            job_name: "scheduled_payouts".to_string(),
+        };
+
+        // create -> confirm -> fail -> retry -> success
+        let transitions = [
+            (
+                storage_enums::PayoutAuditEventType::Created,
+                None,
+                storage_enums::PayoutStatus::RequiresCreation,
+                api_key_actor.clone(),
+                None,
+            ),
+            (
+                storage_enums::PayoutAuditEventType::Confirmed,
+                Some(storage_enums::PayoutStatus::RequiresCreation),
+                storage_enums::PayoutStatus::RequiresFulfillment,
+                api_key_actor.clone(),
+                None,
+            ),
+            (
+                storage_enums::PayoutAuditEventType::Failed,
+                Some(storage_enums::PayoutStatus::RequiresFulfillment),
+                storage_enums::PayoutStatus::Failed,
+                api_key_actor.clone(),
+                Some("Insufficient balance".to_string()),
+            ),
+            (
+                storage_enums::PayoutAuditEventType::Retried,
+                Some(storage_enums::PayoutStatus::Failed),
+                storage_enums::PayoutStatus::RequiresFulfillment,
+                job_actor.clone(),
+                Some("Retrying the payout with wise".to_string()),
+            ),
+            (
+                storage_enums::PayoutAuditEventType::Fulfilled,
+                Some(storage_enums::PayoutStatus::RequiresFulfillment),
+                storage_enums::PayoutStatus::Success,
+                job_actor,
+                None,
+            ),
+        ];
+        for (event_type, previous_status, new_status, actor, reason) in transitions.iter().cloned()
+        {
+            let audit_event = get_audit_event_new(
+                &get_payouts(new_status),
+                event_type,
+                PayoutAuditEventDetails {
+                    actor,
+                    previous_status,
+                    new_status,
+                    reason,
+                },
+            )
+            .unwrap();
+            store_audit_event(&mockdb, audit_event).await;
+        }
+        // Events of other payouts are not listed
+        let other_audit_event = get_audit_event_new(
+            &storage::Payouts {
+                payout_id: "payout_456".to_string(),
+                ..get_payouts(storage_enums::PayoutStatus::RequiresCreation)
+            },
+            storage_enums::PayoutAuditEventType::Created,
+            PayoutAuditEventDetails {
+                actor: api_key_actor.clone(),
+                previous_status: None,
+                new_status: storage_enums::PayoutStatus::RequiresCreation,
+                reason: None,
+            },
+        )
+        .unwrap();
+        store_audit_event(&mockdb, other_audit_event).await;
+
+        let events = list_payout_events(&mockdb, "merchant_123", "payout_123")
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), transitions.len());
+        for (event, (event_type, previous_status, new_status, actor, reason)) in
+            events.iter().zip(transitions.iter())
+        {
+            assert_eq!(&event.event_type, event_type);
+            assert_eq!(&event.previous_status, previous_status);
+            assert_eq!(&event.new_status, new_status);
+            assert_eq!(&event.actor, actor);
+            assert_eq!(&event.reason, reason);
+        }
+        assert!(events
+            .windows(2)
+            .all(|pair| matches!(pair, [first, second] if first.created_at <= second.created_at)));
+    }
+
+    #[test]
+    fn test_payout_event_type_for_status_transition() {
+        assert_eq!(
+            get_event_type(None, storage_enums::PayoutStatus::Scheduled),
+            storage_enums::PayoutAuditEventType::Created
+        );
+        assert_eq!(
+            get_event_type(
+                Some(storage_enums::PayoutStatus::RequiresCreation),
+                storage_enums::PayoutStatus::Pending
+            ),
+            storage_enums::PayoutAuditEventType::Confirmed
+        );
+        assert_eq!(
+            get_event_type(
+                Some(storage_enums::PayoutStatus::Pending),
+                storage_enums::PayoutStatus::Ineligible
+            ),
+            storage_enums::PayoutAuditEventType::Failed
+        );
+        assert_eq!(
+            get_event_type(
+                Some(storage_enums::PayoutStatus::RequiresFulfillment),
+                storage_enums::PayoutStatus::Cancelled
+            ),
+            storage_enums::PayoutAuditEventType::Cancelled
+        );
+        assert_eq!(
+            get_event_type(
+                Some(storage_enums::PayoutStatus::RequiresFulfillment),
+                storage_enums::PayoutStatus::PartiallyFulfilled
+            ),
+            storage_enums::PayoutAuditEventType::Fulfilled
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::storage::{self, enums as storage_enums},
+    utils::{self, currency as forex},
+};
+
+/// Exchange rate quoted for a cross currency payout
+#[derive(Clone, Debug, PartialEq)]
+pub struct FxQuote {
+    /// Units of the destination currency for one unit of the source currency
+    pub rate: Decimal,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[async_trait]
+pub trait FxQuoteProvider: Send + Sync {
+    /// Returns the number of units of `destination_currency` for one unit of `source_currency`
+    async fn get_fx_rate(
+        &self,
+        source_currency: storage_enums::Currency,
+        destination_currency: storage_enums::Currency,
+    ) -> RouterResult<Decimal>;
+}
+
+/// Quotes rates from the forex API configured for the application
+pub struct ForexApiQuoteProvider<'a> {
+    state: &'a AppState,
+}
+
+impl<'a> ForexApiQuoteProvider<'a> {
+    pub fn new(state: &'a AppState) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl FxQuoteProvider for ForexApiQuoteProvider<'_> {
+    async fn get_fx_rate(
+        &self,
+        source_currency: storage_enums::Currency,
+        destination_currency: storage_enums::Currency,
+    ) -> RouterResult<Decimal> {
+        let conversion = Box::pin(forex::convert_currency(
+            self.state.clone(),
+            get_minor_unit_factor(source_currency)?,
+            destination_currency.to_string(),
+            source_currency.to_string(),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to fetch FX rate from {source_currency} to {destination_currency}")
+        })?;
+
+        Decimal::from_str(&conversion.converted_amount)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse converted amount returned by the forex API")
+    }
+}
+
+/// Quotes a fixed rate irrespective of the currencies, for use where no forex API is available
+#[derive(Clone, Debug)]
+pub struct MockFxQuoteProvider {
+    pub rate: Decimal,
+}
+
+#[async_trait]
+impl FxQuoteProvider for MockFxQuoteProvider {
+    async fn get_fx_rate(
+        &self,
+        _source_currency: storage_enums::Currency,
+        _destination_currency: storage_enums::Currency,
+    ) -> RouterResult<Decimal> {
+        Ok(self.rate)
+    }
+}
+
+/// Fetches a quote valid for `validity_in_seconds` from `current_time`, if the source and
+/// destination currencies of the payout differ
+pub async fn get_fx_quote(
+    provider: &dyn FxQuoteProvider,
+    source_currency: storage_enums::Currency,
+    destination_currency: storage_enums::Currency,
+    validity_in_seconds: i64,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<Option<FxQuote>> {
+    if source_currency == destination_currency {
+        return Ok(None);
+    }
+
+    let rate = provider
+        .get_fx_rate(source_currency, destination_currency)
+        .await?;
+    utils::when(rate <= Decimal::ZERO, || {
+        Err(
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Invalid FX rate {rate} quoted from {source_currency} to {destination_currency}"
+            )),
+        )
+    })?;
+
+    Ok(Some(FxQuote {
+        rate,
+        expires_at: current_time.saturating_add(Duration::seconds(validity_in_seconds)),
+    }))
+}
+
+/// Converts `amount`, in the lowest denomination of `destination_currency`, to the lowest
+/// denomination of `source_currency` using a rate quoted from source to destination
+pub fn convert_to_source_amount(
+    amount: i64,
+    source_currency: storage_enums::Currency,
+    destination_currency: storage_enums::Currency,
+    rate: Decimal,
+) -> RouterResult<i64> {
+    let destination_factor = Decimal::from(get_minor_unit_factor(destination_currency)?);
+    let source_factor = Decimal::from(get_minor_unit_factor(source_currency)?);
+
+    Decimal::from(amount)
+        .checked_div(destination_factor)
+        .and_then(|destination_amount| destination_amount.checked_div(rate))
+        .and_then(|source_amount| source_amount.checked_mul(source_factor))
+        .and_then(|source_amount| source_amount.round().to_i64())
+        .ok_or(report!(errors::ApiErrorResponse::CurrencyConversionFailed))
+        .attach_printable_lazy(|| {
+            format!("Failed to convert {amount} {destination_currency} to {source_currency} at rate {rate}")
+        })
+}
+
+/// Returns the payout amount converted to the source currency at the quoted rate, if the payout
+/// has been quoted
+pub fn get_converted_amount(payouts: &storage::Payouts) -> Option<i64> {
+    let rate = Decimal::from_str(payouts.fx_rate.as_deref()?).ok()?;
+    convert_to_source_amount(
+        payouts.amount,
+        payouts.source_currency,
+        payouts.destination_currency,
+        rate,
+    )
+    .ok()
+}
+
+fn get_minor_unit_factor(currency: storage_enums::Currency) -> RouterResult<i64> {
+    10_i64
+        .checked_pow(u32::from(currency.number_of_digits_after_decimal_point()))
+        .ok_or(report!(errors::ApiErrorResponse::CurrencyConversionFailed))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_same_currency_payout_is_not_quoted() {
+        let provider = MockFxQuoteProvider {
+            rate: Decimal::new(9215, 4),
+        };
+
+        let quote = get_fx_quote(
+            &provider,
+            storage_enums::Currency::EUR,
+            storage_enums::Currency::EUR,
+            900,
+            datetime!(2024-04-30 10:00:00),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(quote, None);
+    }
+
+    #[tokio::test]
+    async fn test_cross_currency_payout_is_quoted() {
+        let provider = MockFxQuoteProvider {
+            rate: Decimal::new(9215, 4),
+        };
+
+        let quote = get_fx_quote(
+            &provider,
+            storage_enums::Currency::USD,
+            storage_enums::Currency::EUR,
+            900,
+            datetime!(2024-04-30 10:00:00),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(quote.rate, Decimal::new(9215, 4));
+        assert_eq!(quote.expires_at, datetime!(2024-04-30 10:15:00));
+        // 10.00 EUR costs 10.85 USD at 0.9215 EUR per USD
+        assert_eq!(
+            convert_to_source_amount(
+                1000,
+                storage_enums::Currency::USD,
+                storage_enums::Currency::EUR,
+                quote.rate,
+            )
+            .unwrap(),
+            1085
+        );
+    }
+
+    #[test]
+    fn test_conversion_to_zero_decimal_source_currency() {
+        // 10.00 USD costs 1535 JPY at 0.006515 USD per JPY
+        assert_eq!(
+            convert_to_source_amount(
+                1000,
+                storage_enums::Currency::JPY,
+                storage_enums::Currency::USD,
+                Decimal::new(6515, 6),
+            )
+            .unwrap(),
+            1535
+        );
+    }
+}
@@ -1,16 +1,26 @@
-use api_models::{enums, payment_methods::Card, payouts};
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{admin, enums, payment_methods::Card, payouts};
 use common_utils::{
     errors::CustomResult,
-    ext_traits::{AsyncExt, StringExt},
+    ext_traits::{AsyncExt, Encode, StringExt, ValueExt},
 };
 use diesel_models::encryption::Encryption;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+#[cfg(feature = "olap")]
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    StreamExt,
+};
 use masking::{ExposeInterface, PeekInterface, Secret};
-use router_env::logger;
+use router_env::{instrument, logger, tracing};
 
-use super::PayoutData;
+use super::{audit, validator, PayoutData};
 use crate::{
+    consts,
     core::{
+        api_locking,
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::{
             cards,
@@ -18,14 +28,14 @@ use crate::{
             vault,
         },
         payments::{
-            customers::get_connector_customer_details_if_present, route_connector_v1, routing,
-            CustomerDetails,
+            customers::get_connector_customer_details_if_present, helpers as payment_helpers,
+            route_connector_v1, routing, CustomerDetails,
         },
         routing::TransactionData,
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::{metrics, AppState},
+    routes::{lock_utils, metrics, AppState},
     services,
     types::{
         api::{self, enums as api_enums},
@@ -35,6 +45,7 @@ use crate::{
         },
         storage,
         transformers::ForeignFrom,
+        ErrorResponse, PayoutsResponseData,
     },
     utils::{self, OptionExt},
 };
@@ -53,9 +64,9 @@ pub async fn make_payout_method_data<'a>(
 ) -> RouterResult<Option<api::PayoutMethodData>> {
     let db = &*state.store;
     let certain_payout_type = payout_type.get_required_value("payout_type")?.to_owned();
-    let hyperswitch_token = if let Some(payout_token) = payout_token {
+    let (hyperswitch_token, payment_method_id) = if let Some(payout_token) = payout_token {
         if payout_token.starts_with("temporary_token_") {
-            Some(payout_token.to_string())
+            (Some(payout_token.to_string()), None)
         } else {
             let key = format!(
                 "pm_token_{}_{}_hyperswitch",
@@ -85,21 +96,24 @@ pub async fn make_payout_method_data<'a>(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to deserialize hyperswitch token data")?;
 
-            let payment_token = match payment_token_data {
+            let (payment_token, payment_method_id) = match payment_token_data {
                 storage::PaymentTokenData::PermanentCard(storage::CardTokenData {
+                    payment_method_id,
                     locker_id,
                     token,
-                    ..
-                }) => locker_id.or(Some(token)),
+                }) => (locker_id.or(Some(token)), payment_method_id),
                 storage::PaymentTokenData::TemporaryGeneric(storage::GenericTokenData {
                     token,
-                }) => Some(token),
-                _ => None,
+                }) => (Some(token), None),
+                _ => (None, None),
             };
-            payment_token.or(Some(payout_token.to_string()))
+            (
+                payment_token.or(Some(payout_token.to_string())),
+                payment_method_id,
+            )
         }
     } else {
-        None
+        (None, None)
     };
 
     match (
@@ -121,16 +135,23 @@ pub async fn make_payout_method_data<'a>(
                 .attach_printable(
                     "Payout method for given token not found or there was a problem fetching it",
                 )?;
-                utils::when(
-                    supplementary_data
-                        .customer_id
-                        .ne(&Some(customer_id.to_owned())),
-                    || {
-                        Err(errors::ApiErrorResponse::PreconditionFailed { message: "customer associated with payout method and customer passed in payout are not same".into() })
-                    },
+                validator::validate_payout_method_owner(
+                    supplementary_data.customer_id.as_deref(),
+                    customer_id,
                 )?;
                 Ok(pm)
             } else {
+                // Saved cards are owned by the customer of their payment method
+                if let Some(payment_method_id) = payment_method_id {
+                    let payment_method = db
+                        .find_payment_method(&payment_method_id, storage_scheme)
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+                    validator::validate_payout_method_owner(
+                        Some(&payment_method.customer_id),
+                        customer_id,
+                    )?;
+                }
                 let resp = cards::get_card_from_locker(
                     state,
                     customer_id,
@@ -574,6 +595,140 @@ pub async fn save_payout_data_to_locker(
     Ok(())
 }
 
+/// Payment methods which can be saved in the locker and reused as payout methods
+pub const PAYOUT_CAPABLE_PAYMENT_METHODS: [api_enums::PaymentMethod; 3] = [
+    api_enums::PaymentMethod::Card,
+    api_enums::PaymentMethod::BankTransfer,
+    api_enums::PaymentMethod::Wallet,
+];
+
+/// Fetches a payout method saved for the customer, ensuring it belongs to the merchant and the
+/// customer and can be used for payouts
+pub async fn find_saved_payout_method(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    payout_method_id: &str,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> RouterResult<storage::PaymentMethod> {
+    let payment_method = db
+        .find_payment_method(payout_method_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.merchant_id != merchant_id
+            || payment_method.customer_id != customer_id
+            || payment_method.status != storage::enums::PaymentMethodStatus::Active
+            || !payment_method
+                .payment_method
+                .is_some_and(|pm| PAYOUT_CAPABLE_PAYMENT_METHODS.contains(&pm)),
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)).attach_printable_lazy(
+                || format!("Invalid saved payout method: {payout_method_id}"),
+            )
+        },
+    )?;
+
+    Ok(payment_method)
+}
+
+/// Retrieves the payout method data of a saved payout method from the locker
+pub async fn get_saved_payout_method_data(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<api::PayoutMethodData> {
+    let locker_ref = payment_method
+        .locker_id
+        .as_ref()
+        .unwrap_or(&payment_method.payment_method_id);
+
+    if payment_method.payment_method == Some(api_enums::PaymentMethod::Card) {
+        let card = cards::get_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            locker_ref,
+        )
+        .await
+        .attach_printable("Payout method [card] could not be fetched from HS locker")?;
+        Ok(api::PayoutMethodData::Card(api::CardPayout {
+            card_number: card.card_number,
+            expiry_month: card.card_exp_month,
+            expiry_year: card.card_exp_year,
+            card_holder_name: card.name_on_card,
+        }))
+    } else {
+        cards::get_payment_method_from_hs_locker(
+            state,
+            key_store,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            locker_ref,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error getting payout method from locker")?
+        .peek()
+        .to_string()
+        .parse_struct("PayoutMethodData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payout method data fetched from locker")
+    }
+}
+
+/// Builds the list entry of a saved payout method, exposing only the last four characters of
+/// card and account numbers
+pub fn get_customer_payout_method(
+    payment_method: &storage::PaymentMethod,
+    payout_method_data: &api::PayoutMethodData,
+) -> payouts::CustomerPayoutMethod {
+    let (payout_type, card_last4, masked_account_number) = match payout_method_data {
+        api::PayoutMethodData::Card(card) => (
+            api_enums::PayoutType::Card,
+            Some(card.card_number.clone().get_last4()),
+            None,
+        ),
+        api::PayoutMethodData::Bank(bank) => {
+            let account_number = match bank {
+                api::BankPayout::Ach(ach) => &ach.bank_account_number,
+                api::BankPayout::Bacs(bacs) => &bacs.bank_account_number,
+                api::BankPayout::Sepa(sepa) => &sepa.iban,
+            };
+            (
+                api_enums::PayoutType::Bank,
+                None,
+                Some(mask_account_number(account_number.peek())),
+            )
+        }
+        api::PayoutMethodData::Wallet(_) => (api_enums::PayoutType::Wallet, None, None),
+    };
+
+    payouts::CustomerPayoutMethod {
+        payout_method_id: payment_method.payment_method_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        payout_type,
+        payout_method_type: payment_method.payment_method_type,
+        card_last4,
+        masked_account_number,
+        created: payment_method.created_at,
+    }
+}
+
+/// Masks all but the last four characters of an account number; account numbers which are not
+/// longer than four characters are masked entirely
+fn mask_account_number(account_number: &str) -> String {
+    let length = account_number.chars().count();
+    let masked_length = if length > 4 { length - 4 } else { length };
+    account_number
+        .chars()
+        .enumerate()
+        .map(|(index, c)| if index < masked_length { '*' } else { c })
+        .collect()
+}
+
 pub async fn get_or_create_customer_details(
     state: &AppState,
     customer_details: &CustomerDetails,
@@ -623,6 +778,7 @@ pub async fn get_or_create_customer_details(
                 modified_at: common_utils::date_time::now(),
                 address_id: None,
                 default_payment_method_id: None,
+                kyc_status: None,
             };
 
             Ok(Some(
@@ -634,6 +790,47 @@ pub async fn get_or_create_customer_details(
     }
 }
 
+pub async fn update_customer_contact_details(
+    state: &AppState,
+    customer: domain::Customer,
+    req: &payouts::PayoutUpdateRequest,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<domain::Customer> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key = key_store.key.get_inner().peek();
+
+    let customer_update = storage::CustomerUpdate::Update {
+        name: None,
+        email: domain_types::encrypt_optional(req.email.to_owned().map(|e| e.expose()), key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        phone: Box::new(
+            domain_types::encrypt_optional(req.phone.to_owned(), key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        ),
+        description: None,
+        phone_country_code: req.phone_country_code.to_owned(),
+        metadata: None,
+        connector_customer: None,
+        address_id: None,
+        kyc_status: None,
+    };
+
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id.to_owned(),
+        merchant_account.merchant_id.to_owned(),
+        customer,
+        customer_update,
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating customer contact details for payout")
+}
+
 pub async fn decide_payout_connector(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -875,6 +1072,404 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+/// Creates a new attempt for the payout bound to the connector and makes it the active attempt
+#[instrument(skip_all)]
+pub async fn create_new_payout_attempt(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    manual_routing_override: bool,
+    routing_trail: Option<serde_json::Value>,
+) -> RouterResult<()> {
+    let new_attempt_count = payout_data.payouts.attempt_count + 1;
+
+    let db = &*state.store;
+
+    // update payout table's attempt count
+    let payouts = payout_data.payouts.to_owned();
+    let updated_payouts = storage::PayoutsUpdate::AttemptCountUpdate {
+        attempt_count: new_attempt_count,
+    };
+
+    let payout_id = payouts.payout_id.clone();
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            updated_payouts,
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts")?;
+
+    let payout_attempt_id =
+        utils::get_payment_attempt_id(payout_id.to_owned(), payout_data.payouts.attempt_count);
+
+    let payout_attempt_req = storage::PayoutAttemptNew {
+        payout_attempt_id: payout_attempt_id.to_string(),
+        payout_id: payout_id.to_owned(),
+        customer_id: payout_data.payout_attempt.customer_id.to_owned(),
+        connector: Some(connector.connector_name.to_string()),
+        merchant_id: payout_data.payout_attempt.merchant_id.to_owned(),
+        address_id: payout_data.payout_attempt.address_id.to_owned(),
+        business_country: payout_data.payout_attempt.business_country.to_owned(),
+        business_label: payout_data.payout_attempt.business_label.to_owned(),
+        payout_token: payout_data.payout_attempt.payout_token.to_owned(),
+        profile_id: payout_data.payout_attempt.profile_id.to_string(),
+        manual_routing_override,
+        routing_trail,
+        ..Default::default()
+    };
+    payout_data.payout_attempt = db
+        .insert_payout_attempt(
+            payout_attempt_req,
+            &payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+        .attach_printable("Error inserting payouts in db")?;
+
+    payout_data.merchant_connector_account = None;
+
+    Ok(())
+}
+
+/// Returns the payout processor accounts enabled for the merchant under the business profile
+async fn get_payout_merchant_connector_accounts(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+) -> RouterResult<Vec<domain::MerchantConnectorAccount>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &key_store.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok(payment_helpers::filter_mca_based_on_business_profile(
+        merchant_connector_accounts,
+        Some(profile_id.to_string()),
+    )
+    .into_iter()
+    .filter(|mca| mca.connector_type == api_enums::ConnectorType::PayoutProcessor)
+    .collect())
+}
+
+/// Returns the payout connectors enabled for the merchant under the business profile
+pub async fn get_configured_payout_connectors(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+) -> RouterResult<Vec<api_enums::PayoutConnectors>> {
+    Ok(
+        get_payout_merchant_connector_accounts(state, key_store, profile_id)
+            .await?
+            .into_iter()
+            .filter_map(|mca| api_enums::PayoutConnectors::from_str(&mca.connector_name).ok())
+            .collect(),
+    )
+}
+
+/// Returns the connector a payout would be created with, which is the first of the requested
+/// connectors configured for the business profile, or the first configured connector if none
+/// were requested
+pub fn resolve_payout_connector(
+    requested_connectors: Option<&[api_enums::PayoutConnectors]>,
+    configured_connectors: &[api_enums::PayoutConnectors],
+) -> Option<api_enums::PayoutConnectors> {
+    match requested_connectors {
+        Some(requested_connectors) => requested_connectors
+            .iter()
+            .find(|connector| configured_connectors.contains(connector))
+            .copied(),
+        None => configured_connectors.first().copied(),
+    }
+}
+
+/// Runs the connector selection checks over the routed connectors and records the routing
+/// decision trail on the payout attempt. Skipped connectors are not attempted, and the payout is
+/// failed with the reasons for skipping every connector if none of them can process it.
+#[instrument(skip_all)]
+pub async fn apply_payout_routing_checks(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_call_type: api::ConnectorCallType,
+    payout_data: &mut PayoutData,
+) -> RouterResult<api::ConnectorCallType> {
+    let (connectors, is_retryable) = match connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector_data) => (vec![connector_data], false),
+        api::ConnectorCallType::Retryable(connectors) => (connectors, true),
+        connector_call_type @ api::ConnectorCallType::SessionMultiple(_) => {
+            return Ok(connector_call_type)
+        }
+    };
+
+    let enabled_payment_methods =
+        get_payout_merchant_connector_accounts(state, key_store, &payout_data.profile_id)
+            .await?
+            .into_iter()
+            .map(|mca| {
+                let payment_methods_enabled = mca
+                    .payment_methods_enabled
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|payment_method| {
+                        serde_json::from_value::<admin::PaymentMethodsEnabled>(payment_method)
+                            .map_err(|error| {
+                                logger::warn!(?error, "Failed to parse payment methods enabled")
+                            })
+                            .ok()
+                    })
+                    .collect::<Vec<_>>();
+                (mca.connector_name, payment_methods_enabled)
+            })
+            .collect::<HashMap<_, _>>();
+
+    let candidates = connectors
+        .iter()
+        .map(|connector_data| connector_data.connector_name)
+        .collect::<Vec<_>>();
+    let mut routing_trail = build_payout_routing_trail(&candidates, |connector| {
+        get_payout_connector_skip_reason(
+            connector,
+            &payout_data.payouts,
+            enabled_payment_methods
+                .get(&connector.to_string())
+                .map(Vec::as_slice),
+        )
+    });
+    routing_trail.routing_algorithm =
+        get_payout_routing_algorithm_version(merchant_account, payout_data)?;
+    payout_data.payout_attempt.routing_trail = Some(
+        routing_trail
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode payout routing trail")?,
+    );
+
+    if routing_trail.chosen_connector.is_none() {
+        let error_message = get_payout_routing_failure_message(&routing_trail);
+        let db = &*state.store;
+        let previous_status = payout_data.payouts.status;
+        let updated_payout_attempt = storage::PayoutAttemptUpdate::RoutingFailed {
+            error_code: consts::PAYOUT_ROUTING_FAILED_ERROR_CODE.to_string(),
+            error_message: error_message.clone(),
+            routing_trail: payout_data.payout_attempt.routing_trail.clone(),
+        };
+        payout_data.payout_attempt = db
+            .update_payout_attempt(
+                &payout_data.payout_attempt,
+                updated_payout_attempt,
+                &payout_data.payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payout_attempt in db")?;
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                storage::PayoutsUpdate::StatusUpdate {
+                    status: api_enums::PayoutStatus::Failed,
+                },
+                &payout_data.payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payouts in db")?;
+        audit::record_status_transition(state, payout_data, Some(previous_status));
+
+        return Err(report!(errors::ApiErrorResponse::PayoutFailed {
+            data: payout_data.payout_attempt.routing_trail.clone(),
+        })
+        .attach_printable(error_message));
+    }
+
+    let mut connectors = connectors.into_iter().filter(|connector_data| {
+        !routing_trail
+            .skipped
+            .iter()
+            .any(|skipped| skipped.connector == connector_data.connector_name.to_string())
+    });
+    if is_retryable {
+        Ok(api::ConnectorCallType::Retryable(connectors.collect()))
+    } else {
+        connectors
+            .next()
+            .map(api::ConnectorCallType::PreDetermined)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Chosen connector not found in routed connectors")
+    }
+}
+
+/// Evaluates the routed connectors in the order of preference. The first connector which is not
+/// skipped is chosen for the payout, and the remaining ones are evaluated for retries.
+pub fn build_payout_routing_trail(
+    candidates: &[api_enums::Connector],
+    get_skip_reason: impl Fn(api_enums::Connector) -> Option<payouts::PayoutRoutingSkipReason>,
+) -> payouts::PayoutRoutingTrail {
+    let skipped = candidates
+        .iter()
+        .filter_map(|connector| {
+            get_skip_reason(*connector).map(|reason| payouts::PayoutRoutingSkippedCandidate {
+                connector: connector.to_string(),
+                reason,
+            })
+        })
+        .collect::<Vec<_>>();
+    let chosen_connector = candidates
+        .iter()
+        .map(ToString::to_string)
+        .find(|connector| {
+            skipped
+                .iter()
+                .all(|skipped| &skipped.connector != connector)
+        });
+
+    payouts::PayoutRoutingTrail {
+        candidates: candidates.iter().map(ToString::to_string).collect(),
+        chosen_connector,
+        skipped,
+        routing_algorithm: None,
+    }
+}
+
+/// Returns the version of the routing algorithm which ordered the connectors of the payout. There
+/// is none if the payout was routed by the routing algorithm in the request, or by the fallback
+/// configuration when no routing algorithm is active.
+fn get_payout_routing_algorithm_version(
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) -> RouterResult<Option<payouts::PayoutRoutingAlgorithmVersion>> {
+    if payout_data.payout_attempt.routing_info.is_some() {
+        return Ok(None);
+    }
+    let routing_algorithm = if cfg!(feature = "business_profile_routing") {
+        payout_data
+            .business_profile
+            .payout_routing_algorithm
+            .clone()
+    } else {
+        merchant_account.payout_routing_algorithm.clone()
+    };
+    let algorithm_ref = routing_algorithm
+        .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant payout routing algorithm ref")?;
+
+    Ok(algorithm_ref.and_then(|algorithm_ref| {
+        algorithm_ref
+            .algorithm_id
+            .map(|algorithm_id| payouts::PayoutRoutingAlgorithmVersion {
+                algorithm_id,
+                activated_at: algorithm_ref.timestamp,
+            })
+    }))
+}
+
+/// Returns the reason the connector cannot process the payout, if any. `enabled_payment_methods`
+/// is `None` if the connector is not enabled for payouts under the business profile.
+pub fn get_payout_connector_skip_reason(
+    connector: api_enums::Connector,
+    payouts: &storage::Payouts,
+    enabled_payment_methods: Option<&[admin::PaymentMethodsEnabled]>,
+) -> Option<payouts::PayoutRoutingSkipReason> {
+    let Some(enabled_payment_methods) = enabled_payment_methods else {
+        return Some(payouts::PayoutRoutingSkipReason::Disabled);
+    };
+    if !validator::is_payout_priority_supported(payouts.priority, payouts.payout_type, connector) {
+        return Some(payouts::PayoutRoutingSkipReason::UnsupportedPriority);
+    }
+
+    // Currencies and limits are only checked if configured for the payout method of the payout
+    let payment_method = api_enums::PaymentMethod::foreign_from(payouts.payout_type);
+    let payment_method_types = enabled_payment_methods
+        .iter()
+        .filter(|enabled| enabled.payment_method == payment_method)
+        .flat_map(|enabled| enabled.payment_method_types.iter().flatten())
+        .collect::<Vec<_>>();
+    if payment_method_types.is_empty() {
+        return None;
+    }
+
+    let currency = payouts.destination_currency;
+    let currency_accepted = payment_method_types
+        .into_iter()
+        .filter(|payment_method_type| {
+            payment_method_type
+                .accepted_currencies
+                .as_ref()
+                .map_or(true, |accepted_currencies| match accepted_currencies {
+                    admin::AcceptedCurrencies::EnableOnly(currencies) => {
+                        currencies.contains(&currency)
+                    }
+                    admin::AcceptedCurrencies::DisableOnly(currencies) => {
+                        !currencies.contains(&currency)
+                    }
+                    admin::AcceptedCurrencies::AllAccepted => true,
+                })
+        })
+        .collect::<Vec<_>>();
+    if currency_accepted.is_empty() {
+        return Some(payouts::PayoutRoutingSkipReason::UnsupportedCurrency);
+    }
+
+    let within_limit = currency_accepted.into_iter().any(|payment_method_type| {
+        payment_method_type
+            .maximum_amount
+            .map_or(true, |maximum_amount| {
+                payouts.amount <= i64::from(maximum_amount)
+            })
+    });
+    (!within_limit).then_some(payouts::PayoutRoutingSkipReason::OverLimit)
+}
+
+/// Aggregates the reasons for skipping the connectors in a routing trail
+pub fn get_payout_routing_failure_message(routing_trail: &payouts::PayoutRoutingTrail) -> String {
+    let reasons = routing_trail
+        .skipped
+        .iter()
+        .map(|skipped| format!("{} ({})", skipped.connector, skipped.reason))
+        .collect::<Vec<_>>();
+    if reasons.is_empty() {
+        "No connector was routed for the payout".to_string()
+    } else {
+        format!(
+            "No connector could process the payout: {}",
+            reasons.join(", ")
+        )
+    }
+}
+
+/// Returns the validation error to be reported for a payout create request, if the error was
+/// caused by the request. Errors not caused by the request are to be propagated as is.
+pub fn get_payout_validation_error(
+    error: &errors::ApiErrorResponse,
+) -> Option<payouts::PayoutValidationError> {
+    let mut api_error = common_utils::errors::ErrorSwitch::<
+        api_models::errors::types::ApiErrorResponse,
+    >::switch(error);
+    if !actix_web::ResponseError::status_code(&api_error).is_client_error() {
+        return None;
+    }
+
+    let api_error = api_error.get_internal_error_mut();
+    Some(payouts::PayoutValidationError {
+        code: format!("{}_{:02}", api_error.sub_code, api_error.error_identifier),
+        message: api_error.error_message.clone(),
+    })
+}
+
 pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
@@ -882,6 +1477,15 @@ pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Whether the payout has already been sent to the connector for fulfillment, in which case a
+/// repeated confirm or fulfill returns the payout as it is instead of sending it again
+pub fn is_payout_fulfillment_started(status: api_enums::PayoutStatus) -> bool {
+    matches!(
+        status,
+        api_enums::PayoutStatus::Pending | api_enums::PayoutStatus::Success
+    )
+}
+
 pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
     !matches!(
         status,
@@ -889,6 +1493,9 @@ pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
             | api_enums::PayoutStatus::RequiresCreation
             | api_enums::PayoutStatus::RequiresFulfillment
             | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::Scheduled
+            | api_enums::PayoutStatus::RequiresVendorAccountCreation
+            | api_enums::PayoutStatus::PartiallyFulfilled
     )
 }
 
@@ -901,23 +1508,1598 @@ pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Normalizes the payout status reported by the connector on sync.
+/// A payout in a terminal state is never moved back, and a missing status keeps the current one.
+pub fn normalize_payout_sync_status(
+    current_status: api_enums::PayoutStatus,
+    connector_status: Option<api_enums::PayoutStatus>,
+) -> api_enums::PayoutStatus {
+    match connector_status {
+        Some(connector_status) if !is_payout_terminal_state(current_status) => connector_status,
+        _ => current_status,
+    }
+}
+
+/// Outcome of syncing a payout with the connector
+#[derive(Debug)]
+pub enum PayoutSyncResult {
+    /// The connector reported the status the payout is already in
+    Unchanged,
+    /// The payout attempt is to be updated with the status or error reported by the connector
+    Updated(storage::PayoutAttemptUpdate),
+}
+
+/// Decides how the payout attempt is updated with the connector's payout sync response.
+/// A response without a status leaves the payout in the status it is in.
+pub fn get_payout_sync_result(
+    payout_attempt: &storage::PayoutAttempt,
+    sync_response: Result<PayoutsResponseData, ErrorResponse>,
+) -> PayoutSyncResult {
+    match sync_response {
+        Ok(payout_response_data) => {
+            let status =
+                normalize_payout_sync_status(payout_attempt.status, payout_response_data.status);
+            if status == payout_attempt.status {
+                PayoutSyncResult::Unchanged
+            } else {
+                PayoutSyncResult::Updated(storage::PayoutAttemptUpdate::StatusUpdate {
+                    connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+                    status,
+                    error_message: None,
+                    error_code: None,
+                    is_eligible: payout_response_data
+                        .payout_eligible
+                        .or(payout_attempt.is_eligible),
+                })
+            }
+        }
+        Err(err) => PayoutSyncResult::Updated(storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+            status: payout_attempt.status,
+            error_message: Some(err.message),
+            error_code: Some(err.code),
+            is_eligible: payout_attempt.is_eligible,
+        }),
+    }
+}
+
+/// Returns the lock under which the payouts funded by a payment and the refunds of the payment are
+/// validated and created, so that concurrent requests cannot allocate more than its captured amount
+pub fn get_source_payment_locking_action(
+    source_payment_id: Option<&str>,
+) -> api_locking::LockAction {
+    match source_payment_id {
+        Some(source_payment_id) => api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: source_payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payouts,
+                override_lock_retries: None,
+            },
+        },
+        None => api_locking::LockAction::NotApplicable,
+    }
+}
+
+/// Returns the amount of the payout that is yet to be fulfilled
+pub fn get_unfulfilled_amount(payouts: &storage::Payouts) -> i64 {
+    payouts
+        .amount
+        .saturating_sub(payouts.fulfilled_amount.unwrap_or(0))
+}
+
+/// Returns the fulfilled amount and status of the payout after the status of a tranche of it
+/// changes. Only the tranches fulfilled successfully are counted towards the fulfilled amount, and
+/// the payout stays partially fulfilled until its whole amount is fulfilled. A tranche which is
+/// pending or failed leaves the tranches fulfilled earlier as they are.
+pub fn get_payout_fulfillment_status(
+    payouts: &storage::Payouts,
+    tranche_amount: i64,
+    previous_tranche_status: api_enums::PayoutStatus,
+    tranche_status: api_enums::PayoutStatus,
+) -> (Option<i64>, api_enums::PayoutStatus) {
+    let was_fulfilled = previous_tranche_status == api_enums::PayoutStatus::Success;
+    let is_fulfilled = tranche_status == api_enums::PayoutStatus::Success;
+    let fulfilled_amount = payouts.fulfilled_amount.unwrap_or(0);
+    let fulfilled_amount = match (was_fulfilled, is_fulfilled) {
+        (false, true) => Some(fulfilled_amount.saturating_add(tranche_amount)),
+        (true, false) => Some(fulfilled_amount.saturating_sub(tranche_amount)),
+        _ => payouts.fulfilled_amount,
+    };
+
+    let status = match fulfilled_amount.unwrap_or(0) {
+        amount if is_fulfilled && amount >= payouts.amount => tranche_status,
+        amount if amount > 0 => api_enums::PayoutStatus::PartiallyFulfilled,
+        _ => tranche_status,
+    };
+    (fulfilled_amount, status)
+}
+
+/// Returns the amount paid out by the attempt, which is the whole amount of the payout yet to be
+/// fulfilled unless the attempt fulfills a tranche of it
+pub fn get_tranche_amount(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> i64 {
+    payout_attempt
+        .amount
+        .unwrap_or_else(|| get_unfulfilled_amount(payouts))
+}
+
+/// Returns the status of the payout to be reported. A partially fulfilled payout is reported as
+/// such, irrespective of the status of the attempt of its last tranche.
+pub fn get_payout_status(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+) -> api_enums::PayoutStatus {
+    match payouts.status {
+        api_enums::PayoutStatus::PartiallyFulfilled => payouts.status,
+        _ => payout_attempt.status,
+    }
+}
+
+/// Validates that the connector account holds enough balance to fulfill the payout.
+/// Connectors without a balance API report no balance, in which case fulfillment proceeds.
+pub fn validate_connector_balance(
+    available_balance: Option<i64>,
+    required_amount: i64,
+    currency: api_enums::Currency,
+) -> RouterResult<()> {
+    match available_balance {
+        Some(available_amount) if available_amount < required_amount => Err(report!(
+            errors::ApiErrorResponse::InsufficientConnectorBalance {
+                available_amount,
+                required_amount,
+                currency: currency.to_string(),
+            }
+        )),
+        Some(_) => Ok(()),
+        None => {
+            logger::warn!("Connector balance is unavailable, proceeding with payout fulfillment");
+            Ok(())
+        }
+    }
+}
+
 pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
         api_enums::PayoutStatus::RequiresCreation
-            | api_enums::PayoutStatus::RequiresPayoutMethodData,
+            | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::Scheduled,
     )
 }
 
+pub async fn get_payout_attempts(
+    db: &dyn StorageInterface,
+    payouts: &storage::Payouts,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> RouterResult<Vec<payouts::PayoutAttemptResponse>> {
+    let payout_attempts = db
+        .find_payout_attempts_by_merchant_id_payout_id(
+            &payouts.merchant_id,
+            &payouts.payout_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout attempts")?;
+
+    Ok(payout_attempts
+        .into_iter()
+        .map(|payout_attempt| {
+            payouts::PayoutAttemptResponse::foreign_from((payouts, payout_attempt))
+        })
+        .collect())
+}
+
+/// Columns of the payouts export, in the order they are written
 #[cfg(feature = "olap")]
-pub(super) async fn filter_by_constraints(
+const PAYOUTS_EXPORT_COLUMNS: [&str; 10] = [
+    "payout_id",
+    "status",
+    "amount",
+    "currency",
+    "connector",
+    "customer_id",
+    "created_at",
+    "last_modified_at",
+    "error_code",
+    "error_message",
+];
+
+/// Formats a CSV record, quoting fields that contain a delimiter, a quote or a line break
+#[cfg(feature = "olap")]
+fn get_csv_record<I: IntoIterator<Item = String>>(fields: I) -> String {
+    let mut record = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    record.push('\n');
+    record
+}
+
+#[cfg(feature = "olap")]
+fn get_payouts_export_record(
+    payouts: &storage::Payouts,
+    payout_attempt: Option<&storage::PayoutAttempt>,
+) -> RouterResult<String> {
+    let format_timestamp = |timestamp: time::PrimitiveDateTime| {
+        timestamp
+            .format(time::macros::format_description!(
+                "[year]-[month]-[day]T[hour]:[minute]:[second]Z"
+            ))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to format payout timestamp for export")
+    };
+    Ok(get_csv_record([
+        payouts.payout_id.clone(),
+        payouts.status.to_string(),
+        payouts.amount.to_string(),
+        payouts.destination_currency.to_string(),
+        payout_attempt
+            .and_then(|payout_attempt| payout_attempt.connector.clone())
+            .unwrap_or_default(),
+        payouts.customer_id.clone(),
+        format_timestamp(payouts.created_at)?,
+        format_timestamp(payouts.last_modified_at)?,
+        payout_attempt
+            .and_then(|payout_attempt| payout_attempt.error_code.clone())
+            .unwrap_or_default(),
+        payout_attempt
+            .and_then(|payout_attempt| payout_attempt.error_message.clone())
+            .unwrap_or_default(),
+    ]))
+}
+
+/// Writes the CSV records of a page of payouts created within the time range, continuing after
+/// the cursor. The active attempts of the page are loaded in a single query. Returns the cursor of
+/// the next page, if there can be one.
+#[cfg(feature = "olap")]
+async fn get_payouts_export_page(
     db: &dyn StorageInterface,
-    constraints: &api::PayoutListConstraints,
     merchant_id: &str,
+    time_range: &api_models::payments::TimeRange,
+    cursor: Option<&data_models::payouts::PayoutsCursor>,
+    page_size: u32,
     storage_scheme: storage::enums::MerchantStorageScheme,
-) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
-    let result = db
-        .filter_payouts_by_constraints(merchant_id, &constraints.clone().into(), storage_scheme)
-        .await?;
+) -> RouterResult<(String, Option<data_models::payouts::PayoutsCursor>)> {
+    let payouts = db
+        .filter_payouts_by_time_range_after_cursor(
+            merchant_id,
+            time_range,
+            cursor,
+            page_size,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payouts for export")?;
+
+    let payout_attempt_ids = payouts
+        .iter()
+        .map(|payouts| utils::get_payment_attempt_id(&payouts.payout_id, payouts.attempt_count))
+        .collect::<Vec<_>>();
+    let payout_attempts = if payout_attempt_ids.is_empty() {
+        HashMap::new()
+    } else {
+        db.find_payout_attempts_by_merchant_id_payout_attempt_ids(
+            merchant_id,
+            &payout_attempt_ids,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout attempts for export")?
+        .into_iter()
+        .map(|payout_attempt| (payout_attempt.payout_attempt_id.clone(), payout_attempt))
+        .collect::<HashMap<_, _>>()
+    };
+
+    let mut records = String::new();
+    for (payouts, payout_attempt_id) in payouts.iter().zip(payout_attempt_ids.iter()) {
+        let payout_attempt = payout_attempts.get(payout_attempt_id);
+        if payout_attempt.is_none() {
+            logger::warn!(
+                "payout_attempt missing for payout_id : {}",
+                payouts.payout_id
+            );
+        }
+        records.push_str(&get_payouts_export_record(payouts, payout_attempt)?);
+    }
+
+    let is_last_page = u32::try_from(payouts.len()).map_or(false, |len| len < page_size);
+    let next_cursor = payouts.last().filter(|_| !is_last_page).map(|last_payout| {
+        data_models::payouts::PayoutsCursor {
+            created_at: last_payout.created_at,
+            payout_id: last_payout.payout_id.clone(),
+        }
+    });
+    Ok((records, next_cursor))
+}
+
+/// Exports the payouts of a merchant created within the time range as CSV. Payouts are read from
+/// the database a page at a time while the CSV is being sent, continuing after the last payout of
+/// the previous page, so that large ranges are never loaded in a single query or held in memory.
+#[cfg(feature = "olap")]
+pub fn export_payouts_as_csv(
+    db: Box<dyn StorageInterface>,
+    merchant_id: String,
+    time_range: api_models::payments::TimeRange,
+    page_size: u32,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> BoxStream<'static, RouterResult<bytes::Bytes>> {
+    let header = get_csv_record(PAYOUTS_EXPORT_COLUMNS.map(String::from));
+    // The state holds the cursor of the next page, or nothing once the last page is written
+    let records = stream::try_unfold(
+        (db, Some(None)),
+        move |(db, next_page): (
+            Box<dyn StorageInterface>,
+            Option<Option<data_models::payouts::PayoutsCursor>>,
+        )| {
+            let merchant_id = merchant_id.clone();
+            async move {
+                match next_page {
+                    Some(cursor) => {
+                        let (records, next_cursor) = get_payouts_export_page(
+                            db.as_ref(),
+                            &merchant_id,
+                            &time_range,
+                            cursor.as_ref(),
+                            page_size,
+                            storage_scheme,
+                        )
+                        .await?;
+                        Ok(Some((
+                            bytes::Bytes::from(records),
+                            (db, next_cursor.map(Some)),
+                        )))
+                    }
+                    None => Ok(None),
+                }
+            }
+        },
+    );
+    stream::once(future::ready(Ok(bytes::Bytes::from(header))))
+        .chain(records)
+        .boxed()
+}
+
+pub fn get_scheduled_payout_process_tracker_entry(
+    payouts: &storage::Payouts,
+    scheduled_at: time::PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let tracking_data = storage::payouts::PayoutScheduleTrackingData {
+        payout_id: payouts.payout_id.clone(),
+        merchant_id: payouts.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::ScheduledPayoutWorkflow;
+    let task = "SCHEDULED_PAYOUT";
+    let tag = ["PAYOUT", "SCHEDULED"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &payouts.payout_id,
+        &payouts.merchant_id,
+    );
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        scheduled_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct scheduled payout process tracker task")
+}
+
+pub async fn add_scheduled_payout_task(
+    db: &dyn StorageInterface,
+    payouts: &storage::Payouts,
+    scheduled_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_entry = get_scheduled_payout_process_tracker_entry(payouts, scheduled_at)?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting scheduled payout to process_tracker: payout_id: {}",
+                payouts.payout_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "ScheduledPayout")],
+    );
+
+    Ok(())
+}
+
+/// Updates the status of the payout and its current attempt, along with the error of the attempt
+pub async fn update_payout_and_attempt_status(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    status: api_enums::PayoutStatus,
+    error_code: Option<String>,
+    error_message: Option<String>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let previous_status = payout_data.payouts.status;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_code,
+        error_message,
+        is_eligible: None,
+    };
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    audit::record_status_transition(state, payout_data, Some(previous_status));
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+pub(super) async fn filter_payouts_held_for_recipient_kyc(
+    db: &dyn StorageInterface,
+    customer_id: &str,
+    merchant_id: &str,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+    let constraints = data_models::payouts::PayoutFetchConstraints::List(Box::new(
+        data_models::payouts::PayoutListParams {
+            offset: 0,
+            starting_at: None,
+            ending_at: None,
+            connector: None,
+            currency: None,
+            status: Some(vec![
+                storage::enums::PayoutStatus::RequiresVendorAccountCreation,
+            ]),
+            payout_method: None,
+            profile_id: None,
+            customer_id: Some(customer_id.to_string()),
+            starting_after_id: None,
+            ending_before_id: None,
+            entity_type: None,
+            metadata: None,
+            limit: None,
+        },
+    ));
+    let result = db
+        .filter_payouts_by_constraints(merchant_id, &constraints, storage_scheme)
+        .await?;
     Ok(result)
 }
+
+#[cfg(feature = "olap")]
+pub(super) async fn filter_by_constraints(
+    db: &dyn StorageInterface,
+    constraints: &api::PayoutListConstraints,
+    merchant_id: &str,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+    let result = db
+        .filter_payouts_by_constraints(merchant_id, &constraints.clone().into(), storage_scheme)
+        .await?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{configs::settings, types::api::Payouts};
+
+    fn get_payouts(scheduled_at: Option<time::PrimitiveDateTime>) -> storage::Payouts {
+        storage::Payouts {
+            payout_id: "payout_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            address_id: "addr_123".to_string(),
+            payout_type: storage::enums::PayoutType::Bank,
+            payout_method_id: None,
+            amount: 1000,
+            destination_currency: storage::enums::Currency::EUR,
+            source_currency: storage::enums::Currency::EUR,
+            description: None,
+            recurring: false,
+            auto_fulfill: true,
+            return_url: None,
+            entity_type: storage::enums::PayoutEntityType::Individual,
+            metadata: None,
+            created_at: datetime!(2024-04-25 10:00:00),
+            last_modified_at: datetime!(2024-04-25 10:00:00),
+            profile_id: "pro_123".to_string(),
+            status: storage::enums::PayoutStatus::Scheduled,
+            attempt_count: 1,
+            scheduled_at,
+            fx_rate: None,
+            fx_quote_expires_at: None,
+            priority: storage::enums::PayoutPriority::Standard,
+            fulfilled_amount: None,
+            payout_schedule_id: None,
+            source_payment_id: None,
+        }
+    }
+
+    fn get_payout_attempt(
+        payout_attempt_id: &str,
+        created_at: time::PrimitiveDateTime,
+    ) -> diesel_models::PayoutAttempt {
+        diesel_models::PayoutAttempt {
+            payout_attempt_id: payout_attempt_id.to_string(),
+            payout_id: "payout_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            address_id: "addr_123".to_string(),
+            connector: Some("adyen".to_string()),
+            connector_payout_id: format!("{payout_attempt_id}_connector"),
+            payout_token: None,
+            status: storage::enums::PayoutStatus::Failed,
+            is_eligible: None,
+            error_message: Some("Insufficient balance".to_string()),
+            error_code: Some("E001".to_string()),
+            business_country: None,
+            business_label: None,
+            created_at,
+            last_modified_at: created_at,
+            profile_id: "pro_123".to_string(),
+            merchant_connector_id: None,
+            routing_info: None,
+            manual_routing_override: false,
+            routing_trail: None,
+            amount: None,
+        }
+    }
+
+    #[test]
+    fn test_scheduled_payout_process_tracker_entry() {
+        let scheduled_at = datetime!(2024-05-01 10:00:00);
+        let payouts = get_payouts(Some(scheduled_at));
+
+        let process_tracker_entry =
+            get_scheduled_payout_process_tracker_entry(&payouts, scheduled_at).unwrap();
+
+        assert_eq!(process_tracker_entry.schedule_time, Some(scheduled_at));
+        assert_eq!(
+            process_tracker_entry.runner,
+            Some(storage::ProcessTrackerRunner::ScheduledPayoutWorkflow.to_string())
+        );
+        let tracking_data: storage::payouts::PayoutScheduleTrackingData = process_tracker_entry
+            .tracking_data
+            .parse_value("PayoutScheduleTrackingData")
+            .unwrap();
+        assert_eq!(tracking_data.payout_id, payouts.payout_id);
+        assert_eq!(tracking_data.merchant_id, payouts.merchant_id);
+    }
+
+    #[test]
+    fn test_scheduled_payout_is_eligible_for_local_cancellation() {
+        assert!(is_eligible_for_local_payout_cancellation(
+            api_enums::PayoutStatus::Scheduled
+        ));
+        assert!(!is_payout_terminal_state(
+            api_enums::PayoutStatus::Scheduled
+        ));
+    }
+
+    #[test]
+    fn test_normalize_payout_sync_status() {
+        assert_eq!(
+            normalize_payout_sync_status(
+                api_enums::PayoutStatus::Pending,
+                Some(api_enums::PayoutStatus::Failed)
+            ),
+            api_enums::PayoutStatus::Failed
+        );
+        assert_eq!(
+            normalize_payout_sync_status(api_enums::PayoutStatus::Pending, None),
+            api_enums::PayoutStatus::Pending
+        );
+        assert_eq!(
+            normalize_payout_sync_status(
+                api_enums::PayoutStatus::Success,
+                Some(api_enums::PayoutStatus::Pending)
+            ),
+            api_enums::PayoutStatus::Success
+        );
+    }
+
+    fn get_pending_payout_attempt() -> diesel_models::PayoutAttempt {
+        diesel_models::PayoutAttempt {
+            status: storage::enums::PayoutStatus::Pending,
+            error_message: None,
+            error_code: None,
+            ..get_payout_attempt("payout_attempt_123", datetime!(2024-04-25 10:00:00))
+        }
+    }
+
+    #[test]
+    fn test_payout_sync_with_changed_status() {
+        let payout_attempt = get_pending_payout_attempt();
+        let sync_response = Ok(PayoutsResponseData {
+            status: Some(storage::enums::PayoutStatus::Success),
+            connector_payout_id: "payout_attempt_123_connector".to_string(),
+            payout_eligible: None,
+        });
+
+        assert!(matches!(
+            get_payout_sync_result(&payout_attempt, sync_response),
+            PayoutSyncResult::Updated(storage::PayoutAttemptUpdate::StatusUpdate {
+                status: storage::enums::PayoutStatus::Success,
+                error_code: None,
+                ..
+            })
+        ));
+
+        let sync_response = Err(ErrorResponse {
+            code: "transfer.not_found".to_string(),
+            message: "Transfer not found".to_string(),
+            ..Default::default()
+        });
+        assert!(matches!(
+            get_payout_sync_result(&payout_attempt, sync_response),
+            PayoutSyncResult::Updated(storage::PayoutAttemptUpdate::StatusUpdate {
+                status: storage::enums::PayoutStatus::Pending,
+                error_code: Some(code),
+                ..
+            }) if code == "transfer.not_found"
+        ));
+    }
+
+    #[test]
+    fn test_payout_sync_with_unchanged_status() {
+        let payout_attempt = get_pending_payout_attempt();
+        let sync_response = Ok(PayoutsResponseData {
+            status: Some(storage::enums::PayoutStatus::Pending),
+            connector_payout_id: "payout_attempt_123_connector".to_string(),
+            payout_eligible: None,
+        });
+
+        assert!(matches!(
+            get_payout_sync_result(&payout_attempt, sync_response),
+            PayoutSyncResult::Unchanged
+        ));
+    }
+
+    #[test]
+    fn test_payout_sync_without_status() {
+        let payout_attempt = get_pending_payout_attempt();
+        let sync_response = Ok(PayoutsResponseData {
+            status: None,
+            connector_payout_id: "payout_attempt_123_connector".to_string(),
+            payout_eligible: None,
+        });
+
+        assert!(matches!(
+            get_payout_sync_result(&payout_attempt, sync_response),
+            PayoutSyncResult::Unchanged
+        ));
+    }
+
+    #[test]
+    fn test_payout_sync_is_supported_by_connector() {
+        let connectors = settings::Connectors::default();
+        let get_connector = |connector_name: &str| {
+            api::ConnectorData::get_payout_connector_by_name(
+                &connectors,
+                connector_name,
+                api::GetToken::Connector,
+                None,
+            )
+            .unwrap()
+            .connector
+        };
+
+        assert!(get_connector("wise").supports_payout_sync());
+        assert!(!get_connector("adyen").supports_payout_sync());
+    }
+
+    #[test]
+    fn test_validate_connector_balance_with_sufficient_balance() {
+        assert!(validate_connector_balance(Some(1500), 1000, api_enums::Currency::EUR).is_ok());
+        assert!(validate_connector_balance(Some(1000), 1000, api_enums::Currency::EUR).is_ok());
+    }
+
+    #[test]
+    fn test_validate_connector_balance_with_insufficient_balance() {
+        let error =
+            validate_connector_balance(Some(400), 1000, api_enums::Currency::EUR).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InsufficientConnectorBalance {
+                available_amount: 400,
+                required_amount: 1000,
+                currency,
+            } if currency == "EUR"
+        ));
+    }
+
+    #[test]
+    fn test_validate_connector_balance_when_balance_is_unavailable() {
+        assert!(validate_connector_balance(None, 1000, api_enums::Currency::EUR).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_payout_attempts() {
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let payouts = get_payouts(None);
+        let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+
+        let attempts = get_payout_attempts(&mock_db, &payouts, storage_scheme)
+            .await
+            .unwrap();
+        assert!(attempts.is_empty());
+
+        mock_db.payout_attempt.lock().await.push(get_payout_attempt(
+            "payout_123_2",
+            datetime!(2024-04-25 10:10:00),
+        ));
+
+        let attempts = get_payout_attempts(&mock_db, &payouts, storage_scheme)
+            .await
+            .unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].attempt_id, "payout_123_2");
+        assert_eq!(attempts[0].connector, Some("adyen".to_string()));
+        assert_eq!(attempts[0].error_code, Some("E001".to_string()));
+        assert_eq!(
+            attempts[0].error_message,
+            Some("Insufficient balance".to_string())
+        );
+        assert_eq!(attempts[0].created_at, Some(datetime!(2024-04-25 10:10:00)));
+
+        mock_db.payout_attempt.lock().await.extend([
+            get_payout_attempt("payout_123_3", datetime!(2024-04-25 10:20:00)),
+            get_payout_attempt("payout_123_1", datetime!(2024-04-25 10:00:00)),
+        ]);
+
+        let attempt_ids = get_payout_attempts(&mock_db, &payouts, storage_scheme)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|attempt| attempt.attempt_id)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attempt_ids,
+            vec!["payout_123_1", "payout_123_2", "payout_123_3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_payout_by_merchant_id_payout_id() {
+        use storage_impl::DataModelExt;
+
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mock_db;
+        let payouts = get_payouts(None);
+        let payout_attempt = storage::PayoutAttempt::from_storage_model(get_payout_attempt(
+            "payout_123_1",
+            datetime!(2024-04-25 10:00:00),
+        ));
+        let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+        mock_db
+            .payouts
+            .lock()
+            .await
+            .push(payouts.clone().to_storage_model());
+
+        let metadata = Secret::new(serde_json::json!({ "invoice_id": "42" }));
+        let updated_payouts = db
+            .update_payout_by_merchant_id_payout_id(
+                &payouts.merchant_id,
+                &payouts.payout_id,
+                storage::PayoutsUpdate::MetadataUpdate {
+                    description: Some("Payout for invoice 42".to_string()),
+                    metadata: Some(metadata.clone()),
+                },
+                &payout_attempt,
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            updated_payouts.description,
+            Some("Payout for invoice 42".to_string())
+        );
+        assert_eq!(updated_payouts.metadata, Some(metadata));
+        assert_eq!(updated_payouts.amount, payouts.amount);
+        assert_eq!(
+            updated_payouts.destination_currency,
+            payouts.destination_currency
+        );
+        assert_eq!(updated_payouts.status, payouts.status);
+
+        let result = db
+            .update_payout_by_merchant_id_payout_id(
+                &payouts.merchant_id,
+                "payout_unknown",
+                storage::PayoutsUpdate::MetadataUpdate {
+                    description: None,
+                    metadata: None,
+                },
+                &payout_attempt,
+                storage_scheme,
+            )
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            data_models::errors::StorageError::ValueNotFound(_)
+        ));
+    }
+
+    fn get_payment_method_new(
+        payment_method_id: &str,
+        customer_id: &str,
+        payment_method: api_enums::PaymentMethod,
+        payment_method_type: api_enums::PaymentMethodType,
+    ) -> storage::PaymentMethodNew {
+        storage::PaymentMethodNew {
+            customer_id: customer_id.to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: payment_method_id.to_string(),
+            locker_id: Some(format!("{payment_method_id}_locker")),
+            payment_method: Some(payment_method),
+            payment_method_type: Some(payment_method_type),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_customer_payout_method_masks_account_details() {
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mock_db;
+        let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+        let payment_method = db
+            .insert_payment_method(
+                get_payment_method_new(
+                    "pm_123",
+                    "cus_123",
+                    api_enums::PaymentMethod::BankTransfer,
+                    api_enums::PaymentMethodType::Sepa,
+                ),
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        let iban = "DE89370400440532013000";
+        let sepa = api::PayoutMethodData::Bank(api::BankPayout::Sepa(api::SepaBankTransfer {
+            iban: Secret::new(iban.to_string()),
+            ..Default::default()
+        }));
+        let customer_payout_method = get_customer_payout_method(&payment_method, &sepa);
+        assert_eq!(customer_payout_method.payout_method_id, "pm_123");
+        assert_eq!(customer_payout_method.customer_id, "cus_123");
+        assert_eq!(
+            customer_payout_method.payout_type,
+            api_enums::PayoutType::Bank
+        );
+        assert_eq!(
+            customer_payout_method.payout_method_type,
+            Some(api_enums::PaymentMethodType::Sepa)
+        );
+        assert_eq!(
+            customer_payout_method.masked_account_number.as_deref(),
+            Some("******************3000")
+        );
+        assert_eq!(customer_payout_method.card_last4, None);
+        let response = serde_json::to_string(&customer_payout_method).unwrap();
+        assert!(!response.contains(iban));
+
+        let ach = api::PayoutMethodData::Bank(api::BankPayout::Ach(api::AchBankTransfer {
+            bank_account_number: Secret::new("000123456".to_string()),
+            bank_routing_number: Secret::new("110000000".to_string()),
+            ..Default::default()
+        }));
+        let customer_payout_method = get_customer_payout_method(&payment_method, &ach);
+        assert_eq!(
+            customer_payout_method.masked_account_number.as_deref(),
+            Some("*****3456")
+        );
+
+        let short_account =
+            api::PayoutMethodData::Bank(api::BankPayout::Bacs(api::BacsBankTransfer {
+                bank_account_number: Secret::new("1234".to_string()),
+                bank_sort_code: Secret::new("98-76-54".to_string()),
+                ..Default::default()
+            }));
+        let customer_payout_method = get_customer_payout_method(&payment_method, &short_account);
+        assert_eq!(
+            customer_payout_method.masked_account_number.as_deref(),
+            Some("****")
+        );
+
+        let card = api::PayoutMethodData::Card(api::CardPayout {
+            card_number: "4111111111111111".parse().unwrap(),
+            expiry_month: Secret::new("12".to_string()),
+            expiry_year: Secret::new("2030".to_string()),
+            card_holder_name: None,
+        });
+        let customer_payout_method = get_customer_payout_method(&payment_method, &card);
+        assert_eq!(
+            customer_payout_method.payout_type,
+            api_enums::PayoutType::Card
+        );
+        assert_eq!(customer_payout_method.card_last4.as_deref(), Some("1111"));
+        assert_eq!(customer_payout_method.masked_account_number, None);
+        let response = serde_json::to_string(&customer_payout_method).unwrap();
+        assert!(!response.contains("4111111111111111"));
+    }
+
+    #[tokio::test]
+    async fn test_find_saved_payout_method() {
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mock_db;
+        let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+        for payment_method_new in [
+            get_payment_method_new(
+                "pm_bank",
+                "cus_123",
+                api_enums::PaymentMethod::BankTransfer,
+                api_enums::PaymentMethodType::Sepa,
+            ),
+            get_payment_method_new(
+                "pm_card",
+                "cus_123",
+                api_enums::PaymentMethod::Card,
+                api_enums::PaymentMethodType::Debit,
+            ),
+            get_payment_method_new(
+                "pm_bank_debit",
+                "cus_123",
+                api_enums::PaymentMethod::BankDebit,
+                api_enums::PaymentMethodType::Ach,
+            ),
+            get_payment_method_new(
+                "pm_other_customer",
+                "cus_456",
+                api_enums::PaymentMethod::BankTransfer,
+                api_enums::PaymentMethodType::Ach,
+            ),
+        ] {
+            db.insert_payment_method(payment_method_new, storage_scheme)
+                .await
+                .unwrap();
+        }
+
+        let saved_payout_method_ids = db
+            .find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+                "cus_123",
+                "merchant_123",
+                storage::enums::PaymentMethodStatus::Active,
+                PAYOUT_CAPABLE_PAYMENT_METHODS.to_vec(),
+                None,
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|payment_method| payment_method.payment_method_id)
+            .collect::<Vec<_>>();
+        assert_eq!(saved_payout_method_ids, vec!["pm_bank", "pm_card"]);
+
+        let payment_method =
+            find_saved_payout_method(db, "merchant_123", "cus_123", "pm_bank", storage_scheme)
+                .await
+                .unwrap();
+        assert_eq!(payment_method.locker_id, Some("pm_bank_locker".to_string()));
+
+        for (customer_id, payout_method_id) in [
+            ("cus_123", "pm_unknown"),
+            ("cus_123", "pm_bank_debit"),
+            ("cus_123", "pm_other_customer"),
+            ("cus_456", "pm_bank"),
+        ] {
+            let result = find_saved_payout_method(
+                db,
+                "merchant_123",
+                customer_id,
+                payout_method_id,
+                storage_scheme,
+            )
+            .await;
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::PaymentMethodNotFound
+            ));
+        }
+    }
+
+    #[test]
+    fn test_requested_payout_connector_is_resolved_if_configured() {
+        let configured_connectors = [
+            api_enums::PayoutConnectors::Adyen,
+            api_enums::PayoutConnectors::Wise,
+        ];
+
+        assert_eq!(
+            resolve_payout_connector(
+                Some(&[
+                    api_enums::PayoutConnectors::Paypal,
+                    api_enums::PayoutConnectors::Wise,
+                ]),
+                &configured_connectors,
+            ),
+            Some(api_enums::PayoutConnectors::Wise)
+        );
+        assert_eq!(
+            resolve_payout_connector(
+                Some(&[api_enums::PayoutConnectors::Paypal]),
+                &configured_connectors,
+            ),
+            None
+        );
+        assert_eq!(
+            resolve_payout_connector(None, &configured_connectors),
+            Some(api_enums::PayoutConnectors::Adyen)
+        );
+        assert_eq!(resolve_payout_connector(None, &[]), None);
+    }
+
+    #[test]
+    fn test_request_errors_are_reported_as_validation_errors() {
+        let validation_error =
+            get_payout_validation_error(&errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payout_method_data",
+            })
+            .unwrap();
+        assert_eq!(validation_error.code, "IR_04");
+        assert_eq!(
+            validation_error.message,
+            "Missing required param: payout_method_data"
+        );
+
+        assert!(
+            get_payout_validation_error(&errors::ApiErrorResponse::DuplicatePayout {
+                payout_id: "payout_123".to_string(),
+            })
+            .is_some()
+        );
+        assert!(
+            get_payout_validation_error(&errors::ApiErrorResponse::InternalServerError).is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_payout_id_validation_does_not_write_to_db() {
+        use storage_impl::DataModelExt;
+
+        use crate::core::payouts::validator;
+
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let db: &dyn StorageInterface = &mock_db;
+        let payouts = get_payouts(None);
+        let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+        mock_db
+            .payouts
+            .lock()
+            .await
+            .push(payouts.clone().to_storage_model());
+
+        let existing_payout = validator::validate_uniqueness_of_payout_id_against_merchant_id(
+            db,
+            &payouts.payout_id,
+            &payouts.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        assert!(existing_payout.is_some());
+
+        let new_payout = validator::validate_uniqueness_of_payout_id_against_merchant_id(
+            db,
+            "payout_456",
+            &payouts.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        assert!(new_payout.is_none());
+
+        assert_eq!(mock_db.payouts.lock().await.len(), 1);
+        assert!(mock_db.payout_attempt.lock().await.is_empty());
+    }
+
+    fn get_bank_payout_methods(
+        accepted_currencies: Option<admin::AcceptedCurrencies>,
+        maximum_amount: Option<i32>,
+    ) -> Vec<admin::PaymentMethodsEnabled> {
+        vec![admin::PaymentMethodsEnabled {
+            payment_method: api_enums::PaymentMethod::BankTransfer,
+            payment_method_types: Some(vec![
+                api_models::payment_methods::RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Sepa,
+                    payment_experience: None,
+                    card_networks: None,
+                    accepted_currencies,
+                    accepted_countries: None,
+                    minimum_amount: None,
+                    maximum_amount,
+                    recurring_enabled: false,
+                    installment_payment_enabled: false,
+                },
+            ]),
+        }]
+    }
+
+    fn get_routing_trail(
+        payouts: &storage::Payouts,
+        candidates: &[api_enums::Connector],
+        enabled_payment_methods: &HashMap<String, Vec<admin::PaymentMethodsEnabled>>,
+    ) -> payouts::PayoutRoutingTrail {
+        build_payout_routing_trail(candidates, |connector| {
+            get_payout_connector_skip_reason(
+                connector,
+                payouts,
+                enabled_payment_methods
+                    .get(&connector.to_string())
+                    .map(Vec::as_slice),
+            )
+        })
+    }
+
+    #[test]
+    fn test_payout_is_routed_to_single_candidate() {
+        let payouts = get_payouts(None);
+        let enabled_payment_methods =
+            HashMap::from([("wise".to_string(), get_bank_payout_methods(None, None))]);
+
+        let routing_trail = get_routing_trail(
+            &payouts,
+            &[api_enums::Connector::Wise],
+            &enabled_payment_methods,
+        );
+
+        assert_eq!(
+            routing_trail,
+            payouts::PayoutRoutingTrail {
+                candidates: vec!["wise".to_string()],
+                chosen_connector: Some("wise".to_string()),
+                skipped: vec![],
+                routing_algorithm: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_payout_falls_back_to_next_candidate_when_first_is_rejected() {
+        let payouts = get_payouts(None);
+        let enabled_payment_methods = HashMap::from([
+            (
+                "wise".to_string(),
+                get_bank_payout_methods(
+                    Some(admin::AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::USD,
+                    ])),
+                    None,
+                ),
+            ),
+            (
+                "adyen".to_string(),
+                get_bank_payout_methods(None, Some(5000)),
+            ),
+        ]);
+
+        let routing_trail = get_routing_trail(
+            &payouts,
+            &[api_enums::Connector::Wise, api_enums::Connector::Adyen],
+            &enabled_payment_methods,
+        );
+
+        assert_eq!(routing_trail.chosen_connector, Some("adyen".to_string()));
+        assert_eq!(
+            routing_trail.skipped,
+            vec![payouts::PayoutRoutingSkippedCandidate {
+                connector: "wise".to_string(),
+                reason: payouts::PayoutRoutingSkipReason::UnsupportedCurrency,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_payout_routing_fails_when_all_candidates_are_rejected() {
+        let payouts = get_payouts(None);
+        let enabled_payment_methods = HashMap::from([
+            ("wise".to_string(), get_bank_payout_methods(None, Some(500))),
+            (
+                "paypal".to_string(),
+                get_bank_payout_methods(
+                    Some(admin::AcceptedCurrencies::DisableOnly(vec![
+                        api_enums::Currency::EUR,
+                    ])),
+                    None,
+                ),
+            ),
+        ]);
+
+        let routing_trail = get_routing_trail(
+            &payouts,
+            &[
+                api_enums::Connector::Wise,
+                api_enums::Connector::Adyen,
+                api_enums::Connector::Paypal,
+            ],
+            &enabled_payment_methods,
+        );
+
+        assert_eq!(routing_trail.chosen_connector, None);
+        assert_eq!(
+            routing_trail
+                .skipped
+                .iter()
+                .map(|skipped| skipped.reason)
+                .collect::<Vec<_>>(),
+            vec![
+                payouts::PayoutRoutingSkipReason::OverLimit,
+                payouts::PayoutRoutingSkipReason::Disabled,
+                payouts::PayoutRoutingSkipReason::UnsupportedCurrency,
+            ]
+        );
+        assert_eq!(
+            get_payout_routing_failure_message(&routing_trail),
+            "No connector could process the payout: wise (over_limit), adyen (disabled), paypal (unsupported_currency)"
+        );
+    }
+
+    fn get_volume_split_order(
+        splits: &[(api_enums::RoutableConnectors, u8)],
+        payout_id: &str,
+    ) -> Vec<api_enums::RoutableConnectors> {
+        let splits = splits
+            .iter()
+            .map(|(connector, split)| {
+                serde_json::from_value::<api::routing::ConnectorVolumeSplit>(serde_json::json!({
+                    "connector": { "connector": connector },
+                    "split": split,
+                }))
+                .unwrap()
+            })
+            .collect();
+        routing::perform_volume_split_with_fallback(splits, payout_id)
+            .unwrap()
+            .into_iter()
+            .map(|choice| choice.connector)
+            .collect()
+    }
+
+    #[test]
+    fn test_payout_volume_split_approximates_configured_split() {
+        let splits = [
+            (api_enums::RoutableConnectors::Wise, 70),
+            (api_enums::RoutableConnectors::Adyen, 30),
+        ];
+
+        let wise_payouts = (0..10000)
+            .filter(|index| {
+                get_volume_split_order(&splits, &format!("payout_{index}")).first()
+                    == Some(&api_enums::RoutableConnectors::Wise)
+            })
+            .count();
+
+        assert!(
+            (6700..=7300).contains(&wise_payouts),
+            "{wise_payouts} out of 10000 payouts routed to wise"
+        );
+        // A payout is always routed the same way
+        assert_eq!(
+            get_volume_split_order(&splits, "payout_123"),
+            get_volume_split_order(&splits, "payout_123")
+        );
+    }
+
+    #[test]
+    fn test_payout_volume_split_fallback_honors_remaining_splits() {
+        let splits = [
+            (api_enums::RoutableConnectors::Wise, 50),
+            (api_enums::RoutableConnectors::Adyen, 30),
+            (api_enums::RoutableConnectors::Paypal, 20),
+            (api_enums::RoutableConnectors::Stripe, 0),
+        ];
+
+        let orders = (0..10000)
+            .map(|index| get_volume_split_order(&splits, &format!("payout_{index}")))
+            .collect::<Vec<_>>();
+        let fallbacks_after_wise = orders
+            .iter()
+            .filter(|order| order.first() == Some(&api_enums::RoutableConnectors::Wise))
+            .filter_map(|order| order.get(1))
+            .collect::<Vec<_>>();
+        let adyen_fallbacks = fallbacks_after_wise
+            .iter()
+            .filter(|connector| ***connector == api_enums::RoutableConnectors::Adyen)
+            .count();
+
+        // Adyen takes 30 out of the 50 remaining once wise is chosen
+        let adyen_share = adyen_fallbacks * 100 / fallbacks_after_wise.len();
+        assert!(
+            (55..=65).contains(&adyen_share),
+            "adyen is the fallback of {adyen_share}% of the payouts routed to wise"
+        );
+        // Every connector is a candidate, with the connectors without volume last
+        assert!(orders.iter().all(|order| order.len() == 4
+            && order.last() == Some(&api_enums::RoutableConnectors::Stripe)));
+    }
+
+    #[cfg(feature = "olap")]
+    fn get_export_payouts(
+        payout_id: &str,
+        created_at: time::PrimitiveDateTime,
+    ) -> storage::Payouts {
+        storage::Payouts {
+            payout_id: payout_id.to_string(),
+            created_at,
+            last_modified_at: created_at,
+            status: storage::enums::PayoutStatus::Success,
+            ..get_payouts(None)
+        }
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_exported_csv(
+        mock_db: &crate::db::MockDb,
+        time_range: &api_models::payments::TimeRange,
+        page_size: u32,
+    ) -> String {
+        use futures::TryStreamExt;
+
+        let chunks = export_payouts_as_csv(
+            Box::new(mock_db.clone()),
+            "merchant_123".to_string(),
+            *time_range,
+            page_size,
+            storage::enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        String::from_utf8(chunks.concat()).unwrap()
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_export_payouts_as_csv_pages_through_time_range() {
+        use storage_impl::DataModelExt;
+
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let header = "payout_id,status,amount,currency,connector,customer_id,created_at,last_modified_at,error_code,error_message\n";
+        let time_range = api_models::payments::TimeRange {
+            start_time: datetime!(2024-04-25 00:00:00),
+            end_time: Some(datetime!(2024-04-25 23:59:59)),
+        };
+
+        // A range without payouts only has the header
+        let csv = get_exported_csv(&mock_db, &time_range, 2).await;
+        assert_eq!(csv, header);
+
+        // Payouts sharing a creation time are split across pages without being skipped or
+        // repeated, and payouts outside the range or of other merchants are left out
+        mock_db.payouts.lock().await.extend(
+            [
+                get_export_payouts("payout_e", datetime!(2024-04-25 12:00:00)),
+                get_export_payouts("payout_b", datetime!(2024-04-25 10:00:00)),
+                get_export_payouts("payout_a", datetime!(2024-04-25 10:00:00)),
+                get_export_payouts("payout_d", datetime!(2024-04-25 11:00:00)),
+                get_export_payouts("payout_c", datetime!(2024-04-25 10:00:00)),
+                get_export_payouts("payout_old", datetime!(2024-04-24 10:00:00)),
+                storage::Payouts {
+                    merchant_id: "merchant_456".to_string(),
+                    ..get_export_payouts("payout_other", datetime!(2024-04-25 10:00:00))
+                },
+            ]
+            .map(|payouts| payouts.to_storage_model()),
+        );
+        mock_db
+            .payout_attempt
+            .lock()
+            .await
+            .push(diesel_models::PayoutAttempt {
+                payout_id: "payout_d".to_string(),
+                ..get_payout_attempt("payout_d_1", datetime!(2024-04-25 11:00:00))
+            });
+
+        let csv = get_exported_csv(&mock_db, &time_range, 2).await;
+        assert_eq!(
+            csv,
+            format!(
+                "{header}\
+                payout_a,success,1000,EUR,,cus_123,2024-04-25T10:00:00Z,2024-04-25T10:00:00Z,,\n\
+                payout_b,success,1000,EUR,,cus_123,2024-04-25T10:00:00Z,2024-04-25T10:00:00Z,,\n\
+                payout_c,success,1000,EUR,,cus_123,2024-04-25T10:00:00Z,2024-04-25T10:00:00Z,,\n\
+                payout_d,success,1000,EUR,adyen,cus_123,2024-04-25T11:00:00Z,2024-04-25T11:00:00Z,E001,Insufficient balance\n\
+                payout_e,success,1000,EUR,,cus_123,2024-04-25T12:00:00Z,2024-04-25T12:00:00Z,,\n"
+            )
+        );
+
+        // A page size that divides the number of payouts ends on an empty page
+        let csv_with_even_pages = get_exported_csv(&mock_db, &time_range, 5).await;
+        assert_eq!(csv_with_even_pages, csv);
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_payouts_export_record_escapes_fields() {
+        use storage_impl::DataModelExt;
+
+        let payouts = get_export_payouts("payout_123", datetime!(2024-04-25 10:00:00));
+        let payout_attempt = storage::PayoutAttempt {
+            error_code: Some("E,001".to_string()),
+            error_message: Some("Account \"12\" closed,\nretry later".to_string()),
+            ..storage::PayoutAttempt::from_storage_model(get_payout_attempt(
+                "payout_123_1",
+                datetime!(2024-04-25 10:00:00),
+            ))
+        };
+
+        assert_eq!(
+            get_payouts_export_record(&payouts, Some(&payout_attempt)).unwrap(),
+            "payout_123,success,1000,EUR,adyen,cus_123,2024-04-25T10:00:00Z,2024-04-25T10:00:00Z,\"E,001\",\"Account \"\"12\"\" closed,\nretry later\"\n"
+        );
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_filter_payouts_by_metadata() {
+        use storage_impl::DataModelExt;
+
+        #[allow(clippy::expect_used)]
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let with_metadata =
+            |payouts: storage::Payouts, metadata: serde_json::Value| storage::Payouts {
+                metadata: Some(Secret::new(metadata)),
+                ..payouts
+            };
+        mock_db.payouts.lock().await.extend(
+            [
+                with_metadata(
+                    get_export_payouts("payout_a", datetime!(2024-04-25 10:00:00)),
+                    serde_json::json!({ "batch_ref": "batch_2024_05", "note": "für Müller" }),
+                ),
+                with_metadata(
+                    get_export_payouts("payout_b", datetime!(2024-04-25 11:00:00)),
+                    serde_json::json!({ "batch_ref": "batch_2024_05" }),
+                ),
+                with_metadata(
+                    get_export_payouts("payout_c", datetime!(2024-04-25 12:00:00)),
+                    serde_json::json!({ "batch_ref": "batch_2024_06" }),
+                ),
+                get_export_payouts("payout_d", datetime!(2024-04-25 13:00:00)),
+                with_metadata(
+                    storage::Payouts {
+                        merchant_id: "merchant_456".to_string(),
+                        ..get_export_payouts("payout_other", datetime!(2024-04-25 10:00:00))
+                    },
+                    serde_json::json!({ "batch_ref": "batch_2024_05" }),
+                ),
+            ]
+            .map(|payouts| payouts.to_storage_model()),
+        );
+        async fn filter_payouts(db: &dyn StorageInterface, query: &str) -> Vec<storage::Payouts> {
+            let constraints =
+                serde_urlencoded::from_str::<api::PayoutListConstraints>(query).unwrap();
+            filter_by_constraints(
+                db,
+                &constraints,
+                "merchant_123",
+                storage::enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap()
+        }
+        let get_payout_ids = |payouts: Vec<storage::Payouts>| {
+            payouts
+                .into_iter()
+                .map(|payouts| payouts.payout_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            get_payout_ids(filter_payouts(&mock_db, "metadata%5Bbatch_ref%5D=batch_2024_05").await),
+            vec!["payout_b", "payout_a"]
+        );
+        // Every key of the filter has to match, including values with unicode characters, and
+        // the metadata of the payouts is kept as is through storage
+        let payouts = filter_payouts(
+            &mock_db,
+            "limit=5&metadata%5Bbatch_ref%5D=batch_2024_05&metadata%5Bnote%5D=f%C3%BCr%20M%C3%BCller",
+        )
+        .await;
+        assert_eq!(
+            payouts
+                .iter()
+                .map(|payouts| (
+                    payouts.payout_id.as_str(),
+                    payouts.metadata.clone().map(|metadata| metadata.expose())
+                ))
+                .collect::<Vec<_>>(),
+            vec![(
+                "payout_a",
+                Some(serde_json::json!({ "batch_ref": "batch_2024_05", "note": "für Müller" }))
+            )]
+        );
+        assert!(
+            filter_payouts(&mock_db, "metadata%5Bbatch_ref%5D=batch_2024_07")
+                .await
+                .is_empty()
+        );
+        assert_eq!(
+            get_payout_ids(filter_payouts(&mock_db, "limit=5").await),
+            vec!["payout_d", "payout_c", "payout_b", "payout_a"]
+        );
+    }
+
+    #[test]
+    fn test_payouts_list_query_rejects_unknown_parameters() {
+        for query in [
+            "unknown=1",
+            "metadata=batch_2024_05",
+            "metadata%5B%5D=batch_2024_05",
+        ] {
+            assert!(serde_urlencoded::from_str::<api::PayoutListConstraints>(query).is_err());
+        }
+
+        let constraints = serde_urlencoded::from_str::<api::PayoutListConstraints>(
+            "customer_id=cus_123&metadata%5Bbatch_ref%5D=batch_2024_05",
+        )
+        .unwrap();
+        assert_eq!(constraints.customer_id.as_deref(), Some("cus_123"));
+        assert_eq!(
+            constraints.metadata,
+            Some(HashMap::from([(
+                "batch_ref".to_string(),
+                "batch_2024_05".to_string()
+            )]))
+        );
+    }
+}
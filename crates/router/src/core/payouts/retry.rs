@@ -10,13 +10,12 @@ use router_env::{
 use super::{call_connector_payout, PayoutData};
 use crate::{
     core::{
-        errors::{self, RouterResult, StorageErrorExt},
+        errors::{self, RouterResult},
         payouts,
     },
     db::StorageInterface,
     routes::{self, app, metrics},
     types::{api, domain, storage},
-    utils,
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -250,7 +249,19 @@ pub async fn do_retry(
 ) -> RouterResult<PayoutData> {
     metrics::AUTO_RETRY_PAYOUT_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
-    modify_trackers(state, &connector, merchant_account, &mut payout_data).await?;
+    let previous_status = payout_data.payouts.status;
+    // The retried attempt is routed by the same decision as the attempt it retries
+    let routing_trail = payout_data.payout_attempt.routing_trail.clone();
+    payouts::helpers::create_new_payout_attempt(
+        state,
+        &connector,
+        merchant_account,
+        &mut payout_data,
+        false,
+        routing_trail,
+    )
+    .await?;
+    payouts::audit::record_retry(state, &payout_data, previous_status);
 
     call_connector_payout(
         state,
@@ -263,66 +274,6 @@ pub async fn do_retry(
     .await
 }
 
-#[instrument(skip_all)]
-pub async fn modify_trackers(
-    state: &routes::AppState,
-    connector: &api::ConnectorData,
-    merchant_account: &domain::MerchantAccount,
-    payout_data: &mut PayoutData,
-) -> RouterResult<()> {
-    let new_attempt_count = payout_data.payouts.attempt_count + 1;
-
-    let db = &*state.store;
-
-    // update payout table's attempt count
-    let payouts = payout_data.payouts.to_owned();
-    let updated_payouts = storage::PayoutsUpdate::AttemptCountUpdate {
-        attempt_count: new_attempt_count,
-    };
-
-    let payout_id = payouts.payout_id.clone();
-    payout_data.payouts = db
-        .update_payout(
-            &payout_data.payouts,
-            updated_payouts,
-            &payout_data.payout_attempt,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Error updating payouts")?;
-
-    let payout_attempt_id =
-        utils::get_payment_attempt_id(payout_id.to_owned(), payout_data.payouts.attempt_count);
-
-    let payout_attempt_req = storage::PayoutAttemptNew {
-        payout_attempt_id: payout_attempt_id.to_string(),
-        payout_id: payout_id.to_owned(),
-        customer_id: payout_data.payout_attempt.customer_id.to_owned(),
-        connector: Some(connector.connector_name.to_string()),
-        merchant_id: payout_data.payout_attempt.merchant_id.to_owned(),
-        address_id: payout_data.payout_attempt.address_id.to_owned(),
-        business_country: payout_data.payout_attempt.business_country.to_owned(),
-        business_label: payout_data.payout_attempt.business_label.to_owned(),
-        payout_token: payout_data.payout_attempt.payout_token.to_owned(),
-        profile_id: payout_data.payout_attempt.profile_id.to_string(),
-        ..Default::default()
-    };
-    payout_data.payout_attempt = db
-        .insert_payout_attempt(
-            payout_attempt_req,
-            &payouts,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
-        .attach_printable("Error inserting payouts in db")?;
-
-    payout_data.merchant_connector_account = None;
-
-    Ok(())
-}
-
 pub async fn config_should_call_gsm_payout(
     db: &dyn StorageInterface,
     merchant_id: &String,
@@ -363,7 +314,10 @@ impl GsmValidation for PayoutData {
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
             | common_enums::PayoutStatus::RequiresPayoutMethodData
-            | common_enums::PayoutStatus::RequiresFulfillment => false,
+            | common_enums::PayoutStatus::RequiresFulfillment
+            | common_enums::PayoutStatus::Scheduled
+            | common_enums::PayoutStatus::RequiresVendorAccountCreation
+            | common_enums::PayoutStatus::PartiallyFulfilled => false,
             common_enums::PayoutStatus::Failed => true,
         }
     }
@@ -0,0 +1,941 @@
+use common_utils::{
+    crypto::{self, Encryptable, SignMessage},
+    ext_traits::{BytesExt, Encode, ValueExt},
+    pii,
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::{Date, Duration, PrimitiveDateTime};
+
+use super::{helpers, validator};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    headers,
+    routes::AppState,
+    services,
+    types::{
+        api::{self, payouts},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, OptionExt},
+};
+
+/// Name of the job the payouts created by the runs of payout schedules are attributed to
+const PAYOUT_SCHEDULE_JOB_NAME: &str = "payout_schedules";
+const PAYOUT_SCHEDULE_CALLBACK_TIMEOUT_SECS: u64 = 10;
+
+#[instrument(skip_all)]
+pub async fn payout_schedule_create_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutScheduleCreateRequest,
+) -> RouterResponse<payouts::PayoutScheduleResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    validate_payout_schedule_create_request(&req)?;
+
+    // The payout method is validated again on every run, as it may be deleted in the meantime
+    let payment_method = helpers::find_saved_payout_method(
+        db,
+        merchant_id,
+        &req.customer_id,
+        &req.payout_method_id,
+        merchant_account.storage_scheme,
+    )
+    .await?;
+    validator::validate_saved_payout_method_currency(
+        payment_method.accepted_currency.as_deref(),
+        req.currency,
+        &req.payout_method_id,
+    )?;
+
+    let now = common_utils::date_time::now();
+    let next_run_at = get_first_run_at(&req.cadence, req.starts_at.unwrap_or(now))?;
+    let payout_schedule_new = storage::PayoutScheduleNew {
+        schedule_id: utils::generate_id(crate::consts::ID_LENGTH, "ps"),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id,
+        payout_method_id: req.payout_method_id,
+        profile_id: req.profile_id,
+        currency: req.currency,
+        payout_type: req.payout_type,
+        cadence: req
+            .cadence
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the cadence of the payout schedule")?,
+        amount_source: req
+            .amount_source
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the amount source of the payout schedule")?,
+        description: req.description,
+        status: storage_enums::PayoutScheduleStatus::Active,
+        next_run_at: Some(next_run_at),
+        created_at: now,
+        last_modified_at: now,
+    };
+    let payout_schedule = db
+        .insert_payout_schedule(payout_schedule_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payout schedule")?;
+
+    let process_tracker_entry =
+        get_payout_schedule_process_tracker_entry(&payout_schedule, next_run_at)?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting payout schedule to process_tracker: schedule_id: {}",
+                payout_schedule.schedule_id
+            )
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_schedule_response(payout_schedule)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_schedule_retrieve_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutScheduleRetrieveRequest,
+) -> RouterResponse<payouts::PayoutScheduleResponse> {
+    let payout_schedule = state
+        .store
+        .find_payout_schedule_by_merchant_id_schedule_id(
+            &merchant_account.merchant_id,
+            &req.schedule_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutScheduleNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_schedule_response(payout_schedule)?,
+    ))
+}
+
+/// Cancels the payout schedule, so that no further payouts are made. The payouts already made by
+/// the schedule are not affected.
+#[instrument(skip_all)]
+pub async fn payout_schedule_cancel_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutScheduleRetrieveRequest,
+) -> RouterResponse<payouts::PayoutScheduleResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let payout_schedule = db
+        .find_payout_schedule_by_merchant_id_schedule_id(merchant_id, &req.schedule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutScheduleNotFound)?;
+    utils::when(
+        payout_schedule.status == storage_enums::PayoutScheduleStatus::Cancelled,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout schedule {} is already cancelled",
+                    payout_schedule.schedule_id
+                ),
+            }))
+        },
+    )?;
+
+    // The pending run of the schedule is finished by the workflow once it finds the schedule
+    // cancelled
+    let payout_schedule = db
+        .update_payout_schedule_by_merchant_id_schedule_id(
+            merchant_id,
+            &req.schedule_id,
+            storage::PayoutScheduleUpdate::StatusUpdate {
+                status: storage_enums::PayoutScheduleStatus::Cancelled,
+                next_run_at: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel the payout schedule")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_schedule_response(payout_schedule)?,
+    ))
+}
+
+/// Runs the payout schedule by creating and confirming a payout through the payout create flow,
+/// so that the payout is validated like any other. Failures to create the payout are recorded on
+/// the schedule without stopping it.
+///
+/// Returns the time of the next run of the schedule, which is absent once the schedule is
+/// cancelled.
+#[instrument(skip_all)]
+pub async fn run_payout_schedule(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    schedule_id: &str,
+    now: PrimitiveDateTime,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let payout_schedule = db
+        .find_payout_schedule_by_merchant_id_schedule_id(merchant_id, schedule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutScheduleNotFound)?;
+    let Some(run_at) = get_payout_schedule_run_at(&payout_schedule) else {
+        logger::info!(
+            "Skipping run of payout schedule {} in status {}",
+            schedule_id,
+            payout_schedule.status
+        );
+        return Ok(None);
+    };
+
+    let cadence = parse_cadence(&payout_schedule)?;
+    let payout_result =
+        create_scheduled_payout(state, merchant_account, key_store, &payout_schedule, run_at).await;
+    if let Err(error) = payout_result.as_ref() {
+        logger::error!(
+            ?error,
+            "Failed to create payout for run of payout schedule {}",
+            schedule_id
+        );
+    }
+
+    let payout_schedule_update =
+        get_payout_schedule_run_update(&cadence, run_at, now, &payout_result)?;
+    let payout_schedule = db
+        .update_payout_schedule_by_merchant_id_schedule_id(
+            merchant_id,
+            schedule_id,
+            payout_schedule_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the run of the payout schedule")?;
+
+    Ok(payout_schedule.next_run_at)
+}
+
+/// Creates the payout of the run of the schedule. The payout is identified by the run, so that a
+/// run retried by the scheduler does not create a second payout.
+async fn create_scheduled_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_schedule: &storage::PayoutSchedule,
+    run_at: PrimitiveDateTime,
+) -> RouterResult<String> {
+    let payout_id = get_scheduled_payout_id(&payout_schedule.schedule_id, run_at);
+    let existing_payout = state
+        .store
+        .find_optional_payout_by_merchant_id_payout_id(
+            &merchant_account.merchant_id,
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payout of the run of the payout schedule")?;
+    if existing_payout.is_some() {
+        logger::info!(
+            "Payout {} of the run of payout schedule {} was already created",
+            payout_id,
+            payout_schedule.schedule_id
+        );
+        return Ok(payout_id);
+    }
+
+    let amount_source = payout_schedule
+        .amount_source
+        .clone()
+        .parse_value::<payouts::PayoutScheduleAmountSource>("PayoutScheduleAmountSource")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the amount source of the payout schedule")?;
+    let amount = match amount_source {
+        payouts::PayoutScheduleAmountSource::Fixed { amount } => amount,
+        payouts::PayoutScheduleAmountSource::Callback { url } => {
+            get_callback_amount(state, merchant_account, payout_schedule, &url, run_at).await?
+        }
+    };
+
+    let billing = get_customer_billing_address(
+        state,
+        merchant_account,
+        key_store,
+        &payout_schedule.customer_id,
+    )
+    .await?;
+    let req = get_payout_create_request(payout_schedule, &payout_id, amount, billing);
+    let response = Box::pin(super::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        req,
+        payouts::PayoutEventActor::InternalJob {
+            job_name: PAYOUT_SCHEDULE_JOB_NAME.to_string(),
+        },
+    ))
+    .await;
+    match response {
+        Ok(services::ApplicationResponse::Json(response)) => Ok(response.payout_id),
+        // The payout was created by a concurrent run of the schedule
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayout { .. }
+            ) =>
+        {
+            Ok(payout_id)
+        }
+        Err(error) => Err(error),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response when creating the scheduled payout")),
+    }
+}
+
+/// Returns the address of the customer of the schedule, which is used as the billing address of
+/// its payouts
+async fn get_customer_billing_address(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+) -> RouterResult<Option<api::payments::Address>> {
+    let db = &*state.store;
+    let Some(address_id) = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer of the payout schedule")?
+        .and_then(|customer| customer.address_id)
+    else {
+        return Ok(None);
+    };
+    let address = db
+        .find_address_by_address_id(&address_id, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the address of the customer of the payout schedule")?;
+
+    let phone = api::payments::PhoneDetails {
+        number: address.phone_number.clone().map(Encryptable::into_inner),
+        country_code: address.country_code.clone(),
+    };
+    let email = address.email.clone().map(pii::Email::from);
+    Ok(Some(api::payments::Address {
+        address: Some(address.into()),
+        phone: Some(phone),
+        email,
+    }))
+}
+
+/// Returns the id of the payout of the run of the schedule at the given time
+pub fn get_scheduled_payout_id(schedule_id: &str, run_at: PrimitiveDateTime) -> String {
+    format!("{schedule_id}_{}", run_at.assume_utc().unix_timestamp())
+}
+
+/// Fetches the amount of the payout of the run of the schedule from the merchant's endpoint. The
+/// callback is signed like the outgoing webhooks of the business profile, so that the merchant
+/// can verify that it was made by us.
+async fn get_callback_amount(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_schedule: &storage::PayoutSchedule,
+    url: &str,
+    run_at: PrimitiveDateTime,
+) -> RouterResult<i64> {
+    let profile_id = payout_schedule
+        .profile_id
+        .as_ref()
+        .or(merchant_account.default_profile.as_ref());
+    let payment_response_hash_key = core_utils::validate_and_get_business_profile(
+        &*state.store,
+        profile_id,
+        &merchant_account.merchant_id,
+    )
+    .await?
+    .and_then(|business_profile| business_profile.payment_response_hash_key)
+    .get_required_value("payment_response_hash_key")
+    .attach_printable("Amount callback of the payout schedule cannot be signed")?;
+
+    let callback_request = payouts::PayoutScheduleAmountCallbackRequest {
+        schedule_id: payout_schedule.schedule_id.to_owned(),
+        customer_id: payout_schedule.customer_id.to_owned(),
+        currency: payout_schedule.currency,
+        run_at,
+    };
+    let body = callback_request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the amount callback request")?;
+    let signature = get_callback_signature(&payment_response_hash_key, &body)?;
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url)
+        .attach_default_headers()
+        .headers(vec![
+            (
+                reqwest::header::CONTENT_TYPE.to_string(),
+                mime::APPLICATION_JSON.essence_str().into(),
+            ),
+            (headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()),
+        ])
+        .set_body(RequestContent::RawBytes(body.into_bytes()))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(
+            state,
+            request,
+            Some(PAYOUT_SCHEDULE_CALLBACK_TIMEOUT_SECS),
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the amount callback of the payout schedule")?;
+    let status_code = response.status();
+    utils::when(!status_code.is_success(), || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(format!(
+            "Amount callback of the payout schedule responded with status {status_code}"
+        ))
+    })?;
+
+    let callback_response = response
+        .bytes()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read the response of the amount callback")?
+        .parse_struct::<payouts::PayoutScheduleAmountCallbackResponse>(
+            "PayoutScheduleAmountCallbackResponse",
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the response of the amount callback")?;
+
+    Ok(callback_response.amount)
+}
+
+/// Returns the hex encoded HMAC-SHA512 of the body of the amount callback
+pub fn get_callback_signature(payment_response_hash_key: &str, body: &str) -> RouterResult<String> {
+    crypto::HmacSha512
+        .sign_message(payment_response_hash_key.as_bytes(), body.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the amount callback request")
+}
+
+/// Validates the request on below checks
+/// - cadence refers to a valid day of the week or of the month
+/// - fixed amount is positive
+/// - callback url is a valid https url
+pub fn validate_payout_schedule_create_request(
+    req: &payouts::PayoutScheduleCreateRequest,
+) -> RouterResult<()> {
+    match req.cadence {
+        payouts::PayoutScheduleCadence::Weekly { day_of_week } => {
+            utils::when(!(1..=7).contains(&day_of_week), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "day_of_week must be between 1 and 7".to_string(),
+                }))
+            })
+        }
+        payouts::PayoutScheduleCadence::Monthly { day_of_month } => {
+            utils::when(!(1..=31).contains(&day_of_month), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "day_of_month must be between 1 and 31".to_string(),
+                }))
+            })
+        }
+    }?;
+
+    match &req.amount_source {
+        payouts::PayoutScheduleAmountSource::Fixed { amount } => utils::when(*amount <= 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount must be greater than 0".to_string(),
+            }))
+        }),
+        payouts::PayoutScheduleAmountSource::Callback { url } => url::Url::parse(url)
+            .ok()
+            .filter(|url| url.scheme() == "https")
+            .map(|_| ())
+            .ok_or(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "amount_source.url".to_string(),
+                expected_format: "a valid https url".to_string(),
+            })),
+    }
+}
+
+/// Returns the time of the first run of a schedule with the cadence, starting from the given time.
+/// Runs are made at the same time of the day as the start time.
+pub fn get_first_run_at(
+    cadence: &payouts::PayoutScheduleCadence,
+    starts_at: PrimitiveDateTime,
+) -> RouterResult<PrimitiveDateTime> {
+    let start_date = starts_at.date();
+    let run_date = match *cadence {
+        payouts::PayoutScheduleCadence::Weekly { day_of_week } => {
+            let days_until_run = (i64::from(day_of_week)
+                - i64::from(start_date.weekday().number_from_monday()))
+            .rem_euclid(7);
+            start_date.checked_add(Duration::days(days_until_run))
+        }
+        payouts::PayoutScheduleCadence::Monthly { day_of_month } => {
+            // Months shorter than the day of the month have their run on the last day
+            let get_run_date = |date: Date| {
+                let days_in_month = time::util::days_in_year_month(date.year(), date.month());
+                Date::from_calendar_date(date.year(), date.month(), day_of_month.min(days_in_month))
+                    .ok()
+            };
+            get_run_date(start_date)
+                .filter(|run_date| *run_date >= start_date)
+                .or_else(|| {
+                    let days_in_month =
+                        time::util::days_in_year_month(start_date.year(), start_date.month());
+                    Date::from_calendar_date(start_date.year(), start_date.month(), days_in_month)
+                        .ok()
+                        .and_then(|last_date| last_date.next_day())
+                        .and_then(get_run_date)
+                })
+        }
+    };
+
+    run_date
+        .map(|run_date| PrimitiveDateTime::new(run_date, starts_at.time()))
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Failed to compute the run of the payout schedule")
+}
+
+/// Returns the time of the run after the given one. Runs missed while the scheduler was
+/// unavailable are skipped rather than made all at once.
+pub fn get_next_run_at(
+    cadence: &payouts::PayoutScheduleCadence,
+    run_at: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> RouterResult<PrimitiveDateTime> {
+    let last_run_at = PrimitiveDateTime::new(run_at.date().max(now.date()), run_at.time());
+    let starts_at = last_run_at
+        .date()
+        .next_day()
+        .map(|date| PrimitiveDateTime::new(date, run_at.time()))
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Failed to compute the run of the payout schedule")?;
+    get_first_run_at(cadence, starts_at)
+}
+
+/// Returns the time of the pending run of the schedule, absent if the schedule is not active
+pub fn get_payout_schedule_run_at(
+    payout_schedule: &storage::PayoutSchedule,
+) -> Option<PrimitiveDateTime> {
+    payout_schedule
+        .next_run_at
+        .filter(|_| payout_schedule.status == storage_enums::PayoutScheduleStatus::Active)
+}
+
+/// Returns the update recording the run of the schedule, with the payout created by it or the
+/// reason it failed to create one
+pub fn get_payout_schedule_run_update(
+    cadence: &payouts::PayoutScheduleCadence,
+    run_at: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+    payout_result: &RouterResult<String>,
+) -> RouterResult<storage::PayoutScheduleUpdate> {
+    let (last_payout_id, last_failure_reason) = match payout_result {
+        Ok(payout_id) => (Some(payout_id.to_owned()), None),
+        Err(error) => (
+            None,
+            Some(
+                helpers::get_payout_validation_error(error.current_context())
+                    .map(|validation_error| {
+                        format!("{}: {}", validation_error.code, validation_error.message)
+                    })
+                    .unwrap_or_else(|| "Failed to create the payout".to_string()),
+            ),
+        ),
+    };
+
+    Ok(storage::PayoutScheduleUpdate::RunUpdate {
+        next_run_at: get_next_run_at(cadence, run_at, now)?,
+        last_run_at: now,
+        last_payout_id,
+        last_failure_reason,
+    })
+}
+
+/// Returns the request creating and confirming the payout of a run of the schedule
+pub fn get_payout_create_request(
+    payout_schedule: &storage::PayoutSchedule,
+    payout_id: &str,
+    amount: i64,
+    billing: Option<api::payments::Address>,
+) -> payouts::PayoutCreateRequest {
+    payouts::PayoutCreateRequest {
+        payout_id: Some(payout_id.to_owned()),
+        merchant_id: Some(payout_schedule.merchant_id.to_owned()),
+        amount: Some(api::Amount::from(amount)),
+        currency: Some(payout_schedule.currency),
+        customer_id: Some(payout_schedule.customer_id.to_owned()),
+        payout_type: payout_schedule.payout_type,
+        payout_method_id: Some(payout_schedule.payout_method_id.to_owned()),
+        description: payout_schedule.description.to_owned(),
+        profile_id: payout_schedule.profile_id.to_owned(),
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        payout_schedule_id: Some(payout_schedule.schedule_id.to_owned()),
+        billing,
+        ..Default::default()
+    }
+}
+
+pub fn get_payout_schedule_process_tracker_entry(
+    payout_schedule: &storage::PayoutSchedule,
+    run_at: PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let tracking_data = storage::PayoutScheduleRunTrackingData {
+        schedule_id: payout_schedule.schedule_id.clone(),
+        merchant_id: payout_schedule.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::PayoutScheduleWorkflow;
+    let task = "PAYOUT_SCHEDULE_RUN";
+    let tag = ["PAYOUT", "SCHEDULE"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_schedule.schedule_id,
+        &payout_schedule.merchant_id,
+    );
+    storage::ProcessTrackerNew::new(process_tracker_id, task, runner, tag, tracking_data, run_at)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct payout schedule process tracker task")
+}
+
+fn parse_cadence(
+    payout_schedule: &storage::PayoutSchedule,
+) -> RouterResult<payouts::PayoutScheduleCadence> {
+    payout_schedule
+        .cadence
+        .clone()
+        .parse_value("PayoutScheduleCadence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the cadence of the payout schedule")
+}
+
+fn get_payout_schedule_response(
+    payout_schedule: storage::PayoutSchedule,
+) -> RouterResult<payouts::PayoutScheduleResponse> {
+    let cadence = parse_cadence(&payout_schedule)?;
+    let amount_source = payout_schedule
+        .amount_source
+        .parse_value("PayoutScheduleAmountSource")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the amount source of the payout schedule")?;
+
+    Ok(payouts::PayoutScheduleResponse {
+        schedule_id: payout_schedule.schedule_id,
+        merchant_id: payout_schedule.merchant_id,
+        customer_id: payout_schedule.customer_id,
+        payout_method_id: payout_schedule.payout_method_id,
+        profile_id: payout_schedule.profile_id,
+        currency: payout_schedule.currency,
+        payout_type: payout_schedule.payout_type,
+        cadence,
+        amount_source,
+        description: payout_schedule.description,
+        status: payout_schedule.status,
+        next_run_at: payout_schedule.next_run_at,
+        last_run_at: payout_schedule.last_run_at,
+        last_payout_id: payout_schedule.last_payout_id,
+        last_failure_reason: payout_schedule.last_failure_reason,
+        created_at: payout_schedule.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    const WEEKLY_ON_FRIDAY: payouts::PayoutScheduleCadence =
+        payouts::PayoutScheduleCadence::Weekly { day_of_week: 5 };
+
+    fn get_payout_schedule(next_run_at: PrimitiveDateTime) -> storage::PayoutSchedule {
+        storage::PayoutSchedule {
+            schedule_id: "ps_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            payout_method_id: "pm_123".to_string(),
+            profile_id: None,
+            currency: storage_enums::Currency::USD,
+            payout_type: Some(storage_enums::PayoutType::Bank),
+            cadence: WEEKLY_ON_FRIDAY.encode_to_value().unwrap(),
+            amount_source: payouts::PayoutScheduleAmountSource::Fixed { amount: 1000 }
+                .encode_to_value()
+                .unwrap(),
+            description: None,
+            status: storage_enums::PayoutScheduleStatus::Active,
+            next_run_at: Some(next_run_at),
+            last_run_at: None,
+            last_payout_id: None,
+            last_failure_reason: None,
+            created_at: datetime!(2024-05-13 09:00:00),
+            last_modified_at: datetime!(2024-05-13 09:00:00),
+        }
+    }
+
+    /// Runs the schedule at the given time of the mocked clock, with the outcome of the payout
+    fn run(
+        payout_schedule: storage::PayoutSchedule,
+        now: PrimitiveDateTime,
+        payout_result: RouterResult<String>,
+    ) -> storage::PayoutSchedule {
+        let run_at = get_payout_schedule_run_at(&payout_schedule).unwrap();
+        get_payout_schedule_run_update(
+            &parse_cadence(&payout_schedule).unwrap(),
+            run_at,
+            now,
+            &payout_result,
+        )
+        .unwrap()
+        .apply_changeset(payout_schedule)
+    }
+
+    #[test]
+    fn test_first_run_follows_the_cadence() {
+        // 2024-05-13 is a Monday
+        let starts_at = datetime!(2024-05-13 09:00:00);
+        assert_eq!(
+            get_first_run_at(&WEEKLY_ON_FRIDAY, starts_at).unwrap(),
+            datetime!(2024-05-17 09:00:00)
+        );
+        assert_eq!(
+            get_first_run_at(
+                &payouts::PayoutScheduleCadence::Weekly { day_of_week: 1 },
+                starts_at
+            )
+            .unwrap(),
+            starts_at
+        );
+        assert_eq!(
+            get_first_run_at(
+                &payouts::PayoutScheduleCadence::Monthly { day_of_month: 10 },
+                starts_at
+            )
+            .unwrap(),
+            datetime!(2024-06-10 09:00:00)
+        );
+        // Months shorter than the day of the month have their run on the last day
+        let month_end = payouts::PayoutScheduleCadence::Monthly { day_of_month: 31 };
+        assert_eq!(
+            get_first_run_at(&month_end, datetime!(2024-02-10 09:00:00)).unwrap(),
+            datetime!(2024-02-29 09:00:00)
+        );
+        assert_eq!(
+            get_next_run_at(
+                &month_end,
+                datetime!(2024-02-29 09:00:00),
+                datetime!(2024-02-29 09:00:05)
+            )
+            .unwrap(),
+            datetime!(2024-03-31 09:00:00)
+        );
+        assert_eq!(
+            get_next_run_at(
+                &month_end,
+                datetime!(2024-12-31 09:00:00),
+                datetime!(2024-12-31 09:00:05)
+            )
+            .unwrap(),
+            datetime!(2025-01-31 09:00:00)
+        );
+    }
+
+    #[test]
+    fn test_payout_schedule_runs_on_every_tick() {
+        let payout_schedule = get_payout_schedule(datetime!(2024-05-17 09:00:00));
+        let payout_id =
+            get_scheduled_payout_id(&payout_schedule.schedule_id, datetime!(2024-05-17 09:00:00));
+        assert_eq!(payout_id, "ps_123_1715936400");
+        let req = get_payout_create_request(&payout_schedule, &payout_id, 1000, None);
+        assert_eq!(req.payout_id.as_deref(), Some("ps_123_1715936400"));
+        assert_eq!(req.payout_schedule_id.as_deref(), Some("ps_123"));
+        assert_eq!(req.payout_method_id.as_deref(), Some("pm_123"));
+        assert_eq!(req.confirm, Some(true));
+        assert_eq!(i64::from(req.amount.unwrap()), 1000);
+
+        let payout_schedule = run(
+            payout_schedule,
+            datetime!(2024-05-17 09:00:05),
+            Ok("payout_1".to_string()),
+        );
+        assert_eq!(
+            payout_schedule.next_run_at,
+            Some(datetime!(2024-05-24 09:00:00))
+        );
+        assert_eq!(
+            payout_schedule.last_run_at,
+            Some(datetime!(2024-05-17 09:00:05))
+        );
+        assert_eq!(payout_schedule.last_payout_id.as_deref(), Some("payout_1"));
+
+        let payout_schedule = run(
+            payout_schedule,
+            datetime!(2024-05-24 09:00:02),
+            Ok("payout_2".to_string()),
+        );
+        assert_eq!(
+            payout_schedule.next_run_at,
+            Some(datetime!(2024-05-31 09:00:00))
+        );
+        assert_eq!(payout_schedule.last_payout_id.as_deref(), Some("payout_2"));
+        assert_eq!(
+            payout_schedule.status,
+            storage_enums::PayoutScheduleStatus::Active
+        );
+
+        // Runs missed while the scheduler was unavailable are skipped
+        let payout_schedule = run(
+            payout_schedule,
+            datetime!(2024-06-12 10:00:00),
+            Ok("payout_3".to_string()),
+        );
+        assert_eq!(
+            payout_schedule.next_run_at,
+            Some(datetime!(2024-06-14 09:00:00))
+        );
+    }
+
+    #[test]
+    fn test_cancelled_payout_schedule_is_not_run() {
+        let payout_schedule = storage::PayoutScheduleUpdate::StatusUpdate {
+            status: storage_enums::PayoutScheduleStatus::Cancelled,
+            next_run_at: None,
+        }
+        .apply_changeset(get_payout_schedule(datetime!(2024-05-17 09:00:00)));
+
+        assert_eq!(get_payout_schedule_run_at(&payout_schedule), None);
+        assert_eq!(
+            get_payout_schedule_run_at(&storage::PayoutSchedule {
+                status: storage_enums::PayoutScheduleStatus::Cancelled,
+                ..get_payout_schedule(datetime!(2024-05-17 09:00:00))
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_failed_run_is_recorded_and_schedule_keeps_going() {
+        let payout_schedule = get_payout_schedule(datetime!(2024-05-17 09:00:00));
+        let payout_result = validator::validate_saved_payout_method_currency(
+            Some(&[storage_enums::Currency::EUR]),
+            payout_schedule.currency,
+            &payout_schedule.payout_method_id,
+        )
+        .map(|_| "payout_1".to_string());
+
+        let payout_schedule = run(
+            payout_schedule,
+            datetime!(2024-05-17 09:00:05),
+            payout_result,
+        );
+        assert_eq!(
+            payout_schedule.status,
+            storage_enums::PayoutScheduleStatus::Active
+        );
+        assert_eq!(
+            payout_schedule.next_run_at,
+            Some(datetime!(2024-05-24 09:00:00))
+        );
+        assert_eq!(payout_schedule.last_payout_id, None);
+        assert_eq!(
+            payout_schedule.last_failure_reason.as_deref(),
+            Some("IR_19: currency USD is not supported by the payout method pm_123")
+        );
+
+        // The failure is cleared by the next successful run
+        let payout_schedule = run(
+            payout_schedule,
+            datetime!(2024-05-24 09:00:05),
+            Ok("payout_2".to_string()),
+        );
+        assert_eq!(payout_schedule.last_payout_id.as_deref(), Some("payout_2"));
+        assert_eq!(payout_schedule.last_failure_reason, None);
+    }
+
+    #[test]
+    fn test_invalid_payout_schedule_is_rejected() {
+        let req = payouts::PayoutScheduleCreateRequest {
+            customer_id: "cus_123".to_string(),
+            payout_method_id: "pm_123".to_string(),
+            currency: storage_enums::Currency::USD,
+            payout_type: None,
+            cadence: WEEKLY_ON_FRIDAY,
+            amount_source: payouts::PayoutScheduleAmountSource::Callback {
+                url: "https://merchant.example.com/payout_amount".to_string(),
+            },
+            starts_at: None,
+            description: None,
+            profile_id: None,
+        };
+        assert!(validate_payout_schedule_create_request(&req).is_ok());
+
+        for req in [
+            payouts::PayoutScheduleCreateRequest {
+                cadence: payouts::PayoutScheduleCadence::Weekly { day_of_week: 0 },
+                ..req.clone()
+            },
+            payouts::PayoutScheduleCreateRequest {
+                cadence: payouts::PayoutScheduleCadence::Monthly { day_of_month: 32 },
+                ..req.clone()
+            },
+            payouts::PayoutScheduleCreateRequest {
+                amount_source: payouts::PayoutScheduleAmountSource::Fixed { amount: 0 },
+                ..req.clone()
+            },
+            payouts::PayoutScheduleCreateRequest {
+                amount_source: payouts::PayoutScheduleAmountSource::Callback {
+                    url: "not a url".to_string(),
+                },
+                ..req.clone()
+            },
+            payouts::PayoutScheduleCreateRequest {
+                amount_source: payouts::PayoutScheduleAmountSource::Callback {
+                    url: "http://merchant.example.com/payout_amount".to_string(),
+                },
+                ..req.clone()
+            },
+        ] {
+            assert!(validate_payout_schedule_create_request(&req).is_err());
+        }
+    }
+
+    #[test]
+    fn test_callback_signature_depends_on_the_key_and_the_body() {
+        let body = r#"{"schedule_id":"ps_123"}"#;
+        let signature = get_callback_signature("key_123", body).unwrap();
+
+        assert_eq!(signature.len(), 128);
+        assert_eq!(get_callback_signature("key_123", body).unwrap(), signature);
+        assert_ne!(get_callback_signature("key_456", body).unwrap(), signature);
+        assert_ne!(
+            get_callback_signature("key_123", r#"{"schedule_id":"ps_456"}"#).unwrap(),
+            signature
+        );
+    }
+}
@@ -1,19 +1,38 @@
+use std::collections::HashSet;
+
+use api_models::admin;
 #[cfg(feature = "olap")]
 use common_utils::errors::CustomResult;
+use common_utils::{
+    consts::{PAYOUT_METADATA_MAX_KEYS, PAYOUT_METADATA_MAX_SIZE_IN_BYTES},
+    crypto::{self, SignMessage},
+    ext_traits::{StringExt, ValueExt},
+    pii::{self, Email},
+    validation as common_validation,
+};
 pub use data_models::errors::StorageError;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use time::{Duration, PrimitiveDateTime};
 
-use super::helpers;
+use super::{fx, helpers, PayoutData};
 use crate::{
+    configs::settings,
     core::{
-        errors::{self, RouterResult},
-        utils as core_utils,
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResult, StorageErrorExt},
+        refunds, utils as core_utils,
     },
     db::StorageInterface,
     routes::AppState,
-    types::{api::payouts, domain, storage},
-    utils,
+    types::{
+        api::{enums as api_enums, payouts},
+        domain, storage,
+        transformers::ForeignFrom,
+    },
+    utils::{self, OptionExt},
 };
 
 #[instrument(skip(db))]
@@ -40,16 +59,56 @@ pub async fn validate_uniqueness_of_payout_id_against_merchant_id(
     }
 }
 
+/// Values of a payout create request resolved while validating it
+#[derive(Debug)]
+pub struct ValidatedPayoutCreateRequest {
+    pub payout_id: String,
+    /// Payout method data referenced by the payout_token or payout_method_id of the request
+    pub payout_method_data: Option<payouts::PayoutMethodData>,
+    pub profile_id: String,
+    pub fx_quote: Option<fx::FxQuote>,
+    pub recipient_contact_details: RecipientContactDetails,
+    /// Payout method data passed in the request
+    pub request_payout_method_data: Option<payouts::PayoutMethodData>,
+    pub payout_type: api_enums::PayoutType,
+    /// Amount of the payout computed from the split percentage of the source payment
+    pub source_payment_amount: Option<i64>,
+}
+
 /// Validates the request on below checks
 /// - merchant_id passed is same as the one in merchant_account table
 /// - payout_id is unique against merchant_id
+/// - metadata is a flat map of string values within the limits on its keys and size
 /// - payout_token provided is legitimate
+/// - payout_method_id, if passed, refers to a payout method saved for the customer
+/// - payout method referenced by payout_token or payout_method_id has not expired
+/// - saved payout method referenced by payout_method_id accepts the payout currency
+/// - bank details in payout_method_data are well formed
+/// - bank payout method supports the payout currency and destination country
+/// - connectors passed in the request support the payout priority
+/// - scheduled_at, if passed, lies within the allowed scheduling window
+/// - FX quote for a cross currency payout, if being confirmed, has not expired
+/// - recipient email and phone number, if passed, are well formed
+/// - beneficiary country is not blocked for payouts
+/// - beneficiary account is not one of the accounts of the merchant, if self payouts are
+///   restricted for the merchant
+/// - KYC of the merchant is verified, if KYC is required for payouts of the payout_type by the
+///   merchant
+/// - beneficiary of the payout method has a name
+/// - payout method data matches the payout_type, which defaults to the default payout method of
+///   the business profile when not passed
+/// - source payment, if passed, is captured, is in the payout currency and has enough of its
+///   captured amount left for the payout
+///
+/// The payout method data referenced by the request and the one passed in it are returned with
+/// the beneficiary name normalized, along with the amount of the payout computed from the split
+/// percentage of the source payment
 pub async fn validate_create_request(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
     req: &payouts::PayoutCreateRequest,
     merchant_key_store: &domain::MerchantKeyStore,
-) -> RouterResult<(String, Option<payouts::PayoutMethodData>, String)> {
+) -> RouterResult<ValidatedPayoutCreateRequest> {
     let merchant_id = &merchant_account.merchant_id;
 
     // Merchant ID
@@ -85,9 +144,44 @@ pub async fn validate_create_request(
         None => Ok(()),
     }?;
 
-    // Payout token
-    let payout_method_data = match req.payout_token.to_owned() {
-        Some(payout_token) => {
+    // Metadata
+    validate_payout_metadata(req.metadata.as_ref())?;
+
+    // Payout method data
+    if let Some(payout_method_data) = req.payout_method_data.as_ref() {
+        validate_payout_method_data(payout_method_data)?;
+    }
+
+    // Profile ID
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        req.business_country,
+        req.business_label.as_ref(),
+        merchant_account,
+        req.profile_id.as_ref(),
+        &*state.store,
+        false,
+    )
+    .await?;
+    let business_profile =
+        core_utils::validate_and_get_business_profile(db, Some(&profile_id), merchant_id).await?;
+
+    // Default payout method
+    let default_payout_method = business_profile
+        .as_ref()
+        .and_then(|business_profile| business_profile.default_payout_method.clone())
+        .map(|default_payout_method| {
+            default_payout_method.parse_value::<admin::DefaultPayoutMethod>("DefaultPayoutMethod")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse default_payout_method of the business profile")?;
+
+    // Payout token / saved payout method
+    let payout_method_data = match (req.payout_token.to_owned(), req.payout_method_id.as_ref()) {
+        (Some(_), Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only one of payout_token or payout_method_id can be passed".to_string(),
+        }))?,
+        (Some(payout_token), None) => {
             let customer_id = req.customer_id.to_owned().map_or("".to_string(), |c| c);
             helpers::make_payout_method_data(
                 state,
@@ -95,63 +189,3437 @@ pub async fn validate_create_request(
                 Some(&payout_token),
                 &customer_id,
                 &merchant_account.merchant_id,
-                req.payout_type.as_ref(),
+                req.payout_type
+                    .or(default_payout_method
+                        .as_ref()
+                        .map(|default_payout_method| default_payout_method.payout_type))
+                    .as_ref(),
                 merchant_key_store,
                 None,
                 merchant_account.storage_scheme,
             )
             .await?
         }
-        None => None,
+        (None, Some(payout_method_id)) => {
+            let customer_id = req.customer_id.as_ref().get_required_value("customer_id")?;
+            let payment_method = helpers::find_saved_payout_method(
+                db,
+                merchant_id,
+                customer_id,
+                payout_method_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+            if let Some(currency) = req.currency {
+                validate_saved_payout_method_currency(
+                    payment_method.accepted_currency.as_deref(),
+                    currency,
+                    payout_method_id,
+                )?;
+            }
+            Some(
+                helpers::get_saved_payout_method_data(state, &payment_method, merchant_key_store)
+                    .await?,
+            )
+        }
+        (None, None) => None,
     };
+    if let Some(payout_method_data) = payout_method_data.as_ref() {
+        validate_payout_method_expiry(payout_method_data, common_utils::date_time::now())?;
+    }
 
-    // Profile ID
-    let profile_id = core_utils::get_profile_id_from_business_details(
-        req.business_country,
-        req.business_label.as_ref(),
+    // Payout type
+    let payout_type = resolve_payout_type(
+        req.payout_type,
+        default_payout_method.as_ref(),
+        payout_method_data
+            .as_ref()
+            .or(req.payout_method_data.as_ref()),
+    )?;
+
+    // Bank payout compatibility
+    if let (Some(payout_method_data), Some(currency)) = (
+        payout_method_data
+            .as_ref()
+            .or(req.payout_method_data.as_ref()),
+        req.currency,
+    ) {
+        validate_bank_payout_compatibility(
+            payout_method_data,
+            currency,
+            req.billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+        )?;
+    }
+
+    // Payout priority
+    if let Some(connectors) = req.connector.as_ref() {
+        validate_payout_priority(
+            req.priority.unwrap_or_default(),
+            payout_type,
+            &connectors
+                .iter()
+                .map(|connector| api_enums::Connector::from(*connector))
+                .collect::<Vec<_>>(),
+        )?;
+    }
+
+    // Payout schedule
+    if let Some(scheduled_at) = req.scheduled_at {
+        validate_payout_schedule(
+            scheduled_at,
+            common_utils::date_time::now(),
+            state.conf.payouts.max_schedule_horizon_in_days,
+        )?;
+        utils::when(
+            req.payout_method_data.is_none()
+                && req.payout_token.is_none()
+                && req.payout_method_id.is_none(),
+            || {
+                Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "payout_method_data",
+                })
+                .attach_printable("payout_method_data is required for scheduling a payout"))
+            },
+        )?;
+    }
+
+    // Source payment
+    let source_payment_amount = validate_source_payment_for_payout(
+        db,
         merchant_account,
-        req.profile_id.as_ref(),
-        &*state.store,
-        false,
+        req.source_payment_id.as_deref(),
+        req.source_payment_split_percentage,
+        req.amount.map(i64::from),
+        req.source_currency.or(req.currency),
+    )
+    .await?;
+
+    // FX quote
+    let fx_quote = match req.currency {
+        Some(destination_currency) => {
+            fx::get_fx_quote(
+                &fx::ForexApiQuoteProvider::new(state),
+                req.source_currency.unwrap_or(destination_currency),
+                destination_currency,
+                state.conf.payouts.fx_quote_validity_in_seconds,
+                common_utils::date_time::now(),
+            )
+            .await?
+        }
+        None => None,
+    };
+    if let Some(true) = req.confirm {
+        validate_fx_quote_expiry(
+            &payout_id,
+            fx_quote.as_ref().map(|fx_quote| fx_quote.expires_at),
+            common_utils::date_time::now(),
+        )?;
+    }
+
+    // Recipient contact details
+    let recipient_contact_details = validate_recipient_contact_details(
+        req.email.as_ref(),
+        req.phone.as_ref(),
+        req.phone_country_code.as_deref(),
+        req.billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+    )?;
+
+    // Payout country
+    let profile_blocked_countries = business_profile
+        .and_then(|business_profile| business_profile.payout_blocked_countries)
+        .map(|blocked_countries| {
+            blocked_countries
+                .parse_value::<HashSet<api_enums::CountryAlpha2>>("PayoutBlockedCountries")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payout_blocked_countries of the business profile")?;
+    validate_payout_country(
+        get_beneficiary_country(
+            payout_method_data
+                .as_ref()
+                .or(req.payout_method_data.as_ref()),
+            req.billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+        ),
+        profile_blocked_countries
+            .as_ref()
+            .unwrap_or(&state.conf.payouts.blocked_countries),
+    )?;
+
+    // Self payout
+    let self_payout_restriction = get_merchant_config::<SelfPayoutRestriction>(
+        db,
+        &format!("self_payout_restriction_{merchant_id}"),
     )
     .await?;
+    if self_payout_restriction.enabled {
+        validate_self_payout(
+            &self_payout_restriction,
+            payout_method_data
+                .as_ref()
+                .or(req.payout_method_data.as_ref()),
+            &blocklist_utils::get_merchant_fingerprint_secret(state, merchant_id).await?,
+        )?;
+    }
+
+    // Merchant KYC
+    validate_merchant_kyc_for_payout(
+        &get_merchant_config::<PayoutKycRequirement>(
+            db,
+            &format!("payout_kyc_requirement_{merchant_id}"),
+        )
+        .await?,
+        merchant_account.kyc_status,
+        payout_type,
+    )?;
 
-    Ok((payout_id, payout_method_data, profile_id))
+    // Beneficiary name
+    let is_bank_payout = matches!(
+        payout_method_data
+            .as_ref()
+            .or(req.payout_method_data.as_ref()),
+        Some(payouts::PayoutMethodData::Bank(_))
+    );
+    let customer_name = match (req.name.as_ref(), req.customer_id.as_ref()) {
+        (None, Some(customer_id)) if is_bank_payout => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id,
+                merchant_key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer of the payout")?
+            .and_then(|customer| customer.name.map(|name| name.into_inner())),
+        _ => req.name.clone(),
+    };
+    let billing_name = req
+        .billing
+        .as_ref()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.get_optional_full_name());
+    let payout_method_data = payout_method_data
+        .map(|payout_method_data| {
+            validate_beneficiary_name(
+                payout_method_data,
+                customer_name.as_ref(),
+                billing_name.as_ref(),
+            )
+        })
+        .transpose()?;
+    let request_payout_method_data = req
+        .payout_method_data
+        .clone()
+        .map(|payout_method_data| {
+            validate_beneficiary_name(
+                payout_method_data,
+                customer_name.as_ref(),
+                billing_name.as_ref(),
+            )
+        })
+        .transpose()?;
+
+    Ok(ValidatedPayoutCreateRequest {
+        payout_id,
+        payout_method_data,
+        profile_id,
+        fx_quote,
+        recipient_contact_details,
+        request_payout_method_data,
+        payout_type,
+        source_payment_amount,
+    })
 }
 
-#[cfg(feature = "olap")]
-pub(super) fn validate_payout_list_request(
-    req: &payouts::PayoutListConstraints,
-) -> CustomResult<(), errors::ApiErrorResponse> {
-    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET;
+/// Validates the payment funding a payout, and returns the amount of the payout when it is to
+/// be computed from the split percentage of the captured amount of the payment. The payment is
+/// looked up against the merchant of the payout, so payments of other merchants are not found
+async fn validate_source_payment_for_payout(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    source_payment_id: Option<&str>,
+    split_percentage: Option<u8>,
+    amount: Option<i64>,
+    currency: Option<api_enums::Currency>,
+) -> RouterResult<Option<i64>> {
+    let Some(source_payment_id) = source_payment_id else {
+        return match split_percentage {
+            Some(_) => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "source_payment_id",
+            })
+            .attach_printable("source_payment_split_percentage requires a source payment")),
+            None => Ok(None),
+        };
+    };
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            source_payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let amount_captured = validate_source_payment(&payment_intent, currency)?;
+    let payout_amount =
+        get_source_payment_payout_amount(amount, split_percentage, amount_captured)?;
+
+    let source_payment_refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            source_payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the source payment")?;
+    let total_linked_payout_amount = db
+        .get_total_payout_amount_by_merchant_id_source_payment_id(
+            &merchant_account.merchant_id,
+            source_payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to fetch the total amount of the payouts linked to the source payment",
+        )?;
+    validate_source_payment_allocation(
+        payout_amount,
+        amount_captured,
+        refunds::validator::get_total_refunded_amount(&source_payment_refunds),
+        total_linked_payout_amount,
+    )?;
 
+    Ok(split_percentage.map(|_| payout_amount))
+}
+
+/// Validates that a payment can fund a payout, being captured and in the currency of the
+/// payout, and returns its captured amount
+pub fn validate_source_payment(
+    payment_intent: &storage::PaymentIntent,
+    currency: Option<api_enums::Currency>,
+) -> RouterResult<i64> {
     utils::when(
-        req.limit > PAYOUTS_LIST_MAX_LIMIT_GET || req.limit < 1,
+        !matches!(
+            payment_intent.status,
+            storage::enums::IntentStatus::Succeeded
+                | storage::enums::IntentStatus::PartiallyCaptured
+        ),
         || {
-            Err(errors::ApiErrorResponse::InvalidRequestData {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "payout".into(),
+                field_name: "status".into(),
+                current_value: payment_intent.status.to_string(),
+                states: "succeeded, partially_captured".to_string()
+            })
+            .attach_printable("unable to fund a payout with an uncaptured payment"))
+        },
+    )?;
+    if let (Some(payout_currency), Some(payment_currency)) = (currency, payment_intent.currency) {
+        utils::when(payout_currency != payment_currency, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
                 message: format!(
-                    "limit should be in between 1 and {}",
-                    PAYOUTS_LIST_MAX_LIMIT_GET
+                    "source payment in {payment_currency} can not fund a payout in {payout_currency}"
                 ),
-            })
+            }))
+        })?;
+    }
+    Ok(payment_intent
+        .amount_captured
+        .unwrap_or(payment_intent.amount))
+}
+
+/// Resolves the amount of a payout funded by a payment, which is either passed in the request or
+/// computed from the split percentage of the captured amount of the payment
+pub fn get_source_payment_payout_amount(
+    amount: Option<i64>,
+    split_percentage: Option<u8>,
+    amount_captured: i64,
+) -> RouterResult<i64> {
+    match (amount, split_percentage) {
+        (Some(_), Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only one of amount or source_payment_split_percentage can be passed"
+                .to_string(),
+        })),
+        (Some(amount), None) => Ok(amount),
+        (None, Some(split_percentage)) => {
+            utils::when(!(1..=100).contains(&split_percentage), || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "source_payment_split_percentage",
+                })
+                .attach_printable("split percentage needs to be between 1 and 100"))
+            })?;
+            Ok(amount_captured * i64::from(split_percentage) / 100)
+        }
+        (None, None) => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "amount",
+        })),
+    }
+}
+
+/// Validates that the payout does not exceed the captured amount of the source payment less its
+/// refunds and the payouts already linked to it
+pub fn validate_source_payment_allocation(
+    payout_amount: i64,
+    amount_captured: i64,
+    total_refunded_amount: i64,
+    total_linked_payout_amount: i64,
+) -> RouterResult<()> {
+    let available_amount = amount_captured - total_refunded_amount - total_linked_payout_amount;
+    utils::when(payout_amount > available_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount of the payout exceeds the {available_amount} of the source payment left for payouts"
+            ),
+        }))
+    })
+}
+
+/// Validates that an update does not change the source payment of a payout, nor the amount or
+/// currency of a payout funded by a payment, which were validated against the payment on creation
+pub fn validate_source_payment_for_update(
+    payouts: &storage::Payouts,
+    req: &payouts::PayoutCreateRequest,
+) -> RouterResult<()> {
+    utils::when(
+        req.source_payment_id.is_some() || req.source_payment_split_percentage.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "source payment of a payout can not be updated".to_string(),
+            }))
         },
     )?;
-    Ok(())
+    let is_amount_updated = req
+        .amount
+        .is_some_and(|amount| i64::from(amount) != payouts.amount);
+    let is_currency_updated = req
+        .currency
+        .is_some_and(|currency| currency != payouts.destination_currency)
+        || req
+            .source_currency
+            .is_some_and(|currency| currency != payouts.source_currency);
+    utils::when(
+        payouts.source_payment_id.is_some() && (is_amount_updated || is_currency_updated),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount and currency of a payout funded by a payment can not be updated"
+                    .to_string(),
+            }))
+        },
+    )
 }
 
-#[cfg(feature = "olap")]
-pub(super) fn validate_payout_list_request_for_joins(
-    limit: u32,
-) -> CustomResult<(), errors::ApiErrorResponse> {
-    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_POST;
+/// Validates that a refund of a payment leaves enough of its captured amount to cover the
+/// payouts linked to it
+pub fn validate_refund_against_linked_payouts(
+    amount_captured: i64,
+    total_refunded_amount: i64,
+    refund_amount: i64,
+    total_linked_payout_amount: i64,
+) -> RouterResult<()> {
+    let remaining_amount = amount_captured - total_refunded_amount - refund_amount;
+    utils::when(remaining_amount < total_linked_payout_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Refund would leave {remaining_amount} of the captured amount of the payment, less than the {total_linked_payout_amount} paid out through the payouts linked to it"
+            ),
+        }))
+    })
+}
 
-    utils::when(!(1..=PAYOUTS_LIST_MAX_LIMIT_POST).contains(&limit), || {
-        Err(errors::ApiErrorResponse::InvalidRequestData {
+/// Resolves the payout type of a payout from, in that order
+/// - payout_type passed in the request, which overrides the default payout method
+/// - default payout method of the business profile
+/// - payout method data passed in the request or saved for the customer
+///
+/// and validates that the payout method data, if any, is of the resolved payout type
+pub fn resolve_payout_type(
+    payout_type: Option<api_enums::PayoutType>,
+    default_payout_method: Option<&admin::DefaultPayoutMethod>,
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+) -> RouterResult<api_enums::PayoutType> {
+    let default_payout_method = match payout_type {
+        Some(_) => None,
+        None => default_payout_method,
+    };
+    let payout_type = payout_type
+        .or(default_payout_method.map(|default_payout_method| default_payout_method.payout_type))
+        .or(payout_method_data.map(api_enums::PayoutType::foreign_from))
+        .ok_or(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_type",
+        }))
+        .attach_printable(
+            "payout_type can not be inferred without payout method data or a default payout method of the business profile",
+        )?;
+    if let Some(payout_method_data) = payout_method_data {
+        validate_payout_method_type(payout_method_data, payout_type, default_payout_method)?;
+    }
+    Ok(payout_type)
+}
+
+/// Validates that the payout method data is of the payout type, and of the payout method
+/// required by the default payout method of the business profile when the payout type was
+/// defaulted from it
+fn validate_payout_method_type(
+    payout_method_data: &payouts::PayoutMethodData,
+    payout_type: api_enums::PayoutType,
+    default_payout_method: Option<&admin::DefaultPayoutMethod>,
+) -> RouterResult<()> {
+    let payout_method_data_type = api_enums::PayoutType::foreign_from(payout_method_data);
+    utils::when(payout_method_data_type != payout_type, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
             message: format!(
-                "limit should be in between 1 and {}",
-                PAYOUTS_LIST_MAX_LIMIT_POST
+                "payout_method_data of a {payout_method_data_type} payout method can not be used for a {payout_type} payout"
+            ),
+        }))
+    })?;
+
+    let required_payout_method_type = default_payout_method
+        .and_then(|default_payout_method| default_payout_method.payout_method_type);
+    if let Some(required_payout_method_type) = required_payout_method_type {
+        let payout_method_type =
+            api_enums::PaymentMethodType::foreign_from(payout_method_data.clone());
+        utils::when(payout_method_type != required_payout_method_type, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "payout_method_data is required to be of {required_payout_method_type} by the default payout method of the business profile, pass payout_type to use a {payout_method_type} payout method"
+                ),
+            }))
+        })?;
+    }
+    Ok(())
+}
+
+/// Collapses runs of whitespace in a name into single spaces, returning `None` for blank names
+fn normalize_beneficiary_name(name: &Secret<String>) -> Option<Secret<String>> {
+    let name = name.peek().split_whitespace().collect::<Vec<_>>().join(" ");
+    (!name.is_empty()).then(|| Secret::new(name))
+}
+
+/// Validates that the beneficiary of the payout method has a name, returning the payout method
+/// data with the card holder name normalized
+/// - card payouts are made to the card holder
+/// - bank details carry no account holder name, so bank payouts are made to the customer, or
+///   the name on the billing address when the customer has no name
+/// - wallet payouts are addressed by the wallet and need no name
+pub fn validate_beneficiary_name(
+    payout_method_data: payouts::PayoutMethodData,
+    customer_name: Option<&Secret<String>>,
+    billing_name: Option<&Secret<String>>,
+) -> RouterResult<payouts::PayoutMethodData> {
+    match payout_method_data {
+        payouts::PayoutMethodData::Card(card) => {
+            let card_holder_name = card
+                .card_holder_name
+                .as_ref()
+                .and_then(normalize_beneficiary_name)
+                .ok_or(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "payout_method_data.card.card_holder_name".to_string(),
+                    expected_format: "non-empty name of the card holder".to_string(),
+                }))?;
+            Ok(payouts::PayoutMethodData::Card(payouts::CardPayout {
+                card_holder_name: Some(card_holder_name),
+                ..card
+            }))
+        }
+        payouts::PayoutMethodData::Bank(_) => {
+            utils::when(
+                customer_name
+                    .into_iter()
+                    .chain(billing_name)
+                    .find_map(normalize_beneficiary_name)
+                    .is_none(),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "name".to_string(),
+                        expected_format:
+                            "non-empty name of the bank account holder, passed in name or billing.address"
+                                .to_string(),
+                    }))
+                },
+            )?;
+            Ok(payout_method_data)
+        }
+        payouts::PayoutMethodData::Wallet(_) => Ok(payout_method_data),
+    }
+}
+
+/// Returns the country of the beneficiary, preferring the country of the bank over the billing
+/// address for bank payouts
+pub fn get_beneficiary_country(
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+    billing_country: Option<api_enums::CountryAlpha2>,
+) -> Option<api_enums::CountryAlpha2> {
+    let bank_country = match payout_method_data {
+        Some(payouts::PayoutMethodData::Bank(payouts::BankPayout::Ach(ach))) => {
+            ach.bank_country_code
+        }
+        Some(payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(bacs))) => {
+            bacs.bank_country_code
+        }
+        Some(payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(sepa))) => {
+            sepa.bank_country_code
+        }
+        Some(payouts::PayoutMethodData::Card(_) | payouts::PayoutMethodData::Wallet(_)) | None => {
+            None
+        }
+    };
+    bank_country.or(billing_country)
+}
+
+/// Validates that the beneficiary country is not blocked for payouts
+pub fn validate_payout_country(
+    country: Option<api_enums::CountryAlpha2>,
+    blocked_countries: &HashSet<api_enums::CountryAlpha2>,
+) -> RouterResult<()> {
+    match country {
+        Some(country) => utils::when(blocked_countries.contains(&country), || {
+            Err(report!(errors::ApiErrorResponse::PayoutCountryBlocked {
+                country: country.to_string(),
+            }))
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the config of the merchant stored against the key in configs. The default config is
+/// stored against the key when the merchant has not configured it, so that it is cached like the
+/// configured ones instead of being looked up in the database for every payout.
+pub async fn get_merchant_config<T>(db: &dyn StorageInterface, key: &str) -> RouterResult<T>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let default_config = serde_json::to_string(&T::default())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to serialize the default config of {key}"))?;
+    match db
+        .find_config_by_key_unwrap_or(key, Some(default_config))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct(std::any::type_name::<T>())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Config of {key} has invalid structure")),
+        Err(error) if error.current_context().is_db_not_found() => Ok(T::default()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to fetch the config of {key}"))),
+    }
+}
+
+/// Restriction on payouts to the accounts of a merchant, configured against the
+/// `self_payout_restriction_{merchant_id}` key in configs. Payouts are not checked against the
+/// accounts unless the restriction is enabled.
+///
+/// The accounts are configured by their fingerprints, see
+/// [`PayoutBeneficiaryAccount::get_fingerprint`], so that no account numbers are stored in
+/// configs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SelfPayoutRestriction {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub merchant_account_fingerprints: HashSet<String>,
+}
+
+/// Identifiers of the account a payout is made to
+#[derive(Debug, Clone)]
+pub enum PayoutBeneficiaryAccount {
+    Card {
+        card_number: Secret<String>,
+    },
+    Ach {
+        bank_routing_number: Secret<String>,
+        bank_account_number: Secret<String>,
+    },
+    Bacs {
+        bank_sort_code: Secret<String>,
+        bank_account_number: Secret<String>,
+    },
+    Sepa {
+        iban: Secret<String>,
+    },
+    Paypal {
+        email: Secret<String>,
+    },
+}
+
+impl PayoutBeneficiaryAccount {
+    pub fn from_payout_method_data(payout_method_data: &payouts::PayoutMethodData) -> Option<Self> {
+        match payout_method_data {
+            payouts::PayoutMethodData::Card(card) => Some(Self::Card {
+                card_number: Secret::new(card.card_number.peek().to_owned()),
+            }),
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Ach(ach)) => Some(Self::Ach {
+                bank_routing_number: ach.bank_routing_number.clone(),
+                bank_account_number: ach.bank_account_number.clone(),
+            }),
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(bacs)) => Some(Self::Bacs {
+                bank_sort_code: bacs.bank_sort_code.clone(),
+                bank_account_number: bacs.bank_account_number.clone(),
+            }),
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(sepa)) => Some(Self::Sepa {
+                iban: sepa.iban.clone(),
+            }),
+            payouts::PayoutMethodData::Wallet(payouts::WalletPayout::Paypal(paypal)) => {
+                paypal.email.as_ref().map(|email| Self::Paypal {
+                    email: Secret::new(email.peek().to_owned()),
+                })
+            }
+        }
+    }
+
+    /// Identifiers are compared ignoring spaces, dashes and case, as they are formatted
+    /// differently by merchants and recipients
+    fn get_normalized_identifiers(&self) -> (&'static str, Vec<String>) {
+        let normalize = |identifier: &Secret<String>| {
+            identifier
+                .peek()
+                .chars()
+                .filter(|character| !matches!(character, ' ' | '-'))
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        };
+        match self {
+            Self::Card { card_number } => ("card", vec![normalize(card_number)]),
+            Self::Ach {
+                bank_routing_number,
+                bank_account_number,
+            } => (
+                "ach",
+                vec![
+                    normalize(bank_routing_number),
+                    normalize(bank_account_number),
+                ],
             ),
+            Self::Bacs {
+                bank_sort_code,
+                bank_account_number,
+            } => (
+                "bacs",
+                vec![normalize(bank_sort_code), normalize(bank_account_number)],
+            ),
+            Self::Sepa { iban } => ("sepa", vec![normalize(iban)]),
+            Self::Paypal { email } => ("paypal", vec![normalize(email)]),
+        }
+    }
+
+    /// Fingerprint of the account, the hex encoded HMAC-SHA256 of its type and normalized
+    /// identifiers (`{type}:{identifier}[:{identifier}]`) keyed with the fingerprint secret of
+    /// the merchant
+    pub fn get_fingerprint(&self, fingerprint_secret: &str) -> RouterResult<String> {
+        let (account_type, identifiers) = self.get_normalized_identifiers();
+        let message = format!("{account_type}:{}", identifiers.join(":"));
+        crypto::HmacSha256
+            .sign_message(fingerprint_secret.as_bytes(), message.as_bytes())
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate the fingerprint of the beneficiary account")
+    }
+}
+
+/// Validates that the payout is not made to one of the accounts of the merchant, when self
+/// payouts are restricted for the merchant
+pub fn validate_self_payout(
+    restriction: &SelfPayoutRestriction,
+    payout_method_data: Option<&payouts::PayoutMethodData>,
+    fingerprint_secret: &str,
+) -> RouterResult<()> {
+    let beneficiary_account_fingerprint = payout_method_data
+        .filter(|_| restriction.enabled)
+        .and_then(PayoutBeneficiaryAccount::from_payout_method_data)
+        .map(|account| account.get_fingerprint(fingerprint_secret))
+        .transpose()?;
+    match beneficiary_account_fingerprint {
+        Some(fingerprint) => utils::when(
+            restriction
+                .merchant_account_fingerprints
+                .contains(&fingerprint),
+            || {
+                Err(report!(errors::ApiErrorResponse::SelfPayoutBlocked)).attach_printable(
+                    "Beneficiary account of the payout is one of the accounts of the merchant",
+                )
+            },
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Requirement of a verified KYC of the merchant for payouts, configured against the
+/// `payout_kyc_requirement_{merchant_id}` key in configs. The KYC status of the merchant is not
+/// checked unless the requirement is enabled, and only card payouts require a verified KYC unless
+/// other payout types are configured.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutKycRequirement {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "PayoutKycRequirement::default_payout_types")]
+    pub payout_types: Vec<api_enums::PayoutType>,
+}
+
+impl PayoutKycRequirement {
+    fn default_payout_types() -> Vec<api_enums::PayoutType> {
+        vec![api_enums::PayoutType::Card]
+    }
+}
+
+impl Default for PayoutKycRequirement {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            payout_types: Self::default_payout_types(),
+        }
+    }
+}
+
+/// Validates that the KYC of the merchant is verified for payouts of the payout types requiring
+/// it, when KYC is required for payouts of the merchant
+pub fn validate_merchant_kyc_for_payout(
+    requirement: &PayoutKycRequirement,
+    kyc_status: Option<api_enums::KycStatus>,
+    payout_type: api_enums::PayoutType,
+) -> RouterResult<()> {
+    utils::when(
+        requirement.enabled
+            && requirement.payout_types.contains(&payout_type)
+            && kyc_status != Some(api_enums::KycStatus::Verified),
+        || {
+            Err(report!(errors::ApiErrorResponse::MerchantKycIncomplete {
+                payout_type: payout_type.to_string(),
+            }))
+            .attach_printable(format!(
+                "KYC of the merchant is not verified, current KYC status is {kyc_status:?}"
+            ))
+        },
+    )
+}
+
+/// Validates that every connector the payout can be routed to supports the payout priority
+pub fn validate_payout_priority(
+    priority: api_enums::PayoutPriority,
+    payout_type: api_enums::PayoutType,
+    connectors: &[api_enums::Connector],
+) -> RouterResult<()> {
+    connectors.iter().try_for_each(|connector| {
+        utils::when(
+            !is_payout_priority_supported(priority, payout_type, *connector),
+            || {
+                Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: format!(
+                        "Instant {payout_type} payouts are not supported by {connector}"
+                    ),
+                }))
+            },
+        )
+    })
+}
+
+/// Returns whether the connector can process payouts of the payout type at the given priority
+pub fn is_payout_priority_supported(
+    priority: api_enums::PayoutPriority,
+    payout_type: api_enums::PayoutType,
+    connector: api_enums::Connector,
+) -> bool {
+    match priority {
+        api_enums::PayoutPriority::Standard => true,
+        api_enums::PayoutPriority::Instant => connector.supports_instant_payout(payout_type),
+    }
+}
+
+/// Validates that the payout method behind a payout token belongs to the customer of the payout
+pub fn validate_payout_method_owner(
+    owner_customer_id: Option<&str>,
+    customer_id: &str,
+) -> RouterResult<()> {
+    utils::when(owner_customer_id != Some(customer_id), || {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "payout_token".to_string(),
+        })
+        .attach_printable(format!(
+            "Payout method of the payout_token does not belong to customer {customer_id}"
+        )))
+    })
+}
+
+/// Returns the reason for holding the payout when the business profile requires the KYC of
+/// recipients to be verified before fulfillment and the recipient is not verified yet
+pub fn get_recipient_kyc_hold_reason(
+    require_recipient_kyc: bool,
+    kyc_status: Option<api_enums::KycStatus>,
+) -> Option<String> {
+    if !require_recipient_kyc {
+        return None;
+    }
+    match kyc_status {
+        Some(api_enums::KycStatus::Verified) => None,
+        Some(kyc_status) => Some(format!(
+            "Payout is held until the KYC of the recipient is verified, current KYC status is {kyc_status}"
+        )),
+        None => Some(
+            "Payout is held until the KYC of the recipient is submitted and verified".to_string(),
+        ),
+    }
+}
+
+/// Validates the format of bank details passed for bank transfer payouts
+pub fn validate_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+) -> RouterResult<()> {
+    let validation_result = match payout_method_data {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Ach(ach)) => {
+            utils::when(
+                !is_valid_aba_routing_number(ach.bank_routing_number.peek()),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "payout_method_data.bank.bank_routing_number".to_string(),
+                        expected_format: "9 digit ABA routing number".to_string(),
+                    }))
+                },
+            )?;
+            utils::when(
+                !is_numeric_with_length(ach.bank_account_number.peek(), 4..=17),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "payout_method_data.bank.bank_account_number".to_string(),
+                        expected_format: "4 to 17 digit account number".to_string(),
+                    }))
+                },
+            )
+        }
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(bacs)) => {
+            utils::when(
+                !is_numeric_with_length(bacs.bank_sort_code.peek(), 6..=6),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "payout_method_data.bank.bank_sort_code".to_string(),
+                        expected_format: "6 digit sort code".to_string(),
+                    }))
+                },
+            )?;
+            utils::when(
+                !is_numeric_with_length(bacs.bank_account_number.peek(), 8..=8),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "payout_method_data.bank.bank_account_number".to_string(),
+                        expected_format: "8 digit account number".to_string(),
+                    }))
+                },
+            )
+        }
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(sepa)) => {
+            utils::when(!is_valid_iban(sepa.iban.peek()), || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "payout_method_data.bank.iban".to_string(),
+                    expected_format: "IBAN with a valid checksum".to_string(),
+                }))
+            })
+        }
+        payouts::PayoutMethodData::Card(_) | payouts::PayoutMethodData::Wallet(_) => Ok(()),
+    };
+    validation_result.map_err(|error| {
+        logger::warn!(
+            payout_method_data = %payout_method_data.redacted(),
+            "Malformed bank details in payout method data"
+        );
+        error
+    })
+}
+
+/// Lists the payout methods of the payout type which the connector supports in the country and
+/// currency, if given, along with the fields each of them requires, from the payout required
+/// fields registry
+pub fn get_payout_required_fields(
+    registry: &settings::PayoutRequiredFields,
+    request: &payouts::PayoutRequiredFieldsRequest,
+) -> payouts::PayoutRequiredFieldsResponse {
+    let mut payout_methods = registry
+        .0
+        .get(&request.payout_type)
+        .map(|payout_method_types| {
+            payout_method_types
+                .0
+                .iter()
+                .filter_map(|(payout_method_type, connector_fields)| {
+                    connector_fields
+                        .fields
+                        .get(&request.connector)
+                        .filter(|required_fields| {
+                            request.country.map_or(true, |country| {
+                                required_fields
+                                    .countries
+                                    .as_ref()
+                                    .map_or(true, |countries| countries.contains(&country))
+                            }) && request.currency.map_or(true, |currency| {
+                                required_fields
+                                    .currencies
+                                    .as_ref()
+                                    .map_or(true, |currencies| currencies.contains(&currency))
+                            })
+                        })
+                        .map(|required_fields| payouts::PayoutMethodRequiredFields {
+                            payout_method_type: *payout_method_type,
+                            required_fields: get_sorted_required_fields(required_fields),
+                        })
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    payout_methods.sort_by_key(|payout_method| payout_method.payout_method_type.to_string());
+    payouts::PayoutRequiredFieldsResponse {
+        connector: request.connector,
+        payout_type: request.payout_type,
+        payout_methods,
+    }
+}
+
+fn get_sorted_required_fields(
+    required_fields: &settings::PayoutRequiredFieldFinal,
+) -> Vec<payouts::PayoutRequiredFieldInfo> {
+    let mut required_fields = required_fields.fields.values().cloned().collect::<Vec<_>>();
+    required_fields.sort_by(|field, other| field.required_field.cmp(&other.required_field));
+    required_fields
+}
+
+/// Resolves the value of a required field from its path in the payout create request. Blank
+/// values are treated as missing.
+fn get_required_field_value(
+    required_field: &str,
+    payout_method_data: &serde_json::Value,
+    billing_address: Option<&domain::Address>,
+) -> Option<String> {
+    let value = match required_field.strip_prefix("billing.address.") {
+        Some(address_field) => {
+            let billing_address = billing_address?;
+            let secret_value = |value: &common_utils::crypto::OptionalEncryptableSecretString| {
+                value
+                    .as_ref()
+                    .map(|value| value.get_inner().peek().to_owned())
+            };
+            match address_field {
+                "first_name" => secret_value(&billing_address.first_name),
+                "last_name" => secret_value(&billing_address.last_name),
+                "line1" => secret_value(&billing_address.line1),
+                "line2" => secret_value(&billing_address.line2),
+                "state" => secret_value(&billing_address.state),
+                "zip" => secret_value(&billing_address.zip),
+                "city" => billing_address.city.clone(),
+                "country" => billing_address.country.map(|country| country.to_string()),
+                _ => None,
+            }
+        }
+        None => required_field
+            .strip_prefix("payout_method_data.")?
+            .split('.')
+            .try_fold(payout_method_data, |value, key| value.get(key))
+            .and_then(|value| match value {
+                serde_json::Value::String(value) => Some(value.to_owned()),
+                serde_json::Value::Number(value) => Some(value.to_string()),
+                _ => None,
+            }),
+    };
+    value.filter(|value| !value.trim().is_empty())
+}
+
+/// Validates that the payout method data and billing address carry every field the connector
+/// requires for the payout method in the payout required fields registry, the same registry
+/// the required fields API advertises, and that their values match the validation regex
+pub fn validate_payout_required_fields(
+    registry: &settings::PayoutRequiredFields,
+    connector: api_enums::PayoutConnectors,
+    payout_method_data: &payouts::PayoutMethodData,
+    billing_address: Option<&domain::Address>,
+) -> RouterResult<()> {
+    let payout_type = api_enums::PayoutType::foreign_from(payout_method_data);
+    let payout_method_type = api_enums::PaymentMethodType::foreign_from(payout_method_data.clone());
+    let Some(required_fields) = registry
+        .0
+        .get(&payout_type)
+        .and_then(|payout_method_types| payout_method_types.0.get(&payout_method_type))
+        .and_then(|connector_fields| connector_fields.fields.get(&connector))
+    else {
+        return Ok(());
+    };
+
+    let payout_method_data_value = serde_json::to_value(payout_method_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize payout method data")?;
+    let required_fields = get_sorted_required_fields(required_fields)
+        .into_iter()
+        .map(|required_field| {
+            let value = get_required_field_value(
+                &required_field.required_field,
+                &payout_method_data_value,
+                billing_address,
+            );
+            (required_field, value)
         })
+        .collect::<Vec<_>>();
+
+    let missing_fields = required_fields
+        .iter()
+        .filter(|(_, value)| value.is_none())
+        .map(|(required_field, _)| required_field.required_field.as_str())
+        .collect::<Vec<_>>();
+    utils::when(!missing_fields.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{} required for {payout_method_type} payouts through {connector}",
+                missing_fields.join(", ")
+            ),
+        }))
     })?;
+
+    for (required_field, value) in required_fields {
+        let (Some(validation_regex), Some(value)) = (required_field.validation_regex, value) else {
+            continue;
+        };
+        let is_valid = regex::Regex::new(&validation_regex)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Invalid validation regex configured for {}",
+                    required_field.required_field
+                )
+            })?
+            .is_match(&value);
+        utils::when(!is_valid, || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: required_field.required_field,
+                expected_format: format!("a value matching {validation_regex}"),
+            }))
+        })?;
+    }
     Ok(())
 }
+
+/// Currencies and destination countries supported by a bank payout method
+struct BankPayoutCompatibility {
+    bank_payout_method: &'static str,
+    currencies: &'static [api_enums::Currency],
+    countries: &'static [api_enums::CountryAlpha2],
+}
+
+const SEPA_COUNTRIES: &[api_enums::CountryAlpha2] = &[
+    api_enums::CountryAlpha2::AD,
+    api_enums::CountryAlpha2::AT,
+    api_enums::CountryAlpha2::BE,
+    api_enums::CountryAlpha2::BG,
+    api_enums::CountryAlpha2::CH,
+    api_enums::CountryAlpha2::CY,
+    api_enums::CountryAlpha2::CZ,
+    api_enums::CountryAlpha2::DE,
+    api_enums::CountryAlpha2::DK,
+    api_enums::CountryAlpha2::EE,
+    api_enums::CountryAlpha2::ES,
+    api_enums::CountryAlpha2::FI,
+    api_enums::CountryAlpha2::FR,
+    api_enums::CountryAlpha2::GB,
+    api_enums::CountryAlpha2::GR,
+    api_enums::CountryAlpha2::HR,
+    api_enums::CountryAlpha2::HU,
+    api_enums::CountryAlpha2::IE,
+    api_enums::CountryAlpha2::IS,
+    api_enums::CountryAlpha2::IT,
+    api_enums::CountryAlpha2::LI,
+    api_enums::CountryAlpha2::LT,
+    api_enums::CountryAlpha2::LU,
+    api_enums::CountryAlpha2::LV,
+    api_enums::CountryAlpha2::MC,
+    api_enums::CountryAlpha2::MT,
+    api_enums::CountryAlpha2::NL,
+    api_enums::CountryAlpha2::NO,
+    api_enums::CountryAlpha2::PL,
+    api_enums::CountryAlpha2::PT,
+    api_enums::CountryAlpha2::RO,
+    api_enums::CountryAlpha2::SE,
+    api_enums::CountryAlpha2::SI,
+    api_enums::CountryAlpha2::SK,
+    api_enums::CountryAlpha2::SM,
+    api_enums::CountryAlpha2::VA,
+];
+
+static BANK_PAYOUT_COMPATIBILITY: &[BankPayoutCompatibility] = &[
+    BankPayoutCompatibility {
+        bank_payout_method: "ach",
+        currencies: &[api_enums::Currency::USD],
+        countries: &[api_enums::CountryAlpha2::US],
+    },
+    BankPayoutCompatibility {
+        bank_payout_method: "bacs",
+        currencies: &[api_enums::Currency::GBP],
+        countries: &[api_enums::CountryAlpha2::GB],
+    },
+    BankPayoutCompatibility {
+        bank_payout_method: "sepa",
+        currencies: &[api_enums::Currency::EUR],
+        countries: SEPA_COUNTRIES,
+    },
+];
+
+/// Validates that the bank payout method supports the payout currency and the destination country.
+/// The country check is skipped when the destination country is not known.
+pub fn validate_bank_payout_compatibility(
+    payout_method_data: &payouts::PayoutMethodData,
+    currency: api_enums::Currency,
+    country: Option<api_enums::CountryAlpha2>,
+) -> RouterResult<()> {
+    let bank_payout_method = match payout_method_data {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Ach(_)) => "ach",
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(_)) => "bacs",
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(_)) => "sepa",
+        payouts::PayoutMethodData::Card(_) | payouts::PayoutMethodData::Wallet(_) => return Ok(()),
+    };
+    let Some(compatibility) = BANK_PAYOUT_COMPATIBILITY
+        .iter()
+        .find(|compatibility| compatibility.bank_payout_method == bank_payout_method)
+    else {
+        return Ok(());
+    };
+
+    utils::when(!compatibility.currencies.contains(&currency), || {
+        logger::warn!(
+            payout_method_data = %payout_method_data.redacted(),
+            %currency,
+            "Currency is not supported by the bank payout method"
+        );
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "currency {currency} is not supported for {bank_payout_method} bank payouts"
+            ),
+        }))
+    })?;
+
+    match country {
+        Some(country) => utils::when(!compatibility.countries.contains(&country), || {
+            logger::warn!(
+                payout_method_data = %payout_method_data.redacted(),
+                %country,
+                "Destination country is not supported by the bank payout method"
+            );
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "country {country} is not supported for {bank_payout_method} bank payouts"
+                ),
+            }))
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates the bank payout compatibility of the payout method data resolved for a payout
+pub fn validate_payout_data_compatibility(payout_data: &PayoutData) -> RouterResult<()> {
+    match payout_data.payout_method_data.as_ref() {
+        Some(payout_method_data) => validate_bank_payout_compatibility(
+            payout_method_data,
+            payout_data.payouts.destination_currency,
+            payout_data
+                .billing_address
+                .as_ref()
+                .and_then(|address| address.country),
+        ),
+        None => Ok(()),
+    }
+}
+
+fn is_numeric_with_length(value: &str, length: std::ops::RangeInclusive<usize>) -> bool {
+    length.contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Validates a US ABA routing number using its weighted checksum
+fn is_valid_aba_routing_number(routing_number: &str) -> bool {
+    if !is_numeric_with_length(routing_number, 9..=9) {
+        return false;
+    }
+    let checksum = routing_number
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip([3, 7, 1].iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>();
+    checksum % 10 == 0
+}
+
+/// Validates an IBAN using the ISO 13616 mod-97 checksum
+fn is_valid_iban(iban: &str) -> bool {
+    let iban: String = iban.chars().filter(|c| !c.is_whitespace()).collect();
+    if !(15..=34).contains(&iban.len()) || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (country_and_check_digits, bban) = iban.split_at(4);
+    let is_well_formed = country_and_check_digits
+        .chars()
+        .take(2)
+        .all(|c| c.is_ascii_alphabetic())
+        && country_and_check_digits
+            .chars()
+            .skip(2)
+            .all(|c| c.is_ascii_digit());
+    if !is_well_formed {
+        return false;
+    }
+    let remainder = bban
+        .chars()
+        .chain(country_and_check_digits.chars())
+        .try_fold(0u32, |remainder, c| {
+            let value = c.to_digit(36)?;
+            let remainder = if value < 10 {
+                remainder * 10 + value
+            } else {
+                remainder * 100 + value
+            };
+            Some(remainder % 97)
+        });
+    remainder == Some(1)
+}
+
+/// Validates that the payout method referenced by the payout request has not expired
+pub fn validate_payout_method_expiry(
+    payout_method_data: &payouts::PayoutMethodData,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let is_expired = match payout_method_data {
+        payouts::PayoutMethodData::Card(card) => {
+            let expiry_month = card
+                .expiry_month
+                .peek()
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|month| (1..=12).contains(month));
+            let expiry_year = card
+                .expiry_year
+                .peek()
+                .trim()
+                .parse::<i32>()
+                .ok()
+                .map(|year| if year < 100 { year + 2000 } else { year });
+            // A card is valid until the end of its expiry month
+            expiry_month.zip(expiry_year).is_some_and(|(month, year)| {
+                (year, month) < (current_time.year(), u8::from(current_time.month()))
+            })
+        }
+        payouts::PayoutMethodData::Bank(_) | payouts::PayoutMethodData::Wallet(_) => false,
+    };
+
+    utils::when(is_expired, || {
+        logger::warn!(
+            payout_method_data = %payout_method_data.redacted(),
+            "Payout method referenced by the payout request has expired"
+        );
+        Err(report!(errors::ApiErrorResponse::PayoutNotFound)
+            .attach_printable("Payout method referenced by the payout request has expired"))
+    })
+}
+
+/// Validates that the saved payout method accepts the payout currency.
+/// Payout methods which do not carry any accepted currencies are treated as currency agnostic.
+pub fn validate_saved_payout_method_currency(
+    accepted_currencies: Option<&[api_enums::Currency]>,
+    currency: api_enums::Currency,
+    payout_method_id: &str,
+) -> RouterResult<()> {
+    let Some(accepted_currencies) = accepted_currencies.filter(|currencies| !currencies.is_empty())
+    else {
+        return Ok(());
+    };
+
+    utils::when(!accepted_currencies.contains(&currency), || {
+        logger::warn!(
+            %payout_method_id,
+            %currency,
+            "Currency is not accepted by the saved payout method"
+        );
+        Err(report!(errors::ApiErrorResponse::CurrencyNotSupported {
+            message: format!(
+                "currency {currency} is not supported by the payout method {payout_method_id}"
+            ),
+        }))
+    })
+}
+
+/// Validates that a payout is scheduled in the future and within the configured horizon
+pub fn validate_payout_schedule(
+    scheduled_at: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+    max_schedule_horizon_in_days: i64,
+) -> RouterResult<()> {
+    utils::when(scheduled_at <= current_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "scheduled_at should be a time in the future".to_string(),
+        }))
+    })?;
+
+    utils::when(
+        scheduled_at > current_time.saturating_add(Duration::days(max_schedule_horizon_in_days)),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "scheduled_at should be within {max_schedule_horizon_in_days} days from now"
+                ),
+            }))
+        },
+    )
+}
+
+/// Validates that the FX quote of a cross currency payout has not expired, so that it can be confirmed
+pub fn validate_fx_quote_expiry(
+    payout_id: &str,
+    fx_quote_expires_at: Option<PrimitiveDateTime>,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    utils::when(
+        fx_quote_expires_at.is_some_and(|expires_at| expires_at <= current_time),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "FX quote for payout {payout_id} has expired, update the payout with source_currency to re-quote it"
+                ),
+            }))
+        },
+    )
+}
+
+/// Normalized contact details of the payout recipient
+#[derive(Debug, Default, PartialEq)]
+pub struct RecipientContactDetails {
+    pub email: Option<Email>,
+    pub phone: Option<Secret<String>>,
+    pub phone_country_code: Option<String>,
+}
+
+/// Validates the recipient email and phone number of a payout, returning their normalized forms
+/// - email is trimmed and its domain lowercased
+/// - phone number is split into its country code and national number, the country code being
+///   taken from the number, `phone_country_code` or the billing country, in that order
+pub fn validate_recipient_contact_details(
+    email: Option<&Email>,
+    phone: Option<&Secret<String>>,
+    phone_country_code: Option<&str>,
+    billing_country: Option<api_enums::CountryAlpha2>,
+) -> RouterResult<RecipientContactDetails> {
+    let email = email
+        .map(|email| {
+            let email = email.peek().trim();
+            let email = match email.rsplit_once('@') {
+                Some((local_part, domain)) => format!("{local_part}@{}", domain.to_lowercase()),
+                None => email.to_string(),
+            };
+            common_validation::validate_email(&email)
+                .and_then(|_| email.parse::<Email>())
+                .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "email".to_string(),
+                    expected_format: "valid email address".to_string(),
+                })
+        })
+        .transpose()?;
+
+    let (phone, phone_country_code) = match phone {
+        Some(phone) => {
+            let phone = phone.peek().trim();
+            let phone = match phone_country_code {
+                Some(phone_country_code) if !phone.starts_with('+') => {
+                    format!("+{}{phone}", phone_country_code.trim_start_matches('+'))
+                }
+                _ => phone.to_string(),
+            };
+            let (phone_country_code, phone) = common_validation::normalize_phone_number(
+                &phone,
+                billing_country.map(|country| country.to_string()).as_deref(),
+            )
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "phone".to_string(),
+                expected_format: "phone number with a country code, passed in phone_country_code or inferred from billing.address.country".to_string(),
+            })?;
+            (Some(Secret::new(phone)), Some(phone_country_code))
+        }
+        None => (None, phone_country_code.map(ToString::to_string)),
+    };
+
+    Ok(RecipientContactDetails {
+        email,
+        phone,
+        phone_country_code,
+    })
+}
+
+/// Validates that the payout has not been initiated with the connector or reached a terminal state
+pub fn validate_payout_status_for_update(
+    payout_id: &str,
+    status: api_enums::PayoutStatus,
+) -> RouterResult<()> {
+    utils::when(
+        helpers::is_payout_terminal_state(status) || helpers::is_payout_initiated(status),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Payout {payout_id} cannot be updated for status {status}"),
+            }))
+        },
+    )
+}
+
+/// Validates that an update request only touches non-monetary fields of an updatable payout
+pub fn validate_update_request(
+    req: &payouts::PayoutUpdateRequest,
+    status: api_enums::PayoutStatus,
+) -> RouterResult<()> {
+    let immutable_field = if req.amount.is_some() {
+        Some("amount")
+    } else if req.currency.is_some() {
+        Some("currency")
+    } else {
+        None
+    };
+    if let Some(field_name) = immutable_field {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} cannot be updated for payout {}, cancel it and create a new payout instead",
+                req.payout_id
+            ),
+        }));
+    }
+    validate_payout_metadata(req.metadata.as_ref())?;
+
+    validate_payout_status_for_update(&req.payout_id, status)
+}
+
+/// Validates that the metadata of a payout is a flat map of string values, with at most
+/// `PAYOUT_METADATA_MAX_KEYS` keys and at most `PAYOUT_METADATA_MAX_SIZE_IN_BYTES` bytes in size,
+/// so that payouts can be filtered by its keys
+pub fn validate_payout_metadata(metadata: Option<&pii::SecretSerdeValue>) -> RouterResult<()> {
+    let Some(metadata) = metadata.map(|metadata| metadata.peek()) else {
+        return Ok(());
+    };
+    let invalid_metadata = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    let Some(metadata_map) = metadata.as_object() else {
+        return invalid_metadata("metadata must be an object of string values".to_string());
+    };
+    if let Some(key) = metadata_map
+        .iter()
+        .find_map(|(key, value)| (!value.is_string()).then_some(key))
+    {
+        return invalid_metadata(format!(
+            "metadata must be an object of string values, value of `{key}` is not a string"
+        ));
+    }
+    if metadata_map.len() > PAYOUT_METADATA_MAX_KEYS {
+        return invalid_metadata(format!(
+            "metadata can have at most {PAYOUT_METADATA_MAX_KEYS} keys, {} keys were passed",
+            metadata_map.len()
+        ));
+    }
+    let size = metadata.to_string().len();
+    utils::when(size > PAYOUT_METADATA_MAX_SIZE_IN_BYTES, || {
+        invalid_metadata(format!(
+            "metadata can be at most {PAYOUT_METADATA_MAX_SIZE_IN_BYTES} bytes in size, {size} bytes were passed"
+        ))
+    })
+}
+
+/// Validates that the payout has been created with the connector before it is synced,
+/// returning the connector's reference of the payout
+pub fn validate_sync_request(payout_attempt: &storage::PayoutAttempt) -> RouterResult<&str> {
+    let connector_payout_id = payout_attempt.connector_payout_id.as_str();
+    utils::when(
+        payout_attempt.connector.is_none() || connector_payout_id.is_empty(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payout {} cannot be synced as it has not been created with the connector",
+                    payout_attempt.payout_id
+                ),
+            }))
+        },
+    )?;
+    Ok(connector_payout_id)
+}
+
+/// Validates the amount requested to be fulfilled against the amount of the payout yet to be
+/// fulfilled, and returns the amount of the tranche to be fulfilled
+pub fn validate_payout_fulfillment(
+    payouts: &storage::Payouts,
+    amount: Option<i64>,
+) -> RouterResult<i64> {
+    let payout_id = &payouts.payout_id;
+    let status = payouts.status;
+    utils::when(helpers::is_payout_terminal_state(status), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Payout {payout_id} cannot be fulfilled for status {status}"),
+        }))
+    })?;
+
+    let unfulfilled_amount = helpers::get_unfulfilled_amount(payouts);
+    let amount = amount.unwrap_or(unfulfilled_amount);
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        }))
+    })?;
+    utils::when(amount > unfulfilled_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount {amount} exceeds the amount {unfulfilled_amount} of payout {payout_id} yet to be fulfilled"
+            ),
+        }))
+    })?;
+
+    Ok(amount)
+}
+
+/// Validates that a tranche of the payout can be fulfilled, i.e. no other tranche of it is pending
+/// with the connector, and the connector supports fulfilling a part of the payout amount
+pub fn validate_payout_tranche(
+    payouts: &storage::Payouts,
+    payout_attempt: &storage::PayoutAttempt,
+    fulfillment_amount: i64,
+    connector: Option<api_enums::Connector>,
+) -> RouterResult<()> {
+    let payout_id = &payouts.payout_id;
+    utils::when(
+        payouts.status == api_enums::PayoutStatus::PartiallyFulfilled
+            && payout_attempt.status == api_enums::PayoutStatus::Pending,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("A tranche of payout {payout_id} is pending with the connector"),
+            }))
+        },
+    )?;
+
+    let payout_type = payouts.payout_type;
+    utils::when(
+        fulfillment_amount < helpers::get_unfulfilled_amount(payouts)
+            && !connector.is_some_and(|connector| {
+                connector.supports_partial_payout_fulfillment(payout_type)
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payout {payout_id} cannot be partially fulfilled through {}",
+                    connector.map_or("its connector".to_string(), |connector| connector
+                        .to_string())
+                ),
+            }))
+        },
+    )
+}
+
+/// Validates that the payout can be forced through another connector in a fulfill request, i.e.
+/// the payout has not succeeded and is not in flight with its current connector
+pub fn validate_connector_override(payout_attempt: &storage::PayoutAttempt) -> RouterResult<()> {
+    let payout_id = &payout_attempt.payout_id;
+    let status = payout_attempt.status;
+    utils::when(status == api_enums::PayoutStatus::Success, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Payout {payout_id} has already succeeded"),
+        }))
+    })?;
+    utils::when(
+        !matches!(
+            status,
+            api_enums::PayoutStatus::RequiresCreation
+                | api_enums::PayoutStatus::RequiresFulfillment
+                | api_enums::PayoutStatus::Failed
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payout {payout_id} cannot be routed through another connector for status {status}"
+                ),
+            }))
+        },
+    )
+}
+
+#[cfg(feature = "olap")]
+pub(super) fn validate_payout_list_request(
+    req: &payouts::PayoutListConstraints,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET;
+
+    utils::when(
+        req.limit > PAYOUTS_LIST_MAX_LIMIT_GET || req.limit < 1,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    PAYOUTS_LIST_MAX_LIMIT_GET
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+pub(super) fn validate_payout_list_request_for_joins(
+    limit: u32,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_POST;
+
+    utils::when(!(1..=PAYOUTS_LIST_MAX_LIMIT_POST).contains(&limit), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be in between 1 and {}",
+                PAYOUTS_LIST_MAX_LIMIT_POST
+            ),
+        })
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn sepa_payout_method_data(iban: &str) -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Sepa(payouts::SepaBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            iban: iban.to_string().into(),
+            bic: None,
+        }))
+    }
+
+    fn ach_payout_method_data(routing_number: &str) -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Ach(payouts::AchBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            bank_account_number: "000123456789".to_string().into(),
+            bank_routing_number: routing_number.to_string().into(),
+        }))
+    }
+
+    #[test]
+    fn test_valid_iban_is_accepted() {
+        assert!(
+            validate_payout_method_data(&sepa_payout_method_data("NL46TEST0136169112")).is_ok()
+        );
+        assert!(validate_payout_method_data(&sepa_payout_method_data(
+            "DE89 3704 0044 0532 0130 00"
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_iban_with_invalid_checksum_is_rejected() {
+        let result =
+            validate_payout_method_data(&sepa_payout_method_data("DE89370400440532013001"));
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. }
+                if field_name == "payout_method_data.bank.iban"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_aba_routing_number_is_rejected() {
+        assert!(validate_payout_method_data(&ach_payout_method_data("110000000")).is_ok());
+
+        let result = validate_payout_method_data(&ach_payout_method_data("110000001"));
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. }
+                if field_name == "payout_method_data.bank.bank_routing_number"
+        ));
+    }
+
+    fn card_payout_method_data_with_holder_name(
+        card_holder_name: Option<&str>,
+    ) -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Card(payouts::CardPayout {
+            card_holder_name: card_holder_name.map(|name| Secret::new(name.to_string())),
+            ..Default::default()
+        })
+    }
+
+    fn get_card_holder_name(payout_method_data: &payouts::PayoutMethodData) -> Option<String> {
+        match payout_method_data {
+            payouts::PayoutMethodData::Card(card) => card
+                .card_holder_name
+                .as_ref()
+                .map(|name| name.peek().to_owned()),
+            _ => None,
+        }
+    }
+
+    fn is_invalid_beneficiary_name(
+        result: RouterResult<payouts::PayoutMethodData>,
+        expected_field_name: &str,
+    ) -> bool {
+        result.is_err_and(|err| {
+            matches!(
+                err.current_context(),
+                errors::ApiErrorResponse::InvalidDataFormat { field_name, .. }
+                    if field_name == expected_field_name
+            )
+        })
+    }
+
+    #[test]
+    fn test_beneficiary_name_is_normalized() {
+        let card = validate_beneficiary_name(
+            card_payout_method_data_with_holder_name(Some("  John \t  Doe ")),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(get_card_holder_name(&card), Some("John Doe".to_string()));
+
+        let customer_name = Secret::new("John Doe".to_string());
+        assert!(validate_beneficiary_name(
+            ach_payout_method_data("110000000"),
+            Some(&customer_name),
+            None
+        )
+        .is_ok());
+        assert!(validate_beneficiary_name(
+            ach_payout_method_data("110000000"),
+            None,
+            Some(&customer_name)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_missing_or_empty_beneficiary_name_is_rejected() {
+        for card_holder_name in [None, Some(""), Some("  \t ")] {
+            assert!(is_invalid_beneficiary_name(
+                validate_beneficiary_name(
+                    card_payout_method_data_with_holder_name(card_holder_name),
+                    None,
+                    None
+                ),
+                "payout_method_data.card.card_holder_name"
+            ));
+        }
+
+        let blank_name = Secret::new("   ".to_string());
+        assert!(is_invalid_beneficiary_name(
+            validate_beneficiary_name(ach_payout_method_data("110000000"), None, None),
+            "name"
+        ));
+        assert!(is_invalid_beneficiary_name(
+            validate_beneficiary_name(
+                ach_payout_method_data("110000000"),
+                Some(&blank_name),
+                Some(&blank_name)
+            ),
+            "name"
+        ));
+
+        // Wallet payouts are addressed by the wallet
+        let paypal = payouts::PayoutMethodData::Wallet(payouts::WalletPayout::Paypal(
+            api_models::payouts::Paypal::default(),
+        ));
+        assert!(validate_beneficiary_name(paypal, None, None).is_ok());
+    }
+
+    fn sepa_default_payout_method() -> admin::DefaultPayoutMethod {
+        admin::DefaultPayoutMethod {
+            payout_type: api_enums::PayoutType::Bank,
+            payout_method_type: Some(api_enums::PaymentMethodType::Sepa),
+        }
+    }
+
+    #[test]
+    fn test_default_payout_method_is_applied() {
+        let default_payout_method = sepa_default_payout_method();
+        let sepa = sepa_payout_method_data("NL46TEST0136169112");
+
+        assert_eq!(
+            resolve_payout_type(None, Some(&default_payout_method), Some(&sepa)).unwrap(),
+            api_enums::PayoutType::Bank
+        );
+        // Payout method data can be passed later on
+        assert_eq!(
+            resolve_payout_type(None, Some(&default_payout_method), None).unwrap(),
+            api_enums::PayoutType::Bank
+        );
+
+        // Payout method data is required to be of the default payout method
+        let result = resolve_payout_type(
+            None,
+            Some(&default_payout_method),
+            Some(&ach_payout_method_data("110000000")),
+        );
+        assert!(result.is_err_and(|err| matches!(
+            err.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        )));
+        let result = resolve_payout_type(
+            None,
+            Some(&default_payout_method),
+            Some(&card_payout_method_data_with_holder_name(Some("John Doe"))),
+        );
+        assert!(result.is_err_and(|err| matches!(
+            err.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        )));
+    }
+
+    #[test]
+    fn test_payout_type_in_request_overrides_default_payout_method() {
+        let default_payout_method = sepa_default_payout_method();
+        let card = card_payout_method_data_with_holder_name(Some("John Doe"));
+
+        assert_eq!(
+            resolve_payout_type(
+                Some(api_enums::PayoutType::Card),
+                Some(&default_payout_method),
+                Some(&card)
+            )
+            .unwrap(),
+            api_enums::PayoutType::Card
+        );
+        // The payout method required by the default payout method does not apply either
+        assert_eq!(
+            resolve_payout_type(
+                Some(api_enums::PayoutType::Bank),
+                Some(&default_payout_method),
+                Some(&ach_payout_method_data("110000000"))
+            )
+            .unwrap(),
+            api_enums::PayoutType::Bank
+        );
+
+        // Payout method data is still required to be of the payout type
+        let result = resolve_payout_type(
+            Some(api_enums::PayoutType::Bank),
+            Some(&default_payout_method),
+            Some(&card),
+        );
+        assert!(result.is_err_and(|err| matches!(
+            err.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        )));
+    }
+
+    #[test]
+    fn test_payout_type_is_inferred_or_required() {
+        assert_eq!(
+            resolve_payout_type(
+                None,
+                None,
+                Some(&card_payout_method_data_with_holder_name(Some("John Doe")))
+            )
+            .unwrap(),
+            api_enums::PayoutType::Card
+        );
+
+        let result = resolve_payout_type(None, None, None);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::MissingRequiredField { field_name }
+                if *field_name == "payout_type"
+        ));
+    }
+
+    #[test]
+    fn test_card_payout_method_data_is_not_validated() {
+        let card = payouts::PayoutMethodData::Card(payouts::CardPayout::default());
+        assert!(validate_payout_method_data(&card).is_ok());
+    }
+
+    fn bacs_payout_method_data() -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(payouts::BacsBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            bank_account_number: "55779911".to_string().into(),
+            bank_sort_code: "200000".to_string().into(),
+        }))
+    }
+
+    fn is_incompatible_bank_payout(result: RouterResult<()>) -> bool {
+        result.is_err_and(|err| {
+            matches!(
+                err.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            )
+        })
+    }
+
+    #[test]
+    fn test_supported_bank_payout_combinations_are_accepted() {
+        let ach = ach_payout_method_data("110000000");
+        let bacs = bacs_payout_method_data();
+        let sepa = sepa_payout_method_data("NL46TEST0136169112");
+
+        assert!(validate_bank_payout_compatibility(
+            &ach,
+            api_enums::Currency::USD,
+            Some(api_enums::CountryAlpha2::US)
+        )
+        .is_ok());
+        assert!(validate_bank_payout_compatibility(
+            &bacs,
+            api_enums::Currency::GBP,
+            Some(api_enums::CountryAlpha2::GB)
+        )
+        .is_ok());
+        for country in SEPA_COUNTRIES {
+            assert!(validate_bank_payout_compatibility(
+                &sepa,
+                api_enums::Currency::EUR,
+                Some(*country)
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_unsupported_bank_payout_currency_is_rejected() {
+        for (payout_method_data, currency, country) in [
+            (
+                ach_payout_method_data("110000000"),
+                api_enums::Currency::EUR,
+                api_enums::CountryAlpha2::US,
+            ),
+            (
+                bacs_payout_method_data(),
+                api_enums::Currency::EUR,
+                api_enums::CountryAlpha2::GB,
+            ),
+            (
+                sepa_payout_method_data("NL46TEST0136169112"),
+                api_enums::Currency::GBP,
+                api_enums::CountryAlpha2::NL,
+            ),
+        ] {
+            assert!(is_incompatible_bank_payout(
+                validate_bank_payout_compatibility(&payout_method_data, currency, Some(country))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_bank_payout_country_is_rejected() {
+        for (payout_method_data, currency, country) in [
+            (
+                ach_payout_method_data("110000000"),
+                api_enums::Currency::USD,
+                api_enums::CountryAlpha2::CA,
+            ),
+            (
+                bacs_payout_method_data(),
+                api_enums::Currency::GBP,
+                api_enums::CountryAlpha2::IE,
+            ),
+            (
+                sepa_payout_method_data("NL46TEST0136169112"),
+                api_enums::Currency::EUR,
+                api_enums::CountryAlpha2::US,
+            ),
+        ] {
+            assert!(is_incompatible_bank_payout(
+                validate_bank_payout_compatibility(&payout_method_data, currency, Some(country))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_bank_payout_currency_is_checked_without_country() {
+        let sepa = sepa_payout_method_data("NL46TEST0136169112");
+
+        assert!(validate_bank_payout_compatibility(&sepa, api_enums::Currency::EUR, None).is_ok());
+        assert!(is_incompatible_bank_payout(
+            validate_bank_payout_compatibility(&sepa, api_enums::Currency::GBP, None)
+        ));
+    }
+
+    #[test]
+    fn test_card_payout_compatibility_is_not_validated() {
+        let card = payouts::PayoutMethodData::Card(payouts::CardPayout::default());
+        assert!(validate_bank_payout_compatibility(
+            &card,
+            api_enums::Currency::JPY,
+            Some(api_enums::CountryAlpha2::JP)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_payout_schedule_in_the_past_is_rejected() {
+        let current_time = datetime!(2024-04-25 10:00:00);
+        let result = validate_payout_schedule(datetime!(2024-04-24 10:00:00), current_time, 90);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+
+        let result = validate_payout_schedule(current_time, current_time, 90);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_payout_schedule_beyond_horizon_is_rejected() {
+        let current_time = datetime!(2024-04-25 10:00:00);
+        let result = validate_payout_schedule(datetime!(2024-07-25 10:00:00), current_time, 90);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+
+    #[test]
+    fn test_payout_schedule_within_horizon_is_accepted() {
+        let current_time = datetime!(2024-04-25 10:00:00);
+        assert!(validate_payout_schedule(datetime!(2024-04-26 10:00:00), current_time, 90).is_ok());
+        assert!(validate_payout_schedule(datetime!(2024-07-24 10:00:00), current_time, 90).is_ok());
+    }
+
+    fn payout_update_request() -> payouts::PayoutUpdateRequest {
+        payouts::PayoutUpdateRequest {
+            payout_id: "payout_123".to_string(),
+            description: Some("Payout for invoice 42".to_string()),
+            metadata: Some(masking::Secret::new(
+                serde_json::json!({ "invoice_id": "42" }),
+            )),
+            email: Some("recipient@example.com".parse().unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn get_error_message(result: RouterResult<()>) -> Option<String> {
+        match result.unwrap_err().current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => Some(message.to_owned()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_update_request_with_non_monetary_fields_is_accepted() {
+        let req = payout_update_request();
+        assert!(validate_update_request(&req, api_enums::PayoutStatus::RequiresCreation).is_ok());
+        assert!(validate_update_request(&req, api_enums::PayoutStatus::Scheduled).is_ok());
+        assert!(
+            validate_update_request(&req, api_enums::PayoutStatus::RequiresPayoutMethodData)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_payout_metadata_within_limits_is_accepted() {
+        let mut metadata = serde_json::Map::new();
+        metadata.insert("batch_ref".to_string(), "batch_2024_05".into());
+        metadata.insert("note".to_string(), "Zahlung für Müller – 支払い 🚀".into());
+        (2..PAYOUT_METADATA_MAX_KEYS).for_each(|index| {
+            metadata.insert(format!("key_{index}"), "value".into());
+        });
+
+        assert!(
+            validate_payout_metadata(Some(&Secret::new(serde_json::Value::Object(metadata))))
+                .is_ok()
+        );
+        assert!(validate_payout_metadata(None).is_ok());
+    }
+
+    #[test]
+    fn test_payout_metadata_not_flat_or_over_limits_is_rejected() {
+        let too_many_keys = (0..=PAYOUT_METADATA_MAX_KEYS)
+            .map(|index| (format!("key_{index}"), serde_json::Value::from("value")))
+            .collect::<serde_json::Map<_, _>>();
+        // The size is measured in bytes, so a value of 400 characters taking 3 bytes each is
+        // oversized
+        let oversized = serde_json::json!({ "note": "€".repeat(400) });
+
+        for (metadata, expected_message) in [
+            (
+                serde_json::json!(["batch_2024_05"]),
+                "metadata must be an object of string values",
+            ),
+            (
+                serde_json::json!({ "batch": { "ref": "batch_2024_05" } }),
+                "metadata must be an object of string values, value of `batch` is not a string",
+            ),
+            (
+                serde_json::json!({ "count": 3 }),
+                "metadata must be an object of string values, value of `count` is not a string",
+            ),
+            (
+                serde_json::Value::Object(too_many_keys),
+                "metadata can have at most 20 keys, 21 keys were passed",
+            ),
+            (
+                oversized,
+                "metadata can be at most 1024 bytes in size, 1211 bytes were passed",
+            ),
+        ] {
+            let message = get_error_message(validate_payout_metadata(Some(&Secret::new(metadata))));
+            assert_eq!(message.as_deref(), Some(expected_message));
+        }
+
+        // Oversized metadata is rejected on updates as well
+        let req = payouts::PayoutUpdateRequest {
+            metadata: Some(Secret::new(serde_json::json!({ "note": "x".repeat(1100) }))),
+            ..payout_update_request()
+        };
+        let message = get_error_message(validate_update_request(
+            &req,
+            api_enums::PayoutStatus::RequiresCreation,
+        ));
+        assert!(message
+            .unwrap()
+            .starts_with("metadata can be at most 1024 bytes"));
+    }
+
+    #[test]
+    fn test_update_request_with_amount_is_rejected() {
+        let req = payouts::PayoutUpdateRequest {
+            amount: Some(api_models::payments::Amount::from(2000)),
+            ..payout_update_request()
+        };
+        let message = get_error_message(validate_update_request(
+            &req,
+            api_enums::PayoutStatus::RequiresCreation,
+        ));
+        assert!(message.unwrap().starts_with("amount cannot be updated"));
+    }
+
+    #[test]
+    fn test_update_request_with_currency_is_rejected() {
+        let req = payouts::PayoutUpdateRequest {
+            currency: Some(api_enums::Currency::USD),
+            ..payout_update_request()
+        };
+        let message = get_error_message(validate_update_request(
+            &req,
+            api_enums::PayoutStatus::RequiresCreation,
+        ));
+        assert!(message.unwrap().starts_with("currency cannot be updated"));
+    }
+
+    #[test]
+    fn test_update_request_on_terminal_or_initiated_payout_is_rejected() {
+        let req = payout_update_request();
+        for status in [
+            api_enums::PayoutStatus::Success,
+            api_enums::PayoutStatus::Failed,
+            api_enums::PayoutStatus::Cancelled,
+            api_enums::PayoutStatus::Pending,
+            api_enums::PayoutStatus::RequiresFulfillment,
+        ] {
+            let message = get_error_message(validate_update_request(&req, status));
+            assert_eq!(
+                message,
+                Some(format!(
+                    "Payout payout_123 cannot be updated for status {status}"
+                ))
+            );
+        }
+    }
+
+    fn card_payout_method_data(expiry_month: &str, expiry_year: &str) -> payouts::PayoutMethodData {
+        payouts::PayoutMethodData::Card(payouts::CardPayout {
+            card_number: "4111111111111111".parse().unwrap(),
+            expiry_month: expiry_month.to_string().into(),
+            expiry_year: expiry_year.to_string().into(),
+            card_holder_name: None,
+        })
+    }
+
+    #[test]
+    fn test_fresh_payout_method_is_accepted() {
+        let current_time = datetime!(2024-04-25 10:00:00);
+        assert!(validate_payout_method_expiry(
+            &card_payout_method_data("12", "2026"),
+            current_time
+        )
+        .is_ok());
+        assert!(
+            validate_payout_method_expiry(&card_payout_method_data("04", "24"), current_time)
+                .is_ok()
+        );
+        assert!(validate_payout_method_expiry(
+            &sepa_payout_method_data("NL46TEST0136169112"),
+            current_time
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_saved_payout_method_with_matching_currency_is_accepted() {
+        assert!(validate_saved_payout_method_currency(
+            Some(&[api_enums::Currency::EUR, api_enums::Currency::USD]),
+            api_enums::Currency::USD,
+            "pm_123",
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_saved_payout_method_with_mismatched_currency_is_rejected() {
+        let result = validate_saved_payout_method_currency(
+            Some(&[api_enums::Currency::EUR]),
+            api_enums::Currency::USD,
+            "pm_123",
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::CurrencyNotSupported { message }
+                if message == "currency USD is not supported by the payout method pm_123"
+        ));
+    }
+
+    #[test]
+    fn test_currency_agnostic_saved_payout_method_is_accepted() {
+        assert!(
+            validate_saved_payout_method_currency(None, api_enums::Currency::JPY, "pm_123").is_ok()
+        );
+        assert!(validate_saved_payout_method_currency(
+            Some(&[]),
+            api_enums::Currency::JPY,
+            "pm_123"
+        )
+        .is_ok());
+    }
+
+    fn get_payout_attempt(
+        connector: Option<&str>,
+        connector_payout_id: &str,
+        status: api_enums::PayoutStatus,
+    ) -> storage::PayoutAttempt {
+        storage::PayoutAttempt {
+            payout_attempt_id: "payout_123_1".to_string(),
+            payout_id: "payout_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            address_id: "addr_123".to_string(),
+            connector: connector.map(str::to_string),
+            connector_payout_id: connector_payout_id.to_string(),
+            payout_token: None,
+            status,
+            is_eligible: None,
+            error_message: None,
+            error_code: None,
+            business_country: None,
+            business_label: None,
+            created_at: datetime!(2024-04-25 10:00:00),
+            last_modified_at: datetime!(2024-04-25 10:00:00),
+            profile_id: "pro_123".to_string(),
+            merchant_connector_id: None,
+            routing_info: None,
+            manual_routing_override: false,
+            routing_trail: None,
+            amount: None,
+        }
+    }
+
+    #[test]
+    fn test_sync_request_with_connector_reference_is_accepted() {
+        let payout_attempt =
+            get_payout_attempt(Some("wise"), "50123456", api_enums::PayoutStatus::Pending);
+
+        assert_eq!(validate_sync_request(&payout_attempt).unwrap(), "50123456");
+        assert_eq!(
+            helpers::normalize_payout_sync_status(
+                payout_attempt.status,
+                Some(api_enums::PayoutStatus::Success)
+            ),
+            api_enums::PayoutStatus::Success
+        );
+    }
+
+    #[test]
+    fn test_sync_request_without_connector_reference_is_rejected() {
+        for payout_attempt in [
+            get_payout_attempt(Some("wise"), "", api_enums::PayoutStatus::Pending),
+            get_payout_attempt(None, "50123456", api_enums::PayoutStatus::Pending),
+        ] {
+            assert!(matches!(
+                validate_sync_request(&payout_attempt).unwrap_err().current_context(),
+                errors::ApiErrorResponse::PreconditionFailed { message }
+                    if message == "Payout payout_123 cannot be synced as it has not been created with the connector"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_expired_payout_method_is_rejected() {
+        let current_time = datetime!(2024-04-25 10:00:00);
+        for (expiry_month, expiry_year) in [("03", "2024"), ("3", "24"), ("12", "2023")] {
+            let result = validate_payout_method_expiry(
+                &card_payout_method_data(expiry_month, expiry_year),
+                current_time,
+            );
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::PayoutNotFound
+            ));
+        }
+    }
+
+    #[test]
+    fn test_payout_with_valid_or_no_fx_quote_can_be_confirmed() {
+        let current_time = datetime!(2024-04-30 10:00:00);
+        assert!(validate_fx_quote_expiry("payout_123", None, current_time).is_ok());
+        assert!(validate_fx_quote_expiry(
+            "payout_123",
+            Some(datetime!(2024-04-30 10:15:00)),
+            current_time
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_payout_with_expired_fx_quote_cannot_be_confirmed() {
+        let result = validate_fx_quote_expiry(
+            "payout_123",
+            Some(datetime!(2024-04-30 10:15:00)),
+            datetime!(2024-04-30 10:15:01),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { message }
+                if message == "FX quote for payout payout_123 has expired, update the payout with source_currency to re-quote it"
+        ));
+    }
+
+    #[test]
+    fn test_valid_recipient_contact_details_are_normalized() {
+        let email = Email::try_from("John.Doe@Example.COM".to_string()).unwrap();
+        let phone = Secret::new("805 659 4427".to_string());
+
+        let contact_details =
+            validate_recipient_contact_details(Some(&email), Some(&phone), Some("+1"), None)
+                .unwrap();
+
+        assert_eq!(
+            contact_details.email.unwrap().peek(),
+            "John.Doe@example.com"
+        );
+        assert_eq!(contact_details.phone.unwrap().peek(), "8056594427");
+        assert_eq!(contact_details.phone_country_code.as_deref(), Some("+1"));
+    }
+
+    #[test]
+    fn test_recipient_phone_country_code_is_inferred_from_billing_country() {
+        let phone = Secret::new("020 7946 0958".to_string());
+
+        let contact_details = validate_recipient_contact_details(
+            None,
+            Some(&phone),
+            None,
+            Some(api_enums::CountryAlpha2::GB),
+        )
+        .unwrap();
+
+        assert_eq!(contact_details.phone.unwrap().peek(), "2079460958");
+        assert_eq!(contact_details.phone_country_code.as_deref(), Some("+44"));
+
+        let result = validate_recipient_contact_details(None, Some(&phone), None, None);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. } if field_name == "phone"
+        ));
+    }
+
+    #[test]
+    fn test_malformed_recipient_email_is_rejected() {
+        // Email accepts the redacted placeholder, which cannot be used to notify the recipient
+        let email = Email::try_from("Redacted".to_string()).unwrap();
+
+        let result = validate_recipient_contact_details(Some(&email), None, None, None);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. } if field_name == "email"
+        ));
+    }
+
+    #[test]
+    fn test_instant_payout_is_allowed_for_supporting_connectors() {
+        assert!(validate_payout_priority(
+            api_enums::PayoutPriority::Instant,
+            api_enums::PayoutType::Wallet,
+            &[api_enums::Connector::Paypal],
+        )
+        .is_ok());
+        assert!(validate_payout_priority(
+            api_enums::PayoutPriority::Instant,
+            api_enums::PayoutType::Card,
+            &[api_enums::Connector::Adyen, api_enums::Connector::Paypal],
+        )
+        .is_ok());
+        assert!(validate_payout_priority(
+            api_enums::PayoutPriority::Standard,
+            api_enums::PayoutType::Bank,
+            &[api_enums::Connector::Wise],
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_instant_payout_is_rejected_for_unsupporting_connectors() {
+        let result = validate_payout_priority(
+            api_enums::PayoutPriority::Instant,
+            api_enums::PayoutType::Wallet,
+            &[api_enums::Connector::Paypal, api_enums::Connector::Adyen],
+        );
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::NotSupported { message }
+                if message == "Instant wallet payouts are not supported by adyen"
+        ));
+    }
+
+    #[test]
+    fn test_payout_token_of_the_same_customer_is_accepted() {
+        assert!(validate_payout_method_owner(Some("cus_123"), "cus_123").is_ok());
+    }
+
+    #[test]
+    fn test_payout_token_of_another_customer_is_rejected() {
+        for owner_customer_id in [Some("cus_456"), None] {
+            let result = validate_payout_method_owner(owner_customer_id, "cus_123");
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::AccessForbidden { resource } if resource == "payout_token"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_payout_to_verified_recipient_is_not_held() {
+        assert_eq!(
+            get_recipient_kyc_hold_reason(true, Some(api_enums::KycStatus::Verified)),
+            None
+        );
+        // Recipients are not gated when the business profile does not require KYC
+        assert_eq!(get_recipient_kyc_hold_reason(false, None), None);
+        assert_eq!(
+            get_recipient_kyc_hold_reason(false, Some(api_enums::KycStatus::Rejected)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_payout_to_unverified_recipient_is_held_until_verified() {
+        assert_eq!(
+            get_recipient_kyc_hold_reason(true, None).as_deref(),
+            Some("Payout is held until the KYC of the recipient is submitted and verified")
+        );
+        assert_eq!(
+            get_recipient_kyc_hold_reason(true, Some(api_enums::KycStatus::Pending)).as_deref(),
+            Some("Payout is held until the KYC of the recipient is verified, current KYC status is pending")
+        );
+
+        // The payout is released once the recipient is verified
+        assert_eq!(
+            get_recipient_kyc_hold_reason(true, Some(api_enums::KycStatus::Verified)),
+            None
+        );
+        assert!(!helpers::is_payout_terminal_state(
+            api_enums::PayoutStatus::RequiresVendorAccountCreation
+        ));
+    }
+
+    #[test]
+    fn test_payout_to_allowed_country_is_accepted() {
+        let blocked_countries = HashSet::from([api_enums::CountryAlpha2::KP]);
+
+        assert!(validate_payout_country(
+            get_beneficiary_country(
+                Some(&sepa_payout_method_data("DE89370400440532013000")),
+                Some(api_enums::CountryAlpha2::DE),
+            ),
+            &blocked_countries,
+        )
+        .is_ok());
+        assert!(validate_payout_country(None, &blocked_countries).is_ok());
+    }
+
+    #[test]
+    fn test_payout_to_blocked_country_is_rejected() {
+        let blocked_countries = HashSet::from([api_enums::CountryAlpha2::KP]);
+        let result = validate_payout_country(
+            get_beneficiary_country(None, Some(api_enums::CountryAlpha2::KP)),
+            &blocked_countries,
+        );
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PayoutCountryBlocked { country } if country == "KP"
+        ));
+    }
+
+    const FINGERPRINT_SECRET: &str = "fs_merchant_fingerprint_secret";
+
+    fn get_self_payout_restriction(enabled: bool) -> SelfPayoutRestriction {
+        let merchant_account_fingerprints = [
+            PayoutBeneficiaryAccount::Sepa {
+                iban: Secret::new("DE89 3704 0044 0532 0130 00".to_string()),
+            },
+            PayoutBeneficiaryAccount::Ach {
+                bank_routing_number: Secret::new("110000000".to_string()),
+                bank_account_number: Secret::new("000123456789".to_string()),
+            },
+        ]
+        .iter()
+        .map(|account| account.get_fingerprint(FINGERPRINT_SECRET).unwrap())
+        .collect::<Vec<_>>();
+        serde_json::json!({
+            "enabled": enabled,
+            "merchant_account_fingerprints": merchant_account_fingerprints
+        })
+        .to_string()
+        .parse_struct("SelfPayoutRestriction")
+        .unwrap()
+    }
+
+    #[test]
+    fn test_self_payout_is_rejected_when_restricted() {
+        let restriction = get_self_payout_restriction(true);
+
+        for payout_method_data in [
+            sepa_payout_method_data("de89370400440532013000"),
+            ach_payout_method_data("110000000"),
+        ] {
+            let result =
+                validate_self_payout(&restriction, Some(&payout_method_data), FINGERPRINT_SECRET);
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::SelfPayoutBlocked
+            ));
+        }
+        // Accounts of other recipients are not restricted
+        for payout_method_data in [
+            sepa_payout_method_data("NL46TEST0136169112"),
+            ach_payout_method_data("021000021"),
+        ] {
+            assert!(validate_self_payout(
+                &restriction,
+                Some(&payout_method_data),
+                FINGERPRINT_SECRET
+            )
+            .is_ok());
+        }
+        assert!(validate_self_payout(&restriction, None, FINGERPRINT_SECRET).is_ok());
+    }
+
+    #[test]
+    fn test_self_payout_is_accepted_when_not_restricted() {
+        let payout_method_data = sepa_payout_method_data("DE89370400440532013000");
+
+        for restriction in [
+            get_self_payout_restriction(false),
+            SelfPayoutRestriction::default(),
+        ] {
+            assert!(validate_self_payout(
+                &restriction,
+                Some(&payout_method_data),
+                FINGERPRINT_SECRET
+            )
+            .is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merchant_config_defaults_when_not_configured() {
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let db: &dyn StorageInterface = &mock_db;
+        db.insert_config(storage::ConfigNew {
+            key: "payout_kyc_requirement_merchant_1".to_string(),
+            config: r#"{"enabled": true}"#.to_string(),
+        })
+        .await
+        .unwrap();
+
+        let requirement =
+            get_merchant_config::<PayoutKycRequirement>(db, "payout_kyc_requirement_merchant_1")
+                .await
+                .unwrap();
+        assert!(requirement.enabled);
+        assert_eq!(requirement.payout_types, vec![api_enums::PayoutType::Card]);
+
+        let restriction =
+            get_merchant_config::<SelfPayoutRestriction>(db, "self_payout_restriction_merchant_1")
+                .await
+                .unwrap();
+        assert!(!restriction.enabled);
+        assert!(restriction.merchant_account_fingerprints.is_empty());
+    }
+
+    #[test]
+    fn test_self_payout_fingerprints_are_keyed_by_the_merchant_secret() {
+        let restriction = get_self_payout_restriction(true);
+        let payout_method_data = sepa_payout_method_data("DE89370400440532013000");
+
+        // Fingerprints generated with the secret of another merchant do not match
+        assert!(validate_self_payout(
+            &restriction,
+            Some(&payout_method_data),
+            "fs_other_merchant_fingerprint_secret"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_card_payout_of_merchant_with_incomplete_kyc_is_rejected() {
+        let requirement = PayoutKycRequirement {
+            enabled: true,
+            ..Default::default()
+        };
+
+        for kyc_status in [
+            None,
+            Some(api_enums::KycStatus::Pending),
+            Some(api_enums::KycStatus::Rejected),
+        ] {
+            let result = validate_merchant_kyc_for_payout(
+                &requirement,
+                kyc_status,
+                api_enums::PayoutType::Card,
+            );
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::MerchantKycIncomplete { payout_type } if payout_type == "card"
+            ));
+            // Bank payouts do not require KYC unless configured
+            assert!(validate_merchant_kyc_for_payout(
+                &requirement,
+                kyc_status,
+                api_enums::PayoutType::Bank
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_payouts_of_merchant_with_complete_kyc_are_accepted() {
+        let requirement: PayoutKycRequirement = serde_json::json!({
+            "enabled": true,
+            "payout_types": ["card", "bank"]
+        })
+        .to_string()
+        .parse_struct("PayoutKycRequirement")
+        .unwrap();
+
+        for payout_type in [api_enums::PayoutType::Card, api_enums::PayoutType::Bank] {
+            assert!(validate_merchant_kyc_for_payout(
+                &requirement,
+                Some(api_enums::KycStatus::Verified),
+                payout_type
+            )
+            .is_ok());
+        }
+        // Bank payouts require KYC when configured
+        assert!(validate_merchant_kyc_for_payout(
+            &requirement,
+            Some(api_enums::KycStatus::Pending),
+            api_enums::PayoutType::Bank
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_payouts_are_accepted_when_merchant_kyc_is_not_required() {
+        assert!(validate_merchant_kyc_for_payout(
+            &PayoutKycRequirement::default(),
+            None,
+            api_enums::PayoutType::Card
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_connector_override_on_failed_payout_is_accepted() {
+        let payout_attempt =
+            get_payout_attempt(Some("adyen"), "50123456", api_enums::PayoutStatus::Failed);
+
+        assert!(validate_connector_override(&payout_attempt).is_ok());
+    }
+
+    #[test]
+    fn test_connector_override_on_payout_in_flight_is_rejected() {
+        let payout_attempt =
+            get_payout_attempt(Some("adyen"), "50123456", api_enums::PayoutStatus::Pending);
+        let result = validate_connector_override(&payout_attempt);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Payout payout_123 cannot be routed through another connector for status pending"
+        ));
+    }
+
+    #[test]
+    fn test_connector_override_on_succeeded_payout_is_rejected() {
+        let payout_attempt =
+            get_payout_attempt(Some("adyen"), "50123456", api_enums::PayoutStatus::Success);
+        let result = validate_connector_override(&payout_attempt);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Payout payout_123 has already succeeded"
+        ));
+    }
+
+    fn get_payouts(
+        fulfilled_amount: Option<i64>,
+        status: api_enums::PayoutStatus,
+    ) -> storage::Payouts {
+        storage::Payouts {
+            payout_id: "payout_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            address_id: "addr_123".to_string(),
+            payout_type: api_enums::PayoutType::Wallet,
+            payout_method_id: None,
+            amount: 1000,
+            destination_currency: api_enums::Currency::USD,
+            source_currency: api_enums::Currency::USD,
+            description: None,
+            recurring: false,
+            auto_fulfill: false,
+            return_url: None,
+            entity_type: api_enums::PayoutEntityType::Individual,
+            metadata: None,
+            created_at: datetime!(2024-05-08 10:00:00),
+            last_modified_at: datetime!(2024-05-08 10:00:00),
+            profile_id: "pro_123".to_string(),
+            status,
+            attempt_count: 1,
+            scheduled_at: None,
+            fx_rate: None,
+            fx_quote_expires_at: None,
+            priority: api_enums::PayoutPriority::Standard,
+            fulfilled_amount,
+            payout_schedule_id: None,
+            source_payment_id: None,
+        }
+    }
+
+    #[test]
+    fn test_payout_is_fulfilled_in_a_single_shot() {
+        let payouts = get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment);
+
+        // The whole amount is fulfilled when the amount is not passed
+        let amount = validate_payout_fulfillment(&payouts, None).unwrap();
+        assert_eq!(amount, 1000);
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                amount,
+                api_enums::PayoutStatus::RequiresFulfillment,
+                api_enums::PayoutStatus::Success
+            ),
+            (Some(1000), api_enums::PayoutStatus::Success)
+        );
+    }
+
+    #[test]
+    fn test_payout_is_fulfilled_in_two_tranches() {
+        let payouts = get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment);
+
+        let amount = validate_payout_fulfillment(&payouts, Some(400)).unwrap();
+        assert_eq!(amount, 400);
+        let (fulfilled_amount, status) = helpers::get_payout_fulfillment_status(
+            &payouts,
+            amount,
+            api_enums::PayoutStatus::RequiresFulfillment,
+            api_enums::PayoutStatus::Success,
+        );
+        assert_eq!(fulfilled_amount, Some(400));
+        assert_eq!(status, api_enums::PayoutStatus::PartiallyFulfilled);
+
+        // A failed tranche leaves the payout partially fulfilled
+        let payouts = get_payouts(fulfilled_amount, status);
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                600,
+                api_enums::PayoutStatus::RequiresFulfillment,
+                api_enums::PayoutStatus::Failed
+            ),
+            (Some(400), api_enums::PayoutStatus::PartiallyFulfilled)
+        );
+
+        // The payout succeeds once the remaining amount is fulfilled
+        let amount = validate_payout_fulfillment(&payouts, None).unwrap();
+        assert_eq!(amount, 600);
+        let (fulfilled_amount, status) = helpers::get_payout_fulfillment_status(
+            &payouts,
+            amount,
+            api_enums::PayoutStatus::RequiresFulfillment,
+            api_enums::PayoutStatus::Success,
+        );
+        assert_eq!(fulfilled_amount, Some(1000));
+        assert_eq!(status, api_enums::PayoutStatus::Success);
+
+        let result = validate_payout_fulfillment(&get_payouts(fulfilled_amount, status), None);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Payout payout_123 cannot be fulfilled for status success"
+        ));
+    }
+
+    #[test]
+    fn test_pending_tranche_is_counted_once_fulfilled() {
+        // A pending tranche is not counted towards the fulfilled amount
+        let payouts = get_payouts(Some(400), api_enums::PayoutStatus::PartiallyFulfilled);
+        let (fulfilled_amount, status) = helpers::get_payout_fulfillment_status(
+            &payouts,
+            600,
+            api_enums::PayoutStatus::RequiresFulfillment,
+            api_enums::PayoutStatus::Pending,
+        );
+        assert_eq!(fulfilled_amount, Some(400));
+        assert_eq!(status, api_enums::PayoutStatus::PartiallyFulfilled);
+
+        // The payout succeeds once the pending tranche is synced as fulfilled
+        let payouts = get_payouts(fulfilled_amount, status);
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                600,
+                api_enums::PayoutStatus::Pending,
+                api_enums::PayoutStatus::Success
+            ),
+            (Some(1000), api_enums::PayoutStatus::Success)
+        );
+
+        // A pending tranche which fails leaves the tranches fulfilled earlier as they are
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                600,
+                api_enums::PayoutStatus::Pending,
+                api_enums::PayoutStatus::Failed
+            ),
+            (Some(400), api_enums::PayoutStatus::PartiallyFulfilled)
+        );
+
+        // The first tranche of a payout stays pending until it is fulfilled
+        let payouts = get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment);
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                400,
+                api_enums::PayoutStatus::RequiresFulfillment,
+                api_enums::PayoutStatus::Pending
+            ),
+            (None, api_enums::PayoutStatus::Pending)
+        );
+    }
+
+    #[test]
+    fn test_fulfilled_tranche_failed_by_the_connector_is_not_counted() {
+        let payouts = get_payouts(Some(1000), api_enums::PayoutStatus::Success);
+        assert_eq!(
+            helpers::get_payout_fulfillment_status(
+                &payouts,
+                600,
+                api_enums::PayoutStatus::Success,
+                api_enums::PayoutStatus::Failed
+            ),
+            (Some(400), api_enums::PayoutStatus::PartiallyFulfilled)
+        );
+    }
+
+    #[test]
+    fn test_partial_tranche_is_validated_against_the_connector() {
+        let payouts = get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment);
+        let payout_attempt = get_payout_attempt(
+            Some("paypal"),
+            "",
+            api_enums::PayoutStatus::RequiresFulfillment,
+        );
+        assert!(validate_payout_tranche(
+            &payouts,
+            &payout_attempt,
+            400,
+            Some(api_enums::Connector::Paypal)
+        )
+        .is_ok());
+
+        // The whole amount can be fulfilled through any connector
+        assert!(validate_payout_tranche(
+            &payouts,
+            &payout_attempt,
+            1000,
+            Some(api_enums::Connector::Wise)
+        )
+        .is_ok());
+
+        let result = validate_payout_tranche(
+            &payouts,
+            &payout_attempt,
+            400,
+            Some(api_enums::Connector::Wise),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Payout payout_123 cannot be partially fulfilled through wise"
+        ));
+    }
+
+    #[test]
+    fn test_tranche_is_rejected_while_another_is_pending() {
+        let payouts = get_payouts(Some(400), api_enums::PayoutStatus::PartiallyFulfilled);
+        let result = validate_payout_tranche(
+            &payouts,
+            &get_payout_attempt(Some("paypal"), "po_123", api_enums::PayoutStatus::Pending),
+            600,
+            Some(api_enums::Connector::Paypal),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "A tranche of payout payout_123 is pending with the connector"
+        ));
+
+        assert!(validate_payout_tranche(
+            &payouts,
+            &get_payout_attempt(Some("paypal"), "po_123", api_enums::PayoutStatus::Failed),
+            600,
+            Some(api_enums::Connector::Paypal),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_payout_over_fulfillment_is_rejected() {
+        let result = validate_payout_fulfillment(
+            &get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment),
+            Some(1001),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "amount 1001 exceeds the amount 1000 of payout payout_123 yet to be fulfilled"
+        ));
+
+        let result = validate_payout_fulfillment(
+            &get_payouts(Some(400), api_enums::PayoutStatus::PartiallyFulfilled),
+            Some(700),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "amount 700 exceeds the amount 600 of payout payout_123 yet to be fulfilled"
+        ));
+
+        let result = validate_payout_fulfillment(
+            &get_payouts(None, api_enums::PayoutStatus::RequiresFulfillment),
+            Some(0),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "amount must be greater than 0"
+        ));
+    }
+
+    fn get_source_payment(
+        status: storage::enums::IntentStatus,
+        amount_captured: Option<i64>,
+    ) -> storage::PaymentIntent {
+        storage::PaymentIntent {
+            id: 1,
+            payment_id: "pay_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            status,
+            amount: 10000,
+            currency: Some(api_enums::Currency::USD),
+            amount_captured,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: datetime!(2024-05-08 10:00:00),
+            modified_at: datetime!(2024-05-08 10:00:00),
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: data_models::RemoteStorageObject::ForeignID("pay_123_1".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            profile_id: None,
+            payment_link_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            updated_by: storage::enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            surcharge_applicable: None,
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            fingerprint_id: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+        }
+    }
+
+    fn get_linked_payout(amount: i64, status: api_enums::PayoutStatus) -> storage::Payouts {
+        storage::Payouts {
+            amount,
+            source_payment_id: Some("pay_123".to_string()),
+            ..get_payouts(None, status)
+        }
+    }
+
+    #[test]
+    fn test_payout_split_from_captured_payment_is_accepted() {
+        let payment_intent =
+            get_source_payment(storage::enums::IntentStatus::PartiallyCaptured, Some(8000));
+        let amount_captured =
+            validate_source_payment(&payment_intent, Some(api_enums::Currency::USD)).unwrap();
+        let payout_amount =
+            get_source_payment_payout_amount(None, Some(25), amount_captured).unwrap();
+
+        assert_eq!(amount_captured, 8000);
+        assert_eq!(payout_amount, 2000);
+        assert!(
+            validate_source_payment_allocation(payout_amount, amount_captured, 1000, 3000).is_ok()
+        );
+        assert_eq!(
+            get_source_payment_payout_amount(Some(1500), None, amount_captured).unwrap(),
+            1500
+        );
+    }
+
+    #[test]
+    fn test_payout_over_allocating_source_payment_is_rejected() {
+        let result = validate_source_payment_allocation(2001, 10000, 2000, 6000);
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "amount of the payout exceeds the 2000 of the source payment left for payouts"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_total_of_linked_payouts_leaves_out_payouts_not_gone_through() {
+        use storage_impl::DataModelExt;
+
+        let mock_db = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let linked_payouts = [
+            ("payout_1", 3000, api_enums::PayoutStatus::Success),
+            ("payout_2", 2000, api_enums::PayoutStatus::Pending),
+            ("payout_3", 5000, api_enums::PayoutStatus::Failed),
+            ("payout_4", 5000, api_enums::PayoutStatus::Cancelled),
+        ];
+        for (payout_id, amount, status) in linked_payouts {
+            mock_db.payouts.lock().await.push(
+                storage::Payouts {
+                    payout_id: payout_id.to_string(),
+                    ..get_linked_payout(amount, status)
+                }
+                .to_storage_model(),
+            );
+        }
+        // Payouts which are not funded by the payment are not counted either
+        mock_db
+            .payouts
+            .lock()
+            .await
+            .push(get_payouts(None, api_enums::PayoutStatus::Success).to_storage_model());
+
+        let db: &dyn StorageInterface = &mock_db;
+        let total_linked_payout_amount = db
+            .get_total_payout_amount_by_merchant_id_source_payment_id(
+                "merchant_123",
+                "pay_123",
+                storage::enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total_linked_payout_amount, 5000);
+    }
+
+    #[test]
+    fn test_payout_from_unusable_source_payment_is_rejected() {
+        let result = validate_source_payment(
+            &get_source_payment(storage::enums::IntentStatus::RequiresCapture, None),
+            Some(api_enums::Currency::USD),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::PaymentUnexpectedState { current_value, .. }
+                if current_value == "requires_capture"
+        ));
+
+        let result = validate_source_payment(
+            &get_source_payment(storage::enums::IntentStatus::Succeeded, Some(10000)),
+            Some(api_enums::Currency::EUR),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+
+        let result = get_source_payment_payout_amount(Some(1000), Some(10), 10000);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+
+        let result = get_source_payment_payout_amount(None, Some(101), 10000);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "source_payment_split_percentage"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_refund_below_linked_payout_total_is_rejected() {
+        assert!(validate_refund_against_linked_payouts(10000, 1000, 3000, 6000).is_ok());
+
+        let result = validate_refund_against_linked_payouts(10000, 1000, 3001, 6000);
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Refund would leave 5999 of the captured amount of the payment, less than the 6000 paid out through the payouts linked to it"
+        ));
+
+        assert!(validate_refund_against_linked_payouts(10000, 0, 10000, 0).is_ok());
+    }
+
+    #[test]
+    fn test_amount_of_linked_payout_cannot_be_updated() {
+        let linked_payout = get_linked_payout(2000, api_enums::PayoutStatus::RequiresCreation);
+
+        let req = payouts::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(2500)),
+            ..Default::default()
+        };
+        assert!(validate_source_payment_for_update(&linked_payout, &req).is_err());
+
+        let req = payouts::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(2000)),
+            description: Some("Seller share".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_source_payment_for_update(&linked_payout, &req).is_ok());
+
+        let req = payouts::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(2500)),
+            ..Default::default()
+        };
+        assert!(validate_source_payment_for_update(
+            &get_payouts(None, api_enums::PayoutStatus::RequiresCreation),
+            &req
+        )
+        .is_ok());
+    }
+
+    fn get_billing_address() -> domain::Address {
+        let encryptable = |value: &str| {
+            Some(common_utils::crypto::Encryptable::new(
+                Secret::new(value.to_string()),
+                Secret::new(Vec::new()),
+            ))
+        };
+        domain::Address {
+            id: None,
+            address_id: "add_123".to_string(),
+            city: Some("Berlin".to_string()),
+            country: Some(api_enums::CountryAlpha2::DE),
+            line1: encryptable("Alexanderplatz 1"),
+            line2: None,
+            line3: None,
+            state: None,
+            zip: encryptable("10178"),
+            first_name: encryptable("Erika"),
+            last_name: encryptable("Mustermann"),
+            phone_number: None,
+            country_code: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            customer_id: None,
+            merchant_id: "merchant_123".to_string(),
+            payment_id: None,
+            updated_by: "postgres_only".to_string(),
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_payout_required_fields_cover_supported_payout_methods() {
+        let registry = settings::PayoutRequiredFields::default();
+        let card = payouts::PayoutMethodData::Card(payouts::CardPayout {
+            card_number: "4111111111111111".parse().unwrap(),
+            expiry_month: "03".to_string().into(),
+            expiry_year: "2030".to_string().into(),
+            card_holder_name: Some("Erika Mustermann".to_string().into()),
+        });
+        let bacs =
+            payouts::PayoutMethodData::Bank(payouts::BankPayout::Bacs(payouts::BacsBankTransfer {
+                bank_account_number: "55779911".to_string().into(),
+                bank_sort_code: "200000".to_string().into(),
+                ..Default::default()
+            }));
+        let paypal = payouts::PayoutMethodData::Wallet(payouts::WalletPayout::Paypal(
+            api_models::payouts::Paypal {
+                email: Some("erika@example.com".to_string().parse().unwrap()),
+            },
+        ));
+        // Payout methods supported by the payout connectors
+        let supported_payout_methods = [
+            (api_enums::PayoutConnectors::Adyen, card),
+            (
+                api_enums::PayoutConnectors::Adyen,
+                sepa_payout_method_data("DE89 3704 0044 0532 0130 00"),
+            ),
+            (api_enums::PayoutConnectors::Adyen, paypal),
+            (
+                api_enums::PayoutConnectors::Wise,
+                ach_payout_method_data("011000015"),
+            ),
+            (api_enums::PayoutConnectors::Wise, bacs),
+            (
+                api_enums::PayoutConnectors::Wise,
+                sepa_payout_method_data("DE89370400440532013000"),
+            ),
+        ];
+        for (connector, payout_method_data) in supported_payout_methods {
+            let payout_method_type =
+                api_enums::PaymentMethodType::foreign_from(payout_method_data.clone());
+            let response = get_payout_required_fields(
+                &registry,
+                &payouts::PayoutRequiredFieldsRequest {
+                    connector,
+                    payout_type: api_enums::PayoutType::foreign_from(&payout_method_data),
+                    country: None,
+                    currency: None,
+                },
+            );
+            let payout_method = response
+                .payout_methods
+                .iter()
+                .find(|payout_method| payout_method.payout_method_type == payout_method_type);
+            assert!(
+                payout_method
+                    .is_some_and(|payout_method| !payout_method.required_fields.is_empty()),
+                "{connector} {payout_method_type} is missing in the registry"
+            );
+
+            // Every advertised field can be resolved, and complete data passes validation
+            assert!(
+                validate_payout_required_fields(
+                    &registry,
+                    connector,
+                    &payout_method_data,
+                    Some(&get_billing_address()),
+                )
+                .is_ok(),
+                "{connector} {payout_method_type} rejected complete payout method data"
+            );
+        }
+    }
+
+    #[test]
+    fn test_payout_required_fields_are_filtered_by_country_and_currency() {
+        let response = get_payout_required_fields(
+            &settings::PayoutRequiredFields::default(),
+            &payouts::PayoutRequiredFieldsRequest {
+                connector: api_enums::PayoutConnectors::Wise,
+                payout_type: api_enums::PayoutType::Bank,
+                country: Some(api_enums::CountryAlpha2::DE),
+                currency: Some(api_enums::Currency::EUR),
+            },
+        );
+        let payout_method_types = response
+            .payout_methods
+            .iter()
+            .map(|payout_method| payout_method.payout_method_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            payout_method_types,
+            vec![api_enums::PaymentMethodType::Sepa]
+        );
+    }
+
+    #[test]
+    fn test_payout_missing_registry_required_field_is_rejected() {
+        let registry = settings::PayoutRequiredFields::default();
+        let payout_method_data = sepa_payout_method_data("DE89370400440532013000");
+
+        // Wise requires the billing address for bank payouts
+        let result = validate_payout_required_fields(
+            &registry,
+            api_enums::PayoutConnectors::Wise,
+            &payout_method_data,
+            None,
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.starts_with("billing.address.city, billing.address.country")
+        ));
+
+        let billing_address = domain::Address {
+            last_name: None,
+            ..get_billing_address()
+        };
+        let result = validate_payout_required_fields(
+            &registry,
+            api_enums::PayoutConnectors::Adyen,
+            &payout_method_data,
+            Some(&billing_address),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "billing.address.last_name required for sepa payouts through adyen"
+        ));
+
+        let result = validate_payout_required_fields(
+            &registry,
+            api_enums::PayoutConnectors::Adyen,
+            &sepa_payout_method_data("de89370400440532013000"),
+            Some(&get_billing_address()),
+        );
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. }
+                if field_name == "payout_method_data.bank.iban"
+        ));
+    }
+}
@@ -105,7 +105,17 @@ pub async fn refund_create_core(
         .await
         .transpose()?;
 
-    Box::pin(validate_and_create_refund(
+    // Refunds are validated against the payouts funded by the payment under the same lock as them
+    #[cfg(feature = "payouts")]
+    let source_payment_locking_action =
+        crate::core::payouts::helpers::get_source_payment_locking_action(Some(&req.payment_id));
+    #[cfg(feature = "payouts")]
+    source_payment_locking_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.to_owned())
+        .await?;
+
+    let response = Box::pin(validate_and_create_refund(
         &state,
         &merchant_account,
         &key_store,
@@ -115,8 +125,14 @@ pub async fn refund_create_core(
         req,
         creds_identifier,
     ))
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    #[cfg(feature = "payouts")]
+    source_payment_locking_action
+        .free_lock_action(&state, merchant_id.to_owned())
+        .await?;
+
+    response.map(services::ApplicationResponse::Json)
 }
 
 #[instrument(skip_all)]
@@ -252,6 +268,32 @@ pub async fn trigger_refund_to_gateway(
         router_data
     };
 
+    // Connectors can carry state across refunds of the same payment (e.g. the amount
+    // already refunded) in the connector metadata, persist it when it has changed
+    if router_data_res.response.is_ok()
+        && router_data_res.request.connector_metadata != payment_attempt.connector_metadata
+    {
+        let attempt_update = storage::PaymentAttemptUpdate::ConnectorMetadataUpdate {
+            connector_metadata: router_data_res.request.connector_metadata.clone(),
+            updated_by: storage_scheme.to_string(),
+        };
+        state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt.to_owned(),
+                attempt_update,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while updating connector metadata for payment attempt: {}",
+                    payment_attempt.attempt_id
+                )
+            })?;
+    }
+
     let refund_update = match router_data_res.response {
         Err(err) => storage::RefundUpdate::ErrorUpdate {
             refund_status: Some(enums::RefundStatus::Failure),
@@ -625,6 +667,27 @@ pub async fn validate_and_create_refund(
     validator::validate_refund_amount(total_amount_captured, &all_refunds, refund_amount)
         .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
+    #[cfg(feature = "payouts")]
+    {
+        let total_linked_payout_amount = db
+            .get_total_payout_amount_by_merchant_id_source_payment_id(
+                &merchant_account.merchant_id,
+                &payment_intent.payment_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to fetch the total amount of the payouts linked to the payment",
+            )?;
+        crate::core::payouts::validator::validate_refund_against_linked_payouts(
+            total_amount_captured,
+            validator::get_total_refunded_amount(&all_refunds),
+            refund_amount,
+            total_linked_payout_amount,
+        )?;
+    }
+
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
         state.conf.refund.max_attempts,
@@ -38,13 +38,9 @@ pub fn validate_success_transaction(
     Ok(())
 }
 
-#[instrument(skip_all)]
-pub fn validate_refund_amount(
-    amount_captured: i64,
-    all_refunds: &[storage::Refund],
-    refund_amount: i64,
-) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
+/// Amount refunded so far through the refunds which have not failed
+pub fn get_total_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    all_refunds
         .iter()
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
@@ -55,7 +51,16 @@ pub fn validate_refund_amount(
                 None
             }
         })
-        .sum();
+        .sum()
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_amount(
+    amount_captured: i64,
+    all_refunds: &[storage::Refund],
+    refund_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    let total_refunded_amount = get_total_refunded_amount(all_refunds);
 
     utils::when(
         refund_amount > (amount_captured - total_refunded_amount),
@@ -263,6 +263,10 @@ pub async fn update_business_profile_active_algorithm_ref(
         payment_link_config: None,
         session_expiry: None,
         authentication_connector_details: None,
+        check_balance_before_fulfill: None,
+        payout_blocked_countries: None,
+        require_recipient_kyc: None,
+        default_payout_method: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -29,6 +29,16 @@ use crate::{
     utils::{generate_id, generate_uuid, OptionExt, ValueExt},
 };
 
+#[cfg(feature = "olap")]
+const IRRELEVANT_PAYMENT_ID_IN_DATE_RANGE_SYNC_FLOW: &str =
+    "irrelevant_payment_id_in_date_range_sync_flow";
+#[cfg(feature = "olap")]
+const IRRELEVANT_ATTEMPT_ID_IN_DATE_RANGE_SYNC_FLOW: &str =
+    "irrelevant_attempt_id_in_date_range_sync_flow";
+#[cfg(feature = "olap")]
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DATE_RANGE_SYNC_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_date_range_sync_flow";
+
 pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DISPUTE_FLOW: &str =
     "irrelevant_connector_request_reference_id_in_dispute_flow";
 const IRRELEVANT_PAYMENT_ID_IN_DISPUTE_FLOW: &str = "irrelevant_payment_id_in_dispute_flow";
@@ -124,6 +134,13 @@ pub async fn construct_payout_router_data<'a, F>(
         .change_context(errors::ApiErrorResponse::InvalidRequestData {
             message: "Could not decide to route the connector".to_string(),
         })?;
+    let webhook_url = helpers::create_webhook_url(
+        &state.conf.server.base_url,
+        &merchant_account.merchant_id,
+        &merchant_connector_account
+            .get_mca_id()
+            .unwrap_or_else(|| connector_name.to_string()),
+    );
     let connector_label = format!("{}_{}", payout_data.profile_id, connector_name);
     let connector_customer_id = customer_details
         .as_ref()
@@ -151,7 +168,7 @@ pub async fn construct_payout_router_data<'a, F>(
         payment_method_status: None,
         request: types::PayoutsData {
             payout_id: payouts.payout_id.to_owned(),
-            amount: payouts.amount,
+            amount: payout_data.get_fulfillment_amount(),
             connector_payout_id: Some(payout_attempt.connector_payout_id.to_owned()),
             destination_currency: payouts.destination_currency,
             source_currency: payouts.source_currency,
@@ -166,6 +183,7 @@ pub async fn construct_payout_router_data<'a, F>(
                     phone: c.phone.map(Encryptable::into_inner),
                     phone_country_code: c.phone_country_code,
                 }),
+            webhook_url: Some(webhook_url),
         },
         response: Ok(types::PayoutsResponseData::default()),
         access_token: None,
@@ -1222,3 +1240,67 @@ pub fn get_incremental_authorization_allowed_value(
         incremental_authorization_allowed
     }
 }
+
+/// Constructs the router data for syncing all the transactions processed through the merchant
+/// connector account in the date range, which belong to no single payment
+#[cfg(feature = "olap")]
+pub fn construct_date_range_sync_router_data(
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: helpers::MerchantConnectorAccountType,
+    connector: String,
+    date_range: types::TransactionDateRange,
+) -> RouterResult<types::PaymentsSyncRouterData> {
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: None,
+        connector_customer: None,
+        connector,
+        payment_id: IRRELEVANT_PAYMENT_ID_IN_DATE_RANGE_SYNC_FLOW.to_string(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_DATE_RANGE_SYNC_FLOW.to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        payment_method_status: None,
+        request: types::PaymentsSyncData {
+            connector_transaction_id: types::ResponseId::NoResponseId,
+            sync_type: types::SyncRequestType::DateRangeSync(date_range),
+            ..Default::default()
+        },
+        response: Err(ErrorResponse::default()),
+        payment_method_id: None,
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DATE_RANGE_SYNC_FLOW.to_string(),
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+    })
+}
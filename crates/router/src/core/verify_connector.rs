@@ -1,6 +1,13 @@
 use api_models::{enums::Connector, verify_connector::VerifyConnectorRequest};
+#[cfg(feature = "payouts")]
+use common_utils::pii;
 use error_stack::ResultExt;
 
+#[cfg(feature = "payouts")]
+use crate::types::{
+    api::{ConnectorCommon, Payouts},
+    ConnectorAuthType, Response,
+};
 use crate::{
     connector,
     core::errors,
@@ -61,3 +68,202 @@ pub async fn verify_connector_credentials(
         .into()),
     }
 }
+
+/// Verifies the credentials of a payout connector with a request which only reads data of the
+/// connector account, so that wrong credentials are found when the connector account is saved
+/// rather than when the first payout fails.
+#[cfg(feature = "payouts")]
+pub async fn verify_payout_connector_credentials(
+    state: &AppState,
+    connector_name: Connector,
+    connector_auth_type: &ConnectorAuthType,
+    connector_meta_data: &Option<pii::SecretSerdeValue>,
+    test_mode: Option<bool>,
+) -> errors::RouterResult<()> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name.to_string(),
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
+    let connector = *connector_data.connector;
+
+    let request = get_payout_credentials_verification_request(
+        connector,
+        connector_auth_type,
+        connector_meta_data,
+        test_mode,
+        &state.conf.connectors,
+    )?;
+    let response =
+        services::call_connector_api(state, request, "verify_payout_credentials_request")
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to call {connector_name} to verify the payout credentials")
+            })?;
+
+    handle_payout_credentials_verification_response(connector, response)
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_credentials_verification_request(
+    connector: &(dyn api::Connector + Sync),
+    connector_auth_type: &ConnectorAuthType,
+    connector_meta_data: &Option<pii::SecretSerdeValue>,
+    test_mode: Option<bool>,
+    connectors: &crate::configs::settings::Connectors,
+) -> errors::RouterResult<services::Request> {
+    connector
+        .build_payout_credentials_verification_request(
+            connector_auth_type,
+            connector_meta_data,
+            test_mode,
+            connectors,
+        )
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The credentials of the {} connector are invalid",
+                connector.id()
+            ),
+        })?
+        .ok_or(
+            errors::ApiErrorResponse::FlowNotSupported {
+                flow: "Verify payout credentials".to_string(),
+                connector: connector.id().to_string(),
+            }
+            .into(),
+        )
+}
+
+#[cfg(feature = "payouts")]
+fn handle_payout_credentials_verification_response(
+    connector: &(dyn api::Connector + Sync),
+    response: Result<Response, Response>,
+) -> errors::RouterResult<()> {
+    match response {
+        Ok(_) => Ok(()),
+        Err(error_response) => {
+            let status_code = error_response.status_code;
+            // The status code alone names the failure when the error body cannot be parsed
+            let error_message = connector
+                .build_error_response(error_response, None)
+                .map(|error| format!(": {}", error.reason.unwrap_or(error.message)))
+                .unwrap_or_default();
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The credentials of the {} connector could not be verified, the connector responded with status code {status_code}{error_message}",
+                    connector.id()
+                ),
+            }
+            .into())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "payouts"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_connector(connector_name: &str) -> &'static (dyn api::Connector + Sync) {
+        *api::ConnectorData::get_connector_by_name(
+            &crate::configs::settings::Connectors::default(),
+            connector_name,
+            api::GetToken::Connector,
+            None,
+        )
+        .unwrap()
+        .connector
+    }
+
+    fn get_response(status_code: u16, body: &str) -> Response {
+        Response {
+            headers: None,
+            response: body.to_string().into(),
+            status_code,
+        }
+    }
+
+    #[test]
+    fn should_build_verification_request_for_payout_connectors() {
+        let auth = ConnectorAuthType::BodyKey {
+            api_key: Secret::new("api_key".to_string()),
+            key1: Secret::new("merchant_account".to_string()),
+        };
+
+        for connector_name in ["wise", "adyen"] {
+            assert!(get_payout_credentials_verification_request(
+                get_connector(connector_name),
+                &auth,
+                &None,
+                None,
+                &crate::configs::settings::Connectors::default(),
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn should_accept_valid_credentials() {
+        assert!(handle_payout_credentials_verification_response(
+            get_connector("wise"),
+            Ok(get_response(200, r#"[{"id":1,"currency":"USD"}]"#)),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn should_reject_invalid_credentials_naming_connector_and_status_code() {
+        let error = handle_payout_credentials_verification_response(
+            get_connector("adyen"),
+            Err(get_response(
+                401,
+                r#"{"status":401,"errorCode":"000","message":"HTTP Status Response - Unauthorized","errorType":"security"}"#,
+            )),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "The credentials of the adyen connector could not be verified, the connector responded with status code 401: HTTP Status Response - Unauthorized"
+        ));
+
+        // The status code is reported even when the error body cannot be parsed
+        let error = handle_payout_credentials_verification_response(
+            get_connector("wise"),
+            Err(get_response(401, "Unauthorized")),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "The credentials of the wise connector could not be verified, the connector responded with status code 401"
+        ));
+    }
+
+    #[test]
+    fn should_reject_verification_for_connectors_which_do_not_support_it() {
+        let error = get_payout_credentials_verification_request(
+            get_connector("stripe"),
+            &ConnectorAuthType::HeaderKey {
+                api_key: Secret::new("api_key".to_string()),
+            },
+            &None,
+            None,
+            &crate::configs::settings::Connectors::default(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FlowNotSupported { flow, connector }
+                if flow == "Verify payout credentials" && connector == "stripe"
+        ));
+    }
+}
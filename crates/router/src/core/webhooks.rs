@@ -25,6 +25,8 @@ use router_env::{
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
     consts,
     core::{
@@ -118,6 +120,7 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
                     merchant_connector_details: None,
                     client_secret: None,
                     expand_attempts: None,
+                    expand_payouts: None,
                     expand_captures: None,
                 },
                 services::AuthFlow::Merchant,
@@ -590,6 +593,53 @@ pub async fn external_authentication_incoming_webhook_flow<Ctx: PaymentMethodRet
     }
 }
 
+/// Payouts are synced with the connector on their webhooks, so that their status is taken from
+/// the connector and not from the webhook body, which may not be verified
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_details: api::IncomingWebhookDetails,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let payout_id = match webhook_details.object_reference_id {
+        webhooks::ObjectReferenceId::PayoutId(webhooks::PayoutIdType::PayoutId(payout_id)) => {
+            payout_id
+        }
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure).attach_printable(
+            "Did not get payout id as object reference id in webhook payouts flow",
+        )?,
+    };
+    let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+        merchant_id: merchant_account.merchant_id.clone(),
+    };
+    let payout_response = payouts::payouts_retrieve_core(
+        state,
+        merchant_account,
+        key_store,
+        api_models::payouts::PayoutRetrieveRequest {
+            payout_id: payout_id.clone(),
+            force_sync: Some(true),
+            expand: None,
+        },
+        audit_actor,
+    )
+    .await
+    .attach_printable("Failed to sync the payout of the incoming webhook")?;
+
+    match payout_response {
+        services::ApplicationResponse::Json(payout_response) => {
+            Ok(WebhookResponseTracker::Payout {
+                payout_id,
+                status: payout_response.status,
+            })
+        }
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("received non-json response from payouts core")?,
+    }
+}
+
 pub async fn mandates_incoming_webhook_flow(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -1829,6 +1879,16 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
                 .attach_printable("Incoming webhook flow for external authentication failed")?
             }
 
+            #[cfg(feature = "payouts")]
+            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                key_store,
+                webhook_details,
+            ))
+            .await
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
         }
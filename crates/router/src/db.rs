@@ -28,6 +28,8 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payout_audit_event;
+pub mod payout_schedule;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
@@ -98,6 +100,8 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface
     + PayoutsInterface
+    + payout_audit_event::PayoutAuditEventInterface
+    + payout_schedule::PayoutScheduleInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
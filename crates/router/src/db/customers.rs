@@ -655,13 +655,24 @@ impl CustomerInterface for MockDb {
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
-        _key_store: &domain::MerchantKeyStore,
-        _storage_scheme: MerchantStorageScheme,
+        customer_id: &str,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await?
+        .ok_or(
+            errors::StorageError::ValueNotFound(format!(
+                "No customer available for customer_id = {customer_id} and merchant_id = {merchant_id}"
+            ))
+            .into(),
+        )
     }
 
     #[allow(clippy::panic)]
@@ -1436,6 +1436,25 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        status: common_enums::PaymentMethodStatus,
+        payment_methods: Vec<common_enums::PaymentMethod>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+                customer_id,
+                merchant_id,
+                status,
+                payment_methods,
+                limit,
+            )
+            .await
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -1582,6 +1601,32 @@ impl PayoutAttemptInterface for KafkaStore {
         Ok(payout_attempt_new)
     }
 
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payout_attempts_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await
+    }
+
+    async fn find_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payout_attempts_by_merchant_id_payout_attempt_ids(
+                merchant_id,
+                payout_attempt_ids,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn get_filters_for_payouts(
         &self,
         payouts: &[data_models::payouts::payouts::Payouts],
@@ -1638,6 +1683,41 @@ impl PayoutsInterface for KafkaStore {
         Ok(payout)
     }
 
+    async fn update_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        payout_update: storage::PayoutsUpdate,
+        payout_attempt: &storage::PayoutAttempt,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::Payouts, errors::DataStorageError> {
+        let original_payout = self
+            .diesel_store
+            .find_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await?;
+        let payout = self
+            .diesel_store
+            .update_payout_by_merchant_id_payout_id(
+                merchant_id,
+                payout_id,
+                payout_update,
+                payout_attempt,
+                storage_scheme,
+            )
+            .await?;
+        if let Err(err) = self
+            .kafka_producer
+            .log_payout(
+                &KafkaPayout::from_storage(&payout, payout_attempt),
+                Some(KafkaPayout::from_storage(&original_payout, payout_attempt)),
+            )
+            .await
+        {
+            logger::error!(message="Failed to update analytics entry for Payouts {payout:?}\n{payout_attempt:?}", error_message=?err);
+        };
+        Ok(payout)
+    }
+
     async fn insert_payout(
         &self,
         payout: storage::PayoutsNew,
@@ -1659,6 +1739,36 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn find_payouts_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payouts_by_merchant_id_source_payment_id(
+                merchant_id,
+                source_payment_id,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn get_total_payout_amount_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::DataStorageError> {
+        self.diesel_store
+            .get_total_payout_amount_by_merchant_id_source_payment_id(
+                merchant_id,
+                source_payment_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -1701,6 +1811,26 @@ impl PayoutsInterface for KafkaStore {
             .filter_payouts_by_time_range_constraints(merchant_id, time_range, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_time_range_after_cursor(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+        cursor: Option<&data_models::payouts::PayoutsCursor>,
+        limit: u32,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+        self.diesel_store
+            .filter_payouts_by_time_range_after_cursor(
+                merchant_id,
+                time_range,
+                cursor,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
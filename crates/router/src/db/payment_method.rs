@@ -37,6 +37,15 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        status: common_enums::PaymentMethodStatus,
+        payment_methods: Vec<common_enums::PaymentMethod>,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -408,6 +417,28 @@ mod storage {
             }
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            status: common_enums::PaymentMethodStatus,
+            payment_methods: Vec<common_enums::PaymentMethod>,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_customer_id_merchant_id_status_payment_methods(
+                &conn,
+                customer_id,
+                merchant_id,
+                status,
+                payment_methods,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &str,
@@ -547,6 +578,28 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            status: common_enums::PaymentMethodStatus,
+            payment_methods: Vec<common_enums::PaymentMethod>,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_customer_id_merchant_id_status_payment_methods(
+                &conn,
+                customer_id,
+                merchant_id,
+                status,
+                payment_methods,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &str,
@@ -719,6 +772,31 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_status_payment_methods(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        status: common_enums::PaymentMethodStatus,
+        payment_methods: Vec<common_enums::PaymentMethod>,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods_store = self.payment_methods.lock().await;
+        let payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods_store
+            .iter()
+            .filter(|pm| {
+                pm.customer_id == customer_id
+                    && pm.merchant_id == merchant_id
+                    && pm.status == status
+                    && pm
+                        .payment_method
+                        .is_some_and(|payment_method| payment_methods.contains(&payment_method))
+            })
+            .cloned()
+            .collect();
+
+        Ok(payment_methods_found)
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -0,0 +1,125 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutAuditEventInterface {
+    async fn insert_payout_audit_event(
+        &self,
+        payout_audit_event: storage::PayoutAuditEventNew,
+    ) -> CustomResult<storage::PayoutAuditEvent, errors::StorageError>;
+
+    async fn list_payout_audit_events_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutAuditEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutAuditEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_audit_event(
+        &self,
+        payout_audit_event: storage::PayoutAuditEventNew,
+    ) -> CustomResult<storage::PayoutAuditEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_audit_event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_audit_events_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutAuditEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutAuditEvent::list_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutAuditEventInterface for MockDb {
+    async fn insert_payout_audit_event(
+        &self,
+        payout_audit_event: storage::PayoutAuditEventNew,
+    ) -> CustomResult<storage::PayoutAuditEvent, errors::StorageError> {
+        let mut payout_audit_events = self.payout_audit_events.lock().await;
+        if payout_audit_events
+            .iter()
+            .any(|event| event.event_id == payout_audit_event.event_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "event_id",
+                key: Some(payout_audit_event.event_id.clone()),
+            })?
+        }
+        let payout_audit_event = storage::PayoutAuditEvent {
+            event_id: payout_audit_event.event_id,
+            merchant_id: payout_audit_event.merchant_id,
+            payout_id: payout_audit_event.payout_id,
+            event_type: payout_audit_event.event_type,
+            actor: payout_audit_event.actor,
+            previous_status: payout_audit_event.previous_status,
+            new_status: payout_audit_event.new_status,
+            reason: payout_audit_event.reason,
+            created_at: payout_audit_event.created_at,
+        };
+        payout_audit_events.push(payout_audit_event.clone());
+        Ok(payout_audit_event)
+    }
+
+    async fn list_payout_audit_events_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutAuditEvent>, errors::StorageError> {
+        let mut payout_audit_events: Vec<_> = self
+            .payout_audit_events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| event.merchant_id == merchant_id && event.payout_id == payout_id)
+            .cloned()
+            .collect();
+        payout_audit_events.sort_by_key(|event| event.created_at);
+        Ok(payout_audit_events)
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutAuditEventInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_audit_event(
+        &self,
+        payout_audit_event: storage::PayoutAuditEventNew,
+    ) -> CustomResult<storage::PayoutAuditEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_audit_event(payout_audit_event)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_audit_events_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutAuditEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_audit_events_by_merchant_id_payout_id(merchant_id, payout_id)
+            .await
+    }
+}
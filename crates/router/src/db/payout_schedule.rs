@@ -0,0 +1,197 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutScheduleInterface {
+    async fn insert_payout_schedule(
+        &self,
+        payout_schedule: storage::PayoutScheduleNew,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError>;
+
+    async fn find_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError>;
+
+    async fn update_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        payout_schedule_update: storage::PayoutScheduleUpdate,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutScheduleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_schedule(
+        &self,
+        payout_schedule: storage::PayoutScheduleNew,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_schedule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutSchedule::find_by_merchant_id_schedule_id(&conn, merchant_id, schedule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        payout_schedule_update: storage::PayoutScheduleUpdate,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutSchedule::update_by_merchant_id_schedule_id(
+            &conn,
+            merchant_id,
+            schedule_id,
+            payout_schedule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutScheduleInterface for MockDb {
+    async fn insert_payout_schedule(
+        &self,
+        payout_schedule: storage::PayoutScheduleNew,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        let mut payout_schedules = self.payout_schedules.lock().await;
+        if payout_schedules
+            .iter()
+            .any(|schedule| schedule.schedule_id == payout_schedule.schedule_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "schedule_id",
+                key: Some(payout_schedule.schedule_id.clone()),
+            })?
+        }
+        let payout_schedule = storage::PayoutSchedule {
+            schedule_id: payout_schedule.schedule_id,
+            merchant_id: payout_schedule.merchant_id,
+            customer_id: payout_schedule.customer_id,
+            payout_method_id: payout_schedule.payout_method_id,
+            profile_id: payout_schedule.profile_id,
+            currency: payout_schedule.currency,
+            payout_type: payout_schedule.payout_type,
+            cadence: payout_schedule.cadence,
+            amount_source: payout_schedule.amount_source,
+            description: payout_schedule.description,
+            status: payout_schedule.status,
+            next_run_at: payout_schedule.next_run_at,
+            last_run_at: None,
+            last_payout_id: None,
+            last_failure_reason: None,
+            created_at: payout_schedule.created_at,
+            last_modified_at: payout_schedule.last_modified_at,
+        };
+        payout_schedules.push(payout_schedule.clone());
+        Ok(payout_schedule)
+    }
+
+    async fn find_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        self.payout_schedules
+            .lock()
+            .await
+            .iter()
+            .find(|schedule| {
+                schedule.merchant_id == merchant_id && schedule.schedule_id == schedule_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout schedule available for merchant_id = {merchant_id} and schedule_id = {schedule_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        payout_schedule_update: storage::PayoutScheduleUpdate,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        let mut payout_schedules = self.payout_schedules.lock().await;
+        let payout_schedule = payout_schedules
+            .iter_mut()
+            .find(|schedule| {
+                schedule.merchant_id == merchant_id && schedule.schedule_id == schedule_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout schedule available for merchant_id = {merchant_id} and schedule_id = {schedule_id}"
+            )))?;
+        *payout_schedule = payout_schedule_update.apply_changeset(payout_schedule.clone());
+        Ok(payout_schedule.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutScheduleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_schedule(
+        &self,
+        payout_schedule: storage::PayoutScheduleNew,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_schedule(payout_schedule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        self.diesel_store
+            .find_payout_schedule_by_merchant_id_schedule_id(merchant_id, schedule_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        payout_schedule_update: storage::PayoutScheduleUpdate,
+    ) -> CustomResult<storage::PayoutSchedule, errors::StorageError> {
+        self.diesel_store
+            .update_payout_schedule_by_merchant_id_schedule_id(
+                merchant_id,
+                schedule_id,
+                payout_schedule_update,
+            )
+            .await
+    }
+}
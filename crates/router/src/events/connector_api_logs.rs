@@ -88,6 +88,11 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// Time taken by the connector to respond to the request, in milliseconds
+    pub fn get_latency(&self) -> u128 {
+        self.latency
+    }
 }
 
 impl KafkaMessage for ConnectorEvent {
@@ -433,6 +433,45 @@ pub async fn payment_connector_delete(
     )
     .await
 }
+/// Merchant Account - Update KYC Status
+///
+/// To update the KYC status of a merchant account, only with the admin API key
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/kyc",
+    request_body = MerchantKycStatusUpdate,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "KYC Status Updated", body = MerchantAccountResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the KYC Status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountUpdate))]
+pub async fn update_merchant_kyc_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::MerchantKycStatusUpdate>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| merchant_account_kyc_status_update(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - Toggle KV
 ///
 /// Toggle KV mode for the Merchant Account
@@ -48,6 +48,7 @@ use crate::routes::fraud_check as frm_routes;
 use crate::routes::recon as recon_routes;
 #[cfg(feature = "olap")]
 use crate::routes::verify_connector::payment_connector_verify;
+use crate::services::authentication::AuthenticationType;
 pub use crate::{
     configs::settings,
     core::routing,
@@ -60,6 +61,8 @@ pub use crate::{
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// How the request was authenticated, absent until the request is authenticated
+    pub auth_type: Option<AuthenticationType>,
 }
 
 #[derive(Clone)]
@@ -248,6 +251,7 @@ impl AppState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
         }
     }
 }
@@ -287,6 +291,19 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(web::resource("/payout").route(web::post().to(dummy_connector_payout)))
+            .service(
+                web::resource("/payouts/{payout_id}")
+                    .route(web::get().to(dummy_connector_payout_data)),
+            )
+            .service(
+                web::resource("/payouts/{payout_id}/fulfill")
+                    .route(web::post().to(dummy_connector_payout_fulfill)),
+            )
+            .service(
+                web::resource("/payouts/{payout_id}/cancel")
+                    .route(web::post().to(dummy_connector_payout_cancel)),
             );
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
@@ -319,6 +336,7 @@ impl Payments {
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/filter_v2").route(web::get().to(get_payment_filters)))
+                .service(web::resource("/reconcile").route(web::post().to(payments_reconcile)))
         }
         #[cfg(feature = "oltp")]
         {
@@ -687,6 +705,19 @@ impl Customers {
                 );
         }
 
+        #[cfg(all(feature = "oltp", feature = "payouts"))]
+        {
+            route = route
+                .service(
+                    web::resource("/{customer_id}/payout_methods")
+                        .route(web::get().to(payouts_list_saved_payout_methods)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payout_methods/{payout_method_id}")
+                        .route(web::delete().to(payouts_delete_saved_payout_method)),
+                );
+        }
+
         route
     }
 }
@@ -726,7 +757,12 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/validate").route(web::post().to(payouts_validate)))
+            .service(
+                web::resource("/required_fields").route(web::get().to(payouts_required_fields)),
+            );
 
         #[cfg(feature = "olap")]
         {
@@ -738,16 +774,30 @@ impl Payouts {
                 )
                 .service(
                     web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
-                );
+                )
+                .service(web::resource("/export").route(web::get().to(payouts_export)));
         }
         route = route
+            .service(web::resource("/schedules").route(web::post().to(payout_schedules_create)))
+            .service(
+                web::resource("/schedules/{schedule_id}")
+                    .route(web::get().to(payout_schedules_retrieve)),
+            )
+            .service(
+                web::resource("/schedules/{schedule_id}/cancel")
+                    .route(web::post().to(payout_schedules_cancel)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
-                    .route(web::put().to(payouts_update)),
+                    .route(web::put().to(payouts_update))
+                    .route(web::post().to(payouts_update_details)),
             )
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
-            .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)));
+            .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)))
+            .service(
+                web::resource("/{payout_id}/events").route(web::get().to(payouts_list_events)),
+            );
         route
     }
 }
@@ -851,6 +901,7 @@ impl MerchantAccount {
                     .route(web::post().to(merchant_account_toggle_kv))
                     .route(web::get().to(merchant_account_kv_status)),
             )
+            .service(web::resource("/{id}/kyc").route(web::post().to(update_merchant_kyc_status)))
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -137,3 +137,82 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCreate))]
+pub async fn dummy_connector_payout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPayoutRequest>,
+) -> impl actix_web::Responder {
+    let payload = json_payload.into_inner();
+    let flow = types::Flow::DummyPayoutCreate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| core::payout(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutRetrieve))]
+pub async fn dummy_connector_payout_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutRetrieve;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutRetrieveRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| core::payout_data(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutFulfill))]
+pub async fn dummy_connector_payout_fulfill(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutFulfill;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutFulfillRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| core::payout_fulfill(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCancel))]
+pub async fn dummy_connector_payout_cancel(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutCancel;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutCancelRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| core::payout_cancel(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
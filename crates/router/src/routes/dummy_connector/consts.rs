@@ -1,4 +1,9 @@
 pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const PAYOUT_ID_PREFIX: &str = "dummy_payout";
+pub const PAYOUT_REDIS_KEY_PREFIX: &str = "dummy_connector_payout";
+pub const PAYOUT_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+pub const PAYOUT_PENDING_IBAN: &str = "DE89370400440532013000";
+pub const PAYOUT_DECLINED_AMOUNT_SUFFIX: i64 = 99;
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
@@ -221,3 +221,83 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+pub async fn payout(
+    state: AppState,
+    req: types::DummyConnectorPayoutRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_duration,
+        state.conf.dummy_connector.payout_tolerance,
+    )
+    .await;
+
+    let fulfill = req.fulfill;
+    let mut payout_data = types::DummyConnectorPayoutData::try_from(req)?;
+    // Instant payouts are created and fulfilled in a single call
+    if fulfill {
+        payout_data = payout_data.fulfill()?;
+    }
+
+    utils::store_payout_data_in_redis(&state, &payout_data).await?;
+    if fulfill {
+        utils::trigger_payout_webhook(&state, &payout_data);
+    }
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
+
+pub async fn payout_data(
+    state: AppState,
+    req: types::DummyConnectorPayoutRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_duration,
+        state.conf.dummy_connector.payout_tolerance,
+    )
+    .await;
+
+    let payout_data = utils::get_payout_data_from_payout_id(&state, req.payout_id).await?;
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
+
+pub async fn payout_fulfill(
+    state: AppState,
+    req: types::DummyConnectorPayoutFulfillRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_duration,
+        state.conf.dummy_connector.payout_tolerance,
+    )
+    .await;
+
+    let stored_payout_data = utils::get_payout_data_from_payout_id(&state, req.payout_id).await?;
+    let previous_status = stored_payout_data.status.clone();
+    let payout_data = stored_payout_data.fulfill()?;
+
+    utils::store_payout_data_in_redis(&state, &payout_data).await?;
+    if payout_data.status != previous_status {
+        utils::trigger_payout_webhook(&state, &payout_data);
+    }
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
+
+pub async fn payout_cancel(
+    state: AppState,
+    req: types::DummyConnectorPayoutCancelRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payout_duration,
+        state.conf.dummy_connector.payout_tolerance,
+    )
+    .await;
+
+    let stored_payout_data = utils::get_payout_data_from_payout_id(&state, req.payout_id).await?;
+    let previous_status = stored_payout_data.status.clone();
+    let payout_data = stored_payout_data.cancel()?;
+
+    utils::store_payout_data_in_redis(&state, &payout_data).await?;
+    if payout_data.status != previous_status {
+        utils::trigger_payout_webhook(&state, &payout_data);
+    }
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
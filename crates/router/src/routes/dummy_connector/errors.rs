@@ -37,6 +37,15 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_09", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "Payout declined: {message}")]
+    PayoutDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_11", message = "Payout cannot be {action} in its current status")]
+    PayoutActionNotAllowed { action: &'static str },
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +92,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::PayoutDeclined { message: _ } => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::PayoutActionNotAllowed { action: _ } => {
+                AER::BadRequest(ApiError::new("DC", 11, self.error_message(), None))
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
-use api_models::enums::Currency;
+use api_models::enums::{Currency, PayoutType};
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use router_env::types::FlowMetric;
 use strum::Display;
 use time::PrimitiveDateTime;
@@ -18,6 +18,10 @@ pub enum Flow {
     DummyPaymentComplete,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyPayoutCreate,
+    DummyPayoutRetrieve,
+    DummyPayoutFulfill,
+    DummyPayoutCancel,
 }
 
 impl FlowMetric for Flow {}
@@ -372,6 +376,168 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(
+    Default, serde::Serialize, serde::Deserialize, strum::Display, Clone, PartialEq, Debug, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutStatus {
+    #[default]
+    Created,
+    Pending,
+    Succeeded,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub payout_type: PayoutType,
+    pub iban: Option<Secret<String>>,
+    pub connector: DummyConnectors,
+    #[serde(default)]
+    pub fulfill: bool,
+    /// Caller's own id for the payout, echoed back in its webhooks
+    pub reference: Option<String>,
+    /// Endpoint notified whenever the payout changes status
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutData {
+    pub payout_id: String,
+    pub status: DummyConnectorPayoutStatus,
+    /// Status the payout moves to once it is fulfilled
+    pub fulfillment_status: DummyConnectorPayoutStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub payout_type: PayoutType,
+    pub connector: DummyConnectors,
+    pub reference: Option<String>,
+    pub webhook_url: Option<String>,
+}
+
+impl TryFrom<DummyConnectorPayoutRequest> for DummyConnectorPayoutData {
+    type Error = error_stack::Report<DummyConnectorErrors>;
+    fn try_from(payout_request: DummyConnectorPayoutRequest) -> Result<Self, Self::Error> {
+        if payout_request.amount % 100 == consts::PAYOUT_DECLINED_AMOUNT_SUFFIX {
+            return Err(report!(DummyConnectorErrors::PayoutDeclined {
+                message: "Insufficient balance"
+            })
+            .attach_printable("Payouts with an amount ending in 99 are declined"));
+        }
+        let fulfillment_status = match payout_request.iban {
+            Some(iban) if iban.peek() == consts::PAYOUT_PENDING_IBAN => {
+                DummyConnectorPayoutStatus::Pending
+            }
+            _ => DummyConnectorPayoutStatus::Succeeded,
+        };
+        Ok(Self {
+            payout_id: generate_id_with_default_len(consts::PAYOUT_ID_PREFIX),
+            status: DummyConnectorPayoutStatus::Created,
+            fulfillment_status,
+            amount: payout_request.amount,
+            currency: payout_request.currency,
+            created: common_utils::date_time::now(),
+            payout_type: payout_request.payout_type,
+            connector: payout_request.connector,
+            reference: payout_request.reference,
+            webhook_url: payout_request.webhook_url,
+        })
+    }
+}
+
+impl DummyConnectorPayoutData {
+    pub fn fulfill(self) -> DummyConnectorResult<Self> {
+        match self.status {
+            DummyConnectorPayoutStatus::Created => Ok(Self {
+                status: self.fulfillment_status.clone(),
+                ..self
+            }),
+            DummyConnectorPayoutStatus::Pending | DummyConnectorPayoutStatus::Succeeded => Ok(self),
+            DummyConnectorPayoutStatus::Cancelled => {
+                Err(report!(DummyConnectorErrors::PayoutActionNotAllowed {
+                    action: "fulfilled"
+                })
+                .attach_printable("Cancelled payouts cannot be fulfilled"))
+            }
+        }
+    }
+
+    pub fn cancel(self) -> DummyConnectorResult<Self> {
+        match self.status {
+            DummyConnectorPayoutStatus::Created | DummyConnectorPayoutStatus::Cancelled => {
+                Ok(Self {
+                    status: DummyConnectorPayoutStatus::Cancelled,
+                    ..self
+                })
+            }
+            DummyConnectorPayoutStatus::Pending | DummyConnectorPayoutStatus::Succeeded => {
+                Err(report!(DummyConnectorErrors::PayoutActionNotAllowed {
+                    action: "cancelled"
+                })
+                .attach_printable("Fulfilled payouts cannot be cancelled"))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutResponse {
+    pub status: DummyConnectorPayoutStatus,
+    pub id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub payout_type: PayoutType,
+}
+
+impl From<DummyConnectorPayoutData> for DummyConnectorPayoutResponse {
+    fn from(value: DummyConnectorPayoutData) -> Self {
+        Self {
+            status: value.status,
+            id: value.payout_id,
+            amount: value.amount,
+            currency: value.currency,
+            created: value.created,
+            payout_type: value.payout_type,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutWebhook {
+    pub reference: Option<String>,
+    pub payout: DummyConnectorPayoutResponse,
+}
+
+impl From<DummyConnectorPayoutData> for DummyConnectorPayoutWebhook {
+    fn from(value: DummyConnectorPayoutData) -> Self {
+        Self {
+            reference: value.reference.clone(),
+            payout: value.into(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRetrieveRequest {
+    pub payout_id: String,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutFulfillRequest {
+    pub payout_id: String,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutCancelRequest {
+    pub payout_id: String,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -1,17 +1,18 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{ext_traits::AsyncExt, request::RequestContent};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
 use rand::{distributions::Uniform, prelude::Distribution};
+use router_env::{logger, tracing::Instrument};
 use tokio::time as tokio;
 
 use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::AppState};
+use crate::{configs::settings, routes::AppState, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -64,6 +65,82 @@ pub async fn get_payment_data_from_payment_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn get_payout_data_from_payout_id(
+    state: &AppState,
+    payout_id: String,
+) -> types::DummyConnectorResult<types::DummyConnectorPayoutData> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorPayoutData>(
+            get_payout_redis_key(&payout_id).as_str(),
+            "DummyConnectorPayoutData",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::PayoutNotFound)
+}
+
+/// Payouts are stored under their own namespace, so that payout ids never collide with the
+/// payment, attempt and refund ids kept in the same redis
+pub fn get_payout_redis_key(payout_id: &str) -> String {
+    format!("{}_{}", consts::PAYOUT_REDIS_KEY_PREFIX, payout_id)
+}
+
+pub async fn store_payout_data_in_redis(
+    state: &AppState,
+    payout_data: &types::DummyConnectorPayoutData,
+) -> types::DummyConnectorResult<()> {
+    store_data_in_redis(
+        state,
+        get_payout_redis_key(&payout_data.payout_id),
+        payout_data,
+        state.conf.dummy_connector.payout_ttl,
+    )
+    .await
+}
+
+/// Notifies the payout's webhook url of its current status in the background, delivery failures
+/// are only logged as the status can always be synced
+pub fn trigger_payout_webhook(state: &AppState, payout_data: &types::DummyConnectorPayoutData) {
+    let Some(webhook_url) = payout_data.webhook_url.clone() else {
+        return;
+    };
+    let webhook = types::DummyConnectorPayoutWebhook::from(payout_data.clone());
+    let state = state.clone();
+    ::tokio::spawn(
+        async move {
+            let request = services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&webhook_url)
+                .attach_default_headers()
+                .set_body(RequestContent::Json(Box::new(webhook)))
+                .build();
+            let response = state
+                .api_client
+                .send_request(
+                    &state,
+                    request,
+                    Some(consts::PAYOUT_WEBHOOK_TIMEOUT_SECS),
+                    false,
+                )
+                .await;
+            match response {
+                Ok(response) if response.status().is_success() => {}
+                Ok(response) => logger::error!(
+                    status=?response.status(),
+                    "Payout webhook was rejected by the receiver"
+                ),
+                Err(error) => logger::error!(?error, "Failed to deliver the payout webhook"),
+            }
+        }
+        .in_current_span(),
+    );
+}
+
 pub async fn get_payment_data_by_attempt_id(
     state: &AppState,
     attempt_id: String,
@@ -117,18 +117,28 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsStart
             | Flow::PaymentsList
             | Flow::PaymentsFilters
+            | Flow::PaymentsReconcile
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize => Self::Payments,
 
             Flow::PayoutsCreate
+            | Flow::PayoutsValidate
             | Flow::PayoutsRetrieve
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsEvents
+            | Flow::PayoutScheduleCreate
+            | Flow::PayoutScheduleRetrieve
+            | Flow::PayoutScheduleCancel
             | Flow::PayoutsList
             | Flow::PayoutsFilter
+            | Flow::PayoutsExport
+            | Flow::PayoutRequiredFields
+            | Flow::PayoutMethodsList
+            | Flow::PayoutMethodsDelete
             | Flow::PayoutsAccounts => Self::Payouts,
 
             Flow::RefundsCreate
@@ -103,6 +103,7 @@ counter_metric!(APPLE_PAY_SIMPLIFIED_FLOW_FAILED_PAYMENT, GLOBAL_METER);
 
 // Metrics for Payment Auto Retries
 counter_metric!(AUTO_RETRY_CONNECTION_CLOSED, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_CONNECTOR_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MISS_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
@@ -250,6 +250,7 @@ pub async fn payments_retrieve(
         force_sync: json_payload.force_sync.unwrap_or(false),
         client_secret: json_payload.client_secret.clone(),
         expand_attempts: json_payload.expand_attempts,
+        expand_payouts: json_payload.expand_payouts,
         expand_captures: json_payload.expand_captures,
         ..Default::default()
     };
@@ -974,6 +975,33 @@ pub async fn get_payment_filters(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsReconcile))]
+#[cfg(feature = "olap")]
+pub async fn payments_reconcile(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsReconcileRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsReconcile;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::reconcile_payments(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 #[cfg(feature = "oltp")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove, payment_id))]
 // #[post("/{payment_id}/approve")]
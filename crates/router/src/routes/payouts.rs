@@ -2,15 +2,19 @@ use actix_web::{
     body::{BoxBody, MessageBody},
     web, HttpRequest, HttpResponse, Responder,
 };
-use router_env::{instrument, tracing, Flow};
+use router_env::{instrument, tracing, types, Flow};
 
 use super::app::AppState;
 #[cfg(feature = "olap")]
 use crate::types::api::payments as payment_types;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{
+        api_locking::{self, GetLockingInput},
+        payouts::*,
+    },
+    routes::lock_utils,
     services::{api, authentication as auth, authorization::permissions::Permission},
-    types::api::payouts as payout_types,
+    types::api::{customers, payouts as payout_types},
 };
 
 /// Payouts - Create
@@ -33,19 +37,101 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let payload = json_payload.into_inner();
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            payouts_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+/// Payouts - Validate
+#[utoipa::path(
+    post,
+    path = "/payouts/validate",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout create request validated", body = PayoutValidateResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "Validate a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsValidate))]
+pub async fn payouts_validate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsValidate;
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
         |state, auth, req, _| {
-            payouts_create_core(state, auth.merchant_account, auth.key_store, req)
+            payouts_validate_core(state, auth.merchant_account, auth.key_store, req)
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
+
+/// Payouts - Required fields
+#[utoipa::path(
+    get,
+    path = "/payouts/required_fields",
+    params(
+        ("connector" = PayoutConnectors, Query, description = "The payout connector the payout would be made through"),
+        ("payout_type" = PayoutType, Query, description = "The type of the payout method"),
+        ("country" = Option<CountryAlpha2>, Query, description = "The country of the payout method"),
+        ("currency" = Option<Currency>, Query, description = "The currency of the payout")
+    ),
+    responses(
+        (status = 200, description = "Required fields of the payout methods retrieved", body = PayoutRequiredFieldsResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve the required fields of payout methods",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutRequiredFields))]
+pub async fn payouts_required_fields(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<payout_types::PayoutRequiredFieldsRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutRequiredFields;
+    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, _, req, _| payouts_required_fields_core(state, req),
+        &*auth_type,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - Retrieve
 #[utoipa::path(
     get,
@@ -71,6 +157,7 @@ pub async fn payouts_retrieve(
     let payout_retrieve_request = payout_types::PayoutRetrieveRequest {
         payout_id: path.into_inner(),
         force_sync: query_params.force_sync,
+        expand: query_params.expand,
     };
     let flow = Flow::PayoutsRetrieve;
     Box::pin(api::server_wrap(
@@ -78,8 +165,14 @@ pub async fn payouts_retrieve(
         state,
         &req,
         payout_retrieve_request,
-        |state, auth, req, _| {
-            payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
+        |state, auth, req, req_state| {
+            payouts_retrieve_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
@@ -92,7 +185,7 @@ pub async fn payouts_retrieve(
 }
 /// Payouts - Update
 #[utoipa::path(
-    post,
+    put,
     path = "/payouts/{payout_id}",
     params(
         ("payout_id" = String, Path, description = "The identifier for payout]")
@@ -117,13 +210,66 @@ pub async fn payouts_update(
     let payout_id = path.into_inner();
     let mut payout_update_payload = json_payload.into_inner();
     payout_update_payload.payout_id = Some(payout_id);
+    let locking_action = payout_update_payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payout_update_payload,
-        |state, auth, req, _| {
-            payouts_update_core(state, auth.merchant_account, auth.key_store, req)
+        |state, auth, req, req_state| {
+            payouts_update_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+/// Payouts - Update Details
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout details updated", body = PayoutCreateResponse),
+        (status = 400, description = "Payout or the requested field cannot be updated"),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Update Payout Details",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
+pub async fn payouts_update_details(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payout_types::PayoutUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsUpdate;
+    let mut payout_update_payload = json_payload.into_inner();
+    payout_update_payload.payout_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payout_update_payload,
+        |state, auth, req, req_state| {
+            payouts_update_details_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -162,8 +308,14 @@ pub async fn payouts_cancel(
         state,
         &req,
         payload,
-        |state, auth, req, _| {
-            payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
+        |state, auth, req, req_state| {
+            payouts_cancel_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -196,14 +348,194 @@ pub async fn payouts_fulfill(
     let flow = Flow::PayoutsFulfill;
     let mut payload = json_payload.into_inner();
     payload.payout_id = path.into_inner();
+    let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
+        |state, auth, req, req_state| {
+            payouts_fulfill_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                audit::get_request_actor(&req_state),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// Payouts - List events
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}/events",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout events retrieved", body = PayoutEventsResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List events of a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsEvents))]
+pub async fn payouts_list_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsEvents;
+    let payload = payout_types::PayoutEventsRequest {
+        payout_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| payouts_list_events_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Schedules - Create
+#[utoipa::path(
+    post,
+    path = "/payouts/schedules",
+    request_body=PayoutScheduleCreateRequest,
+    responses(
+        (status = 200, description = "Payout schedule created", body = PayoutScheduleResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutScheduleCreate))]
+pub async fn payout_schedules_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutScheduleCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutScheduleCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
         |state, auth, req, _| {
-            payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req)
+            schedules::payout_schedule_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Schedules - Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/schedules/{schedule_id}",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for payout schedule")
+    ),
+    responses(
+        (status = 200, description = "Payout schedule retrieved", body = PayoutScheduleResponse),
+        (status = 404, description = "Payout schedule does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutScheduleRetrieve))]
+pub async fn payout_schedules_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutScheduleRetrieve;
+    let payload = payout_types::PayoutScheduleRetrieveRequest {
+        schedule_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            schedules::payout_schedule_retrieve_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Schedules - Cancel
+#[utoipa::path(
+    post,
+    path = "/payouts/schedules/{schedule_id}/cancel",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for payout schedule")
+    ),
+    responses(
+        (status = 200, description = "Payout schedule cancelled", body = PayoutScheduleResponse),
+        (status = 400, description = "Payout schedule is already cancelled"),
+        (status = 404, description = "Payout schedule does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutScheduleCancel))]
+pub async fn payout_schedules_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutScheduleCancel;
+    let payload = payout_types::PayoutScheduleRetrieveRequest {
+        schedule_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            schedules::payout_schedule_cancel_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -329,6 +661,133 @@ pub async fn payouts_list_available_filters(
     .await
 }
 
+/// Payouts - Export
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    get,
+    path = "/payouts/export",
+    params(
+        ("start_time" = PrimitiveDateTime, Query, description = "Export payouts created at or after this time"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "Export payouts created at or before this time, defaults to now")
+    ),
+    responses(
+        (status = 200, description = "Payouts exported as CSV", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid time range")
+    ),
+    tag = "Payouts",
+    operation_id = "Export payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsExport))]
+pub async fn payouts_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<payment_types::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsExport;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| payouts_export_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - List saved payout methods for a Customer
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/payout_methods",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer")
+    ),
+    responses(
+        (status = 200, description = "Saved payout methods retrieved", body = CustomerPayoutMethodsListResponse),
+        (status = 404, description = "Customer does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List saved payout methods for a Customer",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutMethodsList))]
+pub async fn payouts_list_saved_payout_methods(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutMethodsList;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payouts_list_saved_payout_methods_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req.customer_id,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Delete a saved payout method of a Customer
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/payout_methods/{payout_method_id}",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the customer"),
+        ("payout_method_id" = String, Path, description = "The unique identifier for the saved payout method")
+    ),
+    responses(
+        (status = 200, description = "Saved payout method deleted", body = PayoutMethodDeleteResponse),
+        (status = 404, description = "Payout method does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Delete a saved payout method",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutMethodsDelete))]
+pub async fn payouts_delete_saved_payout_method(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::PayoutMethodsDelete;
+    let (customer_id, payout_method_id) = path.into_inner();
+    let payload = payout_types::CustomerPayoutMethodId {
+        customer_id,
+        payout_method_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payouts_delete_saved_payout_method_core(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -339,3 +798,38 @@ pub async fn payouts_accounts() -> impl Responder {
 fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
     HttpResponse::Ok().body(response)
 }
+
+impl GetLockingInput for payout_types::PayoutCreateRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        match self.payout_id {
+            Some(ref id) => api_locking::LockAction::Hold {
+                input: api_locking::LockingInput {
+                    unique_locking_key: id.to_owned(),
+                    api_identifier: lock_utils::ApiIdentifier::from(flow),
+                    override_lock_retries: None,
+                },
+            },
+            None => api_locking::LockAction::NotApplicable,
+        }
+    }
+}
+
+impl GetLockingInput for payout_types::PayoutActionRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payout_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
@@ -16,13 +16,14 @@ use actix_web::{
 use api_models::enums::{CaptureMethod, PaymentMethodType};
 pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
 use common_enums::Currency;
-pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
+pub use common_utils::request::{ContentType, Method, Request, RequestBuilder, RetryPolicy};
 use common_utils::{
     consts::X_HS_LATENCY,
     errors::{ErrorSwitch, ReportSwitchExt},
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
+use futures::{stream::BoxStream, StreamExt};
 use masking::{Maskable, PeekInterface, Secret};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId, Tag};
 use serde::Serialize;
@@ -105,6 +106,12 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    /// Features supported by the connector, used to validate the payment methods enabled on its
+    /// merchant connector accounts. Connectors that do not describe them accept any payment method.
+    fn get_connector_specifications(&self) -> Option<api::ConnectorSpecifications> {
+        None
+    }
 }
 
 #[async_trait::async_trait]
@@ -538,13 +545,111 @@ pub async fn call_connector_api(
     state: &AppState,
     request: Request,
     flow_name: &str,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    match get_retry_policy(&request) {
+        Some(retry_policy) => {
+            send_with_retries(retry_policy, &request.url, || {
+                send_connector_request(state, request.clone_without_body(), flow_name)
+            })
+            .await
+        }
+        None => send_connector_request(state, request, flow_name).await,
+    }
+}
+
+/// Retry policy of a request, requests that could have side effects on the connector are never
+/// retried
+fn get_retry_policy(request: &Request) -> Option<RetryPolicy> {
+    request
+        .retry_policy
+        .filter(|_| request.method == Method::Get && request.body.is_none())
+}
+
+/// Sends a request until it no longer fails with a transient error or the attempts of the retry
+/// policy are exhausted, backing off exponentially with jitter between the attempts
+async fn send_with_retries<F, Fut>(
+    retry_policy: RetryPolicy,
+    url: &str,
+    mut send: F,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<
+        Output = CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    >,
+{
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(ToString::to_string))
+        .unwrap_or_default();
+    let mut attempt = 1;
+    loop {
+        let response = send().await;
+        if !is_transient_failure(&response) {
+            return response;
+        }
+        if attempt >= retry_policy.max_attempts {
+            logger::warn!(url=?url, attempts=?attempt, "Retries of the connector request exhausted");
+            return response;
+        }
+
+        let delay = get_jittered_backoff_delay(&retry_policy, attempt);
+        logger::warn!(
+            url=?url,
+            attempt=?attempt,
+            delay=?delay,
+            "Retrying the connector request after a transient failure"
+        );
+        metrics::AUTO_RETRY_CONNECTOR_REQUEST_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                consts::METRICS_HOST_TAG_NAME,
+                host.clone(),
+            )],
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Failures of the connector after which the same request may succeed, like a bad gateway
+fn is_transient_failure(
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) -> bool {
+    match response {
+        Ok(Ok(_)) => false,
+        Ok(Err(response)) => matches!(response.status_code, 502..=504),
+        Err(error) => {
+            let error = error.current_context();
+            error.is_upstream_timeout()
+                || error.is_connection_closed_before_message_could_complete()
+        }
+    }
+}
+
+/// Exponential backoff delay of the retry with equal jitter, so that the retries of concurrent
+/// requests are spread out
+fn get_jittered_backoff_delay(retry_policy: &RetryPolicy, retry: u32) -> Duration {
+    use rand::Rng;
+
+    let delay_ms = retry_policy.get_backoff_delay_ms(retry);
+    let jitter_ms = rand::thread_rng().gen_range(0..=delay_ms / 2);
+    Duration::from_millis(delay_ms - delay_ms / 2 + jitter_ms)
+}
+
+async fn send_connector_request(
+    state: &AppState,
+    request: Request,
+    flow_name: &str,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
     let headers = request.headers.clone();
     let url = request.url.clone();
+    let timeout_secs = request.timeout_secs;
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, timeout_secs, true)
         .await;
 
     match response.as_ref() {
@@ -838,9 +943,29 @@ pub enum ApplicationResponse<R> {
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    FileDataStream((FileDataStream, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, Maskable<String>)>)),
 }
 
+/// File data produced a chunk at a time while it is being sent, so that large files are never
+/// held in memory as a whole
+pub struct FileDataStream(pub BoxStream<'static, errors::RouterResult<bytes::Bytes>>);
+
+impl Debug for FileDataStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FileDataStream")
+    }
+}
+
+// Streams can only be consumed once, so a stream is only equal to itself
+impl PartialEq for FileDataStream {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for FileDataStream {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum PaymentLinkAction {
     PaymentLinkFormData(PaymentLinkFormData),
@@ -996,6 +1121,7 @@ where
         .switch()?;
 
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -1179,6 +1305,9 @@ where
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileDataStream((file_data_stream, content_type))) => {
+            http_response_file_data_stream(file_data_stream, content_type)
+        }
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -1372,6 +1501,22 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+/// The response has been started by the time a chunk fails, so the failure can only be logged and
+/// the response cut short
+pub fn http_response_file_data_stream(
+    file_data_stream: FileDataStream,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .streaming(file_data_stream.0.map(|chunk| {
+            chunk.map_err(|error| {
+                logger::error!(?error, "Failed to produce file data chunk");
+                std::io::Error::new(std::io::ErrorKind::Other, "Failed to produce file data")
+            })
+        }))
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_HTML).body(res)
 }
@@ -2085,8 +2230,120 @@ pub fn get_payment_link_status(
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+    const RETRY_POLICY: RetryPolicy = RetryPolicy {
+        max_attempts: 3,
+        base_delay_ms: 1,
+        max_delay_ms: 10,
+    };
+
+    async fn send_to_mock_server(
+        url: &str,
+    ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+        let response = reqwest::get(url)
+            .await
+            .map_err(|error| report!(errors::ApiClientError::RequestNotSent(error.to_string())));
+        handle_response(response).await
+    }
+
+    async fn mount_sync_response(mock_server: &MockServer, status_code: u16, times: Option<u64>) {
+        let mock = Mock::given(method("GET"))
+            .and(path("/v2/card-transactions/20163745"))
+            .respond_with(
+                ResponseTemplate::new(status_code)
+                    .set_body_json(json!({ "transactionId": 20163745 })),
+            );
+        match times {
+            Some(times) => mock.up_to_n_times(times).mount(mock_server).await,
+            None => mock.mount(mock_server).await,
+        }
+    }
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[tokio::test]
+    async fn should_retry_sync_request_after_bad_gateway() {
+        let mock_server = MockServer::start().await;
+        mount_sync_response(&mock_server, 502, Some(1)).await;
+        mount_sync_response(&mock_server, 200, None).await;
+        let url = format!("{}/v2/card-transactions/20163745", mock_server.uri());
+
+        let response = send_with_retries(RETRY_POLICY, &url, || send_to_mock_server(&url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.unwrap().status_code, 200);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_stop_retrying_sync_request_after_max_attempts() {
+        let mock_server = MockServer::start().await;
+        mount_sync_response(&mock_server, 502, None).await;
+        let url = format!("{}/v2/card-transactions/20163745", mock_server.uri());
+
+        let response = send_with_retries(RETRY_POLICY, &url, || send_to_mock_server(&url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.unwrap_err().status_code, 502);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_client_errors() {
+        let mock_server = MockServer::start().await;
+        mount_sync_response(&mock_server, 404, None).await;
+        let url = format!("{}/v2/card-transactions/20163745", mock_server.uri());
+
+        let response = send_with_retries(RETRY_POLICY, &url, || send_to_mock_server(&url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.unwrap_err().status_code, 404);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn should_only_retry_requests_without_side_effects() {
+        let sync_request = RequestBuilder::new()
+            .method(Method::Get)
+            .url("https://api.helcim.com/v2/card-transactions/20163745")
+            .retry_policy(Some(RETRY_POLICY))
+            .build();
+        assert_eq!(get_retry_policy(&sync_request), Some(RETRY_POLICY));
+
+        let capture_request = RequestBuilder::new()
+            .method(Method::Post)
+            .url("https://api.helcim.com/v2/payment/capture")
+            .retry_policy(Some(RETRY_POLICY))
+            .set_body(RequestContent::Json(Box::new(json!({ "amount": 10.0 }))))
+            .build();
+        assert_eq!(get_retry_policy(&capture_request), None);
+    }
+
+    #[test]
+    fn should_back_off_exponentially_with_jitter() {
+        let retry_policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay_ms: 200,
+            max_delay_ms: 1000,
+        };
+        for (retry, delay_ms) in [(1, 200), (2, 400), (3, 800), (4, 1000)] {
+            assert_eq!(retry_policy.get_backoff_delay_ms(retry), delay_ms);
+            let delay = get_jittered_backoff_delay(&retry_policy, retry);
+            assert!(delay >= Duration::from_millis(delay_ms / 2));
+            assert!(delay <= Duration::from_millis(delay_ms));
+        }
+    }
 }
@@ -175,6 +175,9 @@ pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
 
 #[cfg(feature = "payouts")]
+pub type PayoutBalanceType =
+    dyn services::ConnectorIntegration<api::PoBalance, PayoutsData, PayoutsBalanceResponseData>;
+#[cfg(feature = "payouts")]
 pub type PayoutCancelType =
     dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
 #[cfg(feature = "payouts")]
@@ -192,6 +195,9 @@ pub type PayoutRecipientType =
 #[cfg(feature = "payouts")]
 pub type PayoutQuoteType =
     dyn services::ConnectorIntegration<api::PoQuote, PayoutsData, PayoutsResponseData>;
+#[cfg(feature = "payouts")]
+pub type PayoutSyncType =
+    dyn services::ConnectorIntegration<api::PoSync, PayoutsData, PayoutsResponseData>;
 
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -261,6 +267,10 @@ pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
 pub type PayoutsResponseRouterData<F, R> =
     ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
 
+#[cfg(feature = "payouts")]
+pub type PayoutsBalanceRouterData =
+    RouterData<api::PoBalance, PayoutsData, PayoutsBalanceResponseData>;
+
 #[derive(Debug, Clone)]
 pub struct RouterData<Flow, Request, Response> {
     pub flow: PhantomData<Flow>,
@@ -334,6 +344,10 @@ pub enum AdditionalPaymentMethodConnectorResponse {
         authentication_data: Option<serde_json::Value>,
         /// Various payment checks that are done for a payment
         payment_checks: Option<serde_json::Value>,
+        /// Card network of the card, as identified by the connector
+        card_network: Option<common_enums::CardNetwork>,
+        /// Card type, can be either `credit` or `debit`, as identified by the connector
+        card_type: Option<String>,
     },
 }
 
@@ -394,6 +408,8 @@ pub struct PayoutsData {
     pub payout_type: storage_enums::PayoutType,
     pub entity_type: storage_enums::PayoutEntityType,
     pub customer_details: Option<CustomerDetails>,
+    /// Endpoint the connector should notify about status changes of this payout
+    pub webhook_url: Option<String>,
 }
 
 #[cfg(feature = "payouts")]
@@ -404,6 +420,13 @@ pub struct PayoutsResponseData {
     pub payout_eligible: Option<bool>,
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, Default)]
+pub struct PayoutsBalanceResponseData {
+    /// Balance available in the connector account for the payout's source currency, in minor units
+    pub available_balance: Option<i64>,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct PayoutsFulfillResponseData {
     pub status: Option<storage_enums::PayoutStatus>,
@@ -566,15 +589,32 @@ pub struct PaymentsSyncData {
     pub mandate_id: Option<api_models::payments::MandateIds>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub currency: storage_enums::Currency,
+    /// Amount of the payment attempt, including the surcharge, for connectors to reconcile the
+    /// synced amount against
+    pub amount: i64,
+    /// Surcharge of the payment attempt, including the tax on it
+    pub surcharge_amount: Option<i64>,
 }
 
 #[derive(Debug, Default, Clone)]
 pub enum SyncRequestType {
     MultipleCaptureSync(Vec<String>),
+    /// Sync of all the transactions processed by the connector in the given date range,
+    /// used for bulk reconciliation
+    DateRangeSync(TransactionDateRange),
+    /// Sync of the transaction processed against the connector request reference id, used when
+    /// the connector transaction id is not known, e.g. after the authorize timed out
+    ReferenceSync,
     #[default]
     SinglePaymentSync,
 }
 
+#[derive(Debug, Clone)]
+pub struct TransactionDateRange {
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: time::PrimitiveDateTime,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PaymentsCancelData {
     pub amount: Option<i64>,
@@ -922,6 +962,10 @@ pub enum PaymentsResponseData {
         // pending_capture_id_list: Vec<String>,
         capture_sync_response_list: HashMap<String, CaptureSyncResponse>,
     },
+    TransactionListSyncResponse {
+        // connector transaction id -> status of the transaction
+        transaction_status_list: HashMap<String, storage_enums::AttemptStatus>,
+    },
     SessionResponse {
         session_token: api::SessionToken,
     },
@@ -1195,7 +1239,8 @@ pub struct ResponseRouterData<Flow, R, Request, Response> {
 
 #[derive(Debug, Clone)]
 pub struct MandateRevokeRequestData {
-    pub mandate_id: String,
+    /// Not present when the token of a payment method is revoked without a mandate
+    pub mandate_id: Option<String>,
     pub connector_mandate_id: Option<String>,
 }
 
@@ -47,7 +47,9 @@ use crate::{
         payments::types as payments_types,
     },
     events::connector_api_logs::ConnectorEvent,
-    services::{request, ConnectorIntegration, ConnectorRedirectResponse, ConnectorValidation},
+    services::{
+        self, request, ConnectorIntegration, ConnectorRedirectResponse, ConnectorValidation,
+    },
     types::{self, api::enums as api_enums},
 };
 
@@ -99,6 +101,147 @@ pub trait ConnectorTransactionId: ConnectorCommon + Sync {
     }
 }
 
+/// Features a connector supports
+#[derive(Clone, Debug)]
+pub struct ConnectorSpecifications {
+    pub supported_payment_methods: &'static [SupportedPaymentMethod],
+    pub supported_capture_methods: &'static [api_enums::CaptureMethod],
+    pub supported_countries: &'static [api_enums::CountryAlpha2],
+    pub supported_currencies: &'static [api_enums::Currency],
+    /// Smallest amount accepted by the connector, in the lowest denomination of the currency
+    pub minimum_amount: Option<i64>,
+    /// Largest amount accepted by the connector, in the lowest denomination of the currency
+    pub maximum_amount: Option<i64>,
+    /// Whether the connector authenticates 3DS payments itself
+    pub supports_three_ds: bool,
+    pub supports_refunds: bool,
+    pub supports_void: bool,
+    /// Whether the payment method tokens saved at the connector are revoked through the mandate
+    /// revoke flow when the payment method is deleted
+    pub supports_payment_method_token_revocation: bool,
+    /// Whether a payment can be looked up by the connector request reference id sent with it,
+    /// which recovers an authorize left without a response
+    pub supports_reference_sync: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SupportedPaymentMethod {
+    pub payment_method: api_enums::PaymentMethod,
+    pub payment_method_type: api_enums::PaymentMethodType,
+    /// Card networks supported for card payment method types
+    pub card_networks: &'static [api_enums::CardNetwork],
+    pub supports_mandates: bool,
+}
+
+impl ConnectorSpecifications {
+    /// Validates that every payment method enabled on a merchant connector account, along with
+    /// the card networks, currencies, countries, amounts and recurring payments it is enabled
+    /// for, is supported by the connector
+    pub fn validate_payment_methods_enabled(
+        &self,
+        connector: &str,
+        payment_methods_enabled: &[api_models::admin::PaymentMethodsEnabled],
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let invalid_request = |message: String| {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message
+            }))
+        };
+
+        for payment_method_enabled in payment_methods_enabled {
+            let payment_method = payment_method_enabled.payment_method;
+            let supported_payment_method_types = self
+                .supported_payment_methods
+                .iter()
+                .filter(|supported| supported.payment_method == payment_method)
+                .collect::<Vec<_>>();
+            if supported_payment_method_types.is_empty() {
+                return invalid_request(format!(
+                    "{connector} does not support the payment method {payment_method}"
+                ));
+            }
+
+            for payment_method_types in payment_method_enabled.payment_method_types.iter().flatten()
+            {
+                let payment_method_type = payment_method_types.payment_method_type;
+                let Some(supported) = supported_payment_method_types
+                    .iter()
+                    .find(|supported| supported.payment_method_type == payment_method_type)
+                else {
+                    return invalid_request(format!(
+                        "{connector} does not support the payment method type {payment_method_type} for the payment method {payment_method}"
+                    ));
+                };
+
+                if let Some(card_network) = payment_method_types
+                    .card_networks
+                    .iter()
+                    .flatten()
+                    .find(|card_network| !supported.card_networks.contains(card_network))
+                {
+                    return invalid_request(format!(
+                        "{connector} does not support the card network {card_network} for the payment method type {payment_method_type}"
+                    ));
+                }
+
+                if payment_method_types.recurring_enabled && !supported.supports_mandates {
+                    return invalid_request(format!(
+                        "{connector} does not support recurring payments for the payment method type {payment_method_type}"
+                    ));
+                }
+
+                if let Some(api_models::admin::AcceptedCurrencies::EnableOnly(currencies)) =
+                    &payment_method_types.accepted_currencies
+                {
+                    if let Some(currency) = currencies
+                        .iter()
+                        .find(|currency| !self.supported_currencies.contains(currency))
+                    {
+                        return invalid_request(format!(
+                            "{connector} does not support the currency {currency} for the payment method type {payment_method_type}"
+                        ));
+                    }
+                }
+
+                if let Some(api_models::admin::AcceptedCountries::EnableOnly(countries)) =
+                    &payment_method_types.accepted_countries
+                {
+                    if let Some(country) = countries
+                        .iter()
+                        .find(|country| !self.supported_countries.contains(country))
+                    {
+                        return invalid_request(format!(
+                            "{connector} does not support the country {country} for the payment method type {payment_method_type}"
+                        ));
+                    }
+                }
+
+                if let (Some(minimum_amount), Some(supported_minimum_amount)) =
+                    (payment_method_types.minimum_amount, self.minimum_amount)
+                {
+                    if i64::from(minimum_amount) < supported_minimum_amount {
+                        return invalid_request(format!(
+                            "minimum_amount {minimum_amount} for the payment method type {payment_method_type} is below the minimum amount {supported_minimum_amount} supported by {connector}"
+                        ));
+                    }
+                }
+
+                if let (Some(maximum_amount), Some(supported_maximum_amount)) =
+                    (payment_method_types.maximum_amount, self.maximum_amount)
+                {
+                    if i64::from(maximum_amount) > supported_maximum_amount {
+                        return invalid_request(format!(
+                            "maximum_amount {maximum_amount} for the payment method type {payment_method_type} is above the maximum amount {supported_maximum_amount} supported by {connector}"
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 pub enum CurrencyUnit {
     Base,
     Minor,
@@ -421,13 +564,33 @@ pub trait FraudCheck {}
 #[cfg(feature = "payouts")]
 pub trait Payouts:
     ConnectorCommon
+    + PayoutBalance
     + PayoutCancel
     + PayoutCreate
     + PayoutEligibility
     + PayoutFulfill
     + PayoutQuote
     + PayoutRecipient
+    + PayoutSync
 {
+    /// Builds a request which only reads data of the connector account, such as its balance, to
+    /// verify the credentials of a payout connector when its merchant connector account is created
+    /// or updated. Returns `None` for connectors which cannot verify their credentials.
+    fn build_payout_credentials_verification_request(
+        &self,
+        _connector_auth_type: &types::ConnectorAuthType,
+        _connector_meta_data: &Option<common_utils::pii::SecretSerdeValue>,
+        _test_mode: Option<bool>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// Whether the connector implements the payout sync flow. The flow is not called for
+    /// connectors which do not, as they have no status to report.
+    fn supports_payout_sync(&self) -> bool {
+        false
+    }
 }
 #[cfg(not(feature = "payouts"))]
 pub trait Payouts {}
@@ -3,7 +3,8 @@ pub use api_models::admin::{
     MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
     MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
     MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
-    MerchantId, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+    MerchantId, MerchantKycStatusUpdate, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse,
+    WebhookDetails,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
@@ -45,6 +46,7 @@ impl TryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             is_recon_enabled: item.is_recon_enabled,
             default_profile: item.default_profile,
             recon_status: item.recon_status,
+            kyc_status: item.kyc_status,
         })
     }
 }
@@ -79,6 +81,24 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                     authentication_connector_details.parse_value("AuthenticationDetails")
                 })
                 .transpose()?,
+            #[cfg(feature = "payouts")]
+            check_balance_before_fulfill: item.check_balance_before_fulfill,
+            #[cfg(feature = "payouts")]
+            payout_blocked_countries: item
+                .payout_blocked_countries
+                .map(|payout_blocked_countries| {
+                    payout_blocked_countries.parse_value("PayoutBlockedCountries")
+                })
+                .transpose()?,
+            #[cfg(feature = "payouts")]
+            require_recipient_kyc: item.require_recipient_kyc,
+            #[cfg(feature = "payouts")]
+            default_payout_method: item
+                .default_payout_method
+                .map(|default_payout_method| {
+                    default_payout_method.parse_value("DefaultPayoutMethod")
+                })
+                .transpose()?,
         })
     }
 }
@@ -176,6 +196,36 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                     field_name: "authentication_connector_details",
                 })?,
             is_extended_card_info_enabled: None,
+            #[cfg(feature = "payouts")]
+            check_balance_before_fulfill: request.check_balance_before_fulfill,
+            #[cfg(not(feature = "payouts"))]
+            check_balance_before_fulfill: None,
+            #[cfg(feature = "payouts")]
+            payout_blocked_countries: request
+                .payout_blocked_countries
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payout_blocked_countries",
+                })?,
+            #[cfg(not(feature = "payouts"))]
+            payout_blocked_countries: None,
+            #[cfg(feature = "payouts")]
+            require_recipient_kyc: request.require_recipient_kyc,
+            #[cfg(not(feature = "payouts"))]
+            require_recipient_kyc: None,
+            #[cfg(feature = "payouts")]
+            default_payout_method: request
+                .default_payout_method
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "default_payout_method",
+                })?,
+            #[cfg(not(feature = "payouts"))]
+            default_payout_method: None,
         })
     }
 }
@@ -33,6 +33,7 @@ impl From<(domain::Customer, Option<payments::AddressDetails>)> for CustomerResp
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            kyc_status: cust.kyc_status,
         }
         .into()
     }
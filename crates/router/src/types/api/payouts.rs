@@ -1,12 +1,23 @@
 pub use api_models::payouts::{
-    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout, PayoutActionRequest,
-    PayoutCreateRequest, PayoutCreateResponse, PayoutListConstraints, PayoutListFilterConstraints,
-    PayoutListFilters, PayoutListResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
-    PayoutRetrieveRequest, SepaBankTransfer, Wallet as WalletPayout,
+    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout,
+    CustomerPayoutMethod, CustomerPayoutMethodId, CustomerPayoutMethodsListResponse,
+    PayoutActionRequest, PayoutAttemptResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutEventActor, PayoutEventsRequest, PayoutEventsResponse, PayoutExpandableField,
+    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
+    PayoutMethodData, PayoutMethodDeleteResponse, PayoutMethodRequiredFields, PayoutRequest,
+    PayoutRequiredFieldInfo, PayoutRequiredFieldsRequest, PayoutRequiredFieldsResponse,
+    PayoutRetrieveBody, PayoutRetrieveRequest, PayoutScheduleAmountCallbackRequest,
+    PayoutScheduleAmountCallbackResponse, PayoutScheduleAmountSource, PayoutScheduleCadence,
+    PayoutScheduleCreateRequest, PayoutScheduleResponse, PayoutScheduleRetrieveRequest,
+    PayoutUpdateRequest, PayoutValidateResponse, PayoutValidationError, SepaBankTransfer,
+    Wallet as WalletPayout,
 };
 
 use crate::{services::api, types};
 
+#[derive(Debug, Clone)]
+pub struct PoBalance;
+
 #[derive(Debug, Clone)]
 pub struct PoCancel;
 
@@ -25,6 +36,14 @@ pub struct PoQuote;
 #[derive(Debug, Clone)]
 pub struct PoRecipient;
 
+#[derive(Debug, Clone)]
+pub struct PoSync;
+
+pub trait PayoutBalance:
+    api::ConnectorIntegration<PoBalance, types::PayoutsData, types::PayoutsBalanceResponseData>
+{
+}
+
 pub trait PayoutCancel:
     api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
 {
@@ -54,3 +73,10 @@ pub trait PayoutRecipient:
     api::ConnectorIntegration<PoRecipient, types::PayoutsData, types::PayoutsResponseData>
 {
 }
+
+/// Fetches the latest status of a payout created with the connector.
+/// Connectors implementing this flow declare it through `Payouts::supports_payout_sync`.
+pub trait PayoutSync:
+    api::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
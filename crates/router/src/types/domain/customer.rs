@@ -1,5 +1,7 @@
 use common_utils::{crypto, date_time, pii};
-use diesel_models::{customers::CustomerUpdateInternal, encryption::Encryption};
+use diesel_models::{
+    customers::CustomerUpdateInternal, encryption::Encryption, enums as storage_enums,
+};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
@@ -23,6 +25,7 @@ pub struct Customer {
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
+    pub kyc_status: Option<storage_enums::KycStatus>,
 }
 
 #[async_trait::async_trait]
@@ -47,6 +50,7 @@ impl super::behaviour::Conversion for Customer {
             connector_customer: self.connector_customer,
             address_id: self.address_id,
             default_payment_method_id: self.default_payment_method_id,
+            kyc_status: self.kyc_status,
         })
     }
 
@@ -75,6 +79,7 @@ impl super::behaviour::Conversion for Customer {
                 connector_customer: item.connector_customer,
                 address_id: item.address_id,
                 default_payment_method_id: item.default_payment_method_id,
+                kyc_status: item.kyc_status,
             })
         }
         .await
@@ -98,6 +103,7 @@ impl super::behaviour::Conversion for Customer {
             modified_at: now,
             connector_customer: self.connector_customer,
             address_id: self.address_id,
+            kyc_status: self.kyc_status,
         })
     }
 }
@@ -113,6 +119,7 @@ pub enum CustomerUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<serde_json::Value>,
         address_id: Option<String>,
+        kyc_status: Option<storage_enums::KycStatus>,
     },
     ConnectorCustomer {
         connector_customer: Option<serde_json::Value>,
@@ -134,6 +141,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 address_id,
+                kyc_status,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -144,6 +152,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer,
                 modified_at: Some(date_time::now()),
                 address_id,
+                kyc_status,
                 ..Default::default()
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
@@ -46,6 +46,7 @@ pub struct MerchantAccount {
     pub default_profile: Option<String>,
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
+    pub kyc_status: Option<diesel_models::enums::KycStatus>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -78,6 +79,9 @@ pub enum MerchantAccountUpdate {
     ReconUpdate {
         recon_status: diesel_models::enums::ReconStatus,
     },
+    KycStatusUpdate {
+        kyc_status: diesel_models::enums::KycStatus,
+    },
     UnsetDefaultProfile,
     ModifiedAtUpdate,
 }
@@ -137,6 +141,11 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status,
                 ..Default::default()
             },
+            MerchantAccountUpdate::KycStatusUpdate { kyc_status } => Self {
+                kyc_status: Some(kyc_status),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
                 ..Default::default()
@@ -184,6 +193,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            kyc_status: self.kyc_status,
         })
     }
 
@@ -229,6 +239,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 default_profile: item.default_profile,
                 recon_status: item.recon_status,
                 payment_link_config: item.payment_link_config,
+                kyc_status: item.kyc_status,
             })
         }
         .await
@@ -265,6 +276,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            kyc_status: self.kyc_status,
         })
     }
 }
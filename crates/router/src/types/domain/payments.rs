@@ -384,6 +384,9 @@ pub struct CardToken {
 
     /// The CVC number for the card
     pub card_cvc: Option<Secret<String>>,
+
+    /// The token issued by the connector for the card
+    pub connector_token: Option<Secret<String>>,
 }
 
 #[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Eq, PartialEq)]
@@ -905,10 +908,12 @@ impl From<api_models::payments::CardToken> for CardToken {
         let api_models::payments::CardToken {
             card_holder_name,
             card_cvc,
+            connector_token,
         } = value;
         Self {
             card_holder_name,
             card_cvc,
+            connector_token,
         }
     }
 }
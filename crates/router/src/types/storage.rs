@@ -29,6 +29,8 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_audit_event;
+pub mod payout_schedule;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
@@ -60,8 +62,8 @@ pub use self::{
     configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
     file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    payout_audit_event::*, payout_schedule::*, process_tracker::*, refund::*, reverse_lookup::*,
+    role::*, routing_algorithm::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
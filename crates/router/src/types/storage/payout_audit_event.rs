@@ -0,0 +1 @@
+pub use diesel_models::payout_audit_event::{PayoutAuditEvent, PayoutAuditEventNew};
@@ -0,0 +1,4 @@
+pub use diesel_models::payout_schedule::{
+    PayoutSchedule, PayoutScheduleNew, PayoutScheduleRunTrackingData, PayoutScheduleUpdate,
+    PayoutScheduleUpdateInternal,
+};
@@ -1 +1,3 @@
-pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+pub use diesel_models::payouts::{
+    PayoutScheduleTrackingData, Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal,
+};
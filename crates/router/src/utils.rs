@@ -358,6 +358,59 @@ pub async fn find_mca_from_authentication_id_type(
     })
 }
 
+#[cfg(feature = "payouts")]
+pub async fn find_mca_from_payout_id_type(
+    db: &dyn StorageInterface,
+    payout_id_type: webhooks::PayoutIdType,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_name: &str,
+) -> CustomResult<domain::MerchantConnectorAccount, errors::ApiErrorResponse> {
+    let payout = match payout_id_type {
+        webhooks::PayoutIdType::PayoutId(payout_id) => db
+            .find_payout_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?,
+    };
+    let payout_attempt = db
+        .find_payout_attempt_by_merchant_id_payout_attempt_id(
+            &merchant_account.merchant_id,
+            &get_payment_attempt_id(&payout.payout_id, payout.attempt_count),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+    match payout_attempt.merchant_connector_id {
+        Some(merchant_connector_id) => db
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &merchant_account.merchant_id,
+                &merchant_connector_id,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id,
+            }),
+        None => db
+            .find_merchant_connector_account_by_profile_id_connector_name(
+                &payout_attempt.profile_id,
+                connector_name,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: format!(
+                    "profile_id {} and connector_name {connector_name}",
+                    payout_attempt.profile_id
+                ),
+            }),
+    }
+}
+
 pub async fn get_mca_from_payment_intent(
     db: &dyn StorageInterface,
     merchant_account: &domain::MerchantAccount,
@@ -482,6 +535,17 @@ pub async fn get_mca_from_object_reference_id(
                 )
                 .await
             }
+            #[cfg(feature = "payouts")]
+            webhooks::ObjectReferenceId::PayoutId(payout_id_type) => {
+                find_mca_from_payout_id_type(
+                    db,
+                    payout_id_type,
+                    merchant_account,
+                    key_store,
+                    connector_name,
+                )
+                .await
+            }
         },
     }
 }
@@ -2,5 +2,9 @@
 pub mod api_key_expiry;
 pub mod outgoing_webhook_retry;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_schedules;
 pub mod refund_router;
+#[cfg(feature = "payouts")]
+pub mod scheduled_payouts;
 pub mod tokenized_data;
@@ -374,7 +374,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::JsonForRedirection(_)
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileDataStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -429,7 +430,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::JsonForRedirection(_)
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileDataStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -460,7 +462,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::JsonForRedirection(_)
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileDataStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
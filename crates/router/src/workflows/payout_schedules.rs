@@ -0,0 +1,74 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{core::payouts, errors, routes::AppState, types::storage};
+
+pub struct PayoutScheduleWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PayoutScheduleWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::PayoutScheduleRunTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("PayoutScheduleRunTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        let next_run_at = payouts::schedules::run_payout_schedule(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.schedule_id,
+            common_utils::date_time::now(),
+        )
+        .await?;
+
+        // The same task is rescheduled for every run, until the schedule is cancelled
+        match next_run_at {
+            Some(next_run_at) => {
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .reset_process(process, next_run_at)
+                    .await?
+            }
+            None => {
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
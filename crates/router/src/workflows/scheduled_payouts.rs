@@ -0,0 +1,60 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{core::payouts, errors, routes::AppState, types::storage};
+
+pub struct ScheduledPayoutWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ScheduledPayoutWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::payouts::PayoutScheduleTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutScheduleTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        payouts::process_scheduled_payout(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.payout_id,
+        )
+        .await?;
+
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
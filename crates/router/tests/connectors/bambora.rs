@@ -110,6 +110,8 @@ async fn should_sync_authorized_payment() {
                 connector_meta: None,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             None,
         )
@@ -226,6 +228,8 @@ async fn should_sync_auto_captured_payment() {
                 connector_meta: None,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             None,
         )
@@ -30,6 +30,17 @@ impl utils::Connector for DummyConnectorTest {
         )
     }
 
+    #[cfg(feature = "payouts")]
+    fn get_payout_data(&self) -> Option<types::api::ConnectorData> {
+        use router::connector::DummyConnector;
+        Some(types::api::ConnectorData {
+            connector: Box::new(&DummyConnector::<1>),
+            connector_name: types::Connector::DummyConnector1,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        })
+    }
+
     fn get_name(&self) -> String {
         "dummyconnector".to_string()
     }
@@ -45,6 +56,28 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
     None
 }
 
+// IBAN which the dummy connector keeps pending once the payout is fulfilled
+#[cfg(feature = "payouts")]
+const PENDING_IBAN: &str = "DE89370400440532013000";
+
+#[cfg(feature = "payouts")]
+fn get_payout_info(iban: &str, payout_amount: i64) -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        currency: Some(enums::Currency::EUR),
+        payout_method_data: Some(types::api::PayoutMethodData::Bank(
+            types::api::BankPayout::Sepa(types::api::SepaBankTransfer {
+                iban: Secret::new(iban.to_string()),
+                bic: None,
+                bank_name: None,
+                bank_country_code: None,
+                bank_city: None,
+            }),
+        )),
+        payout_amount: Some(payout_amount),
+        ..Default::default()
+    })
+}
+
 // Cards Positive Tests
 // Creates a payment using the manual capture flow (Non 3DS).
 #[actix_web::test]
@@ -440,6 +473,164 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
     );
 }
 
+// Creates, fulfills and syncs a SEPA payout
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_create_fulfill_and_sync_sepa_payout() {
+    let payout_type = enums::PayoutType::Bank;
+    let payout_info = get_payout_info("NL46TEST0136169112", 1000);
+    let response = CONNECTOR
+        .create_and_fulfill_payout(None, payout_type, payout_info.clone())
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Success));
+    let sync_response = CONNECTOR
+        .sync_payout(response.connector_payout_id, payout_type, payout_info)
+        .await
+        .expect("Payout sync response");
+    assert_eq!(sync_response.status, Some(enums::PayoutStatus::Success));
+}
+
+// Keeps a payout to the pending IBAN in pending after it is fulfilled
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_keep_payout_to_pending_iban_pending() {
+    let payout_type = enums::PayoutType::Bank;
+    let payout_info = get_payout_info(PENDING_IBAN, 1000);
+    let response = CONNECTOR
+        .create_and_fulfill_payout(None, payout_type, payout_info.clone())
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Pending));
+    let sync_response = CONNECTOR
+        .sync_payout(response.connector_payout_id, payout_type, payout_info)
+        .await
+        .expect("Payout sync response");
+    assert_eq!(sync_response.status, Some(enums::PayoutStatus::Pending));
+}
+
+// Cancels a created payout and syncs it
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_create_and_cancel_created_payout() {
+    let payout_type = enums::PayoutType::Bank;
+    let payout_info = get_payout_info("NL46TEST0136169112", 1000);
+    let response = CONNECTOR
+        .create_and_cancel_payout(None, payout_type, payout_info.clone())
+        .await
+        .expect("Payout cancel response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Cancelled));
+    let sync_response = CONNECTOR
+        .sync_payout(response.connector_payout_id, payout_type, payout_info)
+        .await
+        .expect("Payout sync response");
+    assert_eq!(sync_response.status, Some(enums::PayoutStatus::Cancelled));
+}
+
+// Fulfills an instant card payout, which is not created at the connector beforehand
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_fulfill_instant_card_payout() {
+    let payout_type = enums::PayoutType::Card;
+    let payout_info = Some(utils::PaymentInfo {
+        payout_amount: Some(1000),
+        ..Default::default()
+    });
+    let response = CONNECTOR
+        .fulfill_payout(Some(String::new()), payout_type, payout_info)
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Success));
+}
+
+// Declines payouts with an amount ending in 99
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_decline_payout_with_amount_ending_in_99() {
+    let response = CONNECTOR
+        .make_payout(
+            None,
+            enums::PayoutType::Bank,
+            get_payout_info("NL46TEST0136169112", 1099),
+        )
+        .await
+        .expect("Payout create response");
+    let error = response.response.unwrap_err();
+    assert_eq!(error.code, "DC_10");
+    assert_eq!(error.message, "Payout declined: Insufficient balance");
+}
+
+// Posts a webhook once the payout is fulfilled, which resolves to the payout and its new status
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_emit_payout_webhook_on_fulfillment() {
+    use api_models::webhooks::{ObjectReferenceId, PayoutIdType};
+    use masking::ErasedMaskSerialize;
+    use router::types::api::IncomingWebhook;
+
+    let webhook_receiver = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .respond_with(wiremock::ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&webhook_receiver)
+        .await;
+    let payout_info =
+        get_payout_info("NL46TEST0136169112", 1000).map(|payout_info| utils::PaymentInfo {
+            payout_webhook_url: Some(webhook_receiver.uri()),
+            ..payout_info
+        });
+    let response = CONNECTOR
+        .create_and_fulfill_payout(None, enums::PayoutType::Bank, payout_info)
+        .await
+        .expect("Payout fulfill response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Success));
+
+    // Webhooks are delivered in the background
+    let mut webhooks = Vec::new();
+    for _ in 0..50 {
+        webhooks = webhook_receiver
+            .received_requests()
+            .await
+            .unwrap_or_default();
+        if !webhooks.is_empty() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    let webhook = webhooks.first().expect("Payout webhook");
+    let request = types::api::IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::POST,
+        uri: actix_web::http::Uri::from_static("/"),
+        headers: &actix_web::http::header::HeaderMap::new(),
+        body: &webhook.body,
+        query_params: String::new(),
+    };
+    let connector = router::connector::DummyConnector::<1>;
+
+    let reference_id = connector
+        .get_webhook_object_reference_id(&request)
+        .expect("Payout webhook reference id");
+    assert!(matches!(
+        reference_id,
+        ObjectReferenceId::PayoutId(PayoutIdType::PayoutId(ref payout_id)) if !payout_id.is_empty()
+    ));
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&request)
+            .expect("Payout webhook event type"),
+        types::api::IncomingWebhookEvent::PayoutSuccess
+    );
+    let resource_object = connector
+        .get_webhook_resource_object(&request)
+        .expect("Payout webhook resource object")
+        .masked_serialize()
+        .expect("Serialized payout webhook");
+    assert_eq!(
+        resource_object["payout"]["id"],
+        serde_json::json!(response.connector_payout_id)
+    );
+}
+
 // Connector dependent test cases goes here
 
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
@@ -158,6 +158,8 @@ async fn should_sync_authorized_payment() {
                 mandate_id: None,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             get_default_payment_info(),
         )
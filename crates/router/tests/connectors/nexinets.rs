@@ -126,6 +126,8 @@ async fn should_sync_authorized_payment() {
                 mandate_id: None,
                 payment_method_type: None,
                 currency: enums::Currency::EUR,
+                amount: 100,
+                surcharge_amount: None,
             }),
             None,
         )
@@ -71,6 +71,10 @@ fn get_default_payment_info() -> Option<utils::PaymentInfo> {
         currency: None,
         #[cfg(feature = "payouts")]
         payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        payout_amount: None,
+        #[cfg(feature = "payouts")]
+        payout_webhook_url: None,
     })
 }
 
@@ -143,6 +143,8 @@ async fn should_sync_authorized_payment() {
                 connector_meta,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             get_default_payment_info(),
         )
@@ -341,6 +343,8 @@ async fn should_sync_auto_captured_payment() {
                 connector_meta,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             get_default_payment_info(),
         )
@@ -51,6 +51,10 @@ fn get_default_payment_info(payment_method_token: Option<String>) -> Option<util
         payment_method_token,
         #[cfg(feature = "payouts")]
         payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        payout_amount: None,
+        #[cfg(feature = "payouts")]
+        payout_webhook_url: None,
         currency: None,
         country: None,
     })
@@ -50,6 +50,10 @@ pub struct PaymentInfo {
     pub payment_method_token: Option<String>,
     #[cfg(feature = "payouts")]
     pub payout_method_data: Option<types::api::PayoutMethodData>,
+    #[cfg(feature = "payouts")]
+    pub payout_amount: Option<i64>,
+    #[cfg(feature = "payouts")]
+    pub payout_webhook_url: Option<String>,
     pub currency: Option<enums::Currency>,
     pub country: Option<enums::CountryAlpha2>,
 }
@@ -467,7 +471,10 @@ pub trait ConnectorActions: Connector {
             types::PayoutsData {
                 payout_id: core_utils::get_or_generate_uuid("payout_id", None)
                     .map_or("payout_3154763247".to_string(), |p| p),
-                amount: 1,
+                amount: payment_info
+                    .as_ref()
+                    .and_then(|pi| pi.payout_amount)
+                    .unwrap_or(1),
                 connector_payout_id,
                 destination_currency: payment_info.to_owned().map_or(enums::Currency::EUR, |pi| {
                     pi.currency.map_or(enums::Currency::EUR, |c| c)
@@ -484,6 +491,9 @@ pub trait ConnectorActions: Connector {
                     phone: Some(Secret::new("620874518".to_string())),
                     phone_country_code: Some("+31".to_string()),
                 }),
+                webhook_url: payment_info
+                    .as_ref()
+                    .and_then(|pi| pi.payout_webhook_url.clone()),
             },
             payment_info,
         )
@@ -570,6 +580,7 @@ pub trait ConnectorActions: Connector {
             Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
             Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
             Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::TransactionListSyncResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -662,6 +673,19 @@ pub trait ConnectorActions: Connector {
         payout_type: enums::PayoutType,
         payment_info: Option<PaymentInfo>,
     ) -> Result<types::PayoutsResponseData, Report<ConnectorError>> {
+        let res = self
+            .make_payout(connector_customer, payout_type, payment_info)
+            .await?;
+        Ok(res.response.unwrap())
+    }
+
+    #[cfg(feature = "payouts")]
+    async fn make_payout(
+        &self,
+        connector_customer: Option<String>,
+        payout_type: enums::PayoutType,
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::PayoutsRouterData<types::api::PoCreate>, Report<ConnectorError>> {
         let connector_integration: services::BoxedConnectorIntegration<
             '_,
             types::api::PoCreate,
@@ -694,7 +718,7 @@ pub trait ConnectorActions: Connector {
             None,
         )
         .await?;
-        Ok(res.response.unwrap())
+        Ok(res)
     }
 
     #[cfg(feature = "payouts")]
@@ -739,6 +763,48 @@ pub trait ConnectorActions: Connector {
         Ok(res.response.unwrap())
     }
 
+    #[cfg(feature = "payouts")]
+    async fn sync_payout(
+        &self,
+        connector_payout_id: String,
+        payout_type: enums::PayoutType,
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::PayoutsResponseData, Report<ConnectorError>> {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            types::api::PoSync,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > = self
+            .get_payout_data()
+            .ok_or(ConnectorError::FailedToObtainPreferredConnector)?
+            .connector
+            .get_connector_integration();
+        let mut request =
+            self.get_payout_request(Some(connector_payout_id), payout_type, payment_info);
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+
+        let state = Box::pin(routes::AppState::with_storage(
+            Settings::new().unwrap(),
+            StorageImpl::PostgresqlTest,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        connector_integration
+            .execute_pretasks(&mut request, &state)
+            .await?;
+        let res = services::api::execute_connector_processing_step(
+            &state,
+            connector_integration,
+            &request,
+            payments::CallConnectorAction::Trigger,
+            None,
+        )
+        .await?;
+        Ok(res.response.unwrap())
+    }
+
     #[cfg(feature = "payouts")]
     async fn create_and_fulfill_payout(
         &self,
@@ -998,6 +1064,8 @@ impl Default for PaymentSyncType {
             connector_meta: None,
             payment_method_type: None,
             currency: enums::Currency::USD,
+            amount: 100,
+            surcharge_amount: None,
         };
         Self(data)
     }
@@ -1063,6 +1131,7 @@ pub fn get_connector_transaction_id(
         Ok(types::PaymentsResponseData::ThreeDSEnrollmentResponse { .. }) => None,
         Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
         Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::TransactionListSyncResponse { .. }) => None,
         Err(_) => None,
     }
 }
@@ -105,6 +105,8 @@ async fn should_sync_authorized_payment() {
                 mandate_id: None,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             None,
         )
@@ -221,6 +223,8 @@ async fn should_sync_auto_captured_payment() {
                 mandate_id: None,
                 payment_method_type: None,
                 currency: enums::Currency::USD,
+                amount: 100,
+                surcharge_amount: None,
             }),
             None,
         )
@@ -34,3 +34,1288 @@ async fn payouts_todo() {
         assert_eq!(response.status(), awc::http::StatusCode::OK);
     }
 }
+
+#[cfg(all(feature = "payouts", feature = "dummy_connector"))]
+mod payout_flows {
+    use api_models::enums as api_enums;
+    use masking::{PeekInterface, Secret};
+    use router::{
+        configs::settings::Settings,
+        core::{
+            admin,
+            api_locking::GetLockingInput,
+            customers,
+            errors::{self, ApiClientError, CustomResult},
+            payment_methods::{cards, Oss},
+            payouts::{self, schedules},
+            refunds, routing, webhooks,
+        },
+        db::StorageImpl,
+        routes::AppState,
+        services::{self, api::client::RequestBuilder},
+        types::{domain, storage},
+        workflows::scheduled_payouts::ScheduledPayoutWorkflow,
+    };
+    use router_env::tracing_actix_web::RequestId;
+    use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+    use serde_json::json;
+    use tokio::sync::oneshot;
+    use wiremock::{
+        matchers::{method, path, path_regex},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const CUSTOMER_ID: &str = "cus_payout_flows";
+    const MERCHANT_CONNECTOR_ID: &str = "mca_payout_flows";
+    const CONNECTOR_PAYOUT_ID: &str = "dummy_payout_flows";
+
+    fn get_json_response<T>(response: services::ApplicationResponse<T>) -> Option<T> {
+        match response {
+            services::ApplicationResponse::Json(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    fn get_connector_response(status: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "status": status,
+            "id": CONNECTOR_PAYOUT_ID,
+            "amount": 1000,
+            "currency": "EUR",
+            "created": "2024-01-01T00:00:00Z",
+            "payout_type": "bank",
+        }))
+    }
+
+    /// Client of a request contending for the payout lock, which is held under the request id
+    #[derive(Clone)]
+    struct RequestClient {
+        inner: services::ProxyClient,
+        request_id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl services::ApiClient for RequestClient {
+        fn request(
+            &self,
+            method: http::Method,
+            url: String,
+        ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+            self.inner.request(method, url)
+        }
+
+        fn request_with_certificate(
+            &self,
+            method: http::Method,
+            url: String,
+            certificate: Option<String>,
+            certificate_key: Option<String>,
+        ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+            self.inner
+                .request_with_certificate(method, url, certificate, certificate_key)
+        }
+
+        async fn send_request(
+            &self,
+            state: &AppState,
+            request: services::Request,
+            option_timeout_secs: Option<u64>,
+            forward_to_kafka: bool,
+        ) -> CustomResult<reqwest::Response, ApiClientError> {
+            self.inner
+                .send_request(state, request, option_timeout_secs, forward_to_kafka)
+                .await
+        }
+
+        fn add_request_id(&mut self, _request_id: RequestId) {}
+
+        fn get_request_id(&self) -> Option<String> {
+            Some(self.request_id.clone())
+        }
+
+        fn add_merchant_id(&mut self, merchant_id: Option<String>) {
+            self.inner.add_merchant_id(merchant_id)
+        }
+
+        fn add_flow_name(&mut self, flow_name: String) {
+            self.inner.add_flow_name(flow_name)
+        }
+    }
+
+    fn get_api_client(conf: &Settings) -> services::ProxyClient {
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(&conf.locker),
+        )
+        .unwrap()
+    }
+
+    /// Creates the application state with the dummy connector pointed to the mock server
+    async fn get_state(mock_server: &MockServer) -> AppState {
+        let mut conf = Settings::new().unwrap();
+        conf.connectors.dummyconnector.base_url = mock_server.uri();
+        // Locks are held under the request id of the client
+        let api_client = RequestClient {
+            inner: get_api_client(&conf),
+            request_id: "req_payout_flows".to_string(),
+        };
+        Box::pin(AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            oneshot::channel().0,
+            Box::new(api_client),
+        ))
+        .await
+    }
+
+    /// Creates the merchant with a customer, a saved SEPA payout method and a payout processor
+    /// configured for the default business profile. The merchant accounts are cached in memory, so
+    /// each test sets up a merchant of its own
+    async fn setup_merchant(
+        state: &AppState,
+        merchant_id: &str,
+    ) -> (domain::MerchantAccount, domain::MerchantKeyStore, String) {
+        let merchant_account_create = serde_json::from_value(json!({
+            "merchant_id": merchant_id,
+            "merchant_name": "Payout flows",
+        }))
+        .unwrap();
+        let merchant_account_response = get_json_response(
+            admin::create_merchant_account(state.clone(), merchant_account_create)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let profile_id = merchant_account_response.default_profile.unwrap();
+
+        let key_store = state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .unwrap();
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+            .await
+            .unwrap();
+
+        let customer_request = serde_json::from_value(json!({
+            "customer_id": CUSTOMER_ID,
+            "name": "John Doe",
+            "email": "john.doe@example.com",
+            "address": {
+                "line1": "Hauptstrasse 1",
+                "city": "Berlin",
+                "zip": "10115",
+                "country": "DE",
+                "first_name": "John",
+                "last_name": "Doe",
+            },
+        }))
+        .unwrap();
+        customers::create_customer(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            customer_request,
+        )
+        .await
+        .unwrap();
+
+        let payment_method_create = serde_json::from_value(json!({
+            "payment_method": "bank_transfer",
+            "payment_method_type": "sepa",
+            "customer_id": CUSTOMER_ID,
+            "bank_transfer": {
+                "iban": "DE89370400440532013000",
+                "bic": "COBADEFFXXX",
+                "bank_name": "Commerzbank",
+                "bank_country_code": "DE",
+                "bank_city": "Berlin",
+            },
+        }))
+        .unwrap();
+        let payout_method_id = get_json_response(
+            cards::add_payment_method(
+                state.clone(),
+                payment_method_create,
+                &merchant_account,
+                &key_store,
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .payment_method_id;
+
+        let now = common_utils::date_time::now();
+        let merchant_connector_account = domain::MerchantConnectorAccount {
+            id: None,
+            merchant_id: merchant_id.to_string(),
+            connector_name: "phonypay".to_string(),
+            connector_account_details: domain::types::encrypt(
+                Secret::new(json!({ "auth_type": "HeaderKey", "api_key": "dummy_api_key" })),
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .unwrap(),
+            test_mode: Some(true),
+            disabled: Some(false),
+            merchant_connector_id: MERCHANT_CONNECTOR_ID.to_string(),
+            payment_methods_enabled: Some(vec![json!({
+                "payment_method": "bank_transfer",
+                "payment_method_types": [{
+                    "payment_method_type": "sepa",
+                    "recurring_enabled": false,
+                    "installment_payment_enabled": false,
+                }],
+            })]),
+            connector_type: api_enums::ConnectorType::PayoutProcessor,
+            metadata: None,
+            frm_configs: None,
+            connector_label: Some("phonypay_default".to_string()),
+            business_country: None,
+            business_label: None,
+            business_sub_label: None,
+            created_at: now,
+            modified_at: now,
+            connector_webhook_details: None,
+            profile_id: Some(profile_id.clone()),
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            status: api_enums::ConnectorStatus::Active,
+        };
+        state
+            .store
+            .insert_merchant_connector_account(merchant_connector_account, &key_store)
+            .await
+            .unwrap();
+
+        routing::helpers::get_merchant_default_config(
+            &*state.store,
+            &profile_id,
+            &storage::enums::TransactionType::Payout,
+        )
+        .await
+        .unwrap();
+        routing::helpers::update_merchant_default_config(
+            &*state.store,
+            &profile_id,
+            vec![serde_json::from_value(json!({
+                "connector": "phonypay",
+                "merchant_connector_id": MERCHANT_CONNECTOR_ID,
+            }))
+            .unwrap()],
+            &storage::enums::TransactionType::Payout,
+        )
+        .await
+        .unwrap();
+
+        (merchant_account, key_store, payout_method_id)
+    }
+
+    /// Creates a payment of the merchant captured through a connector
+    async fn insert_captured_payment(
+        state: &AppState,
+        merchant_account: &domain::MerchantAccount,
+        payment_id: &str,
+        amount: i64,
+    ) {
+        let attempt_id = format!("{payment_id}_1");
+        state
+            .store
+            .insert_payment_intent(
+                storage::PaymentIntentNew {
+                    payment_id: payment_id.to_string(),
+                    merchant_id: merchant_account.merchant_id.clone(),
+                    status: storage::enums::IntentStatus::Succeeded,
+                    amount,
+                    currency: Some(api_enums::Currency::EUR),
+                    amount_captured: Some(amount),
+                    customer_id: Some(CUSTOMER_ID.to_string()),
+                    description: None,
+                    return_url: None,
+                    metadata: None,
+                    connector_id: None,
+                    shipping_address_id: None,
+                    billing_address_id: None,
+                    statement_descriptor_name: None,
+                    statement_descriptor_suffix: None,
+                    created_at: None,
+                    modified_at: None,
+                    last_synced: None,
+                    setup_future_usage: None,
+                    off_session: None,
+                    client_secret: None,
+                    active_attempt: data_models::RemoteStorageObject::ForeignID(attempt_id.clone()),
+                    business_country: None,
+                    business_label: None,
+                    order_details: None,
+                    allowed_payment_method_types: None,
+                    connector_metadata: None,
+                    feature_metadata: None,
+                    attempt_count: 1,
+                    profile_id: None,
+                    merchant_decision: None,
+                    payment_link_id: None,
+                    payment_confirm_source: None,
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                    surcharge_applicable: None,
+                    request_incremental_authorization: None,
+                    incremental_authorization_allowed: None,
+                    authorization_count: None,
+                    fingerprint_id: None,
+                    session_expiry: None,
+                    request_external_three_ds_authentication: None,
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+
+        let payment_attempt = state
+            .store
+            .insert_payment_attempt(
+                storage::PaymentAttemptNew {
+                    payment_id: payment_id.to_string(),
+                    merchant_id: merchant_account.merchant_id.clone(),
+                    attempt_id,
+                    status: storage::enums::AttemptStatus::Charged,
+                    amount,
+                    currency: Some(api_enums::Currency::EUR),
+                    ..Default::default()
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::ConnectorResponse {
+                    authentication_data: None,
+                    encoded_data: None,
+                    connector_transaction_id: Some(format!("txn_{payment_id}")),
+                    connector: Some("stripe".to_string()),
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Returns the request to create a payout to the saved payout method, to be fulfilled later
+    fn get_payout_create_request(
+        payout_method_id: &str,
+    ) -> api_models::payouts::PayoutCreateRequest {
+        serde_json::from_value(json!({
+            "customer_id": CUSTOMER_ID,
+            "payout_method_id": payout_method_id,
+            "amount": 1000,
+            "currency": "EUR",
+            "payout_type": "bank",
+            "confirm": true,
+            "auto_fulfill": false,
+            "billing": {
+                "address": {
+                    "line1": "Hauptstrasse 1",
+                    "city": "Berlin",
+                    "zip": "10115",
+                    "country": "DE",
+                    "first_name": "John",
+                    "last_name": "Doe",
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    /// Creates a payout scheduled an hour from now, returning its id and the process tracker task
+    /// which processes it
+    async fn create_scheduled_payout(
+        state: &AppState,
+        merchant_account: &domain::MerchantAccount,
+        key_store: &domain::MerchantKeyStore,
+        payout_method_id: &str,
+    ) -> (String, storage::ProcessTracker) {
+        let scheduled_at = common_utils::date_time::now().saturating_add(time::Duration::hours(1));
+        let payout = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutCreateRequest {
+                    scheduled_at: Some(scheduled_at),
+                    auto_fulfill: Some(true),
+                    ..get_payout_create_request(payout_method_id)
+                },
+                api_models::payouts::PayoutEventActor::Merchant {
+                    merchant_id: merchant_account.merchant_id.clone(),
+                },
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Scheduled);
+
+        let process_tracker_id = scheduler::utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::ScheduledPayoutWorkflow,
+            "SCHEDULED_PAYOUT",
+            &payout.payout_id,
+            &merchant_account.merchant_id,
+        );
+        let process = state
+            .store
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.schedule_time, Some(scheduled_at));
+
+        (payout.payout_id, process)
+    }
+
+    #[actix_web::test]
+    async fn test_scheduled_payout_is_processed_when_due() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_scheduled_payout_due";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let (payout_id, process) =
+            create_scheduled_payout(&state, &merchant_account, &key_store, &payout_method_id).await;
+        let process_tracker_id = process.id.clone();
+
+        // The process tracker runs the task once the scheduled time is due
+        ScheduledPayoutWorkflow
+            .execute_workflow(&state, process)
+            .await
+            .unwrap();
+
+        let payout = state
+            .store
+            .find_payout_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+        let payout_attempts = state
+            .store
+            .find_payout_attempts_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|payout_attempt| (payout_attempt.connector_payout_id, payout_attempt.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            payout_attempts,
+            vec![(
+                CONNECTOR_PAYOUT_ID.to_string(),
+                api_enums::PayoutStatus::Success
+            )]
+        );
+
+        let process = state
+            .store
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(process.status, storage::enums::ProcessTrackerStatus::Finish);
+        assert_eq!(process.business_status, "COMPLETED_BY_PT");
+
+        // The payout is created and fulfilled with the connector by the task alone
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_scheduled_payout_cancelled_before_due_is_skipped() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_scheduled_payout_cancelled";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let (payout_id, process) =
+            create_scheduled_payout(&state, &merchant_account, &key_store, &payout_method_id).await;
+
+        let payout = get_json_response(
+            payouts::payouts_cancel_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutActionRequest {
+                    payout_id: payout_id.clone(),
+                    connector: None,
+                    amount: None,
+                },
+                api_models::payouts::PayoutEventActor::Merchant {
+                    merchant_id: merchant_id.to_string(),
+                },
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Cancelled);
+
+        // The task still runs at the scheduled time, and leaves the cancelled payout as it is
+        ScheduledPayoutWorkflow
+            .execute_workflow(&state, process)
+            .await
+            .unwrap();
+
+        let payout = state
+            .store
+            .find_payout_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Cancelled);
+
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_retried_schedule_run_creates_a_single_payout() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_schedule_run";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+
+        let payout_schedule_create = serde_json::from_value(json!({
+            "customer_id": CUSTOMER_ID,
+            "payout_method_id": payout_method_id,
+            "currency": "EUR",
+            "payout_type": "bank",
+            "cadence": { "type": "weekly", "day_of_week": 1 },
+            "amount_source": { "type": "fixed", "amount": 1000 },
+        }))
+        .unwrap();
+        let schedule_id = get_json_response(
+            schedules::payout_schedule_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                payout_schedule_create,
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .schedule_id;
+        let run_at = state
+            .store
+            .find_payout_schedule_by_merchant_id_schedule_id(merchant_id, &schedule_id)
+            .await
+            .unwrap()
+            .next_run_at
+            .unwrap();
+        let payout_id = schedules::get_scheduled_payout_id(&schedule_id, run_at);
+
+        let next_run_at = schedules::run_payout_schedule(
+            &state,
+            &merchant_account,
+            &key_store,
+            &schedule_id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .unwrap();
+        assert!(next_run_at.is_some_and(|next_run_at| next_run_at > run_at));
+
+        // The run is retried by the scheduler if it fails to record it on the schedule
+        state
+            .store
+            .update_payout_schedule_by_merchant_id_schedule_id(
+                merchant_id,
+                &schedule_id,
+                storage::PayoutScheduleUpdate::StatusUpdate {
+                    status: api_enums::PayoutScheduleStatus::Active,
+                    next_run_at: Some(run_at),
+                },
+            )
+            .await
+            .unwrap();
+        schedules::run_payout_schedule(
+            &state,
+            &merchant_account,
+            &key_store,
+            &schedule_id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .unwrap();
+
+        let payout_schedule = state
+            .store
+            .find_payout_schedule_by_merchant_id_schedule_id(merchant_id, &schedule_id)
+            .await
+            .unwrap();
+        assert_eq!(payout_schedule.last_payout_id, Some(payout_id.clone()));
+        assert_eq!(payout_schedule.last_failure_reason, None);
+
+        let payout = state
+            .store
+            .find_payout_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+        assert_eq!(payout.amount, 1000);
+        assert_eq!(payout.payout_schedule_id, Some(schedule_id));
+
+        // The connector is called for the first run only
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_payout_is_fulfilled_in_tranches() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+        // The first tranche is fulfilled, the second one fails and the third one is fulfilled
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("cancelled"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_tranches";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_string(),
+        };
+
+        let payout = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                get_payout_create_request(&payout_method_id),
+                audit_actor.clone(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::RequiresFulfillment);
+        let payout_id = payout.payout_id;
+
+        let fulfill = |amount: Option<i64>| {
+            payouts::payouts_fulfill_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutActionRequest {
+                    payout_id: payout_id.clone(),
+                    connector: None,
+                    amount,
+                },
+                audit_actor.clone(),
+            )
+        };
+        let find_payout = || {
+            state.store.find_payout_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+        };
+
+        fulfill(Some(400)).await.unwrap();
+        let payout = find_payout().await.unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::PartiallyFulfilled);
+        assert_eq!(payout.fulfilled_amount, Some(400));
+
+        // A failed tranche leaves the tranches fulfilled earlier as they are
+        assert!(fulfill(None).await.is_err());
+        let payout = find_payout().await.unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::PartiallyFulfilled);
+        assert_eq!(payout.fulfilled_amount, Some(400));
+
+        fulfill(None).await.unwrap();
+        let payout = find_payout().await.unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+        assert_eq!(payout.fulfilled_amount, Some(1000));
+
+        // Each tranche is paid out through an attempt of its own
+        let payout_attempts = state
+            .store
+            .find_payout_attempts_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|payout_attempt| (payout_attempt.amount, payout_attempt.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            payout_attempts,
+            vec![
+                (Some(400), api_enums::PayoutStatus::Success),
+                (Some(600), api_enums::PayoutStatus::Cancelled),
+                (Some(600), api_enums::PayoutStatus::Success),
+            ]
+        );
+
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_payout_audit_trail_is_recorded_by_core_flows() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_audit_trail";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_string(),
+        };
+
+        let payout_id = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                get_payout_create_request(&payout_method_id),
+                audit_actor.clone(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .payout_id;
+        payouts::payouts_fulfill_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store,
+            api_models::payouts::PayoutActionRequest {
+                payout_id: payout_id.clone(),
+                connector: None,
+                amount: None,
+            },
+            audit_actor.clone(),
+        )
+        .await
+        .unwrap();
+
+        // The events are stored in the background, wait for the fulfillment to be recorded
+        let mut events = Vec::new();
+        for _ in 0..50 {
+            events = get_json_response(
+                payouts::payouts_list_events_core(
+                    state.clone(),
+                    merchant_account.clone(),
+                    api_models::payouts::PayoutEventsRequest {
+                        payout_id: payout_id.clone(),
+                    },
+                )
+                .await
+                .unwrap(),
+            )
+            .unwrap()
+            .events;
+            if events.last().map(|event| event.event_type)
+                == Some(api_enums::PayoutAuditEventType::Fulfilled)
+            {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        let first = events.first().unwrap();
+        assert_eq!(first.event_type, api_enums::PayoutAuditEventType::Created);
+        assert_eq!(first.previous_status, None);
+        let last = events.last().unwrap();
+        assert_eq!(last.event_type, api_enums::PayoutAuditEventType::Fulfilled);
+        assert_eq!(
+            last.previous_status,
+            Some(api_enums::PayoutStatus::RequiresFulfillment)
+        );
+        assert_eq!(last.new_status, api_enums::PayoutStatus::Success);
+        // Each transition starts from the status the previous one ended in
+        assert!(events.windows(2).all(|pair| matches!(
+            pair,
+            [previous, next] if next.previous_status == Some(previous.new_status)
+        )));
+        assert!(events.iter().all(|event| event.actor == audit_actor));
+
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_concurrent_fulfillments_call_the_connector_once() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // Slow enough for the fulfillments to contend for the lock
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(
+                get_connector_response("succeeded")
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_concurrent_fulfillments";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_string(),
+        };
+        let payout_id = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                get_payout_create_request(&payout_method_id),
+                audit_actor.clone(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .payout_id;
+
+        // Each request fulfills the payout under the lock held by the fulfill route
+        let fulfill = |request_id: &str| {
+            let mut state = state.clone();
+            state.api_client = Box::new(RequestClient {
+                inner: get_api_client(&state.conf),
+                request_id: request_id.to_string(),
+            });
+            let req = api_models::payouts::PayoutActionRequest {
+                payout_id: payout_id.clone(),
+                connector: None,
+                amount: None,
+            };
+            let locking_action = req.get_locking_input(router_env::Flow::PayoutsFulfill);
+            let merchant_account = merchant_account.clone();
+            let key_store = key_store.clone();
+            let audit_actor = audit_actor.clone();
+            async move {
+                locking_action
+                    .clone()
+                    .perform_locking_action(&state, merchant_id.to_string())
+                    .await
+                    .unwrap();
+                let response = payouts::payouts_fulfill_core(
+                    state.clone(),
+                    merchant_account,
+                    key_store,
+                    req,
+                    audit_actor,
+                )
+                .await;
+                locking_action
+                    .free_lock_action(&state, merchant_id.to_string())
+                    .await
+                    .unwrap();
+                get_json_response(response.unwrap()).unwrap().status
+            }
+        };
+
+        let (first, second) = tokio::join!(fulfill("req_1"), fulfill("req_2"));
+        assert_eq!(first, api_enums::PayoutStatus::Success);
+        assert_eq!(second, api_enums::PayoutStatus::Success);
+
+        // The request which waited on the lock returns the result of the first one
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_payout_webhook_syncs_payout_status() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("pending"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/payouts/{CONNECTOR_PAYOUT_ID}")))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_webhooks";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_string(),
+        };
+
+        let payout_id = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                get_payout_create_request(&payout_method_id),
+                audit_actor.clone(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .payout_id;
+        let payout = get_json_response(
+            payouts::payouts_fulfill_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutActionRequest {
+                    payout_id: payout_id.clone(),
+                    connector: None,
+                    amount: None,
+                },
+                audit_actor,
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Pending);
+
+        // The connector is asked to notify the webhooks endpoint of the connector account
+        let webhook_url = format!(
+            "{}/webhooks/{merchant_id}/{MERCHANT_CONNECTOR_ID}",
+            state.conf.server.base_url
+        );
+        let requests = mock_server.received_requests().await.unwrap();
+        let create_request: serde_json::Value = requests
+            .iter()
+            .find(|request| request.url.path() == "/payout")
+            .unwrap()
+            .body_json()
+            .unwrap();
+        assert_eq!(create_request["webhook_url"], json!(webhook_url));
+        assert_eq!(create_request["reference"], json!(payout_id));
+
+        let webhook = json!({
+            "reference": payout_id,
+            "payout": {
+                "status": "succeeded",
+                "id": CONNECTOR_PAYOUT_ID,
+                "amount": 1000,
+                "currency": "EUR",
+                "created": "2024-01-01T00:00:00Z",
+                "payout_type": "bank",
+            },
+        });
+        let (_, webhook_response, _) = Box::pin(webhooks::webhooks_core::<
+            api_models::webhooks::OutgoingWebhook,
+            Oss,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            &actix_web::test::TestRequest::post().to_http_request(),
+            merchant_account.clone(),
+            key_store,
+            MERCHANT_CONNECTOR_ID,
+            serde_json::to_vec(&webhook).unwrap().into(),
+        ))
+        .await
+        .unwrap();
+        assert!(matches!(
+            webhook_response,
+            api_models::webhooks::WebhookResponseTracker::Payout {
+                status: api_enums::PayoutStatus::Success,
+                ..
+            }
+        ));
+
+        // The status is taken from the connector and not from the webhook
+        let payout = state
+            .store
+            .find_payout_by_merchant_id_payout_id(
+                merchant_id,
+                &payout_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_payout_retrieve_force_syncs_with_connector() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/payouts/.+/fulfill$"))
+            .respond_with(get_connector_response("pending"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // The first sync finds the payout still pending, the second one finds it succeeded
+        Mock::given(method("GET"))
+            .and(path(format!("/payouts/{CONNECTOR_PAYOUT_ID}")))
+            .respond_with(get_connector_response("pending"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/payouts/{CONNECTOR_PAYOUT_ID}")))
+            .respond_with(get_connector_response("succeeded"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_force_sync";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let audit_actor = api_models::payouts::PayoutEventActor::Merchant {
+            merchant_id: merchant_id.to_string(),
+        };
+
+        let payout_id = get_json_response(
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                get_payout_create_request(&payout_method_id),
+                audit_actor.clone(),
+            )
+            .await
+            .unwrap(),
+        )
+        .unwrap()
+        .payout_id;
+        payouts::payouts_fulfill_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            api_models::payouts::PayoutActionRequest {
+                payout_id: payout_id.clone(),
+                connector: None,
+                amount: None,
+            },
+            audit_actor.clone(),
+        )
+        .await
+        .unwrap();
+
+        let retrieve = |force_sync: Option<bool>| {
+            payouts::payouts_retrieve_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutRetrieveRequest {
+                    payout_id: payout_id.clone(),
+                    force_sync,
+                    expand: None,
+                },
+                audit_actor.clone(),
+            )
+        };
+
+        // The connector is not called without force sync
+        let payout = get_json_response(retrieve(None).await.unwrap()).unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Pending);
+        assert_eq!(payout.connector_sync_supported, None);
+
+        let payout = get_json_response(retrieve(Some(true)).await.unwrap()).unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Pending);
+        assert_eq!(payout.connector_sync_supported, Some(true));
+
+        let payout = get_json_response(retrieve(Some(true)).await.unwrap()).unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+        assert_eq!(payout.connector_sync_supported, Some(true));
+
+        // Payouts in a terminal status are not synced again
+        let payout = get_json_response(retrieve(Some(true)).await.unwrap()).unwrap();
+        assert_eq!(payout.status, api_enums::PayoutStatus::Success);
+        assert_eq!(payout.connector_sync_supported, None);
+
+        mock_server.verify().await;
+    }
+
+    #[actix_web::test]
+    async fn test_payouts_and_refunds_cannot_over_allocate_source_payment() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/payout"))
+            .respond_with(get_connector_response("created"))
+            .mount(&mock_server)
+            .await;
+
+        let state = get_state(&mock_server).await;
+        let merchant_id = "merchant_payout_source_payment";
+        let (merchant_account, key_store, payout_method_id) =
+            setup_merchant(&state, merchant_id).await;
+        let payment_id = "pay_payout_source_payment";
+        insert_captured_payment(&state, &merchant_account, payment_id, 10000).await;
+
+        let create_linked_payout = |amount: i64| {
+            payouts::payouts_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                api_models::payouts::PayoutCreateRequest {
+                    amount: Some(api_models::payments::Amount::from(amount)),
+                    source_payment_id: Some(payment_id.to_string()),
+                    ..get_payout_create_request(&payout_method_id)
+                },
+                api_models::payouts::PayoutEventActor::Merchant {
+                    merchant_id: merchant_id.to_string(),
+                },
+            )
+        };
+        let create_refund = |amount: i64| {
+            refunds::refund_create_core(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                serde_json::from_value(json!({ "payment_id": payment_id, "amount": amount }))
+                    .unwrap(),
+            )
+        };
+        let is_rejected = |error: &error_stack::Report<errors::ApiErrorResponse>| {
+            matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            )
+        };
+
+        create_linked_payout(6000).await.unwrap();
+        assert_eq!(
+            state
+                .store
+                .get_total_payout_amount_by_merchant_id_source_payment_id(
+                    merchant_id,
+                    payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .unwrap(),
+            6000
+        );
+
+        // Payouts are validated against the payouts already funded by the payment
+        let error = create_linked_payout(4001).await.unwrap_err();
+        assert!(is_rejected(&error));
+
+        // Refunds cannot take the amount of the payment already paid out
+        let error = create_refund(4001).await.unwrap_err();
+        assert!(is_rejected(&error));
+    }
+}
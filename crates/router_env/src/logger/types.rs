@@ -155,10 +155,15 @@ pub enum Flow {
     PaymentsList,
     // Payments filters flow
     PaymentsFilters,
+    /// Payments reconcile flow.
+    PaymentsReconcile,
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
     #[cfg(feature = "payouts")]
+    /// Payouts validate flow.
+    PayoutsValidate,
+    #[cfg(feature = "payouts")]
     /// Payouts retrieve flow.
     PayoutsRetrieve,
     #[cfg(feature = "payouts")]
@@ -171,11 +176,35 @@ pub enum Flow {
     /// Payouts fulfill flow.
     PayoutsFulfill,
     #[cfg(feature = "payouts")]
+    /// Payouts events list flow.
+    PayoutsEvents,
+    #[cfg(feature = "payouts")]
+    /// Payout schedule create flow.
+    PayoutScheduleCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout schedule retrieve flow.
+    PayoutScheduleRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout schedule cancel flow.
+    PayoutScheduleCancel,
+    #[cfg(feature = "payouts")]
     /// Payouts list flow.
     PayoutsList,
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payouts export flow.
+    PayoutsExport,
+    #[cfg(feature = "payouts")]
+    /// Payout required fields flow.
+    PayoutRequiredFields,
+    #[cfg(feature = "payouts")]
+    /// Saved payout methods list flow.
+    PayoutMethodsList,
+    #[cfg(feature = "payouts")]
+    /// Saved payout method delete flow.
+    PayoutMethodsDelete,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payments Redirect flow.
@@ -283,11 +283,21 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: String,
+        this: storage::ProcessTracker,
+        business_status: String,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let process = processes
+            .iter_mut()
+            .find(|process| process.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process found for id = {}",
+                this.id
+            )))?;
+        process.status = storage_enums::ProcessTrackerStatus::Finish;
+        process.business_status = business_status;
+        process.updated_at = common_utils::date_time::now();
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
@@ -55,6 +55,8 @@ pub struct MockDb {
     pub payout_attempt: Arc<Mutex<Vec<store::payout_attempt::PayoutAttempt>>>,
     #[cfg(feature = "payouts")]
     pub payouts: Arc<Mutex<Vec<store::payouts::Payouts>>>,
+    pub payout_schedules: Arc<Mutex<Vec<store::payout_schedule::PayoutSchedule>>>,
+    pub payout_audit_events: Arc<Mutex<Vec<store::payout_audit_event::PayoutAuditEvent>>>,
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
 }
@@ -98,6 +100,8 @@ impl MockDb {
             payout_attempt: Default::default(),
             #[cfg(feature = "payouts")]
             payouts: Default::default(),
+            payout_schedules: Default::default(),
+            payout_audit_events: Default::default(),
             authentications: Default::default(),
             roles: Default::default(),
         })
@@ -11,38 +11,142 @@ use data_models::{
 use diesel_models::enums as storage_enums;
 
 use super::MockDb;
+use crate::DataModelExt;
 
 #[async_trait::async_trait]
 impl PayoutAttemptInterface for MockDb {
     async fn update_payout_attempt(
         &self,
-        _this: &PayoutAttempt,
-        _payout_attempt_update: PayoutAttemptUpdate,
+        this: &PayoutAttempt,
+        payout_attempt_update: PayoutAttemptUpdate,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        let payout_attempt = payout_attempts
+            .iter_mut()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == this.merchant_id
+                    && payout_attempt.payout_attempt_id == this.payout_attempt_id
+            })
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payout attempt available for merchant_id = {} and payout_attempt_id = {}",
+                this.merchant_id, this.payout_attempt_id
+            )))?;
+        *payout_attempt = payout_attempt_update
+            .to_storage_model()
+            .apply_changeset(payout_attempt.clone());
+        Ok(PayoutAttempt::from_storage_model(payout_attempt.clone()))
     }
 
     async fn insert_payout_attempt(
         &self,
-        _payout_attempt: PayoutAttemptNew,
+        new: PayoutAttemptNew,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        if payout_attempts.iter().any(|payout_attempt| {
+            payout_attempt.merchant_id == new.merchant_id
+                && payout_attempt.payout_attempt_id == new.payout_attempt_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payout_attempt",
+                key: Some(new.payout_attempt_id.clone()),
+            })?
+        }
+        let now = common_utils::date_time::now();
+        let payout_attempt = PayoutAttempt {
+            payout_attempt_id: new.payout_attempt_id,
+            payout_id: new.payout_id,
+            customer_id: new.customer_id,
+            merchant_id: new.merchant_id,
+            address_id: new.address_id,
+            connector: new.connector,
+            connector_payout_id: new.connector_payout_id,
+            payout_token: new.payout_token,
+            status: new.status,
+            is_eligible: new.is_eligible,
+            error_message: new.error_message,
+            error_code: new.error_code,
+            business_country: new.business_country,
+            business_label: new.business_label,
+            created_at: new.created_at.unwrap_or(now),
+            last_modified_at: new.last_modified_at.unwrap_or(now),
+            profile_id: new.profile_id,
+            merchant_connector_id: new.merchant_connector_id,
+            routing_info: new.routing_info,
+            manual_routing_override: new.manual_routing_override,
+            routing_trail: new.routing_trail,
+            amount: new.amount,
+        };
+        payout_attempts.push(payout_attempt.clone().to_storage_model());
+        Ok(payout_attempt)
     }
 
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
-        _merchant_id: &str,
-        _payout_attempt_id: &str,
+        merchant_id: &str,
+        payout_attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.payout_attempt
+            .lock()
+            .await
+            .iter()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == merchant_id
+                    && payout_attempt.payout_attempt_id == payout_attempt_id
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payout attempt available for merchant_id = {merchant_id} and payout_attempt_id = {payout_attempt_id}"
+            )))
+            .map_err(Into::into)
+    }
+
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutAttempt>, StorageError> {
+        let mut payout_attempts = self
+            .payout_attempt
+            .lock()
+            .await
+            .iter()
+            .filter(|payout_attempt| {
+                payout_attempt.merchant_id == merchant_id && payout_attempt.payout_id == payout_id
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .collect::<Vec<_>>();
+        payout_attempts.sort_by_key(|payout_attempt| payout_attempt.created_at);
+        Ok(payout_attempts)
+    }
+
+    async fn find_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutAttempt>, StorageError> {
+        let mut payout_attempts = self
+            .payout_attempt
+            .lock()
+            .await
+            .iter()
+            .filter(|payout_attempt| {
+                payout_attempt.merchant_id == merchant_id
+                    && payout_attempt_ids.contains(&payout_attempt.payout_attempt_id)
+            })
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .collect::<Vec<_>>();
+        payout_attempts.sort_by_key(|payout_attempt| payout_attempt.created_at);
+        Ok(payout_attempts)
     }
 
     async fn get_filters_for_payouts(
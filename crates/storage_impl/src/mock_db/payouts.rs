@@ -7,60 +7,261 @@ use data_models::{
     },
 };
 use diesel_models::enums as storage_enums;
+#[cfg(feature = "olap")]
+use masking::PeekInterface;
 
 use super::MockDb;
+use crate::DataModelExt;
 
 #[async_trait::async_trait]
 impl PayoutsInterface for MockDb {
     async fn find_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &str,
-        _payout_id: &str,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &str,
+        payout_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_optional_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await?
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "No payout available for merchant_id = {merchant_id} and payout_id = {payout_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn update_payout(
         &self,
-        _this: &Payouts,
-        _payout_update: PayoutsUpdate,
+        this: &Payouts,
+        payout_update: PayoutsUpdate,
+        payout_attempt: &PayoutAttempt,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Payouts, StorageError> {
+        self.update_payout_by_merchant_id_payout_id(
+            &this.merchant_id,
+            &this.payout_id,
+            payout_update,
+            payout_attempt,
+            storage_scheme,
+        )
+        .await
+    }
+
+    async fn update_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        payout_update: PayoutsUpdate,
         _payout_attempt: &PayoutAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        let payout = payouts
+            .iter_mut()
+            .find(|payout| payout.merchant_id == merchant_id && payout.payout_id == payout_id)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payout available for merchant_id = {merchant_id} and payout_id = {payout_id}"
+            )))?;
+        *payout = payout_update
+            .to_storage_model()
+            .apply_changeset(payout.clone());
+        Ok(Payouts::from_storage_model(payout.clone()))
     }
 
     async fn insert_payout(
         &self,
-        _payout: PayoutsNew,
+        new: PayoutsNew,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        if payouts.iter().any(|payout| {
+            payout.merchant_id == new.merchant_id && payout.payout_id == new.payout_id
+        }) {
+            Err(StorageError::DuplicateValue {
+                entity: "payouts",
+                key: Some(new.payout_id.clone()),
+            })?
+        }
+        let now = common_utils::date_time::now();
+        let payout = Payouts {
+            payout_id: new.payout_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            address_id: new.address_id,
+            payout_type: new.payout_type,
+            payout_method_id: new.payout_method_id,
+            amount: new.amount,
+            destination_currency: new.destination_currency,
+            source_currency: new.source_currency,
+            description: new.description,
+            recurring: new.recurring,
+            auto_fulfill: new.auto_fulfill,
+            return_url: new.return_url,
+            entity_type: new.entity_type,
+            metadata: new.metadata,
+            created_at: new.created_at.unwrap_or(now),
+            last_modified_at: new.last_modified_at.unwrap_or(now),
+            profile_id: new.profile_id,
+            status: new.status,
+            attempt_count: new.attempt_count,
+            scheduled_at: new.scheduled_at,
+            fx_rate: new.fx_rate,
+            fx_quote_expires_at: new.fx_quote_expires_at,
+            priority: new.priority,
+            fulfilled_amount: new.fulfilled_amount,
+            payout_schedule_id: new.payout_schedule_id,
+            source_payment_id: new.source_payment_id,
+        };
+        payouts.push(payout.clone().to_storage_model());
+        Ok(payout)
     }
 
     async fn find_optional_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &str,
-        _payout_id: &str,
+        merchant_id: &str,
+        payout_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Option<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+        Ok(payouts
+            .iter()
+            .find(|payout| payout.merchant_id == merchant_id && payout.payout_id == payout_id)
+            .cloned()
+            .map(Payouts::from_storage_model))
+    }
+
+    async fn find_payouts_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<Payouts>, StorageError> {
+        let payouts = self.payouts.lock().await;
+        Ok(payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == merchant_id
+                    && payout.source_payment_id.as_deref() == Some(source_payment_id)
+            })
+            .cloned()
+            .map(Payouts::from_storage_model)
+            .collect())
+    }
+
+    async fn get_total_payout_amount_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, StorageError> {
+        let payouts = self.payouts.lock().await;
+        Ok(payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == merchant_id
+                    && payout.source_payment_id.as_deref() == Some(source_payment_id)
+                    && !matches!(
+                        payout.status,
+                        storage_enums::PayoutStatus::Failed
+                            | storage_enums::PayoutStatus::Cancelled
+                            | storage_enums::PayoutStatus::Ineligible
+                    )
+            })
+            .map(|payout| payout.amount)
+            .sum())
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
-        _merchant_id: &str,
-        _filters: &data_models::payouts::PayoutFetchConstraints,
+        merchant_id: &str,
+        filters: &data_models::payouts::PayoutFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.payouts.lock().await;
+        let params = match filters {
+            data_models::payouts::PayoutFetchConstraints::Single { payout_id } => {
+                return Ok(payouts
+                    .iter()
+                    .filter(|payout| {
+                        payout.merchant_id == merchant_id && &payout.payout_id == payout_id
+                    })
+                    .cloned()
+                    .map(Payouts::from_storage_model)
+                    .collect());
+            }
+            data_models::payouts::PayoutFetchConstraints::List(params) => params,
+        };
+        let get_created_at = |payout_id: &String| {
+            payouts
+                .iter()
+                .find(|payout| payout.merchant_id == merchant_id && &payout.payout_id == payout_id)
+                .map(|payout| payout.created_at)
+                .ok_or(StorageError::ValueNotFound(format!(
+                    "No payout available for merchant_id = {merchant_id} and payout_id = {payout_id}"
+                )))
+        };
+        let starting_at = match (params.starting_at, &params.starting_after_id) {
+            (Some(starting_at), _) => Some(starting_at),
+            (None, Some(starting_after_id)) => Some(get_created_at(starting_after_id)?),
+            (None, None) => None,
+        };
+        let ending_at = match (params.ending_at, &params.ending_before_id) {
+            (Some(ending_at), _) => Some(ending_at),
+            (None, Some(ending_before_id)) => Some(get_created_at(ending_before_id)?),
+            (None, None) => None,
+        };
+
+        let mut payouts = payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == merchant_id
+                    && params
+                        .customer_id
+                        .as_ref()
+                        .map_or(true, |customer_id| &payout.customer_id == customer_id)
+                    && params
+                        .profile_id
+                        .as_ref()
+                        .map_or(true, |profile_id| &payout.profile_id == profile_id)
+                    && starting_at.map_or(true, |starting_at| payout.created_at >= starting_at)
+                    && ending_at.map_or(true, |ending_at| payout.created_at <= ending_at)
+                    && params.currency.as_ref().map_or(true, |currency| {
+                        currency.contains(&payout.destination_currency)
+                    })
+                    && params
+                        .status
+                        .as_ref()
+                        .map_or(true, |status| status.contains(&payout.status))
+                    && params.payout_method.as_ref().map_or(true, |payout_method| {
+                        payout_method.contains(&payout.payout_type)
+                    })
+                    && params
+                        .entity_type
+                        .map_or(true, |entity_type| payout.entity_type == entity_type)
+                    && params.metadata.as_ref().map_or(true, |metadata_filter| {
+                        metadata_filter.iter().all(|(key, value)| {
+                            payout
+                                .metadata
+                                .as_ref()
+                                .and_then(|metadata| metadata.peek().get(key))
+                                .and_then(|metadata_value| metadata_value.as_str())
+                                == Some(value.as_str())
+                        })
+                    })
+            })
+            .cloned()
+            .map(Payouts::from_storage_model)
+            .collect::<Vec<_>>();
+        payouts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(payouts
+            .into_iter()
+            .skip(usize::try_from(params.offset).unwrap_or(usize::MAX))
+            .take(params.limit.map_or(usize::MAX, |limit| {
+                usize::try_from(limit).unwrap_or(usize::MAX)
+            }))
+            .collect())
     }
 
     #[cfg(feature = "olap")]
@@ -84,4 +285,35 @@ impl PayoutsInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_time_range_after_cursor(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+        cursor: Option<&data_models::payouts::PayoutsCursor>,
+        limit: u32,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<Payouts>, StorageError> {
+        let payouts = self.payouts.lock().await;
+        let mut payouts = payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == merchant_id
+                    && payout.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .map_or(true, |end_time| payout.created_at <= end_time)
+                    && cursor.map_or(true, |cursor| {
+                        (payout.created_at, &payout.payout_id)
+                            > (cursor.created_at, &cursor.payout_id)
+                    })
+            })
+            .cloned()
+            .map(Payouts::from_storage_model)
+            .collect::<Vec<_>>();
+        payouts.sort_by(|a, b| (a.created_at, &a.payout_id).cmp(&(b.created_at, &b.payout_id)));
+        payouts.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(payouts)
+    }
 }
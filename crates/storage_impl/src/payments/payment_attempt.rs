@@ -1673,6 +1673,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            Self::ConnectorMetadataUpdate {
+                connector_metadata,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::ConnectorMetadataUpdate {
+                connector_metadata,
+                updated_by,
+            },
         }
     }
 
@@ -1990,6 +1997,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_id,
                 updated_by,
             },
+            DieselPaymentAttemptUpdate::ConnectorMetadataUpdate {
+                connector_metadata,
+                updated_by,
+            } => Self::ConnectorMetadataUpdate {
+                connector_metadata,
+                updated_by,
+            },
         }
     }
 }
@@ -78,6 +78,9 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     profile_id: new_payout_attempt.profile_id.clone(),
                     merchant_connector_id: new_payout_attempt.merchant_connector_id.clone(),
                     routing_info: new_payout_attempt.routing_info.clone(),
+                    manual_routing_override: new_payout_attempt.manual_routing_override,
+                    routing_trail: new_payout_attempt.routing_trail.clone(),
+                    amount: new_payout_attempt.amount,
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -246,6 +249,68 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payout_attempts_by_merchant_id_payout_id(
+                        merchant_id,
+                        payout_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let key = PartitionKey::MerchantIdPayoutAttemptId {
+                    merchant_id,
+                    payout_attempt_id: payout_id,
+                };
+                let mut payout_attempts = Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        kv_wrapper(self, KvOperation::<DieselPayoutAttempt>::Scan("poa_*"), key)
+                            .await?
+                            .try_into_scan()
+                    },
+                    || async {
+                        self.router_store
+                            .find_payout_attempts_by_merchant_id_payout_id(
+                                merchant_id,
+                                payout_id,
+                                storage_scheme,
+                            )
+                            .await
+                    },
+                ))
+                .await?;
+                // Entries scanned from redis are not ordered
+                payout_attempts.sort_by_key(|payout_attempt| payout_attempt.created_at);
+                Ok(payout_attempts)
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        self.router_store
+            .find_payout_attempts_by_merchant_id_payout_attempt_ids(
+                merchant_id,
+                payout_attempt_ids,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -320,6 +385,54 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_all_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map(|payout_attempts| {
+                payout_attempts
+                    .into_iter()
+                    .map(PayoutAttempt::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_attempts_by_merchant_id_payout_attempt_ids(
+        &self,
+        merchant_id: &str,
+        payout_attempt_ids: &[String],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayoutAttempt::find_all_by_merchant_id_payout_attempt_ids(
+            &conn,
+            merchant_id,
+            payout_attempt_ids,
+        )
+        .await
+        .map(|payout_attempts| {
+            payout_attempts
+                .into_iter()
+                .map(PayoutAttempt::from_storage_model)
+                .collect()
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payouts(
         &self,
@@ -387,6 +500,9 @@ impl DataModelExt for PayoutAttempt {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            manual_routing_override: self.manual_routing_override,
+            routing_trail: self.routing_trail,
+            amount: self.amount,
         }
     }
 
@@ -411,6 +527,9 @@ impl DataModelExt for PayoutAttempt {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            manual_routing_override: storage_model.manual_routing_override,
+            routing_trail: storage_model.routing_trail,
+            amount: storage_model.amount,
         }
     }
 }
@@ -438,6 +557,9 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: self.profile_id,
             merchant_connector_id: self.merchant_connector_id,
             routing_info: self.routing_info,
+            manual_routing_override: self.manual_routing_override,
+            routing_trail: self.routing_trail,
+            amount: self.amount,
         }
     }
 
@@ -462,6 +584,9 @@ impl DataModelExt for PayoutAttemptNew {
             profile_id: storage_model.profile_id,
             merchant_connector_id: storage_model.merchant_connector_id,
             routing_info: storage_model.routing_info,
+            manual_routing_override: storage_model.manual_routing_override,
+            routing_trail: storage_model.routing_trail,
+            amount: storage_model.amount,
         }
     }
 }
@@ -495,10 +620,22 @@ impl DataModelExt for PayoutAttemptUpdate {
             Self::UpdateRouting {
                 connector,
                 routing_info,
+                routing_trail,
             } => DieselPayoutAttemptUpdate::UpdateRouting {
                 connector,
                 routing_info,
+                routing_trail,
+            },
+            Self::RoutingFailed {
+                error_code,
+                error_message,
+                routing_trail,
+            } => DieselPayoutAttemptUpdate::RoutingFailed {
+                error_code,
+                error_message,
+                routing_trail,
             },
+            Self::AmountUpdate { amount } => DieselPayoutAttemptUpdate::AmountUpdate { amount },
         }
     }
 
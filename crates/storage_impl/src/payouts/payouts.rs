@@ -2,7 +2,7 @@
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
 use common_utils::ext_traits::Encode;
 #[cfg(feature = "olap")]
-use data_models::payouts::PayoutFetchConstraints;
+use data_models::payouts::{PayoutFetchConstraints, PayoutsCursor};
 use data_models::{
     errors::StorageError,
     payouts::{
@@ -11,7 +11,10 @@ use data_models::{
     },
 };
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    PgJsonbExpressionMethods, QueryDsl,
+};
 #[cfg(feature = "olap")]
 use diesel_models::{
     customers::Customer as DieselCustomer,
@@ -43,6 +46,18 @@ use crate::{
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
+/// Returns the storage scheme of a payout. Payouts funded by a payment are always stored in the
+/// database, as they are looked up by the payment to validate its payouts and refunds
+fn get_payout_storage_scheme(
+    source_payment_id: Option<&str>,
+    storage_scheme: MerchantStorageScheme,
+) -> MerchantStorageScheme {
+    match source_payment_id {
+        Some(_) => MerchantStorageScheme::PostgresOnly,
+        None => storage_scheme,
+    }
+}
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
     #[instrument(skip_all)]
@@ -51,7 +66,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         new: PayoutsNew,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, StorageError> {
-        match storage_scheme {
+        match get_payout_storage_scheme(new.source_payment_id.as_deref(), storage_scheme) {
             MerchantStorageScheme::PostgresOnly => {
                 self.router_store.insert_payout(new, storage_scheme).await
             }
@@ -86,6 +101,13 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     profile_id: new.profile_id.clone(),
                     status: new.status,
                     attempt_count: new.attempt_count,
+                    scheduled_at: new.scheduled_at,
+                    fx_rate: new.fx_rate.clone(),
+                    fx_quote_expires_at: new.fx_quote_expires_at,
+                    priority: new.priority,
+                    fulfilled_amount: new.fulfilled_amount,
+                    payout_schedule_id: new.payout_schedule_id.clone(),
+                    source_payment_id: new.source_payment_id.clone(),
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -127,7 +149,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         payout_attempt: &PayoutAttempt,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, StorageError> {
-        match storage_scheme {
+        match get_payout_storage_scheme(this.source_payment_id.as_deref(), storage_scheme) {
             MerchantStorageScheme::PostgresOnly => {
                 self.router_store
                     .update_payout(this, payout_update, payout_attempt, storage_scheme)
@@ -177,6 +199,37 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        payout_update: PayoutsUpdate,
+        payout_attempt: &PayoutAttempt,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Payouts, StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .update_payout_by_merchant_id_payout_id(
+                        merchant_id,
+                        payout_id,
+                        payout_update,
+                        payout_attempt,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let payout = self
+                    .find_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+                    .await?;
+                self.update_payout(&payout, payout_update, payout_attempt, storage_scheme)
+                    .await
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn find_payout_by_merchant_id_payout_id(
         &self,
@@ -271,6 +324,40 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         .map(|payout| payout.map(Payouts::from_storage_model))
     }
 
+    // Payouts linked to a payment are always stored in the database, as the KV store only
+    // supports lookups by payout id
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        self.router_store
+            .find_payouts_by_merchant_id_source_payment_id(
+                merchant_id,
+                source_payment_id,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_total_payout_amount_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, StorageError> {
+        self.router_store
+            .get_total_payout_amount_by_merchant_id_source_payment_id(
+                merchant_id,
+                source_payment_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -310,6 +397,27 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
             .filter_payouts_by_time_range_constraints(merchant_id, time_range, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_time_range_after_cursor(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+        cursor: Option<&PayoutsCursor>,
+        limit: u32,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        self.router_store
+            .filter_payouts_by_time_range_after_cursor(
+                merchant_id,
+                time_range,
+                cursor,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -351,6 +459,30 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             .map(Payouts::from_storage_model)
     }
 
+    #[instrument(skip_all)]
+    async fn update_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+        payout: PayoutsUpdate,
+        _payout_attempt: &PayoutAttempt,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Payouts, StorageError> {
+        let conn = pg_connection_write(self).await?;
+        DieselPayouts::update_by_merchant_id_payout_id(
+            &conn,
+            merchant_id,
+            payout_id,
+            payout.to_storage_model(),
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(Payouts::from_storage_model)
+    }
+
     #[instrument(skip_all)]
     async fn find_payout_by_merchant_id_payout_id(
         &self,
@@ -385,6 +517,50 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::find_by_merchant_id_source_payment_id(&conn, merchant_id, source_payment_id)
+            .await
+            .map(|payouts| {
+                payouts
+                    .into_iter()
+                    .map(Payouts::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_total_payout_amount_by_merchant_id_source_payment_id(
+        &self,
+        merchant_id: &str,
+        source_payment_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<i64, StorageError> {
+        // Read from the primary, as the total is validated against right before creating a payout
+        // or a refund of the payment
+        let conn = pg_connection_write(self).await?;
+        DieselPayouts::get_total_amount_by_merchant_id_source_payment_id(
+            &conn,
+            merchant_id,
+            source_payment_id,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -476,6 +652,10 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
                 if let Some(status) = &params.status {
                     query = query.filter(po_dsl::status.eq_any(status.clone()));
                 }
+
+                if let Some(metadata) = params.get_metadata_filter() {
+                    query = query.filter(po_dsl::metadata.contains(metadata));
+                }
             }
         }
 
@@ -605,6 +785,11 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
                     None => query,
                 };
 
+                query = match params.get_metadata_filter() {
+                    Some(metadata) => query.filter(po_dsl::metadata.contains(metadata)),
+                    None => query,
+                };
+
                 query
             }
         };
@@ -647,6 +832,65 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         self.filter_payouts_by_constraints(merchant_id, &payout_filters, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_time_range_after_cursor(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+        cursor: Option<&PayoutsCursor>,
+        limit: u32,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        use common_utils::errors::ReportSwitchExt;
+
+        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
+
+        let mut query = <DieselPayouts as HasTable>::table()
+            .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(po_dsl::created_at.ge(time_range.start_time))
+            .order((po_dsl::created_at.asc(), po_dsl::payout_id.asc()))
+            .limit(limit.into())
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            query = query.filter(po_dsl::created_at.le(end_time));
+        }
+
+        // Keyset pagination, so that pages stay consistent while payouts are being created
+        if let Some(cursor) = cursor {
+            query = query.filter(
+                po_dsl::created_at
+                    .gt(cursor.created_at)
+                    .or(po_dsl::created_at
+                        .eq(cursor.created_at)
+                        .and(po_dsl::payout_id.gt(cursor.payout_id.clone()))),
+            );
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<DieselPayouts as HasTable>::Table, _, _>(
+            query.get_results_async::<DieselPayouts>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .map(|payouts| {
+            payouts
+                .into_iter()
+                .map(Payouts::from_storage_model)
+                .collect::<Vec<Payouts>>()
+        })
+        .map_err(|er| {
+            StorageError::DatabaseError(
+                error_stack::report!(diesel_models::errors::DatabaseError::from(er))
+                    .attach_printable("Error filtering payout records"),
+            )
+            .into()
+        })
+    }
 }
 
 impl DataModelExt for Payouts {
@@ -674,6 +918,13 @@ impl DataModelExt for Payouts {
             profile_id: self.profile_id,
             status: self.status,
             attempt_count: self.attempt_count,
+            scheduled_at: self.scheduled_at,
+            fx_rate: self.fx_rate,
+            fx_quote_expires_at: self.fx_quote_expires_at,
+            priority: self.priority,
+            fulfilled_amount: self.fulfilled_amount,
+            payout_schedule_id: self.payout_schedule_id,
+            source_payment_id: self.source_payment_id,
         }
     }
 
@@ -699,6 +950,13 @@ impl DataModelExt for Payouts {
             profile_id: storage_model.profile_id,
             status: storage_model.status,
             attempt_count: storage_model.attempt_count,
+            scheduled_at: storage_model.scheduled_at,
+            fx_rate: storage_model.fx_rate,
+            fx_quote_expires_at: storage_model.fx_quote_expires_at,
+            priority: storage_model.priority,
+            fulfilled_amount: storage_model.fulfilled_amount,
+            payout_schedule_id: storage_model.payout_schedule_id,
+            source_payment_id: storage_model.source_payment_id,
         }
     }
 }
@@ -727,6 +985,13 @@ impl DataModelExt for PayoutsNew {
             profile_id: self.profile_id,
             status: self.status,
             attempt_count: self.attempt_count,
+            scheduled_at: self.scheduled_at,
+            fx_rate: self.fx_rate,
+            fx_quote_expires_at: self.fx_quote_expires_at,
+            priority: self.priority,
+            fulfilled_amount: self.fulfilled_amount,
+            payout_schedule_id: self.payout_schedule_id,
+            source_payment_id: self.source_payment_id,
         }
     }
 
@@ -752,6 +1017,13 @@ impl DataModelExt for PayoutsNew {
             profile_id: storage_model.profile_id,
             status: storage_model.status,
             attempt_count: storage_model.attempt_count,
+            scheduled_at: storage_model.scheduled_at,
+            fx_rate: storage_model.fx_rate,
+            fx_quote_expires_at: storage_model.fx_quote_expires_at,
+            priority: storage_model.priority,
+            fulfilled_amount: storage_model.fulfilled_amount,
+            payout_schedule_id: storage_model.payout_schedule_id,
+            source_payment_id: storage_model.source_payment_id,
         }
     }
 }
@@ -794,6 +1066,27 @@ impl DataModelExt for PayoutsUpdate {
                 DieselPayoutsUpdate::AttemptCountUpdate { attempt_count }
             }
             Self::StatusUpdate { status } => DieselPayoutsUpdate::StatusUpdate { status },
+            Self::MetadataUpdate {
+                description,
+                metadata,
+            } => DieselPayoutsUpdate::MetadataUpdate {
+                description,
+                metadata,
+            },
+            Self::FxQuoteUpdate {
+                fx_rate,
+                fx_quote_expires_at,
+            } => DieselPayoutsUpdate::FxQuoteUpdate {
+                fx_rate,
+                fx_quote_expires_at,
+            },
+            Self::FulfillmentUpdate {
+                status,
+                fulfilled_amount,
+            } => DieselPayoutsUpdate::FulfillmentUpdate {
+                status,
+                fulfilled_amount,
+            },
         }
     }
 
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'scheduled';
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS scheduled_at TIMESTAMP;
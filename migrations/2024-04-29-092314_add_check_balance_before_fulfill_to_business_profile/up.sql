@@ -0,0 +1,3 @@
+-- Your SQL goes here
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS check_balance_before_fulfill BOOLEAN DEFAULT FALSE;
\ No newline at end of file
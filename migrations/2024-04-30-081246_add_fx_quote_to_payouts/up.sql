@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payouts
+ADD COLUMN IF NOT EXISTS fx_rate VARCHAR(32),
+ADD COLUMN IF NOT EXISTS fx_quote_expires_at TIMESTAMP;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payout_attempt
+ADD COLUMN IF NOT EXISTS routing_trail JSONB DEFAULT NULL;
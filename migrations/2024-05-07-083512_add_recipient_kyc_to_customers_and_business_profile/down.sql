@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers DROP COLUMN IF EXISTS kyc_status;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS require_recipient_kyc;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'requires_vendor_account_creation';
+
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS kyc_status VARCHAR(32);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS require_recipient_kyc BOOLEAN DEFAULT FALSE;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'partially_fulfilled';
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS fulfilled_amount BIGINT;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_audit_event (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    event_type VARCHAR(32) NOT NULL,
+    actor JSONB NOT NULL,
+    previous_status "PayoutStatus",
+    new_status "PayoutStatus" NOT NULL,
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_audit_event_merchant_id_payout_id_index ON payout_audit_event (merchant_id, payout_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts DROP COLUMN IF EXISTS payout_schedule_id;
+
+DROP INDEX IF EXISTS payout_schedule_merchant_id_schedule_id_index;
+
+DROP TABLE IF EXISTS payout_schedule;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_schedule (
+    schedule_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    payout_method_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    currency "Currency" NOT NULL,
+    payout_type "PayoutType",
+    cadence JSONB NOT NULL,
+    amount_source JSONB NOT NULL,
+    description VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    next_run_at TIMESTAMP,
+    last_run_at TIMESTAMP,
+    last_payout_id VARCHAR(64),
+    last_failure_reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payout_schedule_merchant_id_schedule_id_index ON payout_schedule (merchant_id, schedule_id);
+
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS payout_schedule_id VARCHAR(64);